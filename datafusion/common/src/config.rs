@@ -223,6 +223,11 @@ config_namespace! {
         /// according to this time zone, and then extract the hour
         pub time_zone: Option<String>, default = Some("+00:00".into())
 
+        /// How integer arithmetic (`+`, `-` and `*`) handles results that do not fit
+        /// into the result type. Valid values are `error` (return an error), `wrap`
+        /// (wrap around, using two's complement arithmetic) and `null` (return NULL)
+        pub arithmetic_overflow_mode: String, default = "wrap".to_string()
+
         /// Parquet options
         pub parquet: ParquetOptions, default = Default::default()
 
@@ -309,6 +314,12 @@ config_namespace! {
         /// the filters are applied in the same order as written in the query
         pub reorder_filters: bool, default = false
 
+        /// If true, the row groups of parquet files are distributed across up to
+        /// `target_partitions` partitions at planning time, so that the row groups
+        /// of a single file can be read in parallel. Row groups are pruned based on
+        /// their statistics before they are distributed
+        pub split_row_groups: bool, default = false
+
         // The following map to parquet::file::properties::WriterProperties
 
         /// Sets best effort maximum size of data page in bytes
@@ -415,6 +426,11 @@ config_namespace! {
         /// during aggregations, if possible
         pub enable_topk_aggregation: bool, default = true
 
+        /// When set to true, the physical plan optimizer will remove filter predicates
+        /// which the column statistics prove to be always true, and replace filters
+        /// whose predicate they prove to be always false with an empty relation
+        pub enable_redundant_predicate_elimination: bool, default = true
+
         /// When set to true, the optimizer will insert filters before a join between
         /// a nullable and non-nullable column to filter out nulls on the nullable side. This
         /// filter can add additional overhead when the file format does not fully support
@@ -514,6 +530,10 @@ config_namespace! {
         /// When set to true, the explain statement will print operator statistics
         /// for physical plans
         pub show_statistics: bool, default = false
+
+        /// When set to true, the explain statement will print, for each correlated
+        /// subquery, whether the optimizer was able to rewrite it into a join
+        pub show_subquery_decorrelation: bool, default = false
     }
 }
 
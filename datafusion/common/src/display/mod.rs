@@ -54,6 +54,9 @@ pub enum PlanType {
     },
     /// The final, fully optimized physical which would be executed
     FinalPhysicalPlan,
+    /// A summary of which correlated subqueries were rewritten into joins
+    /// by the optimizer
+    SubqueryDecorrelation,
 }
 
 impl Display for PlanType {
@@ -73,6 +76,7 @@ impl Display for PlanType {
                 write!(f, "physical_plan after {optimizer_name}")
             }
             PlanType::FinalPhysicalPlan => write!(f, "physical_plan"),
+            PlanType::SubqueryDecorrelation => write!(f, "subquery_decorrelation"),
         }
     }
 }
@@ -100,7 +104,9 @@ impl StringifiedPlan {
     /// `verbose_mode = true` will display all available plans
     pub fn should_display(&self, verbose_mode: bool) -> bool {
         match self.plan_type {
-            PlanType::FinalLogicalPlan | PlanType::FinalPhysicalPlan => true,
+            PlanType::FinalLogicalPlan
+            | PlanType::FinalPhysicalPlan
+            | PlanType::SubqueryDecorrelation => true,
             _ => verbose_mode,
         }
     }
@@ -17,6 +17,8 @@
 
 //! Options related to how Arrow files should be written
 
+use arrow::ipc::CompressionType;
+
 use crate::{
     config::ConfigOptions,
     error::{DataFusionError, Result},
@@ -24,13 +26,58 @@ use crate::{
 
 use super::StatementOptions;
 
-#[derive(Clone, Debug)]
-pub struct ArrowWriterOptions {}
+/// Options for writing Arrow IPC files
+#[derive(Clone, Debug, Default)]
+pub struct ArrowWriterOptions {
+    /// The compression of the record batches, if any. Arrow IPC supports
+    /// LZ4 (frame) and ZSTD compression.
+    pub compression: Option<CompressionType>,
+}
+
+impl ArrowWriterOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the compression of the written record batches
+    pub fn with_compression(mut self, compression: Option<CompressionType>) -> Self {
+        self.compression = compression;
+        self
+    }
+}
 
 impl TryFrom<(&ConfigOptions, &StatementOptions)> for ArrowWriterOptions {
     type Error = DataFusionError;
 
-    fn try_from(_value: (&ConfigOptions, &StatementOptions)) -> Result<Self> {
-        Ok(ArrowWriterOptions {})
+    fn try_from(value: (&ConfigOptions, &StatementOptions)) -> Result<Self> {
+        let _configs = value.0;
+        let statement_options = value.1;
+        let mut compression = None;
+        for (option, value) in &statement_options.options {
+            match option.to_lowercase().as_str() {
+                "compression" => {
+                    compression = parse_compression(value.replace('\'', "").as_str())?;
+                }
+                _ => {
+                    return Err(DataFusionError::Configuration(format!(
+                        "Found unsupported option {option} with value {value} for Arrow format!"
+                    )))
+                }
+            }
+        }
+        Ok(ArrowWriterOptions { compression })
+    }
+}
+
+/// Parses the compression of Arrow IPC files, `None` meaning uncompressed
+fn parse_compression(value: &str) -> Result<Option<CompressionType>> {
+    match value.to_lowercase().as_str() {
+        "uncompressed" | "none" => Ok(None),
+        "lz4" | "lz4_frame" => Ok(Some(CompressionType::LZ4_FRAME)),
+        "zstd" => Ok(Some(CompressionType::ZSTD)),
+        _ => Err(DataFusionError::Configuration(format!(
+            "Unsupported compression {value} for Arrow format, \
+             expected one of uncompressed, lz4 or zstd"
+        ))),
     }
 }
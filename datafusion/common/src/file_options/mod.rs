@@ -299,6 +299,7 @@ impl Display for FileTypeWriterOptions {
 mod tests {
     use std::collections::HashMap;
 
+    use arrow::ipc::CompressionType;
     use parquet::{
         basic::{Compression, Encoding, ZstdLevel},
         file::properties::{EnabledStatistics, WriterVersion},
@@ -307,7 +308,10 @@ mod tests {
 
     use crate::{
         config::ConfigOptions,
-        file_options::{csv_writer::CsvWriterOptions, json_writer::JsonWriterOptions},
+        file_options::{
+            arrow_writer::ArrowWriterOptions, csv_writer::CsvWriterOptions,
+            json_writer::JsonWriterOptions,
+        },
         parsers::CompressionTypeVariant,
     };
 
@@ -545,4 +549,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_writeroptions_arrow_from_statement_options() -> Result<()> {
+        let config = ConfigOptions::new();
+        let compression = |value: &str| -> Result<Option<CompressionType>> {
+            let mut option_map: HashMap<String, String> = HashMap::new();
+            option_map.insert("compression".to_owned(), value.to_owned());
+            let options = StatementOptions::from(&option_map);
+            Ok(ArrowWriterOptions::try_from((&config, &options))?.compression)
+        };
+        assert_eq!(compression("lz4")?, Some(CompressionType::LZ4_FRAME));
+        assert_eq!(compression("ZSTD")?, Some(CompressionType::ZSTD));
+        assert_eq!(compression("uncompressed")?, None);
+        assert!(compression("snappy").is_err());
+
+        let mut option_map: HashMap<String, String> = HashMap::new();
+        option_map.insert("row_group_size".to_owned(), "10".to_owned());
+        let options = StatementOptions::from(&option_map);
+        assert!(ArrowWriterOptions::try_from((&config, &options)).is_err());
+
+        Ok(())
+    }
 }
@@ -382,6 +382,21 @@ impl FunctionalDependencies {
         }
     }
 
+    /// Checks whether the columns at `indices` contain a determinant key that
+    /// occurs only once and cannot receive NULL values (i.e. a primary key).
+    /// When this holds, rows of the relation are already distinct with respect
+    /// to these columns.
+    pub fn contains_unique_key(&self, indices: &[usize]) -> bool {
+        self.deps.iter().any(|dep| {
+            dep.mode == Dependency::Single
+                && !dep.nullable
+                && dep
+                    .source_indices
+                    .iter()
+                    .all(|source| indices.contains(source))
+        })
+    }
+
     /// This function downgrades a functional dependency when nullability becomes
     /// a possibility:
     /// - If the dependency in question is UNIQUE (i.e. nullable), a new null value
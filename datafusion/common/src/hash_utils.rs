@@ -27,7 +27,8 @@ use arrow::{downcast_dictionary_array, downcast_primitive_array};
 use arrow_buffer::i256;
 
 use crate::cast::{
-    as_boolean_array, as_generic_binary_array, as_primitive_array, as_string_array,
+    as_boolean_array, as_generic_binary_array, as_map_array, as_primitive_array,
+    as_string_array, as_struct_array,
 };
 use crate::error::{DataFusionError, Result, _internal_err};
 
@@ -240,6 +241,47 @@ where
     Ok(())
 }
 
+fn hash_struct_array(
+    array: &StructArray,
+    random_state: &RandomState,
+    hashes_buffer: &mut [u64],
+) -> Result<()> {
+    let nulls = array.nulls();
+    let mut values_hashes = vec![0u64; array.len()];
+    create_hashes(array.columns(), random_state, &mut values_hashes)?;
+    for (i, (hash, values_hash)) in
+        hashes_buffer.iter_mut().zip(values_hashes).enumerate()
+    {
+        if nulls.map_or(true, |nulls| nulls.is_valid(i)) {
+            *hash = combine_hashes(*hash, values_hash);
+        }
+    }
+    Ok(())
+}
+
+/// Hashes the entries of each map independently of their order, so that maps
+/// with the same entries have the same hash.
+fn hash_map_array(
+    array: &MapArray,
+    random_state: &RandomState,
+    hashes_buffer: &mut [u64],
+) -> Result<()> {
+    let entries = array.entries();
+    let offsets = array.value_offsets();
+    let nulls = array.nulls();
+    let mut entries_hashes = vec![0u64; entries.len()];
+    create_hashes(entries.columns(), random_state, &mut entries_hashes)?;
+    for (i, (start, stop)) in offsets.iter().zip(offsets.iter().skip(1)).enumerate() {
+        if nulls.map_or(true, |nulls| nulls.is_valid(i)) {
+            let entries_hash = entries_hashes[start.as_usize()..stop.as_usize()]
+                .iter()
+                .fold(0u64, |acc, entry_hash| acc.wrapping_add(*entry_hash));
+            hashes_buffer[i] = combine_hashes(hashes_buffer[i], entries_hash);
+        }
+    }
+    Ok(())
+}
+
 /// Test version of `create_hashes` that produces the same value for
 /// all hashes (to test collisions)
 ///
@@ -335,6 +377,14 @@ pub fn create_hashes<'a>(
                 let array = as_large_list_array(array);
                 hash_list_array(array, random_state, hashes_buffer)?;
             }
+            DataType::Struct(_) => {
+                let array = as_struct_array(array)?;
+                hash_struct_array(array, random_state, hashes_buffer)?;
+            }
+            DataType::Map(_, _) => {
+                let array = as_map_array(array)?;
+                hash_map_array(array, random_state, hashes_buffer)?;
+            }
             _ => {
                 // This is internal because we should have caught this before.
                 return _internal_err!(
@@ -515,6 +565,62 @@ mod tests {
         assert_eq!(hashes[2], hashes[3]);
     }
 
+    #[test]
+    // Tests actual values of hashes, which are different if forcing collisions
+    #[cfg(not(feature = "force_hash_collisions"))]
+    fn create_hashes_for_struct_arrays() {
+        let a = Arc::new(Int32Array::from(vec![Some(1), Some(1), Some(2), Some(1)]));
+        let b = Arc::new(StringArray::from(vec!["x", "x", "x", "y"]));
+        let struct_array = Arc::new(StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, true)),
+                a as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, false)),
+                b as ArrayRef,
+            ),
+        ])) as ArrayRef;
+        let random_state = RandomState::with_seeds(0, 0, 0, 0);
+        let mut hashes = vec![0; struct_array.len()];
+        create_hashes(&[struct_array], &random_state, &mut hashes).unwrap();
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[3]);
+    }
+
+    #[test]
+    // Tests actual values of hashes, which are different if forcing collisions
+    #[cfg(not(feature = "force_hash_collisions"))]
+    fn create_hashes_for_map_arrays() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        // {a: 1, b: 2}
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        // {b: 2, a: 1}
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        // {a: 2, b: 1}
+        builder.keys().append_value("a");
+        builder.values().append_value(2);
+        builder.keys().append_value("b");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        let map_array = Arc::new(builder.finish()) as ArrayRef;
+        let random_state = RandomState::with_seeds(0, 0, 0, 0);
+        let mut hashes = vec![0; map_array.len()];
+        create_hashes(&[map_array], &random_state, &mut hashes).unwrap();
+        assert_eq!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+    }
+
     #[test]
     // Tests actual values of hashes, which are different if forcing collisions
     #[cfg(not(feature = "force_hash_collisions"))]
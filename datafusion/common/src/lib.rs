@@ -22,6 +22,8 @@ mod functional_dependencies;
 mod join_type;
 #[cfg(feature = "pyarrow")]
 mod pyarrow;
+#[cfg(feature = "serde")]
+mod scalar_serde;
 mod schema_reference;
 mod table_reference;
 mod unnest;
@@ -28,7 +28,7 @@ use crate::cast::{
     as_decimal128_array, as_decimal256_array, as_dictionary_array,
     as_fixed_size_binary_array, as_fixed_size_list_array, as_struct_array,
 };
-use crate::error::{DataFusionError, Result, _internal_err, _not_impl_err};
+use crate::error::{_internal_err, _not_impl_err, DataFusionError, Result};
 use crate::hash_utils::create_hashes;
 use crate::utils::wrap_into_list_array;
 use arrow::buffer::{NullBuffer, OffsetBuffer};
@@ -1071,6 +1071,15 @@ impl ScalarValue {
         Self::try_from_array(r.as_ref(), 0)
     }
 
+    /// Checked multiplication of `ScalarValue`
+    ///
+    /// NB: operating on `ScalarValue` directly is not efficient, performance sensitive code
+    /// should operate on Arrays directly, using vectorized array kernels
+    pub fn mul_checked<T: Borrow<ScalarValue>>(&self, other: T) -> Result<ScalarValue> {
+        let r = mul(&self.to_scalar(), &other.borrow().to_scalar())?;
+        Self::try_from_array(r.as_ref(), 0)
+    }
+
     pub fn is_unsigned(&self) -> bool {
         matches!(
             self,
@@ -1438,6 +1447,18 @@ impl ScalarValue {
             DataType::Interval(IntervalUnit::MonthDayNano) => {
                 build_array_primitive!(IntervalMonthDayNanoArray, IntervalMonthDayNano)
             }
+            DataType::Duration(TimeUnit::Second) => {
+                build_array_primitive!(DurationSecondArray, DurationSecond)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                build_array_primitive!(DurationMillisecondArray, DurationMillisecond)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                build_array_primitive!(DurationMicrosecondArray, DurationMicrosecond)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                build_array_primitive!(DurationNanosecondArray, DurationNanosecond)
+            }
             DataType::List(fields) if fields.data_type() == &DataType::Int8 => {
                 build_array_list_primitive!(Int8Type, Int8, i8)
             }
@@ -1589,7 +1610,6 @@ impl ScalarValue {
             | DataType::Time32(TimeUnit::Nanosecond)
             | DataType::Time64(TimeUnit::Second)
             | DataType::Time64(TimeUnit::Millisecond)
-            | DataType::Duration(_)
             | DataType::FixedSizeList(_, _)
             | DataType::LargeList(_)
             | DataType::Union(_, _)
@@ -3264,6 +3284,19 @@ mod tests {
         assert_eq!(list_array, &expected);
     }
 
+    #[test]
+    fn iter_to_array_duration_test() {
+        let scalars = vec![
+            ScalarValue::DurationMillisecond(Some(1)),
+            ScalarValue::DurationMillisecond(None),
+            ScalarValue::DurationMillisecond(Some(3)),
+        ];
+        let array = ScalarValue::iter_to_array(scalars).unwrap();
+        let expected: ArrayRef =
+            Arc::new(DurationMillisecondArray::from(vec![Some(1), None, Some(3)]));
+        assert_eq!(&array, &expected);
+    }
+
     #[test]
     fn iter_to_array_string_test() {
         let arr1 =
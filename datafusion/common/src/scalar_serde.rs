@@ -0,0 +1,239 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Serialization and deserialization of [`ScalarValue`]s with `serde`
+
+use std::sync::Arc;
+
+use arrow_buffer::i256;
+use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ScalarValue;
+
+/// The serialized form of a [`ScalarValue`]. Nested values, i.e. lists,
+/// structs and dictionaries, are not supported.
+#[derive(Serialize, Deserialize)]
+enum SerializedScalarValue {
+    Null,
+    Boolean(Option<bool>),
+    Float32(Option<f32>),
+    Float64(Option<f64>),
+    Decimal128(Option<i128>, u8, i8),
+    /// The little-endian bytes of the value
+    Decimal256(Option<[u8; 32]>, u8, i8),
+    Int8(Option<i8>),
+    Int16(Option<i16>),
+    Int32(Option<i32>),
+    Int64(Option<i64>),
+    UInt8(Option<u8>),
+    UInt16(Option<u16>),
+    UInt32(Option<u32>),
+    UInt64(Option<u64>),
+    Utf8(Option<String>),
+    LargeUtf8(Option<String>),
+    Binary(Option<Vec<u8>>),
+    FixedSizeBinary(i32, Option<Vec<u8>>),
+    LargeBinary(Option<Vec<u8>>),
+    Date32(Option<i32>),
+    Date64(Option<i64>),
+    Time32Second(Option<i32>),
+    Time32Millisecond(Option<i32>),
+    Time64Microsecond(Option<i64>),
+    Time64Nanosecond(Option<i64>),
+    TimestampSecond(Option<i64>, Option<String>),
+    TimestampMillisecond(Option<i64>, Option<String>),
+    TimestampMicrosecond(Option<i64>, Option<String>),
+    TimestampNanosecond(Option<i64>, Option<String>),
+    IntervalYearMonth(Option<i32>),
+    IntervalDayTime(Option<i64>),
+    IntervalMonthDayNano(Option<i128>),
+    DurationSecond(Option<i64>),
+    DurationMillisecond(Option<i64>),
+    DurationMicrosecond(Option<i64>),
+    DurationNanosecond(Option<i64>),
+}
+
+impl TryFrom<&ScalarValue> for SerializedScalarValue {
+    type Error = String;
+
+    fn try_from(value: &ScalarValue) -> Result<Self, Self::Error> {
+        let tz = |tz: &Option<Arc<str>>| tz.as_ref().map(|tz| tz.to_string());
+        Ok(match value {
+            ScalarValue::Null => Self::Null,
+            ScalarValue::Boolean(v) => Self::Boolean(*v),
+            ScalarValue::Float32(v) => Self::Float32(*v),
+            ScalarValue::Float64(v) => Self::Float64(*v),
+            ScalarValue::Decimal128(v, p, s) => Self::Decimal128(*v, *p, *s),
+            ScalarValue::Decimal256(v, p, s) => {
+                Self::Decimal256(v.map(|v| v.to_le_bytes()), *p, *s)
+            }
+            ScalarValue::Int8(v) => Self::Int8(*v),
+            ScalarValue::Int16(v) => Self::Int16(*v),
+            ScalarValue::Int32(v) => Self::Int32(*v),
+            ScalarValue::Int64(v) => Self::Int64(*v),
+            ScalarValue::UInt8(v) => Self::UInt8(*v),
+            ScalarValue::UInt16(v) => Self::UInt16(*v),
+            ScalarValue::UInt32(v) => Self::UInt32(*v),
+            ScalarValue::UInt64(v) => Self::UInt64(*v),
+            ScalarValue::Utf8(v) => Self::Utf8(v.clone()),
+            ScalarValue::LargeUtf8(v) => Self::LargeUtf8(v.clone()),
+            ScalarValue::Binary(v) => Self::Binary(v.clone()),
+            ScalarValue::FixedSizeBinary(size, v) => {
+                Self::FixedSizeBinary(*size, v.clone())
+            }
+            ScalarValue::LargeBinary(v) => Self::LargeBinary(v.clone()),
+            ScalarValue::Date32(v) => Self::Date32(*v),
+            ScalarValue::Date64(v) => Self::Date64(*v),
+            ScalarValue::Time32Second(v) => Self::Time32Second(*v),
+            ScalarValue::Time32Millisecond(v) => Self::Time32Millisecond(*v),
+            ScalarValue::Time64Microsecond(v) => Self::Time64Microsecond(*v),
+            ScalarValue::Time64Nanosecond(v) => Self::Time64Nanosecond(*v),
+            ScalarValue::TimestampSecond(v, z) => Self::TimestampSecond(*v, tz(z)),
+            ScalarValue::TimestampMillisecond(v, z) => {
+                Self::TimestampMillisecond(*v, tz(z))
+            }
+            ScalarValue::TimestampMicrosecond(v, z) => {
+                Self::TimestampMicrosecond(*v, tz(z))
+            }
+            ScalarValue::TimestampNanosecond(v, z) => {
+                Self::TimestampNanosecond(*v, tz(z))
+            }
+            ScalarValue::IntervalYearMonth(v) => Self::IntervalYearMonth(*v),
+            ScalarValue::IntervalDayTime(v) => Self::IntervalDayTime(*v),
+            ScalarValue::IntervalMonthDayNano(v) => Self::IntervalMonthDayNano(*v),
+            ScalarValue::DurationSecond(v) => Self::DurationSecond(*v),
+            ScalarValue::DurationMillisecond(v) => Self::DurationMillisecond(*v),
+            ScalarValue::DurationMicrosecond(v) => Self::DurationMicrosecond(*v),
+            ScalarValue::DurationNanosecond(v) => Self::DurationNanosecond(*v),
+            ScalarValue::Fixedsizelist(..)
+            | ScalarValue::List(_)
+            | ScalarValue::Struct(..)
+            | ScalarValue::Dictionary(..) => {
+                return Err(format!(
+                    "Serializing {:?} values is not supported",
+                    value.data_type()
+                ))
+            }
+        })
+    }
+}
+
+impl From<SerializedScalarValue> for ScalarValue {
+    fn from(value: SerializedScalarValue) -> Self {
+        let tz = |tz: Option<String>| -> Option<Arc<str>> { tz.map(Arc::from) };
+        type S = SerializedScalarValue;
+        match value {
+            S::Null => Self::Null,
+            S::Boolean(v) => Self::Boolean(v),
+            S::Float32(v) => Self::Float32(v),
+            S::Float64(v) => Self::Float64(v),
+            S::Decimal128(v, p, s) => Self::Decimal128(v, p, s),
+            S::Decimal256(v, p, s) => Self::Decimal256(v.map(i256::from_le_bytes), p, s),
+            S::Int8(v) => Self::Int8(v),
+            S::Int16(v) => Self::Int16(v),
+            S::Int32(v) => Self::Int32(v),
+            S::Int64(v) => Self::Int64(v),
+            S::UInt8(v) => Self::UInt8(v),
+            S::UInt16(v) => Self::UInt16(v),
+            S::UInt32(v) => Self::UInt32(v),
+            S::UInt64(v) => Self::UInt64(v),
+            S::Utf8(v) => Self::Utf8(v),
+            S::LargeUtf8(v) => Self::LargeUtf8(v),
+            S::Binary(v) => Self::Binary(v),
+            S::FixedSizeBinary(size, v) => Self::FixedSizeBinary(size, v),
+            S::LargeBinary(v) => Self::LargeBinary(v),
+            S::Date32(v) => Self::Date32(v),
+            S::Date64(v) => Self::Date64(v),
+            S::Time32Second(v) => Self::Time32Second(v),
+            S::Time32Millisecond(v) => Self::Time32Millisecond(v),
+            S::Time64Microsecond(v) => Self::Time64Microsecond(v),
+            S::Time64Nanosecond(v) => Self::Time64Nanosecond(v),
+            S::TimestampSecond(v, z) => Self::TimestampSecond(v, tz(z)),
+            S::TimestampMillisecond(v, z) => Self::TimestampMillisecond(v, tz(z)),
+            S::TimestampMicrosecond(v, z) => Self::TimestampMicrosecond(v, tz(z)),
+            S::TimestampNanosecond(v, z) => Self::TimestampNanosecond(v, tz(z)),
+            S::IntervalYearMonth(v) => Self::IntervalYearMonth(v),
+            S::IntervalDayTime(v) => Self::IntervalDayTime(v),
+            S::IntervalMonthDayNano(v) => Self::IntervalMonthDayNano(v),
+            S::DurationSecond(v) => Self::DurationSecond(v),
+            S::DurationMillisecond(v) => Self::DurationMillisecond(v),
+            S::DurationMicrosecond(v) => Self::DurationMicrosecond(v),
+            S::DurationNanosecond(v) => Self::DurationNanosecond(v),
+        }
+    }
+}
+
+/// Serializes scalar values, except for nested ones, i.e. lists, structs and
+/// dictionaries, for which an error is returned.
+impl Serialize for ScalarValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedScalarValue::try_from(self)
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScalarValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SerializedScalarValue::deserialize(deserializer).map(ScalarValue::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_schema::DataType;
+
+    fn round_trip(value: &ScalarValue) -> ScalarValue {
+        let serialized = serde_json::to_string(value).unwrap();
+        serde_json::from_str(&serialized).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = vec![
+            ScalarValue::Null,
+            ScalarValue::Boolean(Some(true)),
+            ScalarValue::Float64(Some(-1.5)),
+            ScalarValue::Float32(None),
+            ScalarValue::Decimal128(Some(12345), 10, 2),
+            ScalarValue::Decimal256(Some(i256::from_i128(-12345)), 40, 2),
+            ScalarValue::Int64(Some(i64::MIN)),
+            ScalarValue::UInt64(Some(u64::MAX)),
+            ScalarValue::Utf8(Some("abc".to_string())),
+            ScalarValue::Binary(Some(vec![1, 2, 3])),
+            ScalarValue::Date32(Some(19000)),
+            ScalarValue::TimestampNanosecond(Some(1), Some("+01:00".into())),
+            ScalarValue::TimestampSecond(None, None),
+            ScalarValue::IntervalMonthDayNano(Some(i128::MAX)),
+            ScalarValue::DurationMillisecond(Some(100)),
+        ];
+        for value in values {
+            assert_eq!(round_trip(&value), value);
+        }
+    }
+
+    #[test]
+    fn test_nested_unsupported() {
+        let list =
+            ScalarValue::new_list(&[ScalarValue::Int32(Some(1))], &DataType::Int32);
+        let value = ScalarValue::List(list);
+        let err = serde_json::to_string(&value).unwrap_err();
+        assert!(err.to_string().contains("not supported"));
+    }
+}
@@ -26,6 +26,7 @@ use arrow_schema::Schema;
 /// Represents a value with a degree of certainty. `Precision` is used to
 /// propagate information the precision of statistical values.
 #[derive(Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Precision<T: Debug + Clone + PartialEq + Eq + PartialOrd> {
     /// The exact value is known
     Exact(T),
@@ -249,6 +250,7 @@ impl Statistics {
                     max_value: cs.max_value.to_inexact(),
                     min_value: cs.min_value.to_inexact(),
                     distinct_count: cs.distinct_count.to_inexact(),
+                    histogram: cs.histogram,
                 })
                 .collect::<Vec<_>>(),
         }
@@ -274,6 +276,17 @@ pub struct ColumnStatistics {
     pub min_value: Precision<ScalarValue>,
     /// Number of distinct values
     pub distinct_count: Precision<usize>,
+    /// Histogram of the column values, if known. Each entry holds the upper
+    /// bound of a bucket and the cumulative number of values less than or
+    /// equal to that bound. Buckets are sorted by their upper bounds, and the
+    /// first bucket starts at the minimum value of the column. Both equi-width
+    /// and equi-depth histograms fit this representation. Estimations only
+    /// rely on the relative bucket counts, so operators scaling all row counts
+    /// uniformly may keep the histogram as is.
+    ///
+    /// Histograms are not part of the protobuf representation of statistics,
+    /// so they are dropped when a plan is serialized.
+    pub histogram: Option<Vec<(ScalarValue, u64)>>,
 }
 
 impl ColumnStatistics {
@@ -293,6 +306,7 @@ impl ColumnStatistics {
             max_value: Precision::Absent,
             min_value: Precision::Absent,
             distinct_count: Precision::Absent,
+            histogram: None,
         }
     }
 }
@@ -31,6 +31,13 @@
 /// If `preserve_nulls` is true (the default), nulls from the input
 /// column are carried through to the output.
 ///
+/// If `outer` is true, nulls and empty lists from the input column each
+/// produce one row with a null value, like an outer join. Defaults to false.
+///
+/// If `recursive` is true, a column containing nested lists (e.g. a list
+/// of lists) is unnested repeatedly until it no longer contains a list
+/// type. Defaults to false, i.e. only the outermost list is unnested.
+///
 /// # Examples
 ///
 /// ## `Unnest(c1)`, preserve_nulls: false
@@ -64,6 +71,12 @@
 pub struct UnnestOptions {
     /// Should nulls in the input be preserved? Defaults to true
     pub preserve_nulls: bool,
+    /// Should nulls and empty lists in the input produce a row with a null
+    /// value? Defaults to false
+    pub outer: bool,
+    /// Should nested lists be unnested until the column is no longer a
+    /// list? Defaults to false
+    pub recursive: bool,
 }
 
 impl Default for UnnestOptions {
@@ -71,6 +84,8 @@ impl Default for UnnestOptions {
         Self {
             // default to true to maintain backwards compatible behavior
             preserve_nulls: true,
+            outer: false,
+            recursive: false,
         }
     }
 }
@@ -87,4 +102,18 @@ impl UnnestOptions {
         self.preserve_nulls = preserve_nulls;
         self
     }
+
+    /// Set whether nulls and empty lists in the input produce a row as
+    /// described on [`Self`]
+    pub fn with_outer(mut self, outer: bool) -> Self {
+        self.outer = outer;
+        self
+    }
+
+    /// Set whether nested lists are unnested recursively as described on
+    /// [`Self`]
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
 }
@@ -18,6 +18,8 @@
 //! [`DataFrame`] API for building and executing query plans.
 
 use std::any::Any;
+use std::collections::HashSet;
+use std::fmt;
 use std::sync::Arc;
 
 use arrow::array::{Array, ArrayRef, Int64Array, StringArray};
@@ -25,6 +27,7 @@ use arrow::compute::{cast, concat};
 use arrow::csv::WriterBuilder;
 use arrow::datatypes::{DataType, Field};
 use async_trait::async_trait;
+use datafusion_common::file_options::arrow_writer::ArrowWriterOptions;
 use datafusion_common::file_options::csv_writer::CsvWriterOptions;
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
 use datafusion_common::file_options::parquet_writer::{
@@ -32,15 +35,17 @@ use datafusion_common::file_options::parquet_writer::{
 };
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::{
-    DataFusionError, FileType, FileTypeWriterOptions, SchemaError, UnnestOptions,
+    plan_err, DataFusionError, FileType, FileTypeWriterOptions, SchemaError,
+    UnnestOptions,
 };
 use datafusion_expr::dml::CopyOptions;
 use parquet::file::properties::WriterProperties;
 
-use datafusion_common::{Column, DFSchema, ScalarValue};
+use datafusion_common::{Column, DFSchema, DFSchemaRef, ScalarValue};
 use datafusion_expr::{
-    avg, count, is_null, max, median, min, stddev, utils::COUNT_STAR_EXPANSION,
-    TableProviderFilterPushDown, UNNAMED_TABLE,
+    avg, count, expr_rewriter::normalize_col, is_null, max, median, min, stddev,
+    utils::COUNT_STAR_EXPANSION, ExprSchemable, Extension, TableProviderFilterPushDown,
+    UserDefinedLogicalNodeCore, UNNAMED_TABLE,
 };
 
 use crate::arrow::datatypes::Schema;
@@ -244,6 +249,62 @@ impl DataFrame {
         Ok(DataFrame::new(self.session_state, plan))
     }
 
+    /// Expand each list element of the given columns to multiple rows.
+    ///
+    /// The columns are unnested one after another, so the output contains
+    /// the cross product of the list elements of each row rather than the
+    /// lists zipped together, like a chain of lateral `UNNEST` joins in SQL.
+    ///
+    /// See also [`Self::unnest_columns_with_options`].
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.sql("SELECT make_array(1, 2) AS a, make_array(3, 4) AS b").await?;
+    /// let df = df.unnest_columns(&["a", "b"])?;
+    /// assert_eq!(df.count().await?, 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unnest_columns(self, columns: &[&str]) -> Result<DataFrame> {
+        self.unnest_columns_with_options(columns, UnnestOptions::new())
+    }
+
+    /// Expand each list element of the given columns to multiple rows, with
+    /// behavior controlled by [`UnnestOptions`], such as whether nulls and
+    /// empty lists produce a row (`outer`) and whether nested lists are
+    /// unnested as well (`recursive`).
+    ///
+    /// Unlike [`Self::unnest_column_with_options`], this function returns an
+    /// error if any of the given columns is not a list.
+    pub fn unnest_columns_with_options(
+        self,
+        columns: &[&str],
+        options: UnnestOptions,
+    ) -> Result<DataFrame> {
+        let mut builder = LogicalPlanBuilder::from(self.plan);
+        for column in columns {
+            let field = builder.schema().field_with_unqualified_name(column)?;
+            if !matches!(
+                field.data_type(),
+                DataType::List(_)
+                    | DataType::FixedSizeList(_, _)
+                    | DataType::LargeList(_)
+            ) {
+                return plan_err!(
+                    "Cannot unnest column {column} of non-list type {}",
+                    field.data_type()
+                );
+            }
+            let column = field.qualified_column();
+            builder = builder.unnest_column_with_options(column, options.clone())?;
+        }
+        Ok(DataFrame::new(self.session_state, builder.build()?))
+    }
+
     /// Filter a DataFrame to only include rows that match the specified filter expression.
     ///
     /// ```
@@ -582,6 +643,52 @@ impl DataFrame {
         Ok(DataFrame::new(self.session_state, plan))
     }
 
+    /// Declare that the DataFrame is already sorted by the specified sorting
+    /// expressions, without sorting it.
+    ///
+    /// This is useful when the data is known to be sorted (e.g. because it
+    /// was written that way) but DataFusion can not infer the ordering from
+    /// the source. The optimizer then removes subsequent sorts on the same
+    /// keys. The declared ordering is not checked: declaring an ordering the
+    /// data does not have produces incorrect results.
+    ///
+    /// The ordering applies to each partition of the DataFrame individually,
+    /// so sorting a multi-partition DataFrame still merges its partitions.
+    ///
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example.csv", CsvReadOptions::new()).await?;
+    /// let df = df.with_order(vec![col("a").sort(true, true)])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_order(self, expr: Vec<Expr>) -> Result<DataFrame> {
+        let expr = expr
+            .into_iter()
+            .map(|e| match e {
+                Expr::Sort(_) => {
+                    let e = normalize_col(e, &self.plan)?;
+                    // Make sure the expression can be resolved against the input
+                    e.get_type(self.plan.schema())?;
+                    Ok(e)
+                }
+                _ => plan_err!("with_order expects sort expressions, got {e}"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let node = DeclaredOrder {
+            input: Arc::new(self.plan),
+            expr,
+        };
+        let plan = LogicalPlan::Extension(Extension {
+            node: Arc::new(node),
+        });
+        Ok(DataFrame::new(self.session_state, plan))
+    }
+
     /// Join this `DataFrame` with another `DataFrame` using explicitly specified
     /// columns and an optional filter expression.
     ///
@@ -1126,6 +1233,37 @@ impl DataFrame {
         DataFrame::new(self.session_state, plan).collect().await
     }
 
+    /// Executes a query and writes the results to a partitioned Arrow IPC file.
+    /// The compression of the written record batches is set via `writer_options`.
+    pub async fn write_arrow(
+        self,
+        path: &str,
+        options: DataFrameWriteOptions,
+        writer_options: Option<ArrowWriterOptions>,
+    ) -> Result<Vec<RecordBatch>, DataFusionError> {
+        if options.overwrite {
+            return Err(DataFusionError::NotImplemented(
+                "Overwrites are not implemented for DataFrame::write_arrow.".to_owned(),
+            ));
+        }
+        match options.compression{
+            CompressionTypeVariant::UNCOMPRESSED => (),
+            _ => return Err(DataFusionError::Configuration("DataFrame::write_arrow method does not support compression set via DataFrameWriteOptions. Set Arrow IPC compression via writer_options instead.".to_owned()))
+        }
+        let file_type_writer_options =
+            FileTypeWriterOptions::Arrow(writer_options.unwrap_or_default());
+        let copy_options = CopyOptions::WriterOptions(Box::new(file_type_writer_options));
+        let plan = LogicalPlanBuilder::copy_to(
+            self.plan,
+            path.into(),
+            FileType::ARROW,
+            options.single_file_output,
+            copy_options,
+        )?
+        .build()?;
+        DataFrame::new(self.session_state, plan).collect().await
+    }
+
     /// Add an additional column to the DataFrame.
     ///
     /// ```
@@ -1294,6 +1432,62 @@ impl DataFrame {
     }
 }
 
+/// Logical node of [`DataFrame::with_order`] declaring that `input` is
+/// sorted by `expr`.
+///
+/// It is planned as a [`DeclaredOrderExec`], or elided if the physical input
+/// already provides the ordering.
+///
+/// [`DeclaredOrderExec`]: crate::physical_plan::sorts::declared_order::DeclaredOrderExec
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct DeclaredOrder {
+    /// The input plan
+    pub(crate) input: Arc<LogicalPlan>,
+    /// The sort expressions the input is declared to be ordered by
+    pub(crate) expr: Vec<Expr>,
+}
+
+impl UserDefinedLogicalNodeCore for DeclaredOrder {
+    fn name(&self) -> &str {
+        "DeclaredOrder"
+    }
+
+    fn inputs(&self) -> Vec<&LogicalPlan> {
+        vec![&self.input]
+    }
+
+    fn schema(&self) -> &DFSchemaRef {
+        self.input.schema()
+    }
+
+    fn expressions(&self) -> Vec<Expr> {
+        self.expr.clone()
+    }
+
+    /// Filtering does not change the order of the remaining rows
+    fn prevent_predicate_push_down_columns(&self) -> HashSet<String> {
+        HashSet::new()
+    }
+
+    fn fmt_for_explain(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DeclaredOrder: ")?;
+        for (i, e) in self.expr.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{e}")?;
+        }
+        Ok(())
+    }
+
+    fn from_template(&self, exprs: &[Expr], inputs: &[LogicalPlan]) -> Self {
+        Self {
+            input: Arc::new(inputs[0].clone()),
+            expr: exprs.to_vec(),
+        }
+    }
+}
+
 struct DataFrameTableProvider {
     plan: LogicalPlan,
 }
@@ -1358,6 +1552,7 @@ mod tests {
 
     use arrow::array::Int32Array;
     use arrow::datatypes::DataType;
+    use arrow::ipc::CompressionType;
 
     use datafusion_expr::{
         avg, cast, count, count_distinct, create_udf, expr, lit, max, min, sum,
@@ -1372,13 +1567,18 @@ mod tests {
     use url::Url;
 
     use crate::execution::context::SessionConfig;
-    use crate::execution::options::{CsvReadOptions, ParquetReadOptions};
+    use crate::execution::options::{
+        ArrowReadOptions, CsvReadOptions, ParquetReadOptions,
+    };
+    use crate::physical_plan::displayable;
     use crate::physical_plan::ColumnarValue;
     use crate::physical_plan::Partitioning;
     use crate::physical_plan::PhysicalExpr;
     use crate::test_util;
     use crate::test_util::parquet_test_data;
-    use crate::{assert_batches_sorted_eq, execution::context::SessionContext};
+    use crate::{
+        assert_batches_eq, assert_batches_sorted_eq, execution::context::SessionContext,
+    };
 
     use super::*;
 
@@ -1554,6 +1754,104 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn with_order() -> Result<()> {
+        let ctx = SessionContext::new();
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            (
+                "b",
+                Arc::new(Int32Array::from(vec![30, 10, 20])) as ArrayRef,
+            ),
+        ])?;
+        let df = ctx
+            .read_batch(batch)?
+            .with_order(vec![col("a").sort(true, false)])?
+            .sort(vec![col("a").sort(true, false)])?;
+
+        // The declared ordering satisfies the ORDER BY, so no sort is executed
+        let physical_plan = df.clone().create_physical_plan().await?;
+        let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+        assert_eq!(
+            formatted.trim().lines().collect::<Vec<_>>(),
+            vec![
+                "DeclaredOrderExec: expr=[a@0 ASC NULLS LAST]",
+                "  MemoryExec: partitions=1, partition_sizes=[1]",
+            ]
+        );
+
+        let results = df.collect().await?;
+        #[rustfmt::skip]
+        assert_batches_eq!(
+            ["+---+----+",
+             "| a | b  |",
+             "+---+----+",
+             "| 1 | 30 |",
+             "| 2 | 10 |",
+             "| 3 | 20 |",
+             "+---+----+"],
+            &results
+        );
+
+        // Sorting on other keys still sorts
+        let physical_plan = ctx
+            .read_batch(results[0].clone())?
+            .with_order(vec![col("a").sort(true, false)])?
+            .sort(vec![col("b").sort(true, false)])?
+            .create_physical_plan()
+            .await?;
+        let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+        assert!(formatted.starts_with("SortExec: expr=[b@1 ASC NULLS LAST]"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_order_over_sorted_input() -> Result<()> {
+        let ctx = SessionContext::new();
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int32Array::from(vec![3, 1, 2])) as ArrayRef,
+        )])?;
+        // The input already provides the ordering, so no marker is planned
+        let physical_plan = ctx
+            .read_batch(batch)?
+            .sort(vec![col("a").sort(false, true)])?
+            .with_order(vec![col("a").sort(false, true)])?
+            .create_physical_plan()
+            .await?;
+        let formatted = displayable(physical_plan.as_ref()).indent(true).to_string();
+        assert_eq!(
+            formatted.trim().lines().collect::<Vec<_>>(),
+            vec![
+                "SortExec: expr=[a@0 DESC]",
+                "  MemoryExec: partitions=1, partition_sizes=[1]",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_order_invalid_expr() -> Result<()> {
+        let ctx = SessionContext::new();
+        let batch = RecordBatch::try_from_iter(vec![(
+            "a",
+            Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+        )])?;
+        let df = ctx.read_batch(batch)?;
+
+        let err = df.clone().with_order(vec![col("a")]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: with_order expects sort expressions, got a"
+        );
+        let err = df.with_order(vec![col("z").sort(true, true)]);
+        assert!(err.is_err());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn join() -> Result<()> {
         let left = test_table().await?.select_columns(&["c1", "c2"])?;
@@ -2587,4 +2885,48 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn write_arrow_with_compression() -> Result<()> {
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            (
+                "b",
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as _,
+            ),
+        ])?;
+
+        let output_path = "file://local/test.arrow";
+        let test_compressions = vec![
+            None,
+            Some(CompressionType::LZ4_FRAME),
+            Some(CompressionType::ZSTD),
+        ];
+        for compression in test_compressions.into_iter() {
+            let ctx = SessionContext::new();
+            let tmp_dir = TempDir::new()?;
+            let local = Arc::new(LocalFileSystem::new_with_prefix(&tmp_dir)?);
+            let local_url = Url::parse("file://local").unwrap();
+            ctx.runtime_env().register_object_store(&local_url, local);
+
+            ctx.read_batch(batch.clone())?
+                .write_arrow(
+                    output_path,
+                    DataFrameWriteOptions::new().with_single_file_output(true),
+                    Some(ArrowWriterOptions::new().with_compression(compression)),
+                )
+                .await?;
+
+            // Read the file back with the Arrow file format
+            let written = tmp_dir.path().join("test.arrow");
+            let read = ctx
+                .read_arrow(written.to_str().unwrap(), ArrowReadOptions::default())
+                .await?
+                .collect()
+                .await?;
+            assert_eq!(read, vec![batch.clone()]);
+        }
+
+        Ok(())
+    }
 }
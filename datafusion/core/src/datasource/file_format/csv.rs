@@ -18,13 +18,13 @@
 //! CSV format abstractions
 
 use std::any::Any;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::Debug;
 use std::sync::Arc;
 
 use arrow_array::RecordBatch;
-use datafusion_common::{exec_err, not_impl_err, DataFusionError, FileType};
+use datafusion_common::{exec_err, not_impl_err, DataFusionError, FileType, ScalarValue};
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::{PhysicalExpr, PhysicalSortRequirement};
 
@@ -39,7 +39,7 @@ use super::{FileFormat, DEFAULT_SCHEMA_INFER_MAX_RECORD};
 use crate::datasource::file_format::file_compression_type::FileCompressionType;
 use crate::datasource::file_format::write::{BatchSerializer, FileWriterMode};
 use crate::datasource::physical_plan::{
-    CsvExec, FileGroupDisplay, FileScanConfig, FileSinkConfig,
+    resolve_column_defaults, CsvExec, FileGroupDisplay, FileScanConfig, FileSinkConfig,
 };
 use crate::error::Result;
 use crate::execution::context::SessionState;
@@ -62,6 +62,7 @@ pub struct CsvFormat {
     escape: Option<u8>,
     schema_infer_max_rec: Option<usize>,
     file_compression_type: FileCompressionType,
+    column_defaults: HashMap<String, ScalarValue>,
 }
 
 impl Default for CsvFormat {
@@ -73,6 +74,7 @@ impl Default for CsvFormat {
             quote: b'"',
             escape: None,
             file_compression_type: FileCompressionType::UNCOMPRESSED,
+            column_defaults: HashMap::new(),
         }
     }
 }
@@ -184,6 +186,17 @@ impl CsvFormat {
         self
     }
 
+    /// Set the values replacing the NULL (i.e. empty) values of the given
+    /// columns when reading
+    /// - defaults to no replacement
+    pub fn with_column_defaults(
+        mut self,
+        column_defaults: HashMap<String, ScalarValue>,
+    ) -> Self {
+        self.column_defaults = column_defaults;
+        self
+    }
+
     /// The delimiter character.
     pub fn delimiter(&self) -> u8 {
         self.delimiter
@@ -198,6 +211,11 @@ impl CsvFormat {
     pub fn escape(&self) -> Option<u8> {
         self.escape
     }
+
+    /// The defaults replacing the NULL values of their columns.
+    pub fn column_defaults(&self) -> &HashMap<String, ScalarValue> {
+        &self.column_defaults
+    }
 }
 
 #[async_trait]
@@ -248,6 +266,8 @@ impl FileFormat for CsvFormat {
         conf: FileScanConfig,
         _filters: Option<&Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
+        let column_defaults =
+            resolve_column_defaults(&conf.file_schema, &self.column_defaults)?;
         let exec = CsvExec::new(
             conf,
             self.has_header,
@@ -255,7 +275,8 @@ impl FileFormat for CsvFormat {
             self.quote,
             self.escape,
             self.file_compression_type.to_owned(),
-        );
+        )
+        .with_column_defaults(column_defaults);
         Ok(Arc::new(exec))
     }
 
@@ -18,6 +18,7 @@
 //! Line delimited JSON format abstractions
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::io::BufReader;
@@ -50,11 +51,13 @@ use super::write::orchestration::{stateless_append_all, stateless_multipart_put}
 use crate::datasource::file_format::file_compression_type::FileCompressionType;
 use crate::datasource::file_format::write::{BatchSerializer, FileWriterMode};
 use crate::datasource::file_format::DEFAULT_SCHEMA_INFER_MAX_RECORD;
-use crate::datasource::physical_plan::{FileSinkConfig, NdJsonExec};
+use crate::datasource::physical_plan::{
+    resolve_column_defaults, FileSinkConfig, NdJsonExec,
+};
 use crate::error::Result;
 use crate::execution::context::SessionState;
 
-use datafusion_common::{not_impl_err, DataFusionError, FileType};
+use datafusion_common::{not_impl_err, DataFusionError, FileType, ScalarValue};
 use datafusion_execution::TaskContext;
 use datafusion_physical_plan::metrics::MetricsSet;
 
@@ -63,6 +66,7 @@ use datafusion_physical_plan::metrics::MetricsSet;
 pub struct JsonFormat {
     schema_infer_max_rec: Option<usize>,
     file_compression_type: FileCompressionType,
+    column_defaults: HashMap<String, ScalarValue>,
 }
 
 impl Default for JsonFormat {
@@ -70,6 +74,7 @@ impl Default for JsonFormat {
         Self {
             schema_infer_max_rec: Some(DEFAULT_SCHEMA_INFER_MAX_RECORD),
             file_compression_type: FileCompressionType::UNCOMPRESSED,
+            column_defaults: HashMap::new(),
         }
     }
 }
@@ -91,6 +96,22 @@ impl JsonFormat {
         self.file_compression_type = file_compression_type;
         self
     }
+
+    /// Set the values replacing the NULL (i.e. absent) values of the given
+    /// columns when reading
+    /// - defaults to no replacement
+    pub fn with_column_defaults(
+        mut self,
+        column_defaults: HashMap<String, ScalarValue>,
+    ) -> Self {
+        self.column_defaults = column_defaults;
+        self
+    }
+
+    /// The defaults replacing the NULL values of their columns.
+    pub fn column_defaults(&self) -> &HashMap<String, ScalarValue> {
+        &self.column_defaults
+    }
 }
 
 #[async_trait]
@@ -160,7 +181,10 @@ impl FileFormat for JsonFormat {
         conf: FileScanConfig,
         _filters: Option<&Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        let exec = NdJsonExec::new(conf, self.file_compression_type.to_owned());
+        let column_defaults =
+            resolve_column_defaults(&conf.file_schema, &self.column_defaults)?;
+        let exec = NdJsonExec::new(conf, self.file_compression_type.to_owned())
+            .with_column_defaults(column_defaults);
         Ok(Arc::new(exec))
     }
 
@@ -17,11 +17,12 @@
 
 //! User facing options for the file formats readers
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow::datatypes::{DataType, Schema, SchemaRef};
 use async_trait::async_trait;
-use datafusion_common::{plan_err, DataFusionError};
+use datafusion_common::{plan_err, DataFusionError, ScalarValue};
 
 use crate::datasource::file_format::arrow::ArrowFormat;
 use crate::datasource::file_format::file_compression_type::FileCompressionType;
@@ -78,6 +79,10 @@ pub struct CsvReadOptions<'a> {
     pub file_sort_order: Vec<Vec<Expr>>,
     /// Setting controls how inserts to this file should be handled
     pub insert_mode: ListingTableInsertMode,
+    /// Values replacing the NULL values of the given columns. CSV does not
+    /// tell empty fields and NULL values apart, so the defaults replace both,
+    /// while rows missing fields are still rejected. Defaults to none.
+    pub column_defaults: HashMap<String, ScalarValue>,
 }
 
 impl<'a> Default for CsvReadOptions<'a> {
@@ -102,6 +107,7 @@ impl<'a> CsvReadOptions<'a> {
             infinite: false,
             file_sort_order: vec![],
             insert_mode: ListingTableInsertMode::AppendToFile,
+            column_defaults: HashMap::new(),
         }
     }
 
@@ -190,6 +196,14 @@ impl<'a> CsvReadOptions<'a> {
         self.insert_mode = insert_mode;
         self
     }
+    /// Specify values replacing the NULL (i.e. empty) values of the given columns
+    pub fn column_defaults(
+        mut self,
+        column_defaults: HashMap<String, ScalarValue>,
+    ) -> Self {
+        self.column_defaults = column_defaults;
+        self
+    }
 }
 
 /// Options that control the reading of Parquet files.
@@ -405,6 +419,9 @@ pub struct NdJsonReadOptions<'a> {
     pub file_sort_order: Vec<Vec<Expr>>,
     /// Setting controls how inserts to this file should be handled
     pub insert_mode: ListingTableInsertMode,
+    /// Values replacing the NULL values of the given columns, e.g. the
+    /// values of columns missing from some rows. Defaults to none.
+    pub column_defaults: HashMap<String, ScalarValue>,
 }
 
 impl<'a> Default for NdJsonReadOptions<'a> {
@@ -418,6 +435,7 @@ impl<'a> Default for NdJsonReadOptions<'a> {
             infinite: false,
             file_sort_order: vec![],
             insert_mode: ListingTableInsertMode::AppendToFile,
+            column_defaults: HashMap::new(),
         }
     }
 }
@@ -470,6 +488,14 @@ impl<'a> NdJsonReadOptions<'a> {
         self.insert_mode = insert_mode;
         self
     }
+    /// Specify values replacing the NULL values of the given columns
+    pub fn column_defaults(
+        mut self,
+        column_defaults: HashMap<String, ScalarValue>,
+    ) -> Self {
+        self.column_defaults = column_defaults;
+        self
+    }
 }
 
 #[async_trait]
@@ -520,7 +546,8 @@ impl ReadOptions<'_> for CsvReadOptions<'_> {
             .with_quote(self.quote)
             .with_escape(self.escape)
             .with_schema_infer_max_rec(Some(self.schema_infer_max_records))
-            .with_file_compression_type(self.file_compression_type.to_owned());
+            .with_file_compression_type(self.file_compression_type.to_owned())
+            .with_column_defaults(self.column_defaults.clone());
 
         ListingOptions::new(Arc::new(file_format))
             .with_file_extension(self.file_extension)
@@ -573,7 +600,8 @@ impl ReadOptions<'_> for NdJsonReadOptions<'_> {
     fn to_listing_options(&self, config: &SessionConfig) -> ListingOptions {
         let file_format = JsonFormat::default()
             .with_schema_infer_max_rec(Some(self.schema_infer_max_records))
-            .with_file_compression_type(self.file_compression_type.to_owned());
+            .with_file_compression_type(self.file_compression_type.to_owned())
+            .with_column_defaults(self.column_defaults.clone());
 
         ListingOptions::new(Arc::new(file_format))
             .with_file_extension(self.file_extension)
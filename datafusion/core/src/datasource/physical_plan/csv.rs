@@ -18,6 +18,7 @@
 //! Execution plan for reading CSV files
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::io::{Read, Seek, SeekFrom};
 use std::ops::Range;
 use std::sync::Arc;
@@ -29,7 +30,7 @@ use crate::datasource::listing::{FileRange, ListingTableUrl};
 use crate::datasource::physical_plan::file_stream::{
     FileOpenFuture, FileOpener, FileStream,
 };
-use crate::datasource::physical_plan::FileMeta;
+use crate::datasource::physical_plan::{fill_column_defaults, FileMeta};
 use crate::error::{DataFusionError, Result};
 use crate::physical_plan::expressions::PhysicalSortExpr;
 use crate::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
@@ -40,13 +41,14 @@ use crate::physical_plan::{
 
 use arrow::csv;
 use arrow::datatypes::SchemaRef;
+use datafusion_common::ScalarValue;
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::{
     ordering_equivalence_properties_helper, LexOrdering, OrderingEquivalenceProperties,
 };
 
 use bytes::{Buf, Bytes};
-use futures::{ready, StreamExt, TryStreamExt};
+use futures::{ready, StreamExt, TryFutureExt, TryStreamExt};
 use object_store::{GetOptions, GetResultPayload, ObjectStore};
 use tokio::io::AsyncWriteExt;
 use tokio::task::JoinSet;
@@ -62,6 +64,8 @@ pub struct CsvExec {
     delimiter: u8,
     quote: u8,
     escape: Option<u8>,
+    /// Values replacing the NULL (i.e. empty) values of the given columns
+    column_defaults: HashMap<String, ScalarValue>,
     /// Execution metrics
     metrics: ExecutionPlanMetricsSet,
     /// Compression type of the file associated with CsvExec
@@ -90,11 +94,26 @@ impl CsvExec {
             delimiter,
             quote,
             escape,
+            column_defaults: HashMap::new(),
             metrics: ExecutionPlanMetricsSet::new(),
             file_compression_type,
         }
     }
 
+    /// Replaces the NULL (i.e. empty) values of the given columns with the
+    /// given defaults, which must have the types of their columns.
+    ///
+    /// CSV does not tell empty fields and NULL values apart, so all of them
+    /// are replaced. Rows missing fields are rejected by the reader rather
+    /// than filled with the defaults.
+    pub fn with_column_defaults(
+        mut self,
+        column_defaults: HashMap<String, ScalarValue>,
+    ) -> Self {
+        self.column_defaults = column_defaults;
+        self
+    }
+
     /// Ref to the base configs
     pub fn base_config(&self) -> &FileScanConfig {
         &self.base_config
@@ -118,6 +137,11 @@ impl CsvExec {
         self.escape
     }
 
+    /// The defaults replacing the NULL values of their columns
+    pub fn column_defaults(&self) -> &HashMap<String, ScalarValue> {
+        &self.column_defaults
+    }
+
     /// Redistribute files across partitions according to their size
     /// See comments on `repartition_file_groups()` for more detail.
     ///
@@ -222,6 +246,7 @@ impl ExecutionPlan for CsvExec {
             delimiter: self.delimiter,
             quote: self.quote,
             escape: self.escape,
+            column_defaults: Arc::new(self.column_defaults.clone()),
             object_store,
         });
 
@@ -253,6 +278,7 @@ pub struct CsvConfig {
     delimiter: u8,
     quote: u8,
     escape: Option<u8>,
+    column_defaults: Arc<HashMap<String, ScalarValue>>,
     object_store: Arc<dyn ObjectStore>,
 }
 
@@ -275,9 +301,24 @@ impl CsvConfig {
             delimiter,
             quote,
             escape: None,
+            column_defaults: Arc::new(HashMap::new()),
             object_store,
         }
     }
+
+    /// Replaces the NULL (i.e. empty) values of the given columns with the
+    /// given defaults, which must have the types of their columns.
+    ///
+    /// CSV does not tell empty fields and NULL values apart, so all of them
+    /// are replaced. Rows missing fields are rejected by the reader rather
+    /// than filled with the defaults.
+    pub fn with_column_defaults(
+        mut self,
+        column_defaults: HashMap<String, ScalarValue>,
+    ) -> Self {
+        self.column_defaults = Arc::new(column_defaults);
+        self
+    }
 }
 
 impl CsvConfig {
@@ -404,6 +445,7 @@ impl FileOpener for CsvOpener {
         };
 
         let file_compression_type = self.file_compression_type.to_owned();
+        let column_defaults = Arc::clone(&config.column_defaults);
 
         if file_meta.range.is_some() {
             assert!(
@@ -412,7 +454,7 @@ impl FileOpener for CsvOpener {
             );
         }
 
-        Ok(Box::pin(async move {
+        let future = async move {
             let file_size = file_meta.object_meta.size;
             // Current partition contains bytes [start_byte, end_byte) (might contain incomplete lines at boundaries)
             let range = match file_meta.range {
@@ -510,7 +552,10 @@ impl FileOpener for CsvOpener {
                     Ok(s.boxed())
                 }
             }
-        }))
+        };
+        Ok(Box::pin(future.map_ok(move |stream| {
+            fill_column_defaults(stream, column_defaults, true)
+        })))
     }
 }
 
@@ -1052,6 +1097,100 @@ mod tests {
         crate::assert_batches_eq!(expected, &result);
     }
 
+    #[tokio::test]
+    async fn csv_exec_with_column_defaults() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let store = object_store::memory::InMemory::new();
+
+        // Empty fields are read as NULL, which the defaults replace
+        let data = bytes::Bytes::from("a,b,c\n1,x,10\n2,,\n3,z,");
+        let path = object_store::path::Path::from("a.csv");
+        store.put(&path, data).await.unwrap();
+        // Rows missing fields are rejected by the reader
+        let data = bytes::Bytes::from("a,b,c\n1,x,10\n2,y");
+        let path = object_store::path::Path::from("ragged/a.csv");
+        store.put(&path, data).await.unwrap();
+
+        let url = Url::parse("memory://").unwrap();
+        session_ctx
+            .runtime_env()
+            .register_object_store(&url, Arc::new(store));
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Int64, true),
+        ]);
+        // The defaults are cast to the types of their columns
+        let column_defaults = HashMap::from([
+            ("b".to_string(), ScalarValue::from("y")),
+            ("c".to_string(), ScalarValue::Int32(Some(0))),
+        ]);
+        let options = CsvReadOptions::new()
+            .schema(&schema)
+            .column_defaults(column_defaults);
+        let result = session_ctx
+            .read_csv("memory:///a.csv", options.clone())
+            .await?
+            .collect()
+            .await?;
+
+        let expected = [
+            "+---+---+----+",
+            "| a | b | c  |",
+            "+---+---+----+",
+            "| 1 | x | 10 |",
+            "| 2 | y | 0  |",
+            "| 3 | z | 0  |",
+            "+---+---+----+",
+        ];
+        crate::assert_batches_eq!(expected, &result);
+
+        let err = session_ctx
+            .read_csv("memory:///ragged/", options)
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("incorrect number of fields"),
+            "{err}"
+        );
+
+        let options = CsvReadOptions::new()
+            .schema(&schema)
+            .column_defaults(HashMap::from([("d".to_string(), ScalarValue::from(1))]));
+        let err = session_ctx
+            .read_csv("memory:///a.csv", options)
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Default value given for unknown column d"
+        );
+
+        let options =
+            CsvReadOptions::new()
+                .schema(&schema)
+                .column_defaults(HashMap::from([(
+                    "a".to_string(),
+                    ScalarValue::from("none"),
+                )]));
+        let err = session_ctx
+            .read_csv("memory:///a.csv", options)
+            .await?
+            .collect()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Default value none of column a can not be cast to Int64"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn write_csv_results_error_handling() -> Result<()> {
         let ctx = SessionContext::new();
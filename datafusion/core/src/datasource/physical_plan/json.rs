@@ -18,6 +18,7 @@
 //! Execution plan for reading line-delimited JSON files
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::io::BufReader;
 use std::sync::Arc;
 use std::task::Poll;
@@ -28,7 +29,7 @@ use crate::datasource::listing::ListingTableUrl;
 use crate::datasource::physical_plan::file_stream::{
     FileOpenFuture, FileOpener, FileStream,
 };
-use crate::datasource::physical_plan::FileMeta;
+use crate::datasource::physical_plan::{fill_column_defaults, FileMeta};
 use crate::error::{DataFusionError, Result};
 use crate::physical_plan::expressions::PhysicalSortExpr;
 use crate::physical_plan::metrics::{ExecutionPlanMetricsSet, MetricsSet};
@@ -39,13 +40,14 @@ use crate::physical_plan::{
 
 use arrow::json::ReaderBuilder;
 use arrow::{datatypes::SchemaRef, json};
+use datafusion_common::ScalarValue;
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::{
     ordering_equivalence_properties_helper, LexOrdering, OrderingEquivalenceProperties,
 };
 
 use bytes::{Buf, Bytes};
-use futures::{ready, stream, StreamExt, TryStreamExt};
+use futures::{ready, stream, StreamExt, TryFutureExt, TryStreamExt};
 use object_store;
 use object_store::{GetResultPayload, ObjectStore};
 use tokio::io::AsyncWriteExt;
@@ -58,6 +60,8 @@ pub struct NdJsonExec {
     projected_statistics: Statistics,
     projected_schema: SchemaRef,
     projected_output_ordering: Vec<LexOrdering>,
+    /// Values replacing the NULL (i.e. absent) values of the given columns
+    column_defaults: HashMap<String, ScalarValue>,
     /// Execution metrics
     metrics: ExecutionPlanMetricsSet,
     file_compression_type: FileCompressionType,
@@ -77,15 +81,31 @@ impl NdJsonExec {
             projected_schema,
             projected_statistics,
             projected_output_ordering,
+            column_defaults: HashMap::new(),
             metrics: ExecutionPlanMetricsSet::new(),
             file_compression_type,
         }
     }
 
+    /// Replaces the NULL (i.e. absent) values of the given columns with the
+    /// given defaults, which must have the types of their columns.
+    pub fn with_column_defaults(
+        mut self,
+        column_defaults: HashMap<String, ScalarValue>,
+    ) -> Self {
+        self.column_defaults = column_defaults;
+        self
+    }
+
     /// Ref to the base configs
     pub fn base_config(&self) -> &FileScanConfig {
         &self.base_config
     }
+
+    /// The defaults replacing the NULL values of their columns
+    pub fn column_defaults(&self) -> &HashMap<String, ScalarValue> {
+        &self.column_defaults
+    }
 }
 
 impl DisplayAs for NdJsonExec {
@@ -155,6 +175,7 @@ impl ExecutionPlan for NdJsonExec {
             batch_size,
             projected_schema,
             file_compression_type: self.file_compression_type.to_owned(),
+            column_defaults: Arc::new(self.column_defaults.clone()),
             object_store,
         };
 
@@ -178,6 +199,7 @@ pub struct JsonOpener {
     batch_size: usize,
     projected_schema: SchemaRef,
     file_compression_type: FileCompressionType,
+    column_defaults: Arc<HashMap<String, ScalarValue>>,
     object_store: Arc<dyn ObjectStore>,
 }
 
@@ -193,9 +215,20 @@ impl JsonOpener {
             batch_size,
             projected_schema,
             file_compression_type,
+            column_defaults: Arc::new(HashMap::new()),
             object_store,
         }
     }
+
+    /// Replaces the NULL (i.e. absent) values of the given columns with the
+    /// given defaults, which must have the types of their columns.
+    pub fn with_column_defaults(
+        mut self,
+        column_defaults: HashMap<String, ScalarValue>,
+    ) -> Self {
+        self.column_defaults = Arc::new(column_defaults);
+        self
+    }
 }
 
 impl FileOpener for JsonOpener {
@@ -205,7 +238,8 @@ impl FileOpener for JsonOpener {
         let batch_size = self.batch_size;
 
         let file_compression_type = self.file_compression_type.to_owned();
-        Ok(Box::pin(async move {
+        let column_defaults = Arc::clone(&self.column_defaults);
+        let future = async move {
             let r = store.get(file_meta.location()).await?;
             match r.payload {
                 GetResultPayload::File(file, _) => {
@@ -254,7 +288,10 @@ impl FileOpener for JsonOpener {
                     Ok(s.boxed())
                 }
             }
-        }))
+        };
+        Ok(Box::pin(future.map_ok(move |stream| {
+            fill_column_defaults(stream, column_defaults, false)
+        })))
     }
 }
 
@@ -313,7 +350,7 @@ pub async fn plan_to_json(
 #[cfg(test)]
 mod tests {
     use arrow::array::Array;
-    use arrow::datatypes::{Field, SchemaBuilder};
+    use arrow::datatypes::{DataType, Field, Schema, SchemaBuilder};
     use futures::StreamExt;
     use object_store::local::LocalFileSystem;
 
@@ -776,6 +813,49 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn nd_json_exec_with_column_defaults() -> Result<()> {
+        let session_ctx = SessionContext::new();
+        let store = object_store::memory::InMemory::new();
+
+        let data =
+            bytes::Bytes::from("{\"a\": 1, \"b\": \"x\"}\n{\"a\": 2}\n{\"b\": null}\n");
+        let path = object_store::path::Path::from("a.json");
+        store.put(&path, data).await.unwrap();
+
+        let url = Url::parse("memory://").unwrap();
+        session_ctx
+            .runtime_env()
+            .register_object_store(&url, Arc::new(store));
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let options = NdJsonReadOptions {
+            schema: Some(&schema),
+            ..Default::default()
+        }
+        .column_defaults(HashMap::from([("b".to_string(), ScalarValue::from("y"))]));
+        let result = session_ctx
+            .read_json("memory:///", options)
+            .await?
+            .collect()
+            .await?;
+
+        let expected = [
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | x |",
+            "| 2 | y |",
+            "|   | y |",
+            "+---+---+",
+        ];
+        assert_batches_eq!(expected, &result);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn write_json_results_error_handling() -> Result<()> {
         let ctx = SessionContext::new();
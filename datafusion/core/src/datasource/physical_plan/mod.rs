@@ -41,6 +41,7 @@ pub use file_stream::{FileOpenFuture, FileOpener, FileStream, OnError};
 pub use json::{JsonOpener, NdJsonExec};
 
 use std::{
+    collections::HashMap,
     fmt::{Debug, Formatter, Result as FmtResult},
     sync::Arc,
     vec,
@@ -61,15 +62,23 @@ use crate::{
 };
 
 use arrow::{
-    array::new_null_array,
-    compute::{can_cast_types, cast},
+    array::{new_null_array, Scalar},
+    compute::{
+        can_cast_types, cast, is_null,
+        kernels::{boolean::or_kleene, cmp::eq, zip::zip},
+    },
     datatypes::{DataType, Schema, SchemaRef},
+    error::ArrowError,
     record_batch::{RecordBatch, RecordBatchOptions},
 };
-use datafusion_common::{file_options::FileTypeWriterOptions, plan_err};
+use datafusion_common::{
+    file_options::FileTypeWriterOptions, plan_datafusion_err, plan_err, ScalarValue,
+};
 use datafusion_physical_expr::expressions::Column;
 use datafusion_physical_expr::PhysicalSortExpr;
 use datafusion_physical_plan::ExecutionPlan;
+use futures::stream::BoxStream;
+use futures::StreamExt;
 
 use log::debug;
 use object_store::path::Path;
@@ -378,6 +387,85 @@ impl SchemaMapping {
     }
 }
 
+/// Checks that each column with a default in `column_defaults` is a column of
+/// `file_schema`, and casts the defaults to the types of their columns.
+pub(crate) fn resolve_column_defaults(
+    file_schema: &Schema,
+    column_defaults: &HashMap<String, ScalarValue>,
+) -> Result<HashMap<String, ScalarValue>> {
+    column_defaults
+        .iter()
+        .map(|(name, value)| {
+            let Some((_, field)) = file_schema.fields().find(name) else {
+                return plan_err!("Default value given for unknown column {name}");
+            };
+            let cast_value = cast(&value.to_array(), field.data_type())
+                .map_err(DataFusionError::ArrowError)
+                .and_then(|array| ScalarValue::try_from_array(&array, 0));
+            match cast_value {
+                Ok(cast_value) if cast_value.is_null() == value.is_null() => {
+                    Ok((name.clone(), cast_value))
+                }
+                _ => Err(plan_datafusion_err!(
+                    "Default value {value} of column {name} can not be cast to {}",
+                    field.data_type()
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Replaces the NULL values of the columns with defaults in `column_defaults`
+/// in the batches of `stream` with their defaults. The defaults must have the
+/// types of their columns, see [`resolve_column_defaults`].
+///
+/// If `fill_empty_strings` is set, the empty values of string columns are
+/// replaced as well, as formats like CSV do not tell them apart from missing
+/// values.
+pub(crate) fn fill_column_defaults(
+    stream: BoxStream<'static, Result<RecordBatch, ArrowError>>,
+    column_defaults: Arc<HashMap<String, ScalarValue>>,
+    fill_empty_strings: bool,
+) -> BoxStream<'static, Result<RecordBatch, ArrowError>> {
+    if column_defaults.is_empty() {
+        return stream;
+    }
+    stream
+        .map(move |batch| {
+            let batch = batch?;
+            let mut columns = batch.columns().to_vec();
+            for (column, field) in columns.iter_mut().zip(batch.schema().fields()) {
+                let Some(value) = column_defaults.get(field.name()) else {
+                    continue;
+                };
+                let empty_string = match field.data_type() {
+                    DataType::Utf8 if fill_empty_strings => {
+                        Some(ScalarValue::Utf8(Some(String::new())))
+                    }
+                    DataType::LargeUtf8 if fill_empty_strings => {
+                        Some(ScalarValue::LargeUtf8(Some(String::new())))
+                    }
+                    _ => None,
+                };
+                let mask = match empty_string {
+                    Some(empty_string) => {
+                        let empty_string = Scalar::new(empty_string.to_array());
+                        or_kleene(&is_null(column)?, &eq(column, &empty_string)?)?
+                    }
+                    None if column.null_count() > 0 => is_null(column)?,
+                    None => continue,
+                };
+                let defaults = value.to_array_of_size(batch.num_rows());
+                *column = zip(&mask, &defaults, column)?;
+            }
+            // Necessary to handle batches without columns
+            let options =
+                RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+            RecordBatch::try_new_with_options(batch.schema(), columns, &options)
+        })
+        .boxed()
+}
+
 /// A single file or part of a file that should be read, along with its schema, statistics
 pub struct FileMeta {
     /// Path for the file (e.g. URL, filesystem path, etc)
@@ -31,7 +31,8 @@ use crate::datasource::physical_plan::{
 };
 use crate::{
     config::ConfigOptions,
-    datasource::listing::ListingTableUrl,
+    datasource::file_format::parquet::fetch_parquet_metadata,
+    datasource::listing::{FileRange, ListingTableUrl, PartitionedFile},
     error::{DataFusionError, Result},
     execution::context::TaskContext,
     physical_optimizer::pruning::PruningPredicate,
@@ -263,6 +264,102 @@ impl ParquetExec {
         }
         new_plan
     }
+
+    /// Redistribute the row groups of all files across up to `target_partitions`
+    /// partitions, so that the row groups of a single file can be read in parallel.
+    /// Unlike [`Self::get_repartitioned`], this reads the metadata of the files
+    /// (from `store`) and splits them at row group boundaries. Row groups that are
+    /// pruned by the pruning predicate are not assigned to any partition, and the
+    /// remaining ones are distributed according to their compressed size.
+    ///
+    /// The scan is returned unchanged if it already reads byte ranges of files,
+    /// has an output ordering, or reads fewer than two row groups.
+    pub async fn split_row_groups(
+        &self,
+        store: &dyn ObjectStore,
+        target_partitions: usize,
+    ) -> Result<Self> {
+        let files = self.base_config.file_groups.iter().flatten();
+        if target_partitions < 2
+            || !self.base_config.output_ordering.is_empty()
+            || files.clone().any(|file| file.range.is_some())
+        {
+            return Ok(self.clone());
+        }
+
+        // The pruning is repeated when the files are opened, so the metrics
+        // collected here are discarded:
+        let metrics = ExecutionPlanMetricsSet::new();
+        // The row groups to read, given by their file, index, offset and size
+        let mut row_groups = vec![];
+        for file in files {
+            let metadata =
+                fetch_parquet_metadata(store, &file.object_meta, self.metadata_size_hint)
+                    .await?;
+            let file_metrics =
+                ParquetFileMetrics::new(0, file.object_meta.location.as_ref(), &metrics);
+            let groups = metadata.row_groups();
+            let pruning_predicate = self.pruning_predicate.as_deref();
+            for idx in row_groups::prune_row_groups(
+                groups,
+                None,
+                pruning_predicate,
+                &file_metrics,
+            ) {
+                let offset = row_groups::row_group_offset(&groups[idx]);
+                let size = groups[idx].compressed_size() as usize;
+                row_groups.push((file, idx, offset, size));
+            }
+        }
+        if row_groups.len() < 2 {
+            return Ok(self.clone());
+        }
+
+        let total_size = row_groups.iter().map(|(.., size)| size).sum::<usize>();
+        let num_partitions = target_partitions.min(row_groups.len());
+        let target_partition_size = (total_size + num_partitions - 1) / num_partitions;
+
+        // Assign consecutive row groups to a partition until it reaches the
+        // target size. Files read the row groups whose first page starts within
+        // their range, so consecutive row groups of the same file are combined
+        // into a single range.
+        let mut file_groups = vec![];
+        let mut partition: Vec<(PartitionedFile, usize)> = vec![];
+        let mut partition_size = 0;
+        for (file, idx, offset, size) in row_groups {
+            match partition.last_mut() {
+                Some((last, last_idx))
+                    if last.object_meta.location == file.object_meta.location
+                        && *last_idx + 1 == idx =>
+                {
+                    if let Some(range) = last.range.as_mut() {
+                        range.end = offset + 1;
+                    }
+                    *last_idx = idx;
+                }
+                _ => {
+                    let mut row_group_file = file.clone();
+                    row_group_file.range = Some(FileRange {
+                        start: offset,
+                        end: offset + 1,
+                    });
+                    partition.push((row_group_file, idx));
+                }
+            }
+            partition_size += size;
+            if partition_size >= target_partition_size {
+                file_groups.push(partition.drain(..).map(|(file, _)| file).collect());
+                partition_size = 0;
+            }
+        }
+        if !partition.is_empty() {
+            file_groups.push(partition.into_iter().map(|(file, _)| file).collect());
+        }
+
+        let mut new_plan = self.clone();
+        new_plan.base_config.file_groups = file_groups;
+        Ok(new_plan)
+    }
 }
 
 impl DisplayAs for ParquetExec {
@@ -754,6 +851,7 @@ mod tests {
     };
     use arrow_array::Date64Array;
     use chrono::{TimeZone, Utc};
+    use datafusion_common::cast::as_int32_array;
     use datafusion_common::ScalarValue;
     use datafusion_common::{assert_contains, ToDFSchema};
     use datafusion_expr::{col, lit, when, Expr};
@@ -1494,6 +1592,83 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn parquet_exec_split_row_groups() -> Result<()> {
+        // A single file with 10 row groups of 100 rows each
+        let values = Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter(vec![("a", values)])?;
+        let mut file = tempfile::Builder::new().suffix(".parquet").tempfile()?;
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .build();
+        let mut writer =
+            parquet::arrow::ArrowWriter::try_new(&mut file, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+        let meta = local_unpartitioned_file(&file);
+
+        let file_schema = batch.schema();
+        let scan = |predicate: Option<Expr>| {
+            ParquetExec::new(
+                FileScanConfig {
+                    object_store_url: ObjectStoreUrl::local_filesystem(),
+                    file_groups: vec![vec![meta.clone().into()]],
+                    statistics: Statistics::new_unknown(&file_schema),
+                    file_schema: file_schema.clone(),
+                    projection: None,
+                    limit: None,
+                    table_partition_cols: vec![],
+                    output_ordering: vec![],
+                    infinite_source: false,
+                },
+                predicate.map(|p| logical2physical(&p, &file_schema)),
+                None,
+            )
+        };
+        let store = LocalFileSystem::new();
+        async fn read_values(exec: ParquetExec) -> Result<Vec<i32>> {
+            let task_ctx = SessionContext::new().task_ctx();
+            let batches = collect(Arc::new(exec), task_ctx).await?;
+            let mut values = vec![];
+            for batch in batches {
+                values.extend(as_int32_array(batch.column(0))?.values());
+            }
+            values.sort_unstable();
+            Ok(values)
+        }
+
+        // The row groups are distributed across the target partitions, and
+        // each of them is read exactly once:
+        let exec = scan(None).split_row_groups(&store, 4).await?;
+        assert_eq!(exec.output_partitioning().partition_count(), 4);
+        assert_eq!(read_values(exec).await?, (0..1000).collect::<Vec<_>>());
+
+        // Only the last three row groups may contain values satisfying the
+        // predicate, so they are distributed across three partitions:
+        let exec = scan(Some(col("a").gt_eq(lit(750))))
+            .split_row_groups(&store, 4)
+            .await?;
+        assert_eq!(exec.output_partitioning().partition_count(), 3);
+        assert_eq!(read_values(exec).await?, (700..1000).collect::<Vec<_>>());
+
+        // A single target partition leaves the scan unchanged:
+        let exec = scan(None).split_row_groups(&store, 1).await?;
+        assert_eq!(exec.output_partitioning().partition_count(), 1);
+        assert!(exec.base_config().file_groups[0][0].range.is_none());
+
+        // The row groups are split when planning a query if configured:
+        let mut config = SessionConfig::new().with_target_partitions(4);
+        config.options_mut().execution.parquet.split_row_groups = true;
+        let ctx = SessionContext::new_with_config(config);
+        let path = file.path().to_str().unwrap();
+        ctx.register_parquet("t", path, ParquetReadOptions::default())
+            .await?;
+        let plan = ctx.table("t").await?.create_physical_plan().await?;
+        assert_eq!(plan.output_partitioning().partition_count(), 4);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn parquet_exec_with_range() -> Result<()> {
         fn file_range(meta: &ObjectMeta, start: i64, end: i64) -> PartitionedFile {
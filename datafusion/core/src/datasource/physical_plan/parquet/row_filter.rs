@@ -16,22 +16,26 @@
 // under the License.
 
 use arrow::array::BooleanArray;
-use arrow::datatypes::{DataType, Schema};
+use arrow::datatypes::{DataType, Schema, SchemaRef};
 use arrow::error::{ArrowError, Result as ArrowResult};
 use arrow::record_batch::RecordBatch;
 use datafusion_common::cast::as_boolean_array;
 use datafusion_common::tree_node::{RewriteRecursion, TreeNode, TreeNodeRewriter};
 use datafusion_common::{DataFusionError, Result, ScalarValue};
-use datafusion_physical_expr::expressions::{Column, Literal};
+use datafusion_expr::Operator;
+use datafusion_physical_expr::expressions::{BinaryExpr, Column, LikeExpr, Literal};
 use datafusion_physical_expr::utils::reassign_predicate_columns;
 use std::collections::BTreeSet;
 
-use datafusion_physical_expr::{split_conjunction, PhysicalExpr};
+use datafusion_physical_expr::{
+    analyze, split_conjunction, AnalysisContext, PhysicalExpr, ScalarFunctionExpr,
+};
 use parquet::arrow::arrow_reader::{ArrowPredicate, RowFilter};
 use parquet::arrow::ProjectionMask;
 use parquet::file::metadata::ParquetMetaData;
 use std::sync::Arc;
 
+use crate::datasource::file_format::parquet::statistics_from_parquet_meta;
 use crate::physical_plan::metrics;
 
 use super::ParquetFileMetrics;
@@ -49,8 +53,10 @@ use super::ParquetFileMetrics;
 ///     1. The are relatively inexpensive to evaluate (e.g. they read column chunks which are relatively small)
 ///     2. They filter a lot of rows, reducing the amount of decoding required for subsequent filters and projected columns
 ///
-/// Given the metadata exposed by parquet, the selectivity of filters is not easy to estimate so the heuristics we use here primarily
-/// focus on the evaluation cost.
+/// The evaluation cost of a predicate is estimated from the size of the columns it reads, and from the
+/// expressions it evaluates, where pattern matching (e.g. `LIKE` or regular expressions) is considerably
+/// more expensive than other expressions. The selectivity of a predicate is estimated from the column
+/// statistics in the parquet metadata, see [`analyze`].
 ///
 /// The basic algorithm for constructing the `RowFilter` is as follows
 ///     1. Recursively break conjunctions into separate predicates. An expression like `a = 1 AND (b = 2 AND c = 3)` would be
@@ -59,11 +65,14 @@ use super::ParquetFileMetrics;
 ///        or columns with non-primitive types, then it is considered suitable.
 ///     3. Determine, for each predicate, the total compressed size of all columns required to evaluate the predicate.
 ///     4. Determine, for each predicate, whether all columns required to evaluate the expression are sorted.
-///     5. Re-order the predicate by total size (from step 3).
+///     5. Re-order the predicates by their rank `cost / (1 - selectivity)`, where the cost is the total size
+///        (from step 3) weighted by the cost of the expression, and the selectivity is estimated from the
+///        statistics of the file. Cheap and selective predicates are thus applied first, so that expensive
+///        predicates are only evaluated on the rows which remain.
 ///     6. Partition the predicates according to whether they are sorted (from step 4)
 ///     7. "Compile" each predicate `Expr` to a `DatafusionArrowPredicate`.
 ///     8. Build the `RowFilter` with the sorted predicates followed by the unsorted predicates. Within each partition
-///        the predicates will still be sorted by size.
+///        the predicates will still be sorted by rank.
 
 /// A predicate which can be passed to `ParquetRecordBatchStream` to perform row-level
 /// filtering during parquet decoding.
@@ -150,6 +159,24 @@ pub(crate) struct FilterCandidate {
     required_bytes: usize,
     can_use_index: bool,
     projection: Vec<usize>,
+    /// The relative cost of evaluating `expr` on a single row, see [`evaluation_cost`]
+    evaluation_cost: f64,
+    /// The estimated fraction of rows selected by `expr`
+    selectivity: f64,
+}
+
+impl FilterCandidate {
+    /// The rank of the candidate in the `RowFilter`, lower ranks are applied
+    /// first. Predicates which select every row are applied last.
+    fn rank(&self) -> f64 {
+        let cost = (self.required_bytes as f64).max(1.0) * self.evaluation_cost;
+        let filtered = 1.0 - self.selectivity;
+        if filtered > 0.0 {
+            cost / filtered
+        } else {
+            f64::INFINITY
+        }
+    }
 }
 
 /// Helper to build a `FilterCandidate`. This will do several things
@@ -197,12 +224,15 @@ impl<'a> FilterCandidateBuilder<'a> {
             let required_bytes =
                 size_of_columns(&self.required_column_indices, metadata)?;
             let can_use_index = columns_sorted(&self.required_column_indices, metadata)?;
+            let evaluation_cost = evaluation_cost(&expr);
 
             Ok(Some(FilterCandidate {
                 expr,
                 required_bytes,
                 can_use_index,
                 projection: self.required_column_indices.into_iter().collect(),
+                evaluation_cost,
+                selectivity: 1.0,
             }))
         }
     }
@@ -308,6 +338,80 @@ fn columns_sorted(
     Ok(false)
 }
 
+/// The cost of evaluating pattern matching expressions, such as `LIKE` and regular
+/// expression matches, relative to other expressions.
+const PATTERN_MATCH_COST: f64 = 10.0;
+
+/// Estimate the relative cost of evaluating `expr` on a single row, as the sum of
+/// the costs of its nodes. Pattern matching nodes cost [`PATTERN_MATCH_COST`], and
+/// all other nodes cost 1.
+fn evaluation_cost(expr: &Arc<dyn PhysicalExpr>) -> f64 {
+    let expr_any = expr.as_any();
+    let is_pattern_match = if let Some(binary) = expr_any.downcast_ref::<BinaryExpr>() {
+        matches!(
+            binary.op(),
+            Operator::RegexMatch
+                | Operator::RegexIMatch
+                | Operator::RegexNotMatch
+                | Operator::RegexNotIMatch
+        )
+    } else if let Some(function) = expr_any.downcast_ref::<ScalarFunctionExpr>() {
+        function.name().starts_with("regexp_")
+    } else {
+        expr_any.is::<LikeExpr>()
+    };
+    let cost = if is_pattern_match {
+        PATTERN_MATCH_COST
+    } else {
+        1.0
+    };
+    cost + expr.children().iter().map(evaluation_cost).sum::<f64>()
+}
+
+/// Estimate the fraction of rows of the file selected by `expr` from the statistics
+/// in `context`. If the selectivity can not be estimated, every row is assumed to
+/// be selected.
+fn estimate_selectivity(
+    expr: &Arc<dyn PhysicalExpr>,
+    file_schema: &SchemaRef,
+    context: Option<&AnalysisContext>,
+) -> f64 {
+    let Some(context) = context else {
+        return 1.0;
+    };
+    reassign_predicate_columns(expr.clone(), file_schema, true)
+        .and_then(|expr| analyze(&expr, context.clone()))
+        .ok()
+        .and_then(|analyzed| analyzed.selectivity)
+        .map_or(1.0, |selectivity| selectivity.point)
+}
+
+/// Create an [`AnalysisContext`] from the statistics in the parquet metadata, if any
+fn file_analysis_context(
+    file_schema: &SchemaRef,
+    metadata: &ParquetMetaData,
+) -> Option<AnalysisContext> {
+    let statistics = statistics_from_parquet_meta(metadata, file_schema.clone()).ok()?;
+    AnalysisContext::try_from_statistics(file_schema, &statistics.column_statistics)
+        .ok()
+        .map(|context| context.with_num_rows(statistics.num_rows))
+}
+
+/// Sort the candidates by their rank, see [`FilterCandidate::rank`]
+fn sort_candidates(
+    candidates: &mut [FilterCandidate],
+    file_schema: &Schema,
+    metadata: &ParquetMetaData,
+) {
+    let file_schema = Arc::new(file_schema.clone());
+    let context = file_analysis_context(&file_schema, metadata);
+    for candidate in candidates.iter_mut() {
+        candidate.selectivity =
+            estimate_selectivity(&candidate.expr, &file_schema, context.as_ref());
+    }
+    candidates.sort_by(|a, b| a.rank().total_cmp(&b.rank()));
+}
+
 /// Build a [`RowFilter`] from the given predicate `Expr`
 pub fn build_row_filter(
     expr: &Arc<dyn PhysicalExpr>,
@@ -339,7 +443,7 @@ pub fn build_row_filter(
     if candidates.is_empty() {
         Ok(None)
     } else if reorder_predicates {
-        candidates.sort_by_key(|c| c.required_bytes);
+        sort_candidates(&mut candidates, file_schema, metadata);
 
         let (indexed_candidates, other_candidates): (Vec<_>, Vec<_>) =
             candidates.into_iter().partition(|c| c.can_use_index);
@@ -392,12 +496,15 @@ pub fn build_row_filter(
 #[cfg(test)]
 mod test {
     use super::*;
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::compute::filter_record_batch;
     use arrow::datatypes::Field;
+    use bytes::Bytes;
     use datafusion_common::ToDFSchema;
-    use datafusion_expr::{cast, col, lit, Expr};
+    use datafusion_expr::{binary_expr, cast, col, lit, Expr};
     use datafusion_physical_expr::create_physical_expr;
     use datafusion_physical_expr::execution_props::ExecutionProps;
-    use parquet::arrow::parquet_to_arrow_schema;
+    use parquet::arrow::{parquet_to_arrow_schema, ArrowWriter};
     use parquet::file::reader::{FileReader, SerializedFileReader};
     use rand::prelude::*;
 
@@ -466,6 +573,80 @@ mod test {
         );
     }
 
+    // Cheap and selective predicates should be applied before expensive ones
+    #[test]
+    fn test_sort_candidates_by_rank() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("int_col", DataType::Int64, false),
+            Field::new("string_col", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int64Array::from_iter_values(0..1000)),
+                Arc::new(StringArray::from_iter_values(
+                    (0..1000).map(|i| format!("value-{i}")),
+                )),
+            ],
+        )
+        .expect("creating batch");
+
+        let mut buffer = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buffer, schema.clone(), None)
+            .expect("creating writer");
+        writer.write(&batch).expect("writing batch");
+        writer.close().expect("closing writer");
+
+        let reader =
+            SerializedFileReader::new(Bytes::from(buffer)).expect("creating reader");
+        let metadata = reader.metadata();
+
+        // string_col ~ '^value-[0-9]*5$' AND int_col < 10
+        let expr = binary_expr(
+            col("string_col"),
+            Operator::RegexMatch,
+            lit("^value-[0-9]*5$"),
+        )
+        .and(col("int_col").lt(lit(10i64)));
+        let expr = logical2physical(&expr, &schema);
+
+        let mut candidates: Vec<_> = split_conjunction(&expr)
+            .into_iter()
+            .map(|expr| {
+                FilterCandidateBuilder::new(expr.clone(), &schema, &schema)
+                    .build(metadata)
+                    .expect("building candidate")
+                    .expect("candidate is supported")
+            })
+            .collect();
+
+        // Apply the candidates in order, counting the rows the regex is evaluated on
+        let regex_evaluations = |candidates: &[FilterCandidate]| {
+            let mut remaining = batch.clone();
+            let mut evaluations = 0;
+            for candidate in candidates {
+                if candidate.evaluation_cost >= PATTERN_MATCH_COST {
+                    evaluations += remaining.num_rows();
+                }
+                let mask = candidate
+                    .expr
+                    .evaluate(&remaining)
+                    .expect("evaluating predicate")
+                    .into_array(remaining.num_rows());
+                remaining =
+                    filter_record_batch(&remaining, as_boolean_array(&mask).unwrap())
+                        .expect("filtering batch");
+            }
+            assert_eq!(remaining.num_rows(), 1);
+            evaluations
+        };
+
+        assert_eq!(regex_evaluations(&candidates), 1000);
+        sort_candidates(&mut candidates, &schema, metadata);
+        assert!(candidates[0].selectivity < 0.1);
+        assert_eq!(regex_evaluations(&candidates), 10);
+    }
+
     #[test]
     fn test_remap_projection() {
         let mut rng = thread_rng();
@@ -37,6 +37,17 @@ use crate::{
 
 use super::ParquetFileMetrics;
 
+/// Returns the offset of the first dictionary page (or the first data page) of
+/// the given row group. A scan of a [`FileRange`] reads the row groups whose
+/// offset is within the range.
+pub(crate) fn row_group_offset(metadata: &RowGroupMetaData) -> i64 {
+    // note don't use the location of metadata
+    // <https://github.com/apache/arrow-datafusion/issues/5995>
+    let col = metadata.column(0);
+    col.dictionary_page_offset()
+        .unwrap_or_else(|| col.data_page_offset())
+}
+
 /// Returns a vector of indexes into `groups` which should be scanned.
 ///
 /// If an index is NOT present in the returned Vec it means the
@@ -53,13 +64,7 @@ pub(crate) fn prune_row_groups(
     let mut filtered = Vec::with_capacity(groups.len());
     for (idx, metadata) in groups.iter().enumerate() {
         if let Some(range) = &range {
-            // figure out where the first dictionary page (or first data page are)
-            // note don't use the location of metadata
-            // <https://github.com/apache/arrow-datafusion/issues/5995>
-            let col = metadata.column(0);
-            let offset = col
-                .dictionary_page_offset()
-                .unwrap_or_else(|| col.data_page_offset());
+            let offset = row_group_offset(metadata);
             if offset < range.start || offset >= range.end {
                 continue;
             }
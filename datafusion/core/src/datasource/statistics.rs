@@ -98,6 +98,7 @@ pub async fn get_statistics_with_limit(
                                 max_value: file_max,
                                 min_value: file_min,
                                 distinct_count: _,
+                                histogram: _,
                             },
                             null_count,
                             max_value,
@@ -178,6 +179,7 @@ pub(crate) fn get_col_stats_vec(
             max_value,
             min_value,
             distinct_count: Precision::Absent,
+            histogram: None,
         })
         .collect()
 }
@@ -203,6 +205,7 @@ pub(crate) fn get_col_stats(
                 max_value: max_value.map(Precision::Exact).unwrap_or(Precision::Absent),
                 min_value: min_value.map(Precision::Exact).unwrap_or(Precision::Absent),
                 distinct_count: Precision::Absent,
+                histogram: None,
             }
         })
         .collect()
@@ -39,6 +39,7 @@ use datafusion_expr::{
     StringifiedPlan, UserDefinedLogicalNode, WindowUDF,
 };
 pub use datafusion_physical_expr::execution_props::ExecutionProps;
+use datafusion_physical_expr::expressions::ArithmeticOverflowMode;
 use datafusion_physical_expr::var_provider::is_system_variables;
 use parking_lot::RwLock;
 use std::collections::hash_map::Entry;
@@ -100,6 +101,7 @@ use crate::catalog::listing_schema::ListingSchemaProvider;
 use crate::datasource::object_store::ObjectStoreUrl;
 use datafusion_optimizer::{
     analyzer::{Analyzer, AnalyzerRule},
+    decorrelate::describe_decorrelation,
     OptimizerConfig,
 };
 use datafusion_sql::planner::object_name_to_table_reference;
@@ -726,7 +728,14 @@ impl SessionContext {
         } = stmt;
 
         let mut state = self.state.write();
-        state.config.options_mut().set(&variable, &value)?;
+        let mut options = state.config.options().clone();
+        options.set(&variable, &value)?;
+        // Reject invalid values right away rather than when planning
+        options
+            .execution
+            .arithmetic_overflow_mode
+            .parse::<ArithmeticOverflowMode>()?;
+        *state.config.options_mut() = options;
         drop(state);
 
         self.return_empty_dataframe()
@@ -1176,6 +1185,10 @@ impl SessionContext {
     }
 
     /// Return `true` if the specified table exists in the schema provider.
+    ///
+    /// References which are not fully qualified are resolved against the
+    /// default catalog and schema, as the table references of queries are.
+    /// Returns an error if the referenced catalog or schema does not exist.
     pub fn table_exist<'a>(
         &'a self,
         table_ref: impl Into<TableReference<'a>>,
@@ -1189,6 +1202,54 @@ impl SessionContext {
             .table_exist(&table))
     }
 
+    /// Returns the fully qualified names, i.e. `catalog.schema.table`, of the
+    /// tables in all registered catalogs and schemas, sorted by name. The
+    /// tables of the `information_schema` of each catalog are included if it
+    /// is enabled.
+    ///
+    /// Parts of the names are quoted where needed, so that each name refers to
+    /// its table when used in SQL statements or parsed by
+    /// [`TableReference::parse_str`].
+    pub fn table_names(&self) -> Vec<String> {
+        let state = self.state.read();
+        let information_schema = state
+            .config
+            .information_schema()
+            .then(|| InformationSchemaProvider::new(state.catalog_list.clone()));
+        let mut names = vec![];
+        for catalog_name in state.catalog_list.catalog_names() {
+            let Some(catalog) = state.catalog_list.catalog(&catalog_name) else {
+                continue;
+            };
+            let mut schemas = catalog
+                .schema_names()
+                .into_iter()
+                .filter_map(|name| {
+                    let table_names = catalog.schema(&name)?.table_names();
+                    Some((name, table_names))
+                })
+                .collect::<Vec<_>>();
+            if let Some(information_schema) = &information_schema {
+                schemas.push((
+                    INFORMATION_SCHEMA.to_string(),
+                    information_schema.table_names(),
+                ));
+            }
+            for (schema_name, table_names) in schemas {
+                names.extend(table_names.into_iter().map(|table_name| {
+                    TableReference::full(
+                        catalog_name.as_str(),
+                        schema_name.as_str(),
+                        table_name,
+                    )
+                    .to_quoted_string()
+                }));
+            }
+        }
+        names.sort();
+        names
+    }
+
     /// Retrieves a [`DataFrame`] representing a table previously
     /// registered by calling the [`register_table`] function.
     ///
@@ -1910,6 +1971,15 @@ impl SessionState {
                 Err(e) => return Err(e),
             };
 
+            if logical_optimization_succeeded
+                && self.config_options().explain.show_subquery_decorrelation
+            {
+                stringified_plans.push(StringifiedPlan::new(
+                    PlanType::SubqueryDecorrelation,
+                    describe_decorrelation(&analyzed_plan, &plan)?,
+                ));
+            }
+
             Ok(LogicalPlan::Explain(Explain {
                 verbose: e.verbose,
                 plan,
@@ -1937,8 +2007,24 @@ impl SessionState {
         logical_plan: &LogicalPlan,
     ) -> Result<Arc<dyn ExecutionPlan>> {
         let logical_plan = self.optimize(logical_plan)?;
-        self.query_planner
-            .create_physical_plan(&logical_plan, self)
+        // The execution properties are not updated when the config options
+        // change, so the overflow mode is read from the options here
+        let arithmetic_overflow_mode = self
+            .config_options()
+            .execution
+            .arithmetic_overflow_mode
+            .parse()?;
+        if arithmetic_overflow_mode == self.execution_props.arithmetic_overflow_mode {
+            return self
+                .query_planner
+                .create_physical_plan(&logical_plan, self)
+                .await;
+        }
+        let mut state = self.clone();
+        state.execution_props.arithmetic_overflow_mode = arithmetic_overflow_mode;
+        state
+            .query_planner
+            .create_physical_plan(&logical_plan, &state)
             .await
     }
 
@@ -2246,6 +2332,7 @@ mod tests {
     use crate::test;
     use crate::test_util::parquet_test_data;
     use crate::variable::VarType;
+    use arrow::array::{ArrayRef, Int32Array};
     use arrow::record_batch::RecordBatch;
     use arrow_schema::{Field, Schema};
     use async_trait::async_trait;
@@ -2256,6 +2343,44 @@ mod tests {
     use std::{env, io::prelude::*};
     use tempfile::TempDir;
 
+    #[tokio::test]
+    async fn arithmetic_overflow_mode_from_config() -> Result<()> {
+        let overflow = |mode: &str| {
+            let config = SessionConfig::new()
+                .set_str("datafusion.execution.arithmetic_overflow_mode", mode);
+            let ctx = SessionContext::new_with_config(config);
+            let batch = RecordBatch::try_from_iter(vec![(
+                "a",
+                Arc::new(Int32Array::from(vec![i32::MAX])) as ArrayRef,
+            )])?;
+            ctx.register_batch("t", batch)?;
+            Ok::<_, DataFusionError>(ctx)
+        };
+
+        // The mode is taken from the config the session is created with
+        let ctx = overflow("null")?;
+        let results = ctx.sql("SELECT a + a AS b FROM t").await?.collect().await?;
+        let expected = ["+---+", "| b |", "+---+", "|   |", "+---+"];
+        assert_batches_eq!(expected, &results);
+
+        // and when it is changed afterwards
+        ctx.sql("SET datafusion.execution.arithmetic_overflow_mode = 'error'")
+            .await?;
+        let err = ctx.sql("SELECT a + a FROM t").await?.collect().await;
+        assert!(err.is_err());
+
+        // Invalid modes are an error when planning
+        let ctx = overflow("saturate")?;
+        let err = ctx.sql("SELECT a + a FROM t").await?.collect().await;
+        assert_eq!(
+            err.unwrap_err().strip_backtrace(),
+            "Error during planning: Invalid arithmetic overflow mode 'saturate', \
+            expected one of 'error', 'wrap' or 'null'"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn shared_memory_and_disk_manager() {
         // Demonstrate the ability to share DiskManager and
@@ -2573,6 +2698,71 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn table_names_and_existence() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.register_table("t", test::table_with_sequence(1, 1)?)?;
+
+        let catalog = MemoryCatalogProvider::new();
+        for schema_name in ["schema_a", "Schema_B"] {
+            let schema = MemorySchemaProvider::new();
+            schema.register_table("t".to_owned(), test::table_with_sequence(1, 1)?)?;
+            schema.register_table("u".to_owned(), test::table_with_sequence(1, 1)?)?;
+            catalog.register_schema(schema_name, Arc::new(schema))?;
+        }
+        ctx.register_catalog("catalog_a", Arc::new(catalog));
+
+        assert_eq!(
+            ctx.table_names(),
+            [
+                r#"catalog_a."Schema_B".t"#,
+                r#"catalog_a."Schema_B".u"#,
+                "catalog_a.schema_a.t",
+                "catalog_a.schema_a.u",
+                "datafusion.public.t",
+            ]
+        );
+        // The names refer to their tables:
+        for name in ctx.table_names() {
+            assert!(ctx.table_exist(TableReference::parse_str(&name))?);
+        }
+
+        // Unqualified references are resolved against the default catalog and
+        // schema.
+        assert!(ctx.table_exist("t")?);
+        assert!(ctx.table_exist("public.t")?);
+        assert!(!ctx.table_exist("u")?);
+        assert!(ctx.table_exist(&TableReference::full("catalog_a", "schema_a", "u"))?);
+        assert!(!ctx.table_exist(TableReference::full("catalog_a", "schema_a", "v"))?);
+        assert!(ctx
+            .table_exist(TableReference::partial("schema_a", "t"))
+            .is_err());
+        assert!(ctx.table_exist("catalog_b.schema_a.t").is_err());
+
+        let ctx = SessionContext::new_with_config(
+            SessionConfig::new()
+                .with_default_catalog_and_schema("catalog_a", "schema_a")
+                .with_create_default_catalog_and_schema(false),
+        );
+        let catalog = MemoryCatalogProvider::new();
+        let schema = MemorySchemaProvider::new();
+        schema.register_table("u".to_owned(), test::table_with_sequence(1, 1)?)?;
+        catalog.register_schema("schema_a", Arc::new(schema))?;
+        ctx.register_catalog("catalog_a", Arc::new(catalog));
+        assert!(ctx.table_exist("u")?);
+        assert_eq!(ctx.table_names(), ["catalog_a.schema_a.u"]);
+
+        // The tables of the information schema are listed if it is enabled.
+        let ctx = SessionContext::new_with_config(
+            SessionConfig::new().with_information_schema(true),
+        );
+        let names = ctx.table_names();
+        assert!(names.contains(&"datafusion.information_schema.tables".to_string()));
+        assert!(ctx.table_exist("information_schema.tables")?);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn catalogs_not_leaked() {
         // the information schema used to introduce cyclic Arcs
@@ -1226,13 +1226,13 @@ mod util_tests {
         assert!(check_support(&supported_expr_2, &schema));
         let unsupported_expr = Arc::new(BinaryExpr::new(
             Arc::new(Column::new("a", 0)),
-            Operator::Or,
+            Operator::Multiply,
             Arc::new(Column::new("a", 0)),
         )) as Arc<dyn PhysicalExpr>;
         assert!(!check_support(&unsupported_expr, &schema));
         let unsupported_expr_2 = Arc::new(BinaryExpr::new(
             Arc::new(Column::new("a", 0)),
-            Operator::Or,
+            Operator::Multiply,
             Arc::new(NegativeExpr::new(Arc::new(Column::new("a", 0)))),
         )) as Arc<dyn PhysicalExpr>;
         assert!(!check_support(&unsupported_expr_2, &schema));
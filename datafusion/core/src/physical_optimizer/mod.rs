@@ -31,6 +31,7 @@ pub mod optimizer;
 pub mod output_requirements;
 pub mod pipeline_checker;
 pub mod pruning;
+pub mod redundant_predicate_elimination;
 pub mod replace_with_order_preserving_variants;
 mod sort_pushdown;
 pub mod topk_aggregation;
@@ -0,0 +1,172 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! RedundantPredicateElimination optimizer rule removes the parts of filter
+//! predicates which the statistics of the filter input decide.
+
+use std::sync::Arc;
+
+use super::optimizer::PhysicalOptimizerRule;
+use crate::config::ConfigOptions;
+use crate::error::Result;
+use crate::physical_plan::empty::EmptyExec;
+use crate::physical_plan::filter::FilterExec;
+use crate::physical_plan::ExecutionPlan;
+
+use datafusion_common::stats::Precision;
+use datafusion_common::tree_node::{Transformed, TreeNode};
+use datafusion_common::ColumnStatistics;
+use datafusion_expr::Operator;
+use datafusion_physical_expr::expressions::BinaryExpr;
+use datafusion_physical_expr::intervals::utils::check_support;
+use datafusion_physical_expr::utils::collect_columns;
+use datafusion_physical_expr::{
+    analyze_many, split_conjunction, AnalysisContext, PhysicalExpr, SelectivityEstimate,
+};
+
+/// Optimizer rule that uses the column statistics of the input of each
+/// [`FilterExec`] to find the conjuncts of its predicate which hold for every
+/// row, and those which hold for none:
+///
+/// - Conjuncts which certainly hold are removed, as is the [`FilterExec`]
+///   itself if none of its conjuncts remain.
+/// - If any conjunct certainly does not hold, the [`FilterExec`] is replaced
+///   by an [`EmptyExec`].
+///
+/// Both are only decided from exact statistics, see
+/// [`AnalysisContext::selectivity_precision`], and only for conjuncts whose
+/// columns all have exact minimum and maximum values. The other conjuncts are
+/// kept unchanged.
+#[derive(Default)]
+pub struct RedundantPredicateElimination {}
+
+impl RedundantPredicateElimination {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PhysicalOptimizerRule for RedundantPredicateElimination {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if !config.optimizer.enable_redundant_predicate_elimination {
+            return Ok(plan);
+        }
+        plan.transform_up(&|plan| {
+            let Some(filter) = plan.as_any().downcast_ref::<FilterExec>() else {
+                return Ok(Transformed::No(plan));
+            };
+            Ok(match eliminate_redundant_predicates(filter)? {
+                Some(new_plan) => Transformed::Yes(new_plan),
+                None => Transformed::No(plan),
+            })
+        })
+    }
+
+    fn name(&self) -> &str {
+        "RedundantPredicateElimination"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the plan replacing `filter`, or `None` if the statistics of its
+/// input decide none of the conjuncts of its predicate.
+fn eliminate_redundant_predicates(
+    filter: &FilterExec,
+) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+    let input = filter.input();
+    let schema = input.schema();
+    let conjuncts = split_conjunction(filter.predicate());
+    let supported = conjuncts
+        .iter()
+        .copied()
+        .filter(|conjunct| check_support(conjunct, &schema))
+        .cloned()
+        .collect::<Vec<_>>();
+    if supported.is_empty() {
+        return Ok(None);
+    }
+
+    let input_stats = input.statistics()?;
+    let context =
+        AnalysisContext::try_from_statistics(&schema, &input_stats.column_statistics)?
+            .with_num_rows(input_stats.num_rows);
+    let mut analyses = analyze_many(&supported, context)?.into_iter();
+
+    let mut remaining = vec![];
+    for conjunct in conjuncts.iter() {
+        let selectivity = if check_support(conjunct, &schema) {
+            analyses.next().and_then(|analysis| {
+                exact_selectivity(conjunct, analysis, &input_stats.column_statistics)
+            })
+        } else {
+            None
+        };
+        match selectivity {
+            Some(selectivity) if selectivity.upper_bound <= 0.0 => {
+                let empty = EmptyExec::new(false, filter.schema());
+                return Ok(Some(Arc::new(empty)));
+            }
+            Some(selectivity) if selectivity.lower_bound >= 1.0 => {}
+            _ => remaining.push(Arc::clone(conjunct)),
+        }
+    }
+    if remaining.len() == conjuncts.len() {
+        return Ok(None);
+    }
+
+    let predicate = remaining.into_iter().reduce(|left, right| {
+        Arc::new(BinaryExpr::new(left, Operator::And, right)) as Arc<dyn PhysicalExpr>
+    });
+    let new_plan: Arc<dyn ExecutionPlan> = match predicate {
+        Some(predicate) => Arc::new(FilterExec::try_new(predicate, input.clone())?),
+        None => input.clone(),
+    };
+    // Removing an `IS NOT NULL` conjunct makes its column nullable again in
+    // the output schema, which must not change
+    if new_plan.schema() != filter.schema() {
+        return Ok(None);
+    }
+    Ok(Some(new_plan))
+}
+
+/// Returns the selectivity of the analyzed predicate if it is exact, i.e. if
+/// the statistics prove the predicate to be always true or always false, and
+/// the minimum and maximum values of all columns it references are exact.
+fn exact_selectivity(
+    predicate: &Arc<dyn PhysicalExpr>,
+    analysis: AnalysisContext,
+    column_statistics: &[ColumnStatistics],
+) -> Option<SelectivityEstimate> {
+    let exact_bounds = collect_columns(predicate).iter().all(|column| {
+        column_statistics.get(column.index()).is_some_and(|stats| {
+            stats.min_value.is_exact() == Some(true)
+                && stats.max_value.is_exact() == Some(true)
+        })
+    });
+    match analysis.selectivity_precision {
+        Precision::Exact(_) if exact_bounds => analysis.selectivity,
+        _ => None,
+    }
+}
@@ -21,6 +21,7 @@ use std::collections::HashMap;
 use std::fmt::Write;
 use std::sync::Arc;
 
+use crate::dataframe::DeclaredOrder;
 use crate::datasource::file_format::arrow::ArrowFormat;
 use crate::datasource::file_format::avro::AvroFormat;
 use crate::datasource::file_format::csv::CsvFormat;
@@ -59,6 +60,7 @@ use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
 use crate::physical_plan::memory::MemoryExec;
 use crate::physical_plan::projection::ProjectionExec;
 use crate::physical_plan::repartition::RepartitionExec;
+use crate::physical_plan::sorts::declared_order::DeclaredOrderExec;
 use crate::physical_plan::sorts::sort::SortExec;
 use crate::physical_plan::union::UnionExec;
 use crate::physical_plan::unnest::UnnestExec;
@@ -92,6 +94,7 @@ use datafusion_expr::{
     DescribeTable, DmlStatement, StringifiedPlan, WindowFrame, WindowFrameBound, WriteOp,
 };
 use datafusion_physical_expr::expressions::Literal;
+use datafusion_physical_expr::utils::ordering_satisfy;
 use datafusion_sql::utils::window_expr_common_partition_keys;
 
 use async_trait::async_trait;
@@ -1278,6 +1281,32 @@ impl DefaultPhysicalPlanner {
                 LogicalPlan::Analyze(_) => internal_err!(
                     "Unsupported logical plan: Analyze must be root of the plan"
                 ),
+                LogicalPlan::Extension(e) if e.node.as_any().is::<DeclaredOrder>() => {
+                    let DeclaredOrder { input, expr } = e.node.as_any().downcast_ref::<DeclaredOrder>().unwrap();
+                    let physical_input = self.create_initial_plan(input, session_state).await?;
+                    let input_schema = physical_input.as_ref().schema();
+                    let input_dfschema = input.as_ref().schema();
+                    let sort_expr = expr
+                        .iter()
+                        .map(|e| create_physical_sort_expr(
+                            e,
+                            input_dfschema,
+                            &input_schema,
+                            session_state.execution_props(),
+                        ))
+                        .collect::<Result<Vec<_>>>()?;
+                    // No marker is needed if the input already provides the ordering
+                    if ordering_satisfy(
+                        physical_input.output_ordering(),
+                        Some(&sort_expr),
+                        || physical_input.equivalence_properties(),
+                        || physical_input.ordering_equivalence_properties(),
+                    ) {
+                        Ok(physical_input)
+                    } else {
+                        Ok(Arc::new(DeclaredOrderExec::new(sort_expr, physical_input)))
+                    }
+                }
                 LogicalPlan::Extension(e) => {
                     let physical_inputs = self.create_initial_plan_multi(e.node.inputs(), session_state).await?;
 
@@ -25,8 +25,9 @@ use datafusion::{
     error::Result,
     logical_expr::Expr,
     physical_plan::{
-        expressions::PhysicalSortExpr, ColumnStatistics, DisplayAs, DisplayFormatType,
-        ExecutionPlan, Partitioning, SendableRecordBatchStream, Statistics,
+        displayable, expressions::PhysicalSortExpr, ColumnStatistics, DisplayAs,
+        DisplayFormatType, ExecutionPlan, Partitioning, SendableRecordBatchStream,
+        Statistics,
     },
     prelude::SessionContext,
     scalar::ScalarValue,
@@ -186,12 +187,14 @@ fn fully_defined() -> (Statistics, Schema) {
                     max_value: Precision::Exact(ScalarValue::Int32(Some(1023))),
                     min_value: Precision::Exact(ScalarValue::Int32(Some(-24))),
                     null_count: Precision::Exact(0),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Exact(13),
                     max_value: Precision::Exact(ScalarValue::Int64(Some(5486))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(-6783))),
                     null_count: Precision::Exact(5),
+                    histogram: None,
                 },
             ],
         },
@@ -284,3 +287,94 @@ async fn sql_window() -> Result<()> {
 
     Ok(())
 }
+
+async fn physical_plan_of(ctx: &SessionContext, sql: &str) -> Result<String> {
+    let physical_plan = ctx.sql(sql).await?.create_physical_plan().await?;
+    let displayed = displayable(physical_plan.as_ref()).indent(true).to_string();
+    Ok(displayed)
+}
+
+#[tokio::test]
+async fn sql_filter_always_true() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx = init_ctx(stats, schema)?;
+
+    // c1 is within [-24, 1023] and never NULL, so the filter is removed
+    let plan =
+        physical_plan_of(&ctx, "SELECT * FROM stats_table WHERE c1 > -100").await?;
+    assert!(!plan.contains("FilterExec"), "{plan}");
+    assert!(plan.contains("StatisticsValidation"), "{plan}");
+
+    // c2 holds NULL values, which the filter removes
+    let plan =
+        physical_plan_of(&ctx, "SELECT * FROM stats_table WHERE c2 > -10000").await?;
+    assert!(plan.contains("FilterExec: c2@1 > -10000"), "{plan}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sql_filter_always_false() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx = init_ctx(stats, schema)?;
+
+    let plan =
+        physical_plan_of(&ctx, "SELECT * FROM stats_table WHERE c1 > 2000 AND c2 > 5")
+            .await?;
+    assert!(plan.contains("EmptyExec: produce_one_row=false"), "{plan}");
+    assert!(!plan.contains("FilterExec"), "{plan}");
+    assert!(!plan.contains("StatisticsValidation"), "{plan}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sql_filter_partially_redundant() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx = init_ctx(stats, schema)?;
+
+    // only the conjunct which the statistics do not decide is kept
+    let plan = physical_plan_of(
+        &ctx,
+        "SELECT * FROM stats_table WHERE c1 > -100 AND c1 < 100",
+    )
+    .await?;
+    assert!(plan.contains("FilterExec: c1@0 < 100\n"), "{plan}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sql_filter_inexact_bounds() -> Result<()> {
+    let (mut stats, schema) = fully_defined();
+    let c1_stats = &mut stats.column_statistics[0];
+    c1_stats.min_value = c1_stats.min_value.clone().to_inexact();
+    c1_stats.max_value = c1_stats.max_value.clone().to_inexact();
+    let ctx = init_ctx(stats, schema)?;
+
+    // the bounds of c1 may not hold for every row, so the filters are kept
+    let plan =
+        physical_plan_of(&ctx, "SELECT * FROM stats_table WHERE c1 > -100").await?;
+    assert!(plan.contains("FilterExec: c1@0 > -100"), "{plan}");
+
+    let plan =
+        physical_plan_of(&ctx, "SELECT * FROM stats_table WHERE c1 > 2000").await?;
+    assert!(plan.contains("FilterExec: c1@0 > 2000"), "{plan}");
+    assert!(!plan.contains("EmptyExec"), "{plan}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sql_filter_redundant_predicate_elimination_disabled() -> Result<()> {
+    let (stats, schema) = fully_defined();
+    let ctx = init_ctx(stats, schema)?;
+    ctx.sql("SET datafusion.optimizer.enable_redundant_predicate_elimination = false")
+        .await?;
+
+    let plan =
+        physical_plan_of(&ctx, "SELECT * FROM stats_table WHERE c1 > 2000").await?;
+    assert!(plan.contains("FilterExec: c1@0 > 2000"), "{plan}");
+
+    Ok(())
+}
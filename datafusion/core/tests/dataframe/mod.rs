@@ -1029,6 +1029,122 @@ async fn unnest_column_nulls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn unnest_multiple_columns() -> Result<()> {
+    // Unnesting a single column is the same as `unnest_column`
+    let df = table_with_lists_and_nulls().await?;
+    let results = df.clone().unnest_columns(&["list"])?.collect().await?;
+    let expected = df.clone().unnest_column("list")?.collect().await?;
+    assert_eq!(results, expected);
+
+    // Nulls are preserved, but empty lists (row with C) produce no rows
+    let options = UnnestOptions::new().with_preserve_nulls(true);
+    let results = df
+        .clone()
+        .unnest_columns_with_options(&["list"], options)?
+        .collect()
+        .await?;
+    let expected = [
+        "+------+----+",
+        "| list | id |",
+        "+------+----+",
+        "| 1    | A  |",
+        "| 2    | A  |",
+        "|      | B  |",
+        "| 3    | D  |",
+        "+------+----+",
+    ];
+    assert_batches_eq!(expected, &results);
+
+    // In outer mode, nulls and empty lists both produce a row with a null
+    let options = UnnestOptions::new()
+        .with_preserve_nulls(false)
+        .with_outer(true);
+    let results = df
+        .clone()
+        .unnest_columns_with_options(&["list"], options)?
+        .collect()
+        .await?;
+    let expected = [
+        "+------+----+",
+        "| list | id |",
+        "+------+----+",
+        "| 1    | A  |",
+        "| 2    | A  |",
+        "|      | B  |",
+        "|      | C  |",
+        "| 3    | D  |",
+        "+------+----+",
+    ];
+    assert_batches_eq!(expected, &results);
+
+    // Unnesting a non-list column is an error
+    let err = df.clone().unnest_columns(&["id"]).unwrap_err();
+    assert_eq!(
+        err.strip_backtrace(),
+        "Error during planning: Cannot unnest column id of non-list type Utf8"
+    );
+
+    // Unnesting multiple columns yields the cross product of the lists
+    let df = table_with_nested_types(4).await?;
+    let results = df.unnest_columns(&["points", "tags"])?.collect().await?;
+    let expected = [
+        "+----------+-----------------+------+",
+        "| shape_id | points          | tags |",
+        "+----------+-----------------+------+",
+        "| 1        | {x: -3, y: -4}  | tag1 |",
+        "| 1        | {x: -3, y: 6}   | tag1 |",
+        "| 1        | {x: 2, y: -2}   | tag1 |",
+        "| 2        |                 | tag1 |",
+        "| 2        |                 | tag2 |",
+        "| 3        | {x: -10, y: -4} |      |",
+        "| 3        | {x: -9, y: 2}   |      |",
+        "| 4        | {x: -3, y: 5}   | tag1 |",
+        "| 4        | {x: -3, y: 5}   | tag2 |",
+        "| 4        | {x: -3, y: 5}   | tag3 |",
+        "| 4        | {x: 2, y: -1}   | tag1 |",
+        "| 4        | {x: 2, y: -1}   | tag2 |",
+        "| 4        | {x: 2, y: -1}   | tag3 |",
+        "+----------+-----------------+------+",
+    ];
+    assert_batches_sorted_eq!(expected, &results);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unnest_recursive() -> Result<()> {
+    let ctx = SessionContext::new();
+    let df = ctx
+        .sql("SELECT make_array(make_array(1, 2), make_array(3)) AS list")
+        .await?;
+
+    // Only the outermost list is unnested by default
+    let results = df.clone().unnest_columns(&["list"])?.collect().await?;
+    let expected = [
+        "+--------+",
+        "| list   |",
+        "+--------+",
+        "| [1, 2] |",
+        "| [3]    |",
+        "+--------+",
+    ];
+    assert_batches_eq!(expected, &results);
+
+    let options = UnnestOptions::new().with_recursive(true);
+    let results = df
+        .unnest_columns_with_options(&["list"], options)?
+        .collect()
+        .await?;
+    let expected = [
+        "+------+", "| list |", "+------+", "| 1    |", "| 2    |", "| 3    |",
+        "+------+",
+    ];
+    assert_batches_eq!(expected, &results);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn unnest_fixed_list() -> Result<()> {
     let batch = get_fixed_list_batch()?;
@@ -18,15 +18,18 @@
 //! Built-in functions module contains all the built-in functions definitions.
 
 use crate::nullif::SUPPORTED_NULLIF_TYPES;
-use crate::signature::TIMEZONE_WILDCARD;
+use crate::signature::{DECIMAL_PRECISION_WILDCARD, TIMEZONE_WILDCARD};
 use crate::type_coercion::functions::data_types;
 use crate::{
     conditional_expressions, struct_expressions, utils, FuncMonotonicity, Signature,
     TypeSignature, Volatility,
 };
-use arrow::datatypes::{DataType, Field, Fields, IntervalUnit, TimeUnit};
+use arrow::datatypes::{
+    DataType, Field, Fields, IntervalUnit, TimeUnit, DECIMAL128_MAX_PRECISION,
+    DECIMAL256_MAX_PRECISION,
+};
 use datafusion_common::{
-    internal_err, plan_datafusion_err, plan_err, DataFusionError, Result,
+    internal_err, plan_datafusion_err, plan_err, DataFusionError, Result, ScalarValue,
 };
 use std::collections::HashMap;
 use std::fmt;
@@ -265,6 +268,8 @@ pub enum BuiltinScalarFunction {
     ToTimestampSeconds,
     /// from_unixtime
     FromUnixtime,
+    /// to_unixtime
+    ToUnixtime,
     ///now
     Now,
     ///current_date
@@ -446,6 +451,7 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::RegexpMatch => Volatility::Immutable,
             BuiltinScalarFunction::Struct => Volatility::Immutable,
             BuiltinScalarFunction::FromUnixtime => Volatility::Immutable,
+            BuiltinScalarFunction::ToUnixtime => Volatility::Immutable,
             BuiltinScalarFunction::ArrowTypeof => Volatility::Immutable,
 
             // Stable builtin functions
@@ -749,7 +755,12 @@ impl BuiltinScalarFunction {
             BuiltinScalarFunction::ToTimestampMillis => Ok(Timestamp(Millisecond, None)),
             BuiltinScalarFunction::ToTimestampMicros => Ok(Timestamp(Microsecond, None)),
             BuiltinScalarFunction::ToTimestampSeconds => Ok(Timestamp(Second, None)),
-            BuiltinScalarFunction::FromUnixtime => Ok(Timestamp(Second, None)),
+            BuiltinScalarFunction::FromUnixtime => match input_expr_types.len() {
+                // an explicit unit may be finer than seconds
+                2 => Ok(Timestamp(Nanosecond, None)),
+                _ => Ok(Timestamp(Second, None)),
+            },
+            BuiltinScalarFunction::ToUnixtime => Ok(Int64),
             BuiltinScalarFunction::Now => {
                 Ok(Timestamp(Nanosecond, Some("+00:00".into())))
             }
@@ -812,23 +823,43 @@ impl BuiltinScalarFunction {
 
             BuiltinScalarFunction::Abs => Ok(input_expr_types[0].clone()),
 
+            // Decimals rounded to places that vary per row keep their scale,
+            // see `return_type_from_literals`. Rounding away from zero may
+            // take an additional digit, e.g. `round(9.9, x)` is `10.0`:
+            BuiltinScalarFunction::Trunc
+                if matches!(input_expr_types[0], Decimal128(_, _) | Decimal256(_, _)) =>
+            {
+                Ok(input_expr_types[0].clone())
+            }
+            BuiltinScalarFunction::Ceil
+            | BuiltinScalarFunction::Floor
+            | BuiltinScalarFunction::Round => match input_expr_types[0] {
+                Decimal128(precision, scale) => Ok(Decimal128(
+                    (precision + 1).min(DECIMAL128_MAX_PRECISION),
+                    scale,
+                )),
+                Decimal256(precision, scale) => Ok(Decimal256(
+                    (precision + 1).min(DECIMAL256_MAX_PRECISION),
+                    scale,
+                )),
+                Float32 => Ok(Float32),
+                _ => Ok(Float64),
+            },
+
             BuiltinScalarFunction::Acos
             | BuiltinScalarFunction::Asin
             | BuiltinScalarFunction::Atan
             | BuiltinScalarFunction::Acosh
             | BuiltinScalarFunction::Asinh
             | BuiltinScalarFunction::Atanh
-            | BuiltinScalarFunction::Ceil
             | BuiltinScalarFunction::Cos
             | BuiltinScalarFunction::Cosh
             | BuiltinScalarFunction::Degrees
             | BuiltinScalarFunction::Exp
-            | BuiltinScalarFunction::Floor
             | BuiltinScalarFunction::Ln
             | BuiltinScalarFunction::Log10
             | BuiltinScalarFunction::Log2
             | BuiltinScalarFunction::Radians
-            | BuiltinScalarFunction::Round
             | BuiltinScalarFunction::Signum
             | BuiltinScalarFunction::Sin
             | BuiltinScalarFunction::Sinh
@@ -844,6 +875,68 @@ impl BuiltinScalarFunction {
         }
     }
 
+    /// Returns the output [`DataType`] of this function like [`Self::return_type`],
+    /// where `literal_args[i]` is the value of the i-th argument if it is a
+    /// literal.
+    ///
+    /// Only decimals rounded by `round`, `trunc`, `ceil` and `floor` depend on
+    /// literals: a literal number of decimal places (or no second argument,
+    /// which rounds to an integer) becomes the scale of the result, e.g.
+    /// `round(1.2345, 2)` is `1.23`. Rounding to decimal places that vary per
+    /// row keeps the scale of the input.
+    pub fn return_type_from_literals(
+        self,
+        input_expr_types: &[DataType],
+        literal_args: &[Option<ScalarValue>],
+    ) -> Result<DataType> {
+        use DataType::*;
+
+        let is_decimal_rounding = matches!(
+            self,
+            BuiltinScalarFunction::Ceil
+                | BuiltinScalarFunction::Floor
+                | BuiltinScalarFunction::Round
+                | BuiltinScalarFunction::Trunc
+        );
+        let places = match literal_args {
+            [_] => Some(0),
+            [_, Some(places)] => integer_literal(places),
+            _ => None,
+        };
+        match (is_decimal_rounding, input_expr_types, places) {
+            (true, [Decimal128(precision, scale)], Some(places))
+            | (true, [Decimal128(precision, scale), _], Some(places)) => {
+                let (precision, scale) =
+                    self.rounded_decimal_precision_and_scale(*precision, *scale, places);
+                Ok(Decimal128(precision.min(DECIMAL128_MAX_PRECISION), scale))
+            }
+            (true, [Decimal256(precision, scale)], Some(places))
+            | (true, [Decimal256(precision, scale), _], Some(places)) => {
+                let (precision, scale) =
+                    self.rounded_decimal_precision_and_scale(*precision, *scale, places);
+                Ok(Decimal256(precision.min(DECIMAL256_MAX_PRECISION), scale))
+            }
+            _ => self.return_type(input_expr_types),
+        }
+    }
+
+    /// Returns the precision and scale of a decimal with the given precision
+    /// and scale rounded to `places` decimal places. The integer digits are
+    /// kept, and all but `trunc` may take an additional one, as `ceil(9.5)`
+    /// is `10`. More places than the scale leave the decimals unchanged.
+    fn rounded_decimal_precision_and_scale(
+        self,
+        precision: u8,
+        scale: i8,
+        places: i64,
+    ) -> (u8, i8) {
+        let rounded_scale = places.clamp(scale.min(0) as i64, scale as i64);
+        let carry = i64::from(self != BuiltinScalarFunction::Trunc);
+        let rounded_precision = (precision as i64 - scale as i64 + rounded_scale + carry)
+            .clamp(1, u8::MAX as i64);
+        (rounded_precision as u8, rounded_scale as i8)
+    }
+
     /// Return the argument [`Signature`] supported by this function
     pub fn signature(&self) -> Signature {
         use DataType::*;
@@ -851,6 +944,8 @@ impl BuiltinScalarFunction {
         use TimeUnit::*;
         use TypeSignature::*;
         // note: the physical expression must accept the type returned by this function or the execution panics.
+        let decimal128 = Decimal128(DECIMAL_PRECISION_WILDCARD, 0);
+        let decimal256 = Decimal256(DECIMAL_PRECISION_WILDCARD, 0);
 
         // for now, the list is small, as we do not have many built-in functions.
         match self {
@@ -1000,9 +1095,22 @@ impl BuiltinScalarFunction {
                 ],
                 self.volatility(),
             ),
-            BuiltinScalarFunction::FromUnixtime => {
-                Signature::uniform(1, vec![Int64], self.volatility())
-            }
+            BuiltinScalarFunction::FromUnixtime => Signature::one_of(
+                vec![Exact(vec![Int64]), Exact(vec![Int64, Utf8])],
+                self.volatility(),
+            ),
+            BuiltinScalarFunction::ToUnixtime => Signature::one_of(
+                [Nanosecond, Microsecond, Millisecond, Second]
+                    .into_iter()
+                    .flat_map(|unit| {
+                        [
+                            Exact(vec![Timestamp(unit.clone(), None)]),
+                            Exact(vec![Timestamp(unit, Some(TIMEZONE_WILDCARD.into()))]),
+                        ]
+                    })
+                    .collect(),
+                self.volatility(),
+            ),
             BuiltinScalarFunction::Digest => Signature::one_of(
                 vec![
                     Exact(vec![Utf8, Utf8]),
@@ -1202,6 +1310,10 @@ impl BuiltinScalarFunction {
             ),
             BuiltinScalarFunction::Round => Signature::one_of(
                 vec![
+                    Exact(vec![decimal128.clone(), Int64]),
+                    Exact(vec![decimal256.clone(), Int64]),
+                    Exact(vec![decimal128]),
+                    Exact(vec![decimal256]),
                     Exact(vec![Float64, Int64]),
                     Exact(vec![Float32, Int64]),
                     Exact(vec![Float64]),
@@ -1211,6 +1323,10 @@ impl BuiltinScalarFunction {
             ),
             BuiltinScalarFunction::Trunc => Signature::one_of(
                 vec![
+                    Exact(vec![decimal128.clone(), Int64]),
+                    Exact(vec![decimal256.clone(), Int64]),
+                    Exact(vec![decimal128]),
+                    Exact(vec![decimal256]),
                     Exact(vec![Float32, Int64]),
                     Exact(vec![Float64, Int64]),
                     Exact(vec![Float64]),
@@ -1243,6 +1359,21 @@ impl BuiltinScalarFunction {
             }
             BuiltinScalarFunction::ArrowTypeof => Signature::any(1, self.volatility()),
             BuiltinScalarFunction::Abs => Signature::any(1, self.volatility()),
+            // Only decimals can be rounded up or down to a number of places,
+            // which is not available in SQL, as `CEIL` and `FLOOR` are parsed
+            // with a single argument:
+            BuiltinScalarFunction::Ceil | BuiltinScalarFunction::Floor => {
+                Signature::one_of(
+                    vec![
+                        Exact(vec![decimal128.clone(), Int64]),
+                        Exact(vec![decimal256.clone(), Int64]),
+                        Exact(vec![decimal128]),
+                        Exact(vec![decimal256]),
+                        Uniform(1, vec![Float64, Float32]),
+                    ],
+                    self.volatility(),
+                )
+            }
 
             BuiltinScalarFunction::Acos
             | BuiltinScalarFunction::Asin
@@ -1251,12 +1382,10 @@ impl BuiltinScalarFunction {
             | BuiltinScalarFunction::Asinh
             | BuiltinScalarFunction::Atanh
             | BuiltinScalarFunction::Cbrt
-            | BuiltinScalarFunction::Ceil
             | BuiltinScalarFunction::Cos
             | BuiltinScalarFunction::Cosh
             | BuiltinScalarFunction::Degrees
             | BuiltinScalarFunction::Exp
-            | BuiltinScalarFunction::Floor
             | BuiltinScalarFunction::Ln
             | BuiltinScalarFunction::Log10
             | BuiltinScalarFunction::Log2
@@ -1425,6 +1554,7 @@ fn aliases(func: &BuiltinScalarFunction) -> &'static [&'static str] {
         BuiltinScalarFunction::ToTimestampMicros => &["to_timestamp_micros"],
         BuiltinScalarFunction::ToTimestampSeconds => &["to_timestamp_seconds"],
         BuiltinScalarFunction::FromUnixtime => &["from_unixtime"],
+        BuiltinScalarFunction::ToUnixtime => &["to_unixtime"],
 
         // hashing functions
         BuiltinScalarFunction::Digest => &["digest"],
@@ -1587,6 +1717,21 @@ fn utf8_or_binary_to_binary_type(arg_type: &DataType, name: &str) -> Result<Data
     })
 }
 
+/// Returns the value of an integer literal, e.g. a number of decimal places.
+fn integer_literal(value: &ScalarValue) -> Option<i64> {
+    match value {
+        ScalarValue::Int8(value) => value.map(i64::from),
+        ScalarValue::Int16(value) => value.map(i64::from),
+        ScalarValue::Int32(value) => value.map(i64::from),
+        ScalarValue::Int64(value) => *value,
+        ScalarValue::UInt8(value) => value.map(i64::from),
+        ScalarValue::UInt16(value) => value.map(i64::from),
+        ScalarValue::UInt32(value) => value.map(i64::from),
+        ScalarValue::UInt64(value) => value.and_then(|value| i64::try_from(value).ok()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1603,4 +1748,42 @@ mod tests {
             assert_eq!(func_from_str, *func_original);
         }
     }
+
+    #[test]
+    fn test_decimal_rounding_return_type_from_literals() {
+        use DataType::*;
+        let return_type = |fun: BuiltinScalarFunction, places: Option<Option<i64>>| {
+            let (types, literals) = match places {
+                None => (vec![Decimal128(10, 4)], vec![None]),
+                Some(places) => (
+                    vec![Decimal128(10, 4), Int64],
+                    vec![None, places.map(|places| ScalarValue::Int64(Some(places)))],
+                ),
+            };
+            fun.return_type_from_literals(&types, &literals).unwrap()
+        };
+        let round = BuiltinScalarFunction::Round;
+        let trunc = BuiltinScalarFunction::Trunc;
+
+        assert_eq!(return_type(round, None), Decimal128(7, 0));
+        assert_eq!(return_type(round, Some(Some(2))), Decimal128(9, 2));
+        assert_eq!(return_type(round, Some(Some(6))), Decimal128(11, 4));
+        assert_eq!(return_type(round, Some(Some(-2))), Decimal128(7, 0));
+        assert_eq!(return_type(trunc, Some(Some(2))), Decimal128(8, 2));
+        assert_eq!(
+            return_type(BuiltinScalarFunction::Ceil, None),
+            Decimal128(7, 0)
+        );
+        // Decimal places that are not a literal keep the scale:
+        assert_eq!(return_type(round, Some(None)), Decimal128(11, 4));
+        assert_eq!(return_type(trunc, Some(None)), Decimal128(10, 4));
+
+        // The precision is limited to the maximum:
+        let types = [Decimal128(38, 10), Int64];
+        let literals = [None, Some(ScalarValue::Int32(Some(10)))];
+        assert_eq!(
+            round.return_type_from_literals(&types, &literals).unwrap(),
+            Decimal128(38, 10)
+        );
+    }
 }
@@ -846,6 +846,12 @@ scalar_expr!(
     unixtime,
     "returns the unix time in format"
 );
+scalar_expr!(
+    ToUnixtime,
+    to_unixtime,
+    timestamp,
+    "returns the number of seconds since the unix epoch of a timestamp as `Int64`"
+);
 scalar_expr!(CurrentDate, current_date, ,"returns current UTC date as a [`DataType::Date32`] value");
 scalar_expr!(Now, now, ,"returns current timestamp in nanoseconds, using the same value for all instances of now() in same statement");
 scalar_expr!(CurrentTime, current_time, , "returns current UTC time as a [`DataType::Time64`] value");
@@ -1119,6 +1125,7 @@ mod test {
         test_scalar_expr!(DateTrunc, date_trunc, part, date);
         test_scalar_expr!(DateBin, date_bin, stride, source, origin);
         test_scalar_expr!(FromUnixtime, from_unixtime, unixtime);
+        test_scalar_expr!(ToUnixtime, to_unixtime, timestamp);
 
         test_scalar_expr!(ArrayAppend, array_append, array, element);
         test_scalar_expr!(ArrayPopBack, array_pop_back, array);
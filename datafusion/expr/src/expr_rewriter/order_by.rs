@@ -17,9 +17,9 @@
 
 //! Rewrite for order by expressions
 
-use crate::expr::{Alias, Sort};
+use crate::expr::{AggregateFunction, Alias, Sort};
 use crate::expr_rewriter::normalize_col;
-use crate::{Cast, Expr, ExprSchemable, LogicalPlan, TryCast};
+use crate::{aggregate_function, Cast, Expr, ExprSchemable, LogicalPlan, TryCast};
 use datafusion_common::tree_node::{Transformed, TreeNode};
 use datafusion_common::{Column, Result};
 
@@ -131,10 +131,52 @@ fn rewrite_in_terms_of_projection(
             }));
         }
 
+        // a column that is functionally dependent on the GROUP BY keys is
+        // only available as `FIRST_VALUE(col) AS name`, so sort on that
+        if let Some(found) = find_first_value_of(&expr, &proj_exprs, input) {
+            return Ok(Transformed::Yes(found));
+        }
+
         Ok(Transformed::No(expr))
     })
 }
 
+/// Finds the projection output of `FIRST_VALUE(expr) AS name` for a column
+/// that is aggregated below `input`, returning it as a column reference.
+fn find_first_value_of(
+    expr: &Expr,
+    proj_exprs: &[Expr],
+    input: &LogicalPlan,
+) -> Option<Expr> {
+    if !matches!(expr, Expr::Column(_)) {
+        return None;
+    }
+    let expr = match input.inputs().as_slice() {
+        [agg_input] => {
+            normalize_col(expr.clone(), agg_input).unwrap_or_else(|_| expr.clone())
+        }
+        _ => expr.clone(),
+    };
+    let first_value = Expr::AggregateFunction(AggregateFunction::new(
+        aggregate_function::AggregateFunction::FirstValue,
+        vec![expr],
+        false,
+        None,
+        None,
+    ));
+    // the projection may already refer to the aggregate output column
+    let first_value_col =
+        Expr::Column(Column::from_name(first_value.display_name().ok()?));
+    proj_exprs.iter().find_map(|proj_expr| match proj_expr {
+        Expr::Alias(Alias { expr, name })
+            if expr.as_ref() == &first_value || expr.as_ref() == &first_value_col =>
+        {
+            Some(Expr::Column(Column::from_name(name)))
+        }
+        _ => None,
+    })
+}
+
 /// Does the underlying expr match e?
 /// so avg(c) as average will match avgc
 fn expr_match(needle: &Expr, expr: &Expr) -> bool {
@@ -255,6 +297,50 @@ mod test {
         }
     }
 
+    #[test]
+    fn rewrite_sort_cols_by_agg_first_value() {
+        let agg = make_input()
+            .aggregate(
+                // gby c1
+                vec![col("c1")],
+                // agg: first_value(c2)
+                vec![first_value(col("c2"))],
+            )
+            .unwrap()
+            // projects out the dependent column c2 under its own name
+            .project(vec![col("c1"), col("FIRST_VALUE(t.c2)").alias("c2")])
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let cases = vec![
+            TestCase {
+                desc: r#"t.c2 --> "c2" -- (column *named* "c2" that holds FIRST_VALUE(t.c2))"#,
+                input: sort(col("t.c2")),
+                expected: sort(col("c2")),
+            },
+            TestCase {
+                desc: r#"t.c1 --> t.c1 -- (not aggregated)"#,
+                input: sort(col("t.c1")),
+                expected: sort(col("t.c1")),
+            },
+        ];
+
+        for case in cases {
+            case.run(&agg)
+        }
+    }
+
+    fn first_value(expr: Expr) -> Expr {
+        Expr::AggregateFunction(AggregateFunction::new(
+            aggregate_function::AggregateFunction::FirstValue,
+            vec![expr],
+            false,
+            None,
+            None,
+        ))
+    }
+
     #[test]
     fn preserve_cast() {
         let plan = make_input()
@@ -28,7 +28,7 @@ use arrow::compute::can_cast_types;
 use arrow::datatypes::{DataType, Field};
 use datafusion_common::{
     internal_err, plan_datafusion_err, plan_err, Column, DFField, DFSchema,
-    DataFusionError, ExprSchema, Result,
+    DataFusionError, ExprSchema, Result, ScalarValue,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -93,8 +93,9 @@ impl ExprSchemable for Expr {
                     .iter()
                     .map(|e| e.get_type(schema))
                     .collect::<Result<Vec<_>>>()?;
+                let literal_args = args.iter().map(literal_value).collect::<Vec<_>>();
 
-                fun.return_type(&data_types)
+                fun.return_type_from_literals(&data_types, &literal_args)
             }
             Expr::WindowFunction(WindowFunction { fun, args, .. }) => {
                 let data_types = args
@@ -355,6 +356,19 @@ fn field_for_index<S: ExprSchema>(
     .get_accessed_field(&expr_dt)
 }
 
+/// return the value of `expr` if it is a literal, which may have been cast by
+/// type coercion, so that constant folding does not change the types of
+/// functions that depend on literal arguments.
+fn literal_value(expr: &Expr) -> Option<ScalarValue> {
+    match expr {
+        Expr::Literal(value) => Some(value.clone()),
+        Expr::Cast(Cast { expr, .. }) | Expr::TryCast(TryCast { expr, .. }) => {
+            literal_value(expr)
+        }
+        _ => None,
+    }
+}
+
 /// cast subquery in InSubquery/ScalarSubquery to a given type.
 pub fn cast_subquery(subquery: Subquery, cast_to_type: &DataType) -> Result<Subquery> {
     if subquery.subquery.schema().field(0).data_type() == cast_to_type {
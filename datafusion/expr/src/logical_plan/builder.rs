@@ -1531,12 +1531,25 @@ pub fn unnest_with_options(
             .with_functional_dependencies(input_schema.functional_dependencies().clone()),
     );
 
-    Ok(LogicalPlan::Unnest(Unnest {
+    let column = unnested_field.qualified_column();
+    let recurse = options.recursive
+        && matches!(
+            unnested_field.data_type(),
+            DataType::List(_) | DataType::FixedSizeList(_, _) | DataType::LargeList(_)
+        );
+    let plan = LogicalPlan::Unnest(Unnest {
         input: Arc::new(input),
-        column: unnested_field.qualified_column(),
+        column: column.clone(),
         schema,
-        options,
-    }))
+        options: options.clone(),
+    });
+
+    if recurse {
+        // Unnest the inner lists as well:
+        unnest_with_options(plan, column, options)
+    } else {
+        Ok(plan)
+    }
 }
 
 #[cfg(test)]
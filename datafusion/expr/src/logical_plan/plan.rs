@@ -1598,10 +1598,18 @@ impl LogicalPlan {
                         filter,
                         join_constraint,
                         join_type,
+                        null_equals_null,
                         ..
                     }) => {
-                        let join_expr: Vec<String> =
-                            keys.iter().map(|(l, r)| format!("{l} = {r}")).collect();
+                        let eq_op = if *null_equals_null {
+                            "IS NOT DISTINCT FROM"
+                        } else {
+                            "="
+                        };
+                        let join_expr: Vec<String> = keys
+                            .iter()
+                            .map(|(l, r)| format!("{l} {eq_op} {r}"))
+                            .collect();
                         let filter_expr = filter
                             .as_ref()
                             .map(|expr| format!(" Filter: {expr}"))
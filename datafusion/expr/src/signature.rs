@@ -30,6 +30,17 @@ use arrow::datatypes::DataType;
 /// return results with this timezone.
 pub const TIMEZONE_WILDCARD: &str = "+TZ";
 
+/// Constant that is used as a placeholder for any valid decimal precision
+/// and scale. A [`DataType::Decimal128`] or [`DataType::Decimal256`] with this
+/// precision (and any scale) matches decimals of the same type with any
+/// precision and scale, which is never zero for valid decimal types. See
+/// [`TypeSignature`] for more details.
+///
+/// Type coercion always ensures that functions will be executed using
+/// decimal arrays that have a valid precision. Functions must never return
+/// results with this precision.
+pub const DECIMAL_PRECISION_WILDCARD: u8 = 0;
+
 ///A function's volatility, which defines the functions eligibility for certain optimizations
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 pub enum Volatility {
@@ -80,6 +91,9 @@ pub enum Volatility {
 ///   DataType::Timestamp(TimeUnit::Nanosecond, Some(TIMEZONE_WILDCARD.into())),
 /// ]);
 /// ```
+///
+/// Similarly, to specify a function can handle a decimal with *ANY* precision
+/// and scale, use the [`DECIMAL_PRECISION_WILDCARD`].
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypeSignature {
     /// arbitrary number of arguments of an common type out of a list of valid types.
@@ -16,7 +16,7 @@
 // under the License.
 
 use arrow::datatypes::{
-    DataType, TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE,
+    DataType, IntervalUnit, TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE,
     DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
 };
 
@@ -100,7 +100,16 @@ pub fn coerce_types(
         AggregateFunction::Count | AggregateFunction::ApproxDistinct => {
             Ok(input_types.to_vec())
         }
-        AggregateFunction::ArrayAgg => Ok(input_types.to_vec()),
+        AggregateFunction::ArrayAgg => {
+            if input_types.len() == 2 && !is_integer_arg_type(&input_types[1]) {
+                return plan_err!(
+                    "The LIMIT of {:?} must be integer, not {:?}.",
+                    agg_fun,
+                    input_types[1]
+                );
+            }
+            Ok(input_types.to_vec())
+        }
         AggregateFunction::Min | AggregateFunction::Max => {
             // min and max support the dictionary data type
             // unpack the dictionary to get the value
@@ -115,6 +124,9 @@ pub fn coerce_types(
                 d if d.is_signed_integer() => Int64,
                 d if d.is_unsigned_integer() => UInt64,
                 d if d.is_floating() => Float64,
+                d @ (Duration(_)
+                | Interval(IntervalUnit::DayTime)
+                | Interval(IntervalUnit::MonthDayNano)) => d.clone(),
                 Dictionary(_, v) => {
                     return coerce_types(agg_fun, &[v.as_ref().clone()], signature)
                 }
@@ -135,6 +147,9 @@ pub fn coerce_types(
                 Decimal128(p, s) => Decimal128(*p, *s),
                 Decimal256(p, s) => Decimal256(*p, *s),
                 d if d.is_numeric() => Float64,
+                d @ (Duration(_)
+                | Interval(IntervalUnit::DayTime)
+                | Interval(IntervalUnit::MonthDayNano)) => d.clone(),
                 Dictionary(_, v) => {
                     return coerce_types(agg_fun, &[v.as_ref().clone()], signature)
                 }
@@ -394,6 +409,11 @@ pub fn sum_return_type(arg_type: &DataType) -> Result<DataType> {
             let new_precision = DECIMAL256_MAX_PRECISION.min(*precision + 10);
             Ok(DataType::Decimal256(new_precision, *scale))
         }
+        // Intervals and durations are summed component-wise in their own type
+        DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano) => {
+            Ok(arg_type.clone())
+        }
         other => plan_err!("SUM does not support type \"{other:?}\""),
     }
 }
@@ -452,6 +472,10 @@ pub fn avg_return_type(arg_type: &DataType) -> Result<DataType> {
             Ok(DataType::Decimal256(new_precision, new_scale))
         }
         arg_type if NUMERICS.contains(arg_type) => Ok(DataType::Float64),
+        DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano) => {
+            Ok(arg_type.clone())
+        }
         DataType::Dictionary(_, dict_value_type) => {
             avg_return_type(dict_value_type.as_ref())
         }
@@ -473,6 +497,10 @@ pub fn avg_sum_type(arg_type: &DataType) -> Result<DataType> {
             Ok(DataType::Decimal256(new_precision, *scale))
         }
         arg_type if NUMERICS.contains(arg_type) => Ok(DataType::Float64),
+        DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano) => {
+            Ok(arg_type.clone())
+        }
         DataType::Dictionary(_, dict_value_type) => {
             avg_sum_type(dict_value_type.as_ref())
         }
@@ -496,7 +524,9 @@ pub fn is_sum_support_arg_type(arg_type: &DataType) -> bool {
         _ => matches!(
             arg_type,
             arg_type if NUMERICS.contains(arg_type)
-            || matches!(arg_type, DataType::Decimal128(_, _) | DataType::Decimal256(_, _))
+            || matches!(arg_type, DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
+                | DataType::Duration(_)
+                | DataType::Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano))
         ),
     }
 }
@@ -509,7 +539,9 @@ pub fn is_avg_support_arg_type(arg_type: &DataType) -> bool {
         _ => matches!(
             arg_type,
             arg_type if NUMERICS.contains(arg_type)
-                || matches!(arg_type, DataType::Decimal128(_, _)| DataType::Decimal256(_, _))
+                || matches!(arg_type, DataType::Decimal128(_, _)| DataType::Decimal256(_, _)
+                    | DataType::Duration(_)
+                    | DataType::Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano))
         ),
     }
 }
@@ -154,6 +154,14 @@ fn signature(lhs: &DataType, op: &Operator, rhs: &DataType) -> Result<Signature>
                     rhs: rhs.clone(),
                     ret,
                 })
+            } else if let Some((lhs, rhs)) = temporal_scaling_coercion(lhs, op, rhs) {
+                // Scaling of intervals and durations, e.g. Interval * Int64
+                let ret = if rhs.is_numeric() { lhs.clone() } else { rhs.clone() };
+                Ok(Signature{
+                    lhs,
+                    rhs,
+                    ret,
+                })
             } else if let Some(coerced) = temporal_coercion(lhs, rhs) {
                 // Temporal arithmetic by first coercing to a common time representation
                 // e.g. Date32 - Timestamp
@@ -791,6 +799,43 @@ fn temporal_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataTyp
     }
 }
 
+/// Coercion rules for multiplying intervals and durations by numbers, in either
+/// order, and for dividing them by numbers. Intervals are coerced to
+/// `Interval(MonthDayNano)`, and numbers to `Int64` if they are integers and to
+/// `Float64` otherwise. Returns the coerced types of the left and right side.
+fn temporal_scaling_coercion(
+    lhs_type: &DataType,
+    op: &Operator,
+    rhs_type: &DataType,
+) -> Option<(DataType, DataType)> {
+    use arrow::datatypes::DataType::*;
+    use arrow::datatypes::IntervalUnit::*;
+
+    let temporal = |data_type: &DataType| match data_type {
+        Interval(_) => Some(Interval(MonthDayNano)),
+        Duration(unit) => Some(Duration(unit.clone())),
+        _ => None,
+    };
+    let factor = |data_type: &DataType| {
+        if data_type.is_integer() {
+            Some(Int64)
+        } else if data_type.is_numeric() {
+            Some(Float64)
+        } else {
+            None
+        }
+    };
+    match op {
+        Operator::Multiply => match (temporal(lhs_type), temporal(rhs_type)) {
+            (Some(lhs), None) => Some((lhs, factor(rhs_type)?)),
+            (None, Some(rhs)) => Some((factor(lhs_type)?, rhs)),
+            _ => None,
+        },
+        Operator::Divide => Some((temporal(lhs_type)?, factor(rhs_type)?)),
+        _ => None,
+    }
+}
+
 /// coercion rules from NULL type. Since NULL can be casted to any other type in arrow,
 /// either lhs or rhs is NULL, if NULL can be casted to type of the other side, the coercion is valid.
 fn null_coercion(lhs_type: &DataType, rhs_type: &DataType) -> Option<DataType> {
@@ -1223,6 +1268,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_temporal_scaling_coercion() -> Result<()> {
+        use arrow::datatypes::IntervalUnit;
+
+        let interval = DataType::Interval(IntervalUnit::MonthDayNano);
+        let duration = DataType::Duration(TimeUnit::Millisecond);
+        let cases = [
+            (
+                DataType::Interval(IntervalUnit::DayTime),
+                Operator::Multiply,
+                DataType::Int32,
+                (interval.clone(), DataType::Int64),
+            ),
+            (
+                DataType::UInt8,
+                Operator::Multiply,
+                interval.clone(),
+                (DataType::Int64, interval.clone()),
+            ),
+            (
+                interval.clone(),
+                Operator::Divide,
+                DataType::Float32,
+                (interval.clone(), DataType::Float64),
+            ),
+            (
+                duration.clone(),
+                Operator::Multiply,
+                DataType::Decimal128(10, 2),
+                (duration.clone(), DataType::Float64),
+            ),
+            (
+                duration.clone(),
+                Operator::Divide,
+                DataType::Int64,
+                (duration.clone(), DataType::Int64),
+            ),
+        ];
+        for (lhs, op, rhs, (coerced_lhs, coerced_rhs)) in cases {
+            let result_type = if coerced_lhs.is_numeric() {
+                coerced_rhs.clone()
+            } else {
+                coerced_lhs.clone()
+            };
+            assert_eq!(
+                get_input_types(&lhs, &op, &rhs)?,
+                (coerced_lhs, coerced_rhs)
+            );
+            assert_eq!(get_result_type(&lhs, &op, &rhs)?, result_type);
+        }
+
+        // Numbers can not be divided by intervals, nor can intervals be
+        // multiplied with each other.
+        assert!(get_input_types(&DataType::Int64, &Operator::Divide, &interval).is_err());
+        assert!(get_input_types(&interval, &Operator::Multiply, &interval).is_err());
+        Ok(())
+    }
+
     fn test_math_decimal_coercion_rule(
         lhs_type: DataType,
         rhs_type: DataType,
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::signature::TIMEZONE_WILDCARD;
+use crate::signature::{DECIMAL_PRECISION_WILDCARD, TIMEZONE_WILDCARD};
 use crate::{Signature, TypeSignature};
 use arrow::{
     compute::can_cast_types,
@@ -244,6 +244,18 @@ fn coerced_from<'a>(
                 _ => None,
             }
         }
+        Decimal128(precision, _) if *precision == DECIMAL_PRECISION_WILDCARD => {
+            match type_from {
+                Decimal128(_, _) => Some(type_from.clone()),
+                _ => None,
+            }
+        }
+        Decimal256(precision, _) if *precision == DECIMAL_PRECISION_WILDCARD => {
+            match type_from {
+                Decimal256(_, _) => Some(type_from.clone()),
+                _ => None,
+            }
+        }
         Timestamp(_, Some(_))
             if matches!(
                 type_from,
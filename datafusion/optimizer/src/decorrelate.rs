@@ -20,7 +20,7 @@ use crate::utils::{
     collect_subquery_cols, conjunction, find_join_exprs, split_conjunction,
 };
 use datafusion_common::tree_node::{
-    RewriteRecursion, Transformed, TreeNode, TreeNodeRewriter,
+    RewriteRecursion, Transformed, TreeNode, TreeNodeRewriter, VisitRecursion,
 };
 use datafusion_common::{plan_err, Result};
 use datafusion_common::{Column, DFSchemaRef, DataFusionError, ScalarValue};
@@ -329,6 +329,91 @@ impl PullUpCorrelatedExpr {
     }
 }
 
+/// Describes, for each correlated subquery in `analyzed_plan`, whether the
+/// subquery decorrelation rules rewrote it into a join in `optimized_plan`,
+/// followed by the joins which were introduced by those rules.
+///
+/// Correlated subqueries which are still present in `optimized_plan` are
+/// flagged, as they are not supported by the physical planner.
+pub fn describe_decorrelation(
+    analyzed_plan: &LogicalPlan,
+    optimized_plan: &LogicalPlan,
+) -> Result<String> {
+    let mut remaining = correlated_subqueries(optimized_plan)?;
+    let mut lines = vec![];
+    for (kind, outer_ref_columns) in correlated_subqueries(analyzed_plan)? {
+        let outer_refs = outer_ref_columns
+            .iter()
+            .map(|expr| expr.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let status = match remaining.iter().position(|(remaining_kind, columns)| {
+            *remaining_kind == kind && *columns == outer_ref_columns
+        }) {
+            Some(index) => {
+                remaining.remove(index);
+                "not decorrelated, which is not supported by the physical planner"
+            }
+            None => "decorrelated",
+        };
+        lines.push(format!(
+            "{kind} subquery referencing {outer_refs}: {status}"
+        ));
+    }
+    if lines.is_empty() {
+        lines.push("no correlated subqueries".to_string());
+    }
+
+    let mut joins = vec![];
+    optimized_plan.apply(&mut |plan| {
+        if let LogicalPlan::Join(join) = plan {
+            if let LogicalPlan::SubqueryAlias(alias) = join.right.as_ref() {
+                let name = alias.alias.table();
+                if name.starts_with("__correlated_sq") || name.starts_with("__scalar_sq")
+                {
+                    joins.push(format!("  {}", plan.display()));
+                    let subquery = join.right.display_indent().to_string();
+                    joins.extend(subquery.lines().map(|line| format!("    {line}")));
+                }
+            }
+        }
+        Ok(VisitRecursion::Continue)
+    })?;
+    if !joins.is_empty() {
+        lines.push("joins introduced by decorrelation:".to_string());
+        lines.extend(joins);
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Returns the kind and the outer reference columns of every correlated
+/// subquery in `plan`, including the ones nested in other subqueries
+fn correlated_subqueries(plan: &LogicalPlan) -> Result<Vec<(&'static str, Vec<Expr>)>> {
+    let mut subqueries = vec![];
+    plan.apply(&mut |plan| {
+        for expr in plan.expressions() {
+            expr.apply(&mut |expr| {
+                let (kind, subquery) = match expr {
+                    Expr::Exists(expr::Exists { subquery, negated }) => {
+                        (if *negated { "NOT EXISTS" } else { "EXISTS" }, subquery)
+                    }
+                    Expr::InSubquery(expr::InSubquery {
+                        subquery, negated, ..
+                    }) => (if *negated { "NOT IN" } else { "IN" }, subquery),
+                    Expr::ScalarSubquery(subquery) => ("scalar", subquery),
+                    _ => return Ok(VisitRecursion::Continue),
+                };
+                if !subquery.outer_ref_columns.is_empty() {
+                    subqueries.push((kind, subquery.outer_ref_columns.clone()));
+                }
+                Ok(VisitRecursion::Continue)
+            })?;
+        }
+        Ok(VisitRecursion::Continue)
+    })?;
+    Ok(subqueries)
+}
+
 fn collect_local_correlated_cols(
     plan: &LogicalPlan,
     all_cols_map: &HashMap<LogicalPlan, BTreeSet<Column>>,
@@ -0,0 +1,274 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Optimizer rule to remove a `DISTINCT` over an input that is already unique.
+
+use crate::optimizer::ApplyOrder;
+use crate::{OptimizerConfig, OptimizerRule};
+use datafusion_common::Result;
+use datafusion_expr::expr::{AggregateFunction, Alias};
+use datafusion_expr::logical_plan::{Aggregate, Distinct, LogicalPlan, Projection};
+use datafusion_expr::{aggregate_function, Expr};
+
+/// Optimizer rule that removes a [`Distinct`], or an [`Aggregate`] without
+/// aggregate expressions, when its grouping columns contain a unique,
+/// non-nullable key of the input (e.g. a primary key). Such an input has no
+/// duplicate rows to remove, so
+///
+/// ```text
+/// SELECT DISTINCT id, name FROM tab  -- `id` is the primary key of `tab`
+/// ```
+///
+/// is rewritten to
+///
+/// ```text
+/// SELECT id, name FROM tab
+/// ```
+///
+/// Each group of such an [`Aggregate`] has a single row, so its `FIRST_VALUE`
+/// and `LAST_VALUE` aggregate expressions, e.g. those of the columns selected
+/// for being dependent on the grouping columns, are replaced by their arguments
+/// as well.
+///
+/// Uniqueness is derived from the functional dependencies tracked in the
+/// input schema, which include the `PRIMARY KEY` and `UNIQUE` constraints
+/// declared on table sources. DataFusion does not enforce these constraints,
+/// so this rule assumes the data satisfies them: if a declared key contains
+/// duplicates, the rewritten query returns them as well.
+///
+/// Uniqueness implied by statistics (e.g. a `distinct_count` equal to the
+/// number of rows) is not used, as statistics are not available to logical
+/// plans.
+#[derive(Default)]
+pub struct EliminateDistinct {}
+
+impl EliminateDistinct {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for EliminateDistinct {
+    fn try_optimize(
+        &self,
+        plan: &LogicalPlan,
+        _config: &dyn OptimizerConfig,
+    ) -> Result<Option<LogicalPlan>> {
+        match plan {
+            LogicalPlan::Distinct(Distinct { input }) => {
+                let schema = input.schema();
+                let indices = (0..schema.fields().len()).collect::<Vec<_>>();
+                if schema
+                    .functional_dependencies()
+                    .contains_unique_key(&indices)
+                {
+                    Ok(Some(input.as_ref().clone()))
+                } else {
+                    Ok(None)
+                }
+            }
+            LogicalPlan::Aggregate(Aggregate {
+                input,
+                group_expr,
+                aggr_expr,
+                ..
+            }) => {
+                let Some(aggr_values) = aggr_expr
+                    .iter()
+                    .map(single_row_value)
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    return Ok(None);
+                };
+                let schema = input.schema();
+                // Only plain column references can be matched against the
+                // determinant keys of the input:
+                let indices = group_expr
+                    .iter()
+                    .map(|expr| match expr {
+                        Expr::Column(column) => schema.index_of_column(column).ok(),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+                match indices {
+                    Some(indices)
+                        if schema
+                            .functional_dependencies()
+                            .contains_unique_key(&indices) =>
+                    {
+                        let mut exprs = group_expr.clone();
+                        for (aggr_expr, value) in aggr_expr.iter().zip(aggr_values) {
+                            exprs.push(value.alias(aggr_expr.display_name()?));
+                        }
+                        let projection = Projection::try_new(exprs, input.clone())?;
+                        Ok(Some(LogicalPlan::Projection(projection)))
+                    }
+                    _ => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "eliminate_distinct"
+    }
+
+    fn apply_order(&self) -> Option<ApplyOrder> {
+        Some(ApplyOrder::BottomUp)
+    }
+}
+
+/// Returns the value of the aggregate `expr` for a group with a single row, if
+/// it is the value of its argument in that row, i.e. `expr` is a `FIRST_VALUE`
+/// or `LAST_VALUE` without a filter.
+fn single_row_value(expr: &Expr) -> Option<Expr> {
+    match expr {
+        Expr::AggregateFunction(AggregateFunction {
+            fun:
+                aggregate_function::AggregateFunction::FirstValue
+                | aggregate_function::AggregateFunction::LastValue,
+            args,
+            filter: None,
+            ..
+        }) if args.len() == 1 => Some(args[0].clone()),
+        Expr::Alias(Alias { expr, .. }) => single_row_value(expr),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::{assert_optimized_plan_eq, test_table_scan_fields};
+    use arrow::datatypes::{Schema, SchemaRef};
+    use datafusion_common::{Constraint, Constraints};
+    use datafusion_expr::{col, sum, LogicalPlanBuilder, TableSource};
+    use std::any::Any;
+    use std::sync::Arc;
+
+    /// Table source with a primary key on column `a`
+    struct PrimaryKeyTableSource {
+        schema: SchemaRef,
+        constraints: Constraints,
+    }
+
+    impl TableSource for PrimaryKeyTableSource {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn schema(&self) -> SchemaRef {
+            self.schema.clone()
+        }
+
+        fn constraints(&self) -> Option<&Constraints> {
+            Some(&self.constraints)
+        }
+    }
+
+    fn primary_key_scan() -> Result<LogicalPlanBuilder> {
+        let source = PrimaryKeyTableSource {
+            schema: Arc::new(Schema::new(test_table_scan_fields())),
+            constraints: Constraints::new_unverified(vec![Constraint::PrimaryKey(vec![
+                0,
+            ])]),
+        };
+        LogicalPlanBuilder::scan("test", Arc::new(source), None)
+    }
+
+    fn assert_optimized_plan_equal(plan: &LogicalPlan, expected: &str) -> Result<()> {
+        assert_optimized_plan_eq(Arc::new(EliminateDistinct::new()), plan, expected)
+    }
+
+    #[test]
+    fn eliminate_distinct_over_primary_key() -> Result<()> {
+        let plan = primary_key_scan()?
+            .project(vec![col("a"), col("b")])?
+            .distinct()?
+            .build()?;
+
+        let expected = "Projection: test.a, test.b\
+                        \n  TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn eliminate_group_by_over_primary_key() -> Result<()> {
+        let plan = primary_key_scan()?
+            .aggregate(vec![col("b"), col("a")], Vec::<Expr>::new())?
+            .build()?;
+
+        let expected = "Projection: test.b, test.a\
+                        \n  TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn eliminate_group_by_with_first_value_over_primary_key() -> Result<()> {
+        let first_value = |expr| {
+            Expr::AggregateFunction(AggregateFunction::new(
+                aggregate_function::AggregateFunction::FirstValue,
+                vec![expr],
+                false,
+                None,
+                None,
+            ))
+        };
+        let plan = primary_key_scan()?
+            .aggregate(vec![col("a")], vec![first_value(col("b"))])?
+            .build()?;
+
+        let expected = "Projection: test.a, test.b AS FIRST_VALUE(test.b)\
+                        \n  TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)?;
+
+        let plan = primary_key_scan()?
+            .aggregate(vec![col("a")], vec![first_value(col("b")), sum(col("c"))])?
+            .build()?;
+
+        let expected =
+            "Aggregate: groupBy=[[test.a]], aggr=[[FIRST_VALUE(test.b), SUM(test.c)]]\
+                        \n  TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn retain_distinct_over_non_unique_column() -> Result<()> {
+        let plan = primary_key_scan()?
+            .project(vec![col("b"), col("c")])?
+            .distinct()?
+            .build()?;
+
+        let expected = "Distinct:\
+                        \n  Projection: test.b, test.c\
+                        \n    TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+
+    #[test]
+    fn retain_group_by_over_non_unique_column() -> Result<()> {
+        let plan = primary_key_scan()?
+            .aggregate(vec![col("b")], Vec::<Expr>::new())?
+            .build()?;
+
+        let expected = "Aggregate: groupBy=[[test.b]], aggr=[[]]\
+                        \n  TableScan: test";
+        assert_optimized_plan_equal(&plan, expected)
+    }
+}
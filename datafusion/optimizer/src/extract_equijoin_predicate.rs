@@ -39,6 +39,10 @@ type EquijoinPredicate = (Expr, Expr);
 /// has one equijoin predicate (`A.x = B.y`) and one filter predicate (`B.z > 50`).
 /// See [find_valid_equijoin_key_pair] for more information on what predicates
 /// are considered equijoins.
+///
+/// If a join has no equijoin predicates, `IS NOT DISTINCT FROM` predicates such as
+/// `A.x IS NOT DISTINCT FROM B.y` are used as join keys instead, and the join is
+/// marked as `null_equals_null` so that NULL keys match each other.
 #[derive(Default)]
 pub struct ExtractEquijoinPredicate;
 
@@ -70,13 +74,30 @@ impl OptimizerRule for ExtractEquijoinPredicate {
                 let right_schema = right.schema();
 
                 filter.as_ref().map_or(Result::Ok(None), |expr| {
-                    let (equijoin_predicates, non_equijoin_expr) =
+                    let (mut equijoin_predicates, mut non_equijoin_expr) =
                         split_eq_and_noneq_join_predicate(
                             expr,
+                            Operator::Eq,
                             left_schema,
                             right_schema,
                         )?;
 
+                    // `IS NOT DISTINCT FROM` predicates can only be used as join
+                    // keys if all join keys treat NULL values as equal.
+                    let mut null_equals_null = *null_equals_null;
+                    if equijoin_predicates.is_empty()
+                        && (on.is_empty() || null_equals_null)
+                    {
+                        (equijoin_predicates, non_equijoin_expr) =
+                            split_eq_and_noneq_join_predicate(
+                                expr,
+                                Operator::IsNotDistinctFrom,
+                                left_schema,
+                                right_schema,
+                            )?;
+                        null_equals_null |= !equijoin_predicates.is_empty();
+                    }
+
                     let optimized_plan = (!equijoin_predicates.is_empty()).then(|| {
                         let mut new_on = on.clone();
                         new_on.extend(equijoin_predicates);
@@ -89,7 +110,7 @@ impl OptimizerRule for ExtractEquijoinPredicate {
                             join_type: *join_type,
                             join_constraint: *join_constraint,
                             schema: schema.clone(),
-                            null_equals_null: *null_equals_null,
+                            null_equals_null,
                         })
                     });
 
@@ -109,8 +130,11 @@ impl OptimizerRule for ExtractEquijoinPredicate {
     }
 }
 
+/// Splits the conjunctive `filter` into join key pairs, which are compared with
+/// `eq_op` (either `=` or `IS NOT DISTINCT FROM`), and the remaining predicates.
 fn split_eq_and_noneq_join_predicate(
     filter: &Expr,
+    eq_op: Operator,
     left_schema: &Arc<DFSchema>,
     right_schema: &Arc<DFSchema>,
 ) -> Result<(Vec<EquijoinPredicate>, Option<Expr>)> {
@@ -120,11 +144,7 @@ fn split_eq_and_noneq_join_predicate(
     let mut accum_filters: Vec<Expr> = vec![];
     for expr in exprs {
         match expr {
-            Expr::BinaryExpr(BinaryExpr {
-                left,
-                op: Operator::Eq,
-                right,
-            }) => {
+            Expr::BinaryExpr(BinaryExpr { left, op, right }) if *op == eq_op => {
                 let left = left.as_ref();
                 let right = right.as_ref();
 
@@ -160,9 +180,10 @@ fn split_eq_and_noneq_join_predicate(
 mod tests {
     use super::*;
     use crate::test::*;
+    use crate::OptimizerContext;
     use arrow::datatypes::DataType;
     use datafusion_expr::{
-        col, lit, logical_plan::builder::LogicalPlanBuilder, JoinType,
+        binary_expr, col, lit, logical_plan::builder::LogicalPlanBuilder, JoinType,
     };
 
     fn assert_plan_eq(plan: &LogicalPlan, expected: &str) -> Result<()> {
@@ -378,4 +399,66 @@ mod tests {
 
         assert_plan_eq(&plan, expected)
     }
+
+    fn optimized_null_equals_null(plan: &LogicalPlan) -> Result<bool> {
+        let optimized = ExtractEquijoinPredicate::new()
+            .try_optimize(plan, &OptimizerContext::new())?
+            .unwrap();
+        match optimized {
+            LogicalPlan::Join(join) => Ok(join.null_equals_null),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn join_with_only_is_not_distinct_from_predicate() -> Result<()> {
+        let t1 = test_table_scan_with_name("t1")?;
+        let t2 = test_table_scan_with_name("t2")?;
+
+        let plan = LogicalPlanBuilder::from(t1)
+            .join_on(
+                t2,
+                JoinType::Inner,
+                Some(
+                    binary_expr(col("t1.a"), Operator::IsNotDistinctFrom, col("t2.a"))
+                        .and(col("t1.b").lt(col("t2.b"))),
+                ),
+            )?
+            .build()?;
+        let expected = "Inner Join: t1.a IS NOT DISTINCT FROM t2.a Filter: t1.b < t2.b [a:UInt32, b:UInt32, c:UInt32, a:UInt32, b:UInt32, c:UInt32]\
+            \n  TableScan: t1 [a:UInt32, b:UInt32, c:UInt32]\
+            \n  TableScan: t2 [a:UInt32, b:UInt32, c:UInt32]";
+
+        assert_plan_eq(&plan, expected)?;
+        assert!(optimized_null_equals_null(&plan)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn join_with_eq_and_is_not_distinct_from_predicates() -> Result<()> {
+        let t1 = test_table_scan_with_name("t1")?;
+        let t2 = test_table_scan_with_name("t2")?;
+
+        // `IS NOT DISTINCT FROM` predicates stay in the filter, since the
+        // equijoin predicate does not consider NULL values equal.
+        let plan = LogicalPlanBuilder::from(t1)
+            .join_on(
+                t2,
+                JoinType::Inner,
+                Some(
+                    binary_expr(col("t1.a"), Operator::IsNotDistinctFrom, col("t2.a"))
+                        .and(col("t1.b").eq(col("t2.b"))),
+                ),
+            )?
+            .build()?;
+        let expected = "Inner Join: t1.b = t2.b Filter: t1.a IS NOT DISTINCT FROM t2.a [a:UInt32, b:UInt32, c:UInt32, a:UInt32, b:UInt32, c:UInt32]\
+            \n  TableScan: t1 [a:UInt32, b:UInt32, c:UInt32]\
+            \n  TableScan: t2 [a:UInt32, b:UInt32, c:UInt32]";
+
+        assert_plan_eq(&plan, expected)?;
+        assert!(!optimized_null_equals_null(&plan)?);
+
+        Ok(())
+    }
 }
@@ -376,6 +376,9 @@ fn push_down_all_join(
 
     // For infer predicates, if they can not push through join, just drop them
     for predicate in infer_predicates {
+        if left_push.contains(&predicate) || right_push.contains(&predicate) {
+            continue;
+        }
         if left_preserved && can_pushdown_join_predicate(&predicate, left.schema())? {
             left_push.push(predicate);
         } else if right_preserved
@@ -473,6 +476,103 @@ fn push_down_all_join(
     }
 }
 
+/// Derives `col = literal` predicates for an inner join by propagating the
+/// constants that the predicates equate columns to over the equivalence
+/// classes of the join keys. For example, given
+///
+/// ```sql
+/// SELECT * FROM a JOIN b ON a.x = b.y JOIN c ON b.y = c.z WHERE a.x = 5
+/// ```
+///
+/// `b.y = 5` and `c.z = 5` are derived for the upper join, as the keys of the
+/// lower join make `a.x`, `b.y` and `c.z` equivalent. Besides the keys of
+/// `join`, the classes include the keys of the inner joins below it (as long as
+/// only filters are in between) and the column equalities in `predicates` and
+/// `on_filters`.
+fn infer_constant_predicates(
+    join: &Join,
+    predicates: &[Expr],
+    on_filters: &[Expr],
+) -> Result<Vec<Expr>> {
+    let mut equalities = vec![];
+    collect_join_key_equalities(join, &mut equalities);
+    let mut constants = vec![];
+    for predicate in predicates.iter().chain(on_filters) {
+        if let Expr::BinaryExpr(BinaryExpr {
+            left,
+            op: Operator::Eq,
+            right,
+        }) = predicate
+        {
+            match (left.as_ref(), right.as_ref()) {
+                (Expr::Column(l), Expr::Column(r)) => {
+                    equalities.push((l.clone(), r.clone()))
+                }
+                (Expr::Column(column), Expr::Literal(_))
+                | (Expr::Literal(_), Expr::Column(column)) => {
+                    constants.push((column, predicate))
+                }
+                _ => {}
+            }
+        }
+    }
+    if constants.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // Merge the equalities into equivalence classes, keeping the columns in
+    // order of appearance so that the inferred predicates are deterministic:
+    let mut classes: Vec<Vec<Column>> = vec![];
+    for (l, r) in equalities {
+        let (merged, mut rest): (Vec<_>, Vec<_>) = classes
+            .into_iter()
+            .partition(|class| class.contains(&l) || class.contains(&r));
+        let class = merged
+            .into_iter()
+            .flatten()
+            .chain([l, r])
+            .unique()
+            .collect();
+        rest.push(class);
+        classes = rest;
+    }
+
+    let mut inferred = vec![];
+    for (column, predicate) in constants {
+        let Some(class) = classes.iter().find(|class| class.contains(column)) else {
+            continue;
+        };
+        for other in class.iter().filter(|other| *other != column) {
+            let replace = HashMap::from([(column, other)]);
+            inferred.push(replace_col(predicate.clone(), &replace)?);
+        }
+    }
+    Ok(inferred)
+}
+
+/// Collects the column pairs equated by the keys of the inner `join` and of
+/// the inner joins below it, looking through filters only.
+fn collect_join_key_equalities(join: &Join, equalities: &mut Vec<(Column, Column)>) {
+    if join.join_type != JoinType::Inner {
+        return;
+    }
+    equalities.extend(join.on.iter().filter_map(|(l, r)| {
+        match (l.try_into_col(), r.try_into_col()) {
+            (Ok(l_col), Ok(r_col)) => Some((l_col, r_col)),
+            _ => None,
+        }
+    }));
+    for input in [join.left.as_ref(), join.right.as_ref()] {
+        let mut input = input;
+        while let LogicalPlan::Filter(filter) = input {
+            input = filter.input.as_ref();
+        }
+        if let LogicalPlan::Join(join) = input {
+            collect_join_key_equalities(join, equalities);
+        }
+    }
+}
+
 fn push_down_join(
     plan: &LogicalPlan,
     join: &Join,
@@ -556,6 +656,10 @@ fn push_down_join(
                 Some(Ok(join_side_predicate))
             })
             .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .chain(infer_constant_predicates(join, &predicates, &on_filters)?)
+            .unique()
+            .collect()
     } else {
         vec![]
     };
@@ -1565,6 +1669,55 @@ mod tests {
         assert_optimized_plan_eq(&plan, expected)
     }
 
+    /// constants are propagated over the keys of nested inner joins
+    #[test]
+    fn filter_join_on_common_propagates_constant() -> Result<()> {
+        let table_scan = test_table_scan()?;
+        let test2 = test_table_scan_with_name("test2")?;
+        let test3 = test_table_scan_with_name("test3")?;
+        let plan = LogicalPlanBuilder::from(table_scan)
+            .join(
+                test2,
+                JoinType::Inner,
+                (
+                    vec![Column::from_qualified_name("test.a")],
+                    vec![Column::from_qualified_name("test2.b")],
+                ),
+                None,
+            )?
+            .join(
+                test3,
+                JoinType::Inner,
+                (
+                    vec![Column::from_qualified_name("test2.b")],
+                    vec![Column::from_qualified_name("test3.c")],
+                ),
+                None,
+            )?
+            .filter(col("test.a").eq(lit(5u32)))?
+            .build()?;
+
+        // not part of the test, just good to know:
+        assert_eq!(
+            format!("{plan:?}"),
+            "Filter: test.a = UInt32(5)\
+            \n  Inner Join: test2.b = test3.c\
+            \n    Inner Join: test.a = test2.b\
+            \n      TableScan: test\
+            \n      TableScan: test2\
+            \n    TableScan: test3"
+        );
+
+        // `test2.b = 5` and `test3.c = 5` are derived from `test.a = 5`
+        let expected = "\
+        Inner Join: test2.b = test3.c\
+        \n  Inner Join: test.a = test2.b\
+        \n    TableScan: test, full_filters=[test.a = UInt32(5)]\
+        \n    TableScan: test2, full_filters=[test2.b = UInt32(5)]\
+        \n  TableScan: test3, full_filters=[test3.c = UInt32(5)]";
+        assert_optimized_plan_eq(&plan, expected)
+    }
+
     /// post-using-join predicates on a column common to both sides is pushed to both sides
     #[test]
     fn filter_using_join_on_common_independent() -> Result<()> {
@@ -1263,9 +1263,10 @@ impl<'a, S: SimplifyInfo> TreeNodeRewriter for Simplifier<'a, S> {
                 expr,
                 pattern,
                 negated,
-                escape_char: _,
+                escape_char,
                 case_insensitive: _,
             }) if !is_null(&expr)
+                && escape_char != Some('%')
                 && matches!(
                     pattern.as_ref(),
                     Expr::Literal(ScalarValue::Utf8(Some(pattern_str))) if pattern_str == "%"
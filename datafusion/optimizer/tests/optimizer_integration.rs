@@ -167,9 +167,9 @@ fn intersect() -> Result<()> {
     INTERSECT SELECT col_int32, col_utf8 FROM test";
     let plan = test_sql(sql)?;
     let expected =
-        "LeftSemi Join: test.col_int32 = test.col_int32, test.col_utf8 = test.col_utf8\
+        "LeftSemi Join: test.col_int32 IS NOT DISTINCT FROM test.col_int32, test.col_utf8 IS NOT DISTINCT FROM test.col_utf8\
     \n  Aggregate: groupBy=[[test.col_int32, test.col_utf8]], aggr=[[]]\
-    \n    LeftSemi Join: test.col_int32 = test.col_int32, test.col_utf8 = test.col_utf8\
+    \n    LeftSemi Join: test.col_int32 IS NOT DISTINCT FROM test.col_int32, test.col_utf8 IS NOT DISTINCT FROM test.col_utf8\
     \n      Aggregate: groupBy=[[test.col_int32, test.col_utf8]], aggr=[[]]\
     \n        TableScan: test projection=[col_int32, col_utf8]\
     \n      TableScan: test projection=[col_int32, col_utf8]\
@@ -322,7 +322,7 @@ fn push_down_filter_groupby_expr_contains_alias() {
 fn test_same_name_but_not_ambiguous() {
     let sql = "SELECT t1.col_int32 AS col_int32 FROM test t1 intersect SELECT col_int32 FROM test t2";
     let plan = test_sql(sql).unwrap();
-    let expected = "LeftSemi Join: col_int32 = t2.col_int32\
+    let expected = "LeftSemi Join: col_int32 IS NOT DISTINCT FROM t2.col_int32\
     \n  Aggregate: groupBy=[[col_int32]], aggr=[[]]\
     \n    Projection: t1.col_int32 AS col_int32\
     \n      SubqueryAlias: t1\
@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datafusion_common::ScalarValue;
+use datafusion_expr::Operator;
+use datafusion_physical_expr::expressions::{BinaryExpr, Column, Literal};
+use datafusion_physical_expr::intervals::{ExprIntervalGraph, Interval};
+use datafusion_physical_expr::PhysicalExpr;
+use rand::prelude::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+const NUM_COLUMNS: usize = 20;
+const NUM_ROW_GROUPS: usize = 1000;
+
+/// Returns the columns `c0, ..., c19` and the filter expression
+/// `c0 + c1 > 60 AND (c2 + c3 > 61 AND (...))`. Every column occurs only once,
+/// so the expression graph is a tree.
+fn filter_expr() -> (Vec<Arc<dyn PhysicalExpr>>, Arc<dyn PhysicalExpr>) {
+    let columns = (0..NUM_COLUMNS)
+        .map(|idx| {
+            Arc::new(Column::new(&format!("c{idx}"), idx)) as Arc<dyn PhysicalExpr>
+        })
+        .collect::<Vec<_>>();
+    let binary = |left, op, right| {
+        Arc::new(BinaryExpr::new(left, op, right)) as Arc<dyn PhysicalExpr>
+    };
+    let predicates = columns
+        .chunks(2)
+        .enumerate()
+        .map(|(idx, pair)| {
+            let value = ScalarValue::Int64(Some(60 + idx as i64));
+            binary(
+                binary(pair[0].clone(), Operator::Plus, pair[1].clone()),
+                Operator::Gt,
+                Arc::new(Literal::new(value)),
+            )
+        })
+        .collect::<Vec<_>>();
+    let expr = predicates
+        .into_iter()
+        .rev()
+        .reduce(|acc, predicate| binary(predicate, Operator::And, acc))
+        .unwrap();
+    (columns, expr)
+}
+
+/// Returns the column ranges for each row group, where consecutive row groups
+/// only differ in the range of a single column.
+fn row_group_ranges() -> Vec<(usize, Interval)> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..NUM_ROW_GROUPS)
+        .map(|_| {
+            let column = rng.gen_range(0..NUM_COLUMNS);
+            let lower = rng.gen_range(0..100_i64);
+            let upper = rng.gen_range(lower..=100_i64);
+            (
+                column,
+                Interval::make(Some(lower), Some(upper), (false, false)),
+            )
+        })
+        .collect()
+}
+
+/// Propagates the ranges of each row group through a graph built from scratch.
+fn rebuild(
+    columns: &[Arc<dyn PhysicalExpr>],
+    expr: &Arc<dyn PhysicalExpr>,
+    updates: &[(usize, Interval)],
+    initial: &Interval,
+) {
+    let mut ranges = vec![initial.clone(); NUM_COLUMNS];
+    for (column, interval) in updates {
+        ranges[*column] = interval.clone();
+        let mut graph = ExprIntervalGraph::try_new(expr.clone()).unwrap();
+        let mut leaf_bounds = graph
+            .gather_node_indices(columns)
+            .into_iter()
+            .zip(&ranges)
+            .map(|((_, index), interval)| (index, interval.clone()))
+            .collect::<Vec<_>>();
+        black_box(graph.update_ranges(&mut leaf_bounds).unwrap());
+    }
+}
+
+/// Propagates the ranges of each row group through a single graph, resetting
+/// only the column whose range changed.
+fn incremental(
+    columns: &[Arc<dyn PhysicalExpr>],
+    expr: &Arc<dyn PhysicalExpr>,
+    updates: &[(usize, Interval)],
+    initial: &Interval,
+) {
+    let mut graph = ExprIntervalGraph::try_new(expr.clone()).unwrap();
+    let indices = graph
+        .gather_node_indices(columns)
+        .into_iter()
+        .map(|(_, index)| index)
+        .collect::<Vec<_>>();
+    let leaf_bounds = indices
+        .iter()
+        .map(|index| (*index, initial.clone()))
+        .collect::<Vec<_>>();
+    graph.reset_intervals(&leaf_bounds);
+    for (column, interval) in updates {
+        graph.reset_intervals(&[(indices[*column], interval.clone())]);
+        black_box(graph.propagate_dirty().unwrap());
+    }
+}
+
+/// A global allocator that counts the allocations, so that the benchmarks can
+/// report how many allocations the incremental updates save.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations (including reallocations) of `f`.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let (columns, expr) = filter_expr();
+    let updates = row_group_ranges();
+    let initial = Interval::make(Some(0_i64), Some(100_i64), (false, false));
+
+    let rebuild_allocations =
+        count_allocations(|| rebuild(&columns, &expr, &updates, &initial));
+    let incremental_allocations =
+        count_allocations(|| incremental(&columns, &expr, &updates, &initial));
+    let ratio = rebuild_allocations as f64 / incremental_allocations as f64;
+    println!(
+        "interval_graph allocations: {rebuild_allocations} when rebuilding, \
+        {incremental_allocations} when incremental ({ratio:.1}x fewer)"
+    );
+    assert!(
+        ratio >= 5.0,
+        "incremental updates should allocate at least 5x less than rebuilding the graph"
+    );
+
+    c.bench_function("interval_graph_rebuild", |b| {
+        b.iter(|| rebuild(&columns, &expr, &updates, &initial))
+    });
+
+    c.bench_function("interval_graph_incremental", |b| {
+        b.iter(|| incremental(&columns, &expr, &updates, &initial))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
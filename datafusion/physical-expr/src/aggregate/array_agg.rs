@@ -18,15 +18,15 @@
 //! Defines physical expressions that can evaluated at runtime during query execution
 
 use crate::aggregate::utils::down_cast_any_ref;
-use crate::expressions::format_state_name;
+use crate::expressions::{format_state_name, Literal};
 use crate::{AggregateExpr, PhysicalExpr};
 use arrow::array::ArrayRef;
 use arrow::datatypes::{DataType, Field};
 use arrow_array::Array;
 use datafusion_common::cast::as_list_array;
 use datafusion_common::utils::wrap_into_list_array;
-use datafusion_common::Result;
 use datafusion_common::ScalarValue;
+use datafusion_common::{plan_err, DataFusionError, Result};
 use datafusion_expr::Accumulator;
 use std::any::Any;
 use std::sync::Arc;
@@ -37,6 +37,8 @@ pub struct ArrayAgg {
     name: String,
     input_data_type: DataType,
     expr: Arc<dyn PhysicalExpr>,
+    /// The literal given by `ARRAY_AGG(x LIMIT n)`, and its value
+    limit: Option<(Arc<dyn PhysicalExpr>, usize)>,
 }
 
 impl ArrayAgg {
@@ -50,8 +52,43 @@ impl ArrayAgg {
             name: name.into(),
             expr,
             input_data_type: data_type,
+            limit: None,
         }
     }
+
+    /// Only collect the first `n` values of each group, where `n` is the value
+    /// of the `limit_expr` literal.
+    pub fn with_limit(mut self, limit_expr: Arc<dyn PhysicalExpr>) -> Result<Self> {
+        let limit = validate_limit_expr(&limit_expr)?;
+        self.limit = Some((limit_expr, limit));
+        Ok(self)
+    }
+}
+
+/// Returns the maximum number of values collected by `ARRAY_AGG`, given by the
+/// literal `expr`.
+pub(crate) fn validate_limit_expr(expr: &Arc<dyn PhysicalExpr>) -> Result<usize> {
+    let value = expr
+        .as_any()
+        .downcast_ref::<Literal>()
+        .map(|lit| lit.value());
+    let limit = match value {
+        Some(ScalarValue::Int8(Some(v))) => usize::try_from(*v).ok(),
+        Some(ScalarValue::Int16(Some(v))) => usize::try_from(*v).ok(),
+        Some(ScalarValue::Int32(Some(v))) => usize::try_from(*v).ok(),
+        Some(ScalarValue::Int64(Some(v))) => usize::try_from(*v).ok(),
+        Some(ScalarValue::UInt8(Some(v))) => Some(*v as usize),
+        Some(ScalarValue::UInt16(Some(v))) => Some(*v as usize),
+        Some(ScalarValue::UInt32(Some(v))) => Some(*v as usize),
+        Some(ScalarValue::UInt64(Some(v))) => usize::try_from(*v).ok(),
+        _ => None,
+    };
+    match limit {
+        Some(limit) => Ok(limit),
+        None => plan_err!(
+            "LIMIT of ARRAY_AGG must be a non-negative integer literal, got {expr}"
+        ),
+    }
 }
 
 impl AggregateExpr for ArrayAgg {
@@ -68,9 +105,10 @@ impl AggregateExpr for ArrayAgg {
     }
 
     fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
-        Ok(Box::new(ArrayAggAccumulator::try_new(
-            &self.input_data_type,
-        )?))
+        Ok(Box::new(
+            ArrayAggAccumulator::try_new(&self.input_data_type)?
+                .with_limit(self.limit.as_ref().map(|(_, limit)| *limit)),
+        ))
     }
 
     fn state_fields(&self) -> Result<Vec<Field>> {
@@ -82,7 +120,13 @@ impl AggregateExpr for ArrayAgg {
     }
 
     fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
-        vec![self.expr.clone()]
+        let mut exprs = vec![self.expr.clone()];
+        exprs.extend(
+            self.limit
+                .as_ref()
+                .map(|(limit_expr, _)| limit_expr.clone()),
+        );
+        exprs
     }
 
     fn name(&self) -> &str {
@@ -98,6 +142,8 @@ impl PartialEq<dyn Any> for ArrayAgg {
                 self.name == x.name
                     && self.input_data_type == x.input_data_type
                     && self.expr.eq(&x.expr)
+                    && self.limit.as_ref().map(|(_, limit)| limit)
+                        == x.limit.as_ref().map(|(_, limit)| limit)
             })
             .unwrap_or(false)
     }
@@ -107,6 +153,10 @@ impl PartialEq<dyn Any> for ArrayAgg {
 pub(crate) struct ArrayAggAccumulator {
     values: Vec<ArrayRef>,
     datatype: DataType,
+    // The maximum number of values to collect, if any
+    limit: Option<usize>,
+    // The total number of values in `values`
+    num_values: usize,
 }
 
 impl ArrayAggAccumulator {
@@ -115,8 +165,33 @@ impl ArrayAggAccumulator {
         Ok(Self {
             values: vec![],
             datatype: datatype.clone(),
+            limit: None,
+            num_values: 0,
         })
     }
+
+    /// Stop collecting values once `limit` of them are collected
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    // Append the leading values of `arr` which are within the limit
+    fn push(&mut self, arr: ArrayRef) {
+        let len = match self.limit {
+            Some(limit) => arr.len().min(limit.saturating_sub(self.num_values)),
+            None => arr.len(),
+        };
+        if len == 0 {
+            return;
+        }
+        self.num_values += len;
+        if len == arr.len() {
+            self.values.push(arr);
+        } else {
+            self.values.push(arr.slice(0, len));
+        }
+    }
 }
 
 impl Accumulator for ArrayAggAccumulator {
@@ -125,9 +200,12 @@ impl Accumulator for ArrayAggAccumulator {
         if values.is_empty() {
             return Ok(());
         }
-        assert!(values.len() == 1, "array_agg can only take 1 param!");
-        let val = values[0].clone();
-        self.values.push(val);
+        // The optional second param is the literal LIMIT, which is ignored here
+        assert!(
+            values.len() <= 2,
+            "array_agg can only take 1 param and a LIMIT!"
+        );
+        self.push(values[0].clone());
         Ok(())
     }
 
@@ -140,7 +218,7 @@ impl Accumulator for ArrayAggAccumulator {
 
         let list_arr = as_list_array(&states[0])?;
         for arr in list_arr.iter().flatten() {
-            self.values.push(arr);
+            self.push(arr);
         }
         Ok(())
     }
@@ -182,8 +260,8 @@ impl Accumulator for ArrayAggAccumulator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::expressions::col;
     use crate::expressions::tests::aggregate;
+    use crate::expressions::{col, lit};
     use crate::generic_test_op;
     use arrow::array::ArrayRef;
     use arrow::array::Int32Array;
@@ -211,6 +289,46 @@ mod tests {
         generic_test_op!(a, DataType::Int32, ArrayAgg, list, DataType::Int32)
     }
 
+    #[test]
+    fn array_agg_with_limit() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let agg = ArrayAgg::new(col("a", &schema)?, "bla", DataType::Int32)
+            .with_limit(lit(3_i64))?;
+        let agg = Arc::new(agg) as Arc<dyn AggregateExpr>;
+        let expected = |values: Vec<Option<i32>>| {
+            ScalarValue::List(Arc::new(
+                ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(values)]),
+            ))
+        };
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![a])?;
+        let actual = aggregate(&batch, agg.clone())?;
+        assert_eq!(actual, expected(vec![Some(1), Some(2), Some(3)]));
+
+        // The limit also holds when merging the states of several accumulators
+        let mut states = vec![];
+        for values in [vec![Some(1), None], vec![Some(3), Some(4), Some(5)]] {
+            let mut accum = agg.create_accumulator()?;
+            let len = values.len();
+            accum.update_batch(&[
+                Arc::new(Int32Array::from(values)),
+                lit(3_i64).evaluate(&batch)?.into_array(len),
+            ])?;
+            states.push(accum.state()?[0].to_array());
+        }
+        let mut accum = agg.create_accumulator()?;
+        for state in states {
+            accum.merge_batch(&[state])?;
+        }
+        assert_eq!(accum.evaluate()?, expected(vec![Some(1), None, Some(3)]));
+
+        let agg = ArrayAgg::new(col("a", &schema)?, "bla", DataType::Int32)
+            .with_limit(lit(-1_i64));
+        assert!(agg.is_err());
+        Ok(())
+    }
+
     #[test]
     fn array_agg_nested() -> Result<()> {
         let a1 = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![
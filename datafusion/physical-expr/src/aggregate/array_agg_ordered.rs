@@ -24,6 +24,7 @@ use std::collections::BinaryHeap;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use crate::aggregate::array_agg::validate_limit_expr;
 use crate::aggregate::utils::{down_cast_any_ref, ordering_fields};
 use crate::expressions::format_state_name;
 use crate::{AggregateExpr, LexOrdering, PhysicalExpr, PhysicalSortExpr};
@@ -53,6 +54,8 @@ pub struct OrderSensitiveArrayAgg {
     order_by_data_types: Vec<DataType>,
     expr: Arc<dyn PhysicalExpr>,
     ordering_req: LexOrdering,
+    /// The literal given by `ARRAY_AGG(x ORDER BY y LIMIT n)`, and its value
+    limit: Option<(Arc<dyn PhysicalExpr>, usize)>,
 }
 
 impl OrderSensitiveArrayAgg {
@@ -70,8 +73,17 @@ impl OrderSensitiveArrayAgg {
             input_data_type,
             order_by_data_types,
             ordering_req,
+            limit: None,
         }
     }
+
+    /// Only collect the first `n` values of each group according to the
+    /// ordering requirement, where `n` is the value of the `limit_expr` literal.
+    pub fn with_limit(mut self, limit_expr: Arc<dyn PhysicalExpr>) -> Result<Self> {
+        let limit = validate_limit_expr(&limit_expr)?;
+        self.limit = Some((limit_expr, limit));
+        Ok(self)
+    }
 }
 
 impl AggregateExpr for OrderSensitiveArrayAgg {
@@ -88,11 +100,14 @@ impl AggregateExpr for OrderSensitiveArrayAgg {
     }
 
     fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
-        Ok(Box::new(OrderSensitiveArrayAggAccumulator::try_new(
-            &self.input_data_type,
-            &self.order_by_data_types,
-            self.ordering_req.clone(),
-        )?))
+        Ok(Box::new(
+            OrderSensitiveArrayAggAccumulator::try_new(
+                &self.input_data_type,
+                &self.order_by_data_types,
+                self.ordering_req.clone(),
+            )?
+            .with_limit(self.limit.as_ref().map(|(_, limit)| *limit)),
+        ))
     }
 
     fn state_fields(&self) -> Result<Vec<Field>> {
@@ -111,7 +126,13 @@ impl AggregateExpr for OrderSensitiveArrayAgg {
     }
 
     fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
-        vec![self.expr.clone()]
+        let mut exprs = vec![self.expr.clone()];
+        exprs.extend(
+            self.limit
+                .as_ref()
+                .map(|(limit_expr, _)| limit_expr.clone()),
+        );
+        exprs
     }
 
     fn order_bys(&self) -> Option<&[PhysicalSortExpr]> {
@@ -136,6 +157,8 @@ impl PartialEq<dyn Any> for OrderSensitiveArrayAgg {
                     && self.input_data_type == x.input_data_type
                     && self.order_by_data_types == x.order_by_data_types
                     && self.expr.eq(&x.expr)
+                    && self.limit.as_ref().map(|(_, limit)| limit)
+                        == x.limit.as_ref().map(|(_, limit)| limit)
             })
             .unwrap_or(false)
     }
@@ -156,6 +179,9 @@ pub(crate) struct OrderSensitiveArrayAggAccumulator {
     datatypes: Vec<DataType>,
     // Stores ordering requirement of the Accumulator
     ordering_req: LexOrdering,
+    // The maximum number of values to keep, if any. As the values are received
+    // in the order of `ordering_req`, these are the leading ones.
+    limit: Option<usize>,
 }
 
 impl OrderSensitiveArrayAggAccumulator {
@@ -173,8 +199,15 @@ impl OrderSensitiveArrayAggAccumulator {
             ordering_values: vec![],
             datatypes,
             ordering_req,
+            limit: None,
         })
     }
+
+    /// Only keep the first `limit` values according to the ordering requirement
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
 }
 
 impl Accumulator for OrderSensitiveArrayAggAccumulator {
@@ -183,11 +216,16 @@ impl Accumulator for OrderSensitiveArrayAggAccumulator {
             return Ok(());
         }
 
-        let n_row = values[0].len();
+        // The ordering requirement columns follow the literal LIMIT, if any
+        let ordering_start = if self.limit.is_some() { 2 } else { 1 };
+        let n_row = match self.limit {
+            Some(limit) => values[0].len().min(limit.saturating_sub(self.values.len())),
+            None => values[0].len(),
+        };
         for index in 0..n_row {
             let row = get_row_at_idx(values, index)?;
             self.values.push(row[0].clone());
-            self.ordering_values.push(row[1..].to_vec());
+            self.ordering_values.push(row[ordering_start..].to_vec());
         }
 
         Ok(())
@@ -233,11 +271,15 @@ impl Accumulator for OrderSensitiveArrayAggAccumulator {
                 .iter()
                 .map(|sort_expr| sort_expr.options)
                 .collect::<Vec<_>>();
-            let (new_values, new_orderings) = merge_ordered_arrays(
+            let (mut new_values, mut new_orderings) = merge_ordered_arrays(
                 &partition_values,
                 &partition_ordering_values,
                 &sort_options,
             )?;
+            if let Some(limit) = self.limit {
+                new_values.truncate(limit);
+                new_orderings.truncate(limit);
+            }
             self.values = new_values;
             self.ordering_values = new_orderings;
         } else {
@@ -501,9 +543,13 @@ fn merge_ordered_arrays(
 
 #[cfg(test)]
 mod tests {
-    use crate::aggregate::array_agg_ordered::merge_ordered_arrays;
+    use crate::aggregate::array_agg_ordered::{
+        merge_ordered_arrays, OrderSensitiveArrayAgg,
+    };
+    use crate::expressions::{col, lit};
+    use crate::{AggregateExpr, PhysicalSortExpr};
     use arrow_array::{Array, ArrayRef, Int64Array};
-    use arrow_schema::SortOptions;
+    use arrow_schema::{DataType, Field, Schema, SortOptions};
     use datafusion_common::utils::get_row_at_idx;
     use datafusion_common::{Result, ScalarValue};
     use std::sync::Arc;
@@ -640,4 +686,65 @@ mod tests {
         assert_eq!(&merged_ts, &expected_ts);
         Ok(())
     }
+
+    #[test]
+    fn test_array_agg_ordered_with_limit() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]);
+        let ordering_req = vec![PhysicalSortExpr {
+            expr: col("b", &schema)?,
+            options: SortOptions {
+                descending: true,
+                nulls_first: false,
+            },
+        }];
+        let agg = OrderSensitiveArrayAgg::new(
+            col("a", &schema)?,
+            "array_agg",
+            DataType::Int64,
+            vec![DataType::Int64],
+            ordering_req,
+        )
+        .with_limit(lit(3_i64))?;
+
+        // Each partition receives its values sorted by the ordering requirement,
+        // preceded by the literal LIMIT
+        let partitions = [
+            (vec![1, 2, 3, 4], vec![40, 30, 20, 10]),
+            (vec![5, 6], vec![35, 15]),
+        ];
+        let mut states = vec![];
+        for (values, orderings) in partitions {
+            let mut accum = agg.create_accumulator()?;
+            let limits = vec![3; values.len()];
+            accum.update_batch(&[
+                Arc::new(Int64Array::from(values)),
+                Arc::new(Int64Array::from(limits)),
+                Arc::new(Int64Array::from(orderings)),
+            ])?;
+            states.push(accum.state()?);
+        }
+        assert_eq!(
+            states[0][0],
+            ScalarValue::List(ScalarValue::new_list(
+                &[1, 2, 3].map(|v| ScalarValue::Int64(Some(v))),
+                &DataType::Int64
+            ))
+        );
+
+        let mut accum = agg.create_accumulator()?;
+        for state in states {
+            let state = state.iter().map(|s| s.to_array()).collect::<Vec<_>>();
+            accum.merge_batch(&state)?;
+        }
+        // The values with the 3 largest orderings: 40, 35 and 30
+        let expected = ScalarValue::new_list(
+            &[1, 5, 2].map(|v| ScalarValue::Int64(Some(v))),
+            &DataType::Int64,
+        );
+        assert_eq!(accum.evaluate()?, ScalarValue::List(expected));
+        Ok(())
+    }
 }
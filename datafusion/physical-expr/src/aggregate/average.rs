@@ -44,7 +44,7 @@ use datafusion_expr::type_coercion::aggregates::avg_return_type;
 use datafusion_expr::Accumulator;
 
 use super::groups_accumulator::EmitTo;
-use super::utils::DecimalAverager;
+use super::utils::{DecimalAverager, IntervalSum};
 
 /// AVG aggregate expression
 #[derive(Debug, Clone)]
@@ -111,6 +111,9 @@ impl AggregateExpr for Avg {
                 target_precision: *target_precision,
                 target_scale: *target_scale,
             })),
+            (input_data_type, _) if IntervalSum::supports(input_data_type) => Ok(
+                Box::new(IntervalAvgAccumulator::new(input_data_type.clone())),
+            ),
             _ => not_impl_err!(
                 "AvgAccumulator for ({} --> {})",
                 self.input_data_type,
@@ -384,6 +387,62 @@ impl<T: DecimalType + ArrowNumericType> Accumulator for DecimalAvgAccumulator<T>
     }
 }
 
+/// An accumulator to compute the average of `Duration` and `Interval` values,
+/// see [`IntervalSum::avg`] for how the components are averaged
+#[derive(Debug)]
+struct IntervalAvgAccumulator {
+    sum: IntervalSum,
+    count: u64,
+}
+
+impl IntervalAvgAccumulator {
+    fn new(data_type: DataType) -> Self {
+        Self {
+            sum: IntervalSum::new(data_type),
+            count: 0,
+        }
+    }
+}
+
+impl Accumulator for IntervalAvgAccumulator {
+    fn state(&self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![
+            ScalarValue::from(self.count),
+            self.sum.sum(self.count)?,
+        ])
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.count += self.sum.update(&values[0], false)?;
+        Ok(())
+    }
+
+    fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.count -= self.sum.update(&values[0], true)?;
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        // counts are summed
+        self.count += sum(states[0].as_primitive::<UInt64Type>()).unwrap_or_default();
+        // sums are summed
+        self.sum.update(&states[1], false)?;
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<ScalarValue> {
+        self.sum.avg(self.count)
+    }
+
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+}
+
 /// An accumulator to compute the average of `[PrimitiveArray<T>]`.
 /// Stores values as native types, and does overflow checking
 ///
@@ -573,6 +632,7 @@ mod tests {
     use super::*;
     use crate::expressions::tests::assert_aggregate;
     use arrow::array::*;
+    use arrow_array::types::{IntervalDayTimeType, IntervalMonthDayNanoType};
     use datafusion_expr::AggregateFunction;
 
     #[test]
@@ -611,6 +671,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn avg_interval_day_time() {
+        // 3 days and 1 second over 2 values: the remaining day is carried
+        // over to the milliseconds.
+        let array: ArrayRef = Arc::new(IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTimeType::make_value(1, 0)),
+            None,
+            Some(IntervalDayTimeType::make_value(2, 1_000)),
+        ]));
+        assert_aggregate(
+            array,
+            AggregateFunction::Avg,
+            false,
+            ScalarValue::IntervalDayTime(Some(IntervalDayTimeType::make_value(
+                1, 43_200_500,
+            ))),
+        );
+    }
+
+    #[test]
+    fn avg_interval_month_day_nano() {
+        // The remaining month is carried over as 30 days
+        let array: ArrayRef = Arc::new(IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNanoType::make_value(1, 0, 0),
+            IntervalMonthDayNanoType::make_value(2, 0, 0),
+        ]));
+        assert_aggregate(
+            array,
+            AggregateFunction::Avg,
+            false,
+            ScalarValue::IntervalMonthDayNano(Some(
+                IntervalMonthDayNanoType::make_value(1, 15, 0),
+            )),
+        );
+    }
+
+    #[test]
+    fn avg_duration() {
+        let array: ArrayRef =
+            Arc::new(DurationMillisecondArray::from(vec![Some(1), Some(4), None]));
+        assert_aggregate(
+            array,
+            AggregateFunction::Avg,
+            false,
+            ScalarValue::DurationMillisecond(Some(2)),
+        );
+    }
+
     #[test]
     fn avg_decimal_all_nulls() {
         // test agg
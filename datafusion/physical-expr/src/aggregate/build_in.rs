@@ -59,11 +59,13 @@ pub fn create_aggregate_expr(
         (AggregateFunction::Count, false) => Arc::new(
             expressions::Count::new_with_multiple_exprs(input_phy_exprs, name, data_type),
         ),
-        (AggregateFunction::Count, true) => Arc::new(expressions::DistinctCount::new(
-            data_type,
-            input_phy_exprs[0].clone(),
-            name,
-        )),
+        (AggregateFunction::Count, true) => {
+            Arc::new(expressions::DistinctCount::new_with_multiple_exprs(
+                input_phy_types,
+                input_phy_exprs,
+                name,
+            ))
+        }
         (AggregateFunction::Grouping, _) => Arc::new(expressions::Grouping::new(
             input_phy_exprs[0].clone(),
             name,
@@ -114,16 +116,26 @@ pub fn create_aggregate_expr(
         ),
         (AggregateFunction::ArrayAgg, false) => {
             let expr = input_phy_exprs[0].clone();
+            // The optional second argument is the literal LIMIT
+            let limit_expr = input_phy_exprs.get(1).cloned();
             if ordering_req.is_empty() {
-                Arc::new(expressions::ArrayAgg::new(expr, name, data_type))
+                let agg = expressions::ArrayAgg::new(expr, name, data_type);
+                match limit_expr {
+                    Some(limit_expr) => Arc::new(agg.with_limit(limit_expr)?),
+                    None => Arc::new(agg),
+                }
             } else {
-                Arc::new(expressions::OrderSensitiveArrayAgg::new(
+                let agg = expressions::OrderSensitiveArrayAgg::new(
                     expr,
                     name,
                     data_type,
                     ordering_types,
                     ordering_req.to_vec(),
-                ))
+                );
+                match limit_expr {
+                    Some(limit_expr) => Arc::new(agg.with_limit(limit_expr)?),
+                    None => Arc::new(agg),
+                }
             }
         }
         (AggregateFunction::ArrayAgg, true) => {
@@ -132,6 +144,11 @@ pub fn create_aggregate_expr(
                     "ARRAY_AGG(DISTINCT ORDER BY a ASC) order-sensitive aggregations are not available"
                 );
             }
+            if input_phy_exprs.len() > 1 {
+                return not_impl_err!(
+                    "ARRAY_AGG(DISTINCT a LIMIT n) aggregations are not available"
+                );
+            }
             Arc::new(expressions::DistinctArrayAgg::new(
                 input_phy_exprs[0].clone(),
                 name,
@@ -25,9 +25,13 @@ use ahash::RandomState;
 use arrow::array::{Array, ArrayRef};
 use std::collections::HashSet;
 
+use arrow::row::{OwnedRow, RowConverter, SortField};
+
 use crate::aggregate::utils::down_cast_any_ref;
 use crate::expressions::format_state_name;
 use crate::{AggregateExpr, PhysicalExpr};
+use datafusion_common::cast::as_list_array;
+use datafusion_common::utils::wrap_into_list_array;
 use datafusion_common::Result;
 use datafusion_common::ScalarValue;
 use datafusion_expr::Accumulator;
@@ -35,14 +39,18 @@ use datafusion_expr::Accumulator;
 type DistinctScalarValues = ScalarValue;
 
 /// Expression for a COUNT(DISTINCT) aggregation.
+///
+/// With multiple arguments, e.g. `COUNT(DISTINCT a, b)`, the distinct tuples
+/// of the arguments are counted. Like for `COUNT(a, b)`, a tuple is only
+/// counted if none of its components is NULL.
 #[derive(Debug)]
 pub struct DistinctCount {
     /// Column name
     name: String,
-    /// The DataType used to hold the state for each input
-    state_data_type: DataType,
+    /// The DataTypes used to hold the state for each input
+    state_data_types: Vec<DataType>,
     /// The input arguments
-    expr: Arc<dyn PhysicalExpr>,
+    exprs: Vec<Arc<dyn PhysicalExpr>>,
 }
 
 impl DistinctCount {
@@ -51,11 +59,21 @@ impl DistinctCount {
         input_data_type: DataType,
         expr: Arc<dyn PhysicalExpr>,
         name: String,
+    ) -> Self {
+        Self::new_with_multiple_exprs(vec![input_data_type], vec![expr], name)
+    }
+
+    /// Create a new COUNT(DISTINCT) aggregate function over the tuples of
+    /// multiple arguments.
+    pub fn new_with_multiple_exprs(
+        input_data_types: Vec<DataType>,
+        exprs: Vec<Arc<dyn PhysicalExpr>>,
+        name: String,
     ) -> Self {
         Self {
             name,
-            state_data_type: input_data_type,
-            expr,
+            state_data_types: input_data_types,
+            exprs,
         }
     }
 }
@@ -70,22 +88,48 @@ impl AggregateExpr for DistinctCount {
         Ok(Field::new(&self.name, DataType::Int64, true))
     }
 
+    /// The state holds a list of the distinct values of each argument, such
+    /// that the i-th values of all lists form the i-th distinct tuple.
     fn state_fields(&self) -> Result<Vec<Field>> {
-        Ok(vec![Field::new_list(
-            format_state_name(&self.name, "count distinct"),
-            Field::new("item", self.state_data_type.clone(), true),
-            false,
-        )])
+        Ok(self
+            .state_data_types
+            .iter()
+            .enumerate()
+            .map(|(index, data_type)| {
+                let state_name = if index == 0 {
+                    "count distinct".to_string()
+                } else {
+                    format!("count distinct {index}")
+                };
+                Field::new_list(
+                    format_state_name(&self.name, &state_name),
+                    Field::new("item", data_type.clone(), true),
+                    false,
+                )
+            })
+            .collect())
     }
 
     fn expressions(&self) -> Vec<Arc<dyn PhysicalExpr>> {
-        vec![self.expr.clone()]
+        self.exprs.clone()
     }
 
     fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
-        Ok(Box::new(DistinctCountAccumulator {
+        if let [state_data_type] = self.state_data_types.as_slice() {
+            return Ok(Box::new(DistinctCountAccumulator {
+                values: HashSet::default(),
+                state_data_type: state_data_type.clone(),
+            }));
+        }
+        let fields = self
+            .state_data_types
+            .iter()
+            .map(|data_type| SortField::new(data_type.clone()))
+            .collect();
+        Ok(Box::new(DistinctTupleCountAccumulator {
+            row_converter: RowConverter::new(fields)?,
             values: HashSet::default(),
-            state_data_type: self.state_data_type.clone(),
+            values_size: 0,
         }))
     }
 
@@ -100,8 +144,9 @@ impl PartialEq<dyn Any> for DistinctCount {
             .downcast_ref::<Self>()
             .map(|x| {
                 self.name == x.name
-                    && self.state_data_type == x.state_data_type
-                    && self.expr.eq(&x.expr)
+                    && self.state_data_types == x.state_data_types
+                    && self.exprs.len() == x.exprs.len()
+                    && self.exprs.iter().zip(&x.exprs).all(|(a, b)| a.eq(b))
             })
             .unwrap_or(false)
     }
@@ -187,6 +232,86 @@ impl Accumulator for DistinctCountAccumulator {
     }
 }
 
+/// Accumulator for COUNT(DISTINCT) over multiple arguments. The distinct
+/// tuples are stored in the row format, see [`RowConverter`].
+#[derive(Debug)]
+struct DistinctTupleCountAccumulator {
+    row_converter: RowConverter,
+    values: HashSet<OwnedRow, RandomState>,
+    /// Total size of the rows in `values`, in bytes
+    values_size: usize,
+}
+
+impl DistinctTupleCountAccumulator {
+    /// Inserts the rows of the given columns, skipping those that contain a
+    /// NULL value.
+    fn insert_rows(&mut self, columns: &[ArrayRef]) -> Result<()> {
+        let rows = self.row_converter.convert_columns(columns)?;
+        for (index, row) in rows.iter().enumerate() {
+            if columns.iter().any(|column| column.is_null(index)) {
+                continue;
+            }
+            let row = row.owned();
+            let size = row.as_ref().len();
+            if self.values.insert(row) {
+                self.values_size += size;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Accumulator for DistinctTupleCountAccumulator {
+    fn state(&self) -> Result<Vec<ScalarValue>> {
+        let columns = self
+            .row_converter
+            .convert_rows(self.values.iter().map(|row| row.row()))?;
+        Ok(columns
+            .into_iter()
+            .map(|column| ScalarValue::List(Arc::new(wrap_into_list_array(column))))
+            .collect())
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        self.insert_rows(values)
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        if states.is_empty() {
+            return Ok(());
+        }
+        let lists = states
+            .iter()
+            .map(|state| as_list_array(state))
+            .collect::<Result<Vec<_>>>()?;
+        for index in 0..lists[0].len() {
+            if lists.iter().any(|list| list.is_null(index)) {
+                continue;
+            }
+            let columns = lists
+                .iter()
+                .map(|list| list.value(index))
+                .collect::<Vec<_>>();
+            self.insert_rows(&columns)?;
+        }
+        Ok(())
+    }
+
+    fn evaluate(&self) -> Result<ScalarValue> {
+        Ok(ScalarValue::Int64(Some(self.values.len() as i64)))
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+            + self.row_converter.size()
+            + std::mem::size_of::<OwnedRow>() * self.values.capacity()
+            + self.values_size
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::expressions::NoOp;
@@ -194,7 +319,8 @@ mod tests {
     use super::*;
     use arrow::array::{
         ArrayRef, BooleanArray, Float32Array, Float64Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+        Int64Array, Int8Array, StringArray, UInt16Array, UInt32Array, UInt64Array,
+        UInt8Array,
     };
     use arrow::datatypes::DataType;
     use arrow::datatypes::{
@@ -557,4 +683,58 @@ mod tests {
         assert_eq!(result, ScalarValue::Int64(Some(2)));
         Ok(())
     }
+
+    #[test]
+    fn count_distinct_tuples_update_and_merge() -> Result<()> {
+        let agg = DistinctCount::new_with_multiple_exprs(
+            vec![DataType::Int32, DataType::Utf8],
+            vec![Arc::new(NoOp::new()), Arc::new(NoOp::new())],
+            String::from("__col_name__"),
+        );
+        assert_eq!(agg.state_fields()?.len(), 2);
+        let update = |a: Vec<Option<i32>>, b: Vec<Option<&str>>| {
+            let mut accum = agg.create_accumulator()?;
+            accum.update_batch(&[
+                Arc::new(Int32Array::from(a)) as ArrayRef,
+                Arc::new(StringArray::from(b)) as ArrayRef,
+            ])?;
+            Ok::<_, DataFusionError>(accum)
+        };
+
+        let first = update(
+            vec![Some(1), Some(1), Some(1), Some(2), Some(2), None, None],
+            vec![
+                Some("x"),
+                Some("x"),
+                Some("y"),
+                Some("x"),
+                None,
+                Some("x"),
+                None,
+            ],
+        )?;
+        // Tuples with a NULL component are not counted:
+        assert_eq!(first.evaluate()?, ScalarValue::Int64(Some(3)));
+
+        let second = update(
+            vec![Some(1), Some(3), Some(2), None],
+            vec![Some("y"), Some("y"), Some("y"), Some("z")],
+        )?;
+        assert_eq!(second.evaluate()?, ScalarValue::Int64(Some(3)));
+
+        // Merge the partial states, in which the i-th values of the lists form
+        // the i-th distinct tuple:
+        let states = [first.state()?, second.state()?];
+        let mut accum = agg.create_accumulator()?;
+        for state in states {
+            let state = state
+                .iter()
+                .map(|value| value.to_array())
+                .collect::<Vec<_>>();
+            accum.merge_batch(&state)?;
+        }
+        // (1, "x"), (1, "y"), (2, "x"), (3, "y") and (2, "y"):
+        assert_eq!(accum.evaluate()?, ScalarValue::Int64(Some(5)));
+        Ok(())
+    }
 }
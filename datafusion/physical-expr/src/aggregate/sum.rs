@@ -21,7 +21,7 @@ use std::any::Any;
 use std::sync::Arc;
 
 use super::groups_accumulator::prim_op::PrimitiveGroupsAccumulator;
-use crate::aggregate::utils::down_cast_any_ref;
+use crate::aggregate::utils::{down_cast_any_ref, IntervalSum};
 use crate::expressions::format_state_name;
 use crate::{AggregateExpr, GroupsAccumulator, PhysicalExpr};
 use arrow::compute::sum;
@@ -97,6 +97,11 @@ impl AggregateExpr for Sum {
     }
 
     fn create_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        if IntervalSum::supports(&self.data_type) {
+            return Ok(Box::new(IntervalSumAccumulator::new(
+                self.data_type.clone(),
+            )));
+        }
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
                 Ok(Box::new(SumAccumulator::<$t>::new($dt.clone())))
@@ -122,7 +127,7 @@ impl AggregateExpr for Sum {
     }
 
     fn groups_accumulator_supported(&self) -> bool {
-        true
+        !IntervalSum::supports(&self.data_type)
     }
 
     fn create_groups_accumulator(&self) -> Result<Box<dyn GroupsAccumulator>> {
@@ -142,6 +147,11 @@ impl AggregateExpr for Sum {
     }
 
     fn create_sliding_accumulator(&self) -> Result<Box<dyn Accumulator>> {
+        if IntervalSum::supports(&self.data_type) {
+            return Ok(Box::new(IntervalSumAccumulator::new(
+                self.data_type.clone(),
+            )));
+        }
         macro_rules! helper {
             ($t:ty, $dt:expr) => {
                 Ok(Box::new(SlidingSumAccumulator::<$t>::new($dt.clone())))
@@ -286,10 +296,60 @@ impl<T: ArrowNumericType> Accumulator for SlidingSumAccumulator<T> {
     }
 }
 
+/// This accumulator computes SUM of `Duration` and `Interval` values, see
+/// [`IntervalSum`] for how their components are summed
+#[derive(Debug)]
+struct IntervalSumAccumulator {
+    sum: IntervalSum,
+    count: u64,
+}
+
+impl IntervalSumAccumulator {
+    fn new(data_type: DataType) -> Self {
+        Self {
+            sum: IntervalSum::new(data_type),
+            count: 0,
+        }
+    }
+}
+
+impl Accumulator for IntervalSumAccumulator {
+    fn state(&self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![self.evaluate()?])
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.count += self.sum.update(&values[0], false)?;
+        Ok(())
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.update_batch(states)
+    }
+
+    fn evaluate(&self) -> Result<ScalarValue> {
+        self.sum.sum(self.count)
+    }
+
+    fn size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+
+    fn retract_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.count -= self.sum.update(&values[0], true)?;
+        Ok(())
+    }
+
+    fn supports_retract_batch(&self) -> bool {
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::expressions::tests::assert_aggregate;
+    use arrow_array::types::IntervalMonthDayNanoType;
     use arrow_array::*;
     use datafusion_expr::AggregateFunction;
 
@@ -389,6 +449,53 @@ mod tests {
         assert_aggregate(a, AggregateFunction::Sum, false, ScalarValue::from(15_f64));
     }
 
+    #[test]
+    fn sum_duration() {
+        let a: ArrayRef = Arc::new(DurationSecondArray::from(vec![
+            Some(10),
+            None,
+            Some(20),
+            Some(-5),
+        ]));
+        assert_aggregate(
+            a,
+            AggregateFunction::Sum,
+            false,
+            ScalarValue::DurationSecond(Some(25)),
+        );
+    }
+
+    #[test]
+    fn sum_interval_month_day_nano() -> Result<()> {
+        // Months, days and nanoseconds are summed independently
+        let a: ArrayRef = Arc::new(IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNanoType::make_value(1, 40, 5)),
+            None,
+            Some(IntervalMonthDayNanoType::make_value(2, -10, 7)),
+        ]));
+        assert_aggregate(
+            a,
+            AggregateFunction::Sum,
+            false,
+            ScalarValue::IntervalMonthDayNano(Some(
+                IntervalMonthDayNanoType::make_value(3, 30, 12),
+            )),
+        );
+
+        let a: ArrayRef = Arc::new(IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNanoType::make_value(0, 0, i64::MAX),
+            IntervalMonthDayNanoType::make_value(0, 0, 1),
+        ]));
+        let data_type = a.data_type().clone();
+        let sum = Sum::new(crate::expressions::lit(1), "sum", data_type);
+        let err = sum.create_accumulator()?.update_batch(&[a]).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: Arithmetic Overflow while summing Interval(MonthDayNano) values"
+        );
+        Ok(())
+    }
+
     #[test]
     fn sum_f64() {
         let a: ArrayRef =
@@ -21,13 +21,15 @@ use crate::{AggregateExpr, PhysicalSortExpr};
 use arrow::array::ArrayRef;
 use arrow_array::cast::AsArray;
 use arrow_array::types::{
-    Decimal128Type, DecimalType, TimestampMicrosecondType, TimestampMillisecondType,
+    Decimal128Type, DecimalType, DurationMicrosecondType, DurationMillisecondType,
+    DurationNanosecondType, DurationSecondType, IntervalDayTimeType,
+    IntervalMonthDayNanoType, TimestampMicrosecondType, TimestampMillisecondType,
     TimestampNanosecondType, TimestampSecondType,
 };
-use arrow_array::ArrowNativeTypeOp;
+use arrow_array::{ArrowNativeTypeOp, ArrowPrimitiveType, PrimitiveArray};
 use arrow_buffer::ArrowNativeType;
-use arrow_schema::{DataType, Field};
-use datafusion_common::{exec_err, DataFusionError, Result};
+use arrow_schema::{DataType, Field, IntervalUnit, TimeUnit};
+use datafusion_common::{exec_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::Accumulator;
 use std::any::Any;
 use std::sync::Arc;
@@ -123,6 +125,180 @@ impl<T: DecimalType> DecimalAverager<T> {
     }
 }
 
+/// Sums `Duration`, `Interval(DayTime)` and `Interval(MonthDayNano)` values
+/// component by component, checking for overflow.
+///
+/// Months and days do not have a fixed length, so the months, days and time
+/// components of intervals are summed independently of each other: the sum
+/// of `1 month` and `30 days` is `1 month 30 days`, not `2 months`.
+#[derive(Debug)]
+pub(crate) struct IntervalSum {
+    data_type: DataType,
+    months: i32,
+    days: i32,
+    /// Milliseconds for `Interval(DayTime)`, nanoseconds for
+    /// `Interval(MonthDayNano)` and the unit of the type for `Duration`
+    time: i64,
+}
+
+impl IntervalSum {
+    /// Returns whether values of `data_type` can be summed by `IntervalSum`
+    pub fn supports(data_type: &DataType) -> bool {
+        matches!(
+            data_type,
+            DataType::Duration(_)
+                | DataType::Interval(IntervalUnit::DayTime | IntervalUnit::MonthDayNano)
+        )
+    }
+
+    /// Create a new, empty sum of `data_type` values
+    pub fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            months: 0,
+            days: 0,
+            time: 0,
+        }
+    }
+
+    /// Adds the non-null `values` to the sum, or subtracts them if `retract`
+    /// is set, returning the number of non-null values
+    pub fn update(&mut self, values: &ArrayRef, retract: bool) -> Result<u64> {
+        let components: Vec<(i32, i32, i64)> = match values.data_type() {
+            DataType::Interval(IntervalUnit::DayTime) => values
+                .as_primitive::<IntervalDayTimeType>()
+                .iter()
+                .flatten()
+                .map(|value| {
+                    let (days, millis) = IntervalDayTimeType::to_parts(value);
+                    (0, days, millis as i64)
+                })
+                .collect(),
+            DataType::Interval(IntervalUnit::MonthDayNano) => values
+                .as_primitive::<IntervalMonthDayNanoType>()
+                .iter()
+                .flatten()
+                .map(IntervalMonthDayNanoType::to_parts)
+                .collect(),
+            DataType::Duration(TimeUnit::Second) => {
+                duration_components(values.as_primitive::<DurationSecondType>())
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                duration_components(values.as_primitive::<DurationMillisecondType>())
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                duration_components(values.as_primitive::<DurationMicrosecondType>())
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                duration_components(values.as_primitive::<DurationNanosecondType>())
+            }
+            other => return exec_err!("Can not sum {other} values as intervals"),
+        };
+        for (months, days, time) in &components {
+            let (months, days, time) = if retract {
+                (months.checked_neg(), days.checked_neg(), time.checked_neg())
+            } else {
+                (Some(*months), Some(*days), Some(*time))
+            };
+            let sum = (|| {
+                Some((
+                    self.months.checked_add(months?)?,
+                    self.days.checked_add(days?)?,
+                    self.time.checked_add(time?)?,
+                ))
+            })();
+            let Some((months, days, time)) = sum else {
+                return self.overflow();
+            };
+            (self.months, self.days, self.time) = (months, days, time);
+        }
+        Ok(components.len() as u64)
+    }
+
+    /// Returns the sum of `count` values, or NULL if `count` is zero
+    pub fn sum(&self, count: u64) -> Result<ScalarValue> {
+        if count == 0 {
+            return ScalarValue::try_from(&self.data_type);
+        }
+        self.to_scalar(self.months, self.days, self.time)
+    }
+
+    /// Returns the average of `count` values, or NULL if `count` is zero.
+    ///
+    /// Each component is divided separately. Like in PostgreSQL, the
+    /// remainder of the months is carried over to the days (assuming 30 days
+    /// per month), and the remainder of the days to the time (assuming 24
+    /// hours per day), so `AVG` of `1 month` and `2 months` is
+    /// `1 month 15 days`.
+    pub fn avg(&self, count: u64) -> Result<ScalarValue> {
+        if count == 0 {
+            return ScalarValue::try_from(&self.data_type);
+        }
+        let time_per_day: i128 = match self.data_type {
+            DataType::Interval(IntervalUnit::DayTime) => 86_400_000,
+            DataType::Interval(IntervalUnit::MonthDayNano) => 86_400_000_000_000,
+            _ => 0,
+        };
+        let count = count as i128;
+        let months = self.months as i128;
+        let days = self.days as i128 + months % count * 30;
+        let time = self.time as i128 + days % count * time_per_day;
+        match (
+            i32::try_from(months / count),
+            i32::try_from(days / count),
+            i64::try_from(time / count),
+        ) {
+            (Ok(months), Ok(days), Ok(time)) => self.to_scalar(months, days, time),
+            _ => self.overflow(),
+        }
+    }
+
+    fn to_scalar(&self, months: i32, days: i32, time: i64) -> Result<ScalarValue> {
+        Ok(match self.data_type {
+            DataType::Interval(IntervalUnit::DayTime) => {
+                let Ok(millis) = i32::try_from(time) else {
+                    return self.overflow();
+                };
+                ScalarValue::IntervalDayTime(Some(IntervalDayTimeType::make_value(
+                    days, millis,
+                )))
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                ScalarValue::IntervalMonthDayNano(Some(
+                    IntervalMonthDayNanoType::make_value(months, days, time),
+                ))
+            }
+            DataType::Duration(TimeUnit::Second) => {
+                ScalarValue::DurationSecond(Some(time))
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                ScalarValue::DurationMillisecond(Some(time))
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                ScalarValue::DurationMicrosecond(Some(time))
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                ScalarValue::DurationNanosecond(Some(time))
+            }
+            ref other => return exec_err!("Can not sum {other} values as intervals"),
+        })
+    }
+
+    fn overflow<T>(&self) -> Result<T> {
+        exec_err!(
+            "Arithmetic Overflow while summing {} values",
+            self.data_type
+        )
+    }
+}
+
+/// Returns the components of the non-null durations in `values`
+fn duration_components<T: ArrowPrimitiveType<Native = i64>>(
+    values: &PrimitiveArray<T>,
+) -> Vec<(i32, i32, i64)> {
+    values.iter().flatten().map(|value| (0, 0, value)).collect()
+}
+
 /// Adjust array type metadata if needed
 ///
 /// Since `Decimal128Arrays` created from `Vec<NativeType>` have
@@ -17,32 +17,94 @@
 
 //! Interval and selectivity in [`AnalysisContext`]
 
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
 
-use crate::expressions::Column;
+use crate::expressions::{
+    BinaryExpr, CastExpr, Column, InListExpr, IsNotNullExpr, IsNullExpr, LikeExpr,
+    Literal, NegativeExpr, NotExpr,
+};
 use crate::intervals::cp_solver::PropagationResult;
+use crate::intervals::utils::{check_support, find_unsupported_exprs, negate_comparison};
 use crate::intervals::{cardinality_ratio, ExprIntervalGraph, Interval, IntervalBound};
-use crate::utils::collect_columns;
-use crate::PhysicalExpr;
+use crate::utils::{collect_columns, expr_list_eq_strict_order, split_conjunction};
+use crate::{PhysicalExpr, ScalarFunctionExpr};
 
-use arrow::datatypes::Schema;
+use arrow::datatypes::{DataType, Schema};
+use chrono::{Datelike, NaiveDate};
 use datafusion_common::stats::Precision;
+use datafusion_common::tree_node::{TreeNode, VisitRecursion};
 use datafusion_common::{
-    internal_err, ColumnStatistics, DataFusionError, Result, ScalarValue,
+    internal_err, plan_err, ColumnStatistics, DataFusionError, Result, ScalarValue,
 };
+use datafusion_expr::{BuiltinScalarFunction, Operator};
 
 /// The shared context used during the analysis of an expression. Includes
 /// the boundaries for all known columns.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnalysisContext {
     // A list of known column boundaries, ordered by the index
     // of the column in the current schema.
     pub boundaries: Vec<ExprBoundaries>,
     /// The estimated percentage of rows that this expression would select, if
-    /// it were to be used as a boolean predicate on a filter. The value will be
-    /// between 0.0 (selects nothing) and 1.0 (selects everything).
-    pub selectivity: Option<f64>,
+    /// it were to be used as a boolean predicate on a filter, along with the
+    /// reliability of the estimate. The values will be between 0.0 (selects
+    /// nothing) and 1.0 (selects everything).
+    pub selectivity: Option<SelectivityEstimate>,
+    /// Whether `selectivity` is known to be exact, e.g. because the interval
+    /// propagation proved the predicate to be always true or always false, or
+    /// inexact, because it relies on the assumption that values are uniformly
+    /// distributed. It is [`Precision::Absent`] if `selectivity` is unknown,
+    /// or merely assumed to be the default selectivity.
+    pub selectivity_precision: Precision<()>,
+    /// The number of rows the column boundaries refer to, if known. Together
+    /// with the null counts of the columns, this determines the fraction of
+    /// NULL values in each column.
+    pub num_rows: Precision<usize>,
+    /// The selectivity assumed for predicates whose selectivity the analysis
+    /// can not estimate, such as `LIKE` patterns that are not anchored to a
+    /// prefix, or expressions that interval arithmetic does not support.
+    /// Defaults to 1.0 (selects everything).
+    pub default_selectivity: f64,
+    /// Known correlations between pairs of columns, given as coefficients in
+    /// `[0, 1]`. The selectivities of correlated columns are combined with an
+    /// exponential backoff instead of being multiplied, so that a conjunction
+    /// on fully correlated columns is as selective as its most selective part.
+    /// Columns without an entry are assumed to be independent.
+    pub correlations: Vec<(Column, Column, f64)>,
+    /// The sub-expressions of the analyzed predicate that defeated interval
+    /// arithmetic, e.g. `sqrt(a)` in `sqrt(a) > 3`, because of which (parts
+    /// of) the predicate were assumed to have the default selectivity. This is
+    /// purely diagnostic, and does not affect the estimates. It is not
+    /// serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unsupported_exprs: Vec<Arc<dyn PhysicalExpr>>,
+    /// Whether predicates may reference columns without boundaries, e.g.
+    /// because the boundaries only cover a projection of the schema. The
+    /// conjuncts of the predicate referencing such columns are treated as
+    /// unconstrained, and the selectivity of the predicate is capped at the
+    /// default selectivity, with absent precision. Otherwise, analyzing such
+    /// a predicate is an error. Defaults to false.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub allow_missing_columns: bool,
+}
+
+impl PartialEq for AnalysisContext {
+    fn eq(&self, other: &Self) -> bool {
+        self.boundaries == other.boundaries
+            && self.selectivity == other.selectivity
+            && self.selectivity_precision == other.selectivity_precision
+            && self.num_rows == other.num_rows
+            && self.default_selectivity == other.default_selectivity
+            && self.correlations == other.correlations
+            && self.allow_missing_columns == other.allow_missing_columns
+            && expr_list_eq_strict_order(
+                &self.unsupported_exprs,
+                &other.unsupported_exprs,
+            )
+    }
 }
 
 impl AnalysisContext {
@@ -50,14 +112,106 @@ impl AnalysisContext {
         Self {
             boundaries,
             selectivity: None,
+            selectivity_precision: Precision::Absent,
+            num_rows: Precision::Absent,
+            default_selectivity: 1.0,
+            correlations: vec![],
+            unsupported_exprs: vec![],
+            allow_missing_columns: false,
         }
     }
 
-    pub fn with_selectivity(mut self, selectivity: f64) -> Self {
+    /// Sets the selectivity of the context, keeping its precision, which is
+    /// set by [`Self::with_selectivity_precision`].
+    pub fn with_selectivity(mut self, selectivity: SelectivityEstimate) -> Self {
         self.selectivity = Some(selectivity);
         self
     }
 
+    pub fn with_selectivity_precision(
+        mut self,
+        selectivity_precision: Precision<()>,
+    ) -> Self {
+        self.selectivity_precision = selectivity_precision;
+        self
+    }
+
+    /// Corrects the selectivity of the context by the `feedback` gathered for
+    /// the predicate identified by `predicate_id`, e.g. before using it as the
+    /// starting estimate for a sub-plan whose filter was executed before. The
+    /// corrected selectivity is inexact. Exact selectivities, which are proven
+    /// by the statistics, are kept.
+    pub fn with_feedback(
+        mut self,
+        feedback: &dyn SelectivityFeedback,
+        predicate_id: u64,
+    ) -> Self {
+        if matches!(self.selectivity_precision, Precision::Exact(_)) {
+            return self;
+        }
+        if let Some(selectivity) = &self.selectivity {
+            let point = feedback.adjust_selectivity(selectivity.point, predicate_id);
+            let confidence = selectivity.confidence.clone().to_inexact();
+            self.selectivity = Some(SelectivityEstimate::new(point, confidence));
+            self.selectivity_precision = self.selectivity_precision.clone().to_inexact();
+        }
+        self
+    }
+
+    pub fn with_num_rows(mut self, num_rows: Precision<usize>) -> Self {
+        self.num_rows = num_rows;
+        self
+    }
+
+    /// Sets the selectivity assumed for predicates that can not be estimated,
+    /// which must be between 0.0 and 1.0.
+    pub fn with_default_selectivity(mut self, default_selectivity: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&default_selectivity) {
+            return plan_err!(
+                "Default selectivity must be between 0.0 and 1.0, got {default_selectivity}"
+            );
+        }
+        self.default_selectivity = default_selectivity;
+        Ok(self)
+    }
+
+    /// Sets whether predicates may reference columns without boundaries, see
+    /// [`Self::allow_missing_columns`].
+    pub fn with_allow_missing_columns(mut self, allow_missing_columns: bool) -> Self {
+        self.allow_missing_columns = allow_missing_columns;
+        self
+    }
+
+    pub fn with_correlations(mut self, correlations: Vec<(Column, Column, f64)>) -> Self {
+        self.correlations = correlations;
+        self
+    }
+
+    pub fn with_unsupported_exprs(
+        mut self,
+        unsupported_exprs: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> Self {
+        self.unsupported_exprs = unsupported_exprs;
+        self
+    }
+
+    /// Marks the columns at the given pairs of positions in `boundaries` as
+    /// fully correlated, e.g. because one of them functionally determines the
+    /// other. A conjunction on such columns is then only as selective as its
+    /// most selective part, see [`Self::correlations`]. Positions without
+    /// boundaries are ignored.
+    pub fn with_correlated_columns(mut self, pairs: Vec<(usize, usize)>) -> Self {
+        for (left, right) in pairs {
+            if let (Some(left), Some(right)) =
+                (self.boundaries.get(left), self.boundaries.get(right))
+            {
+                let (left, right) = (left.column.clone(), right.column.clone());
+                self.correlations.push((left, right, 1.0));
+            }
+        }
+        self
+    }
+
     /// Create a new analysis context from column statistics.
     pub fn try_from_statistics(
         input_schema: &Schema,
@@ -70,17 +224,246 @@ impl AnalysisContext {
             .collect::<Result<Vec<_>>>()
             .map(Self::new)
     }
+
+    /// Combines this context with `other`, which describes a separate input
+    /// with the same schema (e.g. another branch of a `UNION ALL`), into a
+    /// context describing both inputs together.
+    ///
+    /// Column intervals are widened to cover both inputs, and distinct and
+    /// NULL counts are summed (the sum is only exact if both counts are).
+    /// Selectivities are averaged, weighted by `num_rows` and `other_num_rows`,
+    /// the number of rows in this and the other input respectively, and are
+    /// only exact if both selectivities are. Columns
+    /// that only one of the contexts has boundaries for are unbounded in the
+    /// result, and their statistics are [`Precision::Absent`].
+    pub fn merge(
+        &self,
+        other: &AnalysisContext,
+        num_rows: usize,
+        other_num_rows: usize,
+    ) -> AnalysisContext {
+        let weights = (num_rows as f64, other_num_rows as f64);
+        let len = self.boundaries.len().max(other.boundaries.len());
+        let boundaries = (0..len)
+            .filter_map(|idx| {
+                match (self.boundaries.get(idx), other.boundaries.get(idx)) {
+                    (Some(left), Some(right)) => Some(left.merge(right, weights)),
+                    (left, right) => left.or(right).map(ExprBoundaries::to_unknown),
+                }
+            })
+            .collect();
+        AnalysisContext {
+            boundaries,
+            selectivity: self
+                .selectivity
+                .as_ref()
+                .zip(other.selectivity.as_ref())
+                .and_then(|(left, right)| {
+                    let point =
+                        weighted_average(Some(left.point), Some(right.point), weights)?;
+                    let confidence = left.confidence.min(&right.confidence);
+                    Some(SelectivityEstimate::new(point, confidence))
+                }),
+            selectivity_precision: self
+                .selectivity_precision
+                .min(&other.selectivity_precision),
+            num_rows: self.num_rows.add(&other.num_rows),
+            default_selectivity: self.default_selectivity,
+            correlations: self.correlations.clone(),
+            unsupported_exprs: union_exprs(
+                &self.unsupported_exprs,
+                &other.unsupported_exprs,
+            ),
+            allow_missing_columns: self.allow_missing_columns,
+        }
+    }
+
+    /// Converts the boundaries back into column statistics, e.g. to describe
+    /// the output of a filter with the analyzed predicate. This is the inverse
+    /// of [`Self::try_from_statistics`]. `row_count` is the number of rows the
+    /// boundaries were created for, i.e. before applying the predicate.
+    ///
+    /// The minimum and maximum values are the (closed) bounds of the intervals,
+    /// which are as exact as the statistics they were created from until the
+    /// analysis narrows them. Distinct counts carry over, and NULL counts are
+    /// scaled by the selectivity, but never exceed the number of remaining
+    /// rows. If the predicate can not be satisfied, or any interval is empty,
+    /// no rows remain, and every column has exactly zero distinct values and
+    /// NULLs.
+    pub fn to_statistics(&self, row_count: &Precision<usize>) -> Vec<ColumnStatistics> {
+        let infeasible = self
+            .boundaries
+            .iter()
+            .any(|bound| bound.interval.is_empty())
+            || (self.selectivity_precision == Precision::Exact(())
+                && self
+                    .selectivity
+                    .as_ref()
+                    .is_some_and(|selectivity| selectivity.point == 0.0));
+        if infeasible {
+            return self
+                .boundaries
+                .iter()
+                .map(|_| ColumnStatistics {
+                    null_count: Precision::Exact(0),
+                    distinct_count: Precision::Exact(0),
+                    ..ColumnStatistics::new_unknown()
+                })
+                .collect();
+        }
+
+        let scale = |count: &Precision<usize>| match &self.selectivity {
+            Some(selectivity) => selectivity_to_row_count(
+                selectivity.point,
+                selectivity.confidence.clone(),
+                count.clone(),
+            ),
+            None => count.clone(),
+        };
+        let remaining_rows = scale(row_count);
+        self.boundaries
+            .iter()
+            .map(|bound| {
+                let narrowed = bound
+                    .selectivity
+                    .is_some_and(|selectivity| selectivity < 1.0);
+                let confidence = if narrowed {
+                    bound.confidence.clone().to_inexact()
+                } else {
+                    bound.confidence.clone()
+                };
+                let to_precision = |bound: &IntervalBound| match confidence {
+                    _ if bound.is_unbounded() => Precision::Absent,
+                    Precision::Exact(()) => Precision::Exact(bound.value.clone()),
+                    Precision::Inexact(()) => Precision::Inexact(bound.value.clone()),
+                    Precision::Absent => Precision::Absent,
+                };
+                let null_count = match (scale(&bound.null_count), &remaining_rows) {
+                    (null_count, Precision::Absent) => null_count,
+                    (null_count, remaining_rows) => null_count.min(remaining_rows),
+                };
+                let interval = bound.interval.clone().close_bounds();
+                ColumnStatistics {
+                    null_count,
+                    max_value: to_precision(&interval.upper),
+                    min_value: to_precision(&interval.lower),
+                    distinct_count: bound.distinct_count.clone(),
+                    histogram: bound.histogram.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// An estimate of the selectivity of a predicate, along with an interval that
+/// likely contains the actual selectivity. Pessimistic cost estimates may use
+/// `lower_bound`, and optimistic ones `upper_bound`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectivityEstimate {
+    /// The most likely selectivity.
+    pub point: f64,
+    /// The smallest likely selectivity.
+    pub lower_bound: f64,
+    /// The largest likely selectivity.
+    pub upper_bound: f64,
+    /// How reliable the estimate is, i.e. the weakest of the precisions of the
+    /// statistics it was derived from.
+    pub confidence: Precision<()>,
+}
+
+/// The relative uncertainty of selectivities derived from inexact statistics.
+const INEXACT_SELECTIVITY_UNCERTAINTY: f64 = 0.1;
+
+impl SelectivityEstimate {
+    /// Creates an estimate of `point`, whose bounds follow from `confidence`:
+    /// They are equal to `point` for exact statistics, deviate from it by 10%
+    /// for inexact statistics, and span `[0.0, 1.0]` for absent statistics.
+    pub fn new(point: f64, confidence: Precision<()>) -> Self {
+        let (lower_bound, upper_bound) = match confidence {
+            Precision::Exact(()) => (point, point),
+            Precision::Inexact(()) => (
+                (point * (1.0 - INEXACT_SELECTIVITY_UNCERTAINTY)).max(0.0),
+                (point * (1.0 + INEXACT_SELECTIVITY_UNCERTAINTY)).min(1.0),
+            ),
+            Precision::Absent => (0.0, 1.0),
+        };
+        Self {
+            point,
+            lower_bound,
+            upper_bound,
+            confidence,
+        }
+    }
+
+    /// Creates an estimate of `point` derived from exact statistics.
+    pub fn exact(point: f64) -> Self {
+        Self::new(point, Precision::Exact(()))
+    }
+
+    /// Scales the estimate by `factor`, which is derived from statistics with
+    /// the given `confidence`.
+    fn scale(&self, factor: f64, confidence: &Precision<()>) -> Self {
+        Self::new(self.point * factor, self.confidence.min(confidence))
+    }
+}
+
+/// A source of corrections for selectivity estimates, e.g. learned from the
+/// actual selectivities of predicates observed during execution.
+pub trait SelectivityFeedback {
+    /// Returns the `estimated` selectivity of the predicate identified by
+    /// `predicate_id`, corrected according to the feedback. This is a value
+    /// between 0.0 and 1.0.
+    fn adjust_selectivity(&self, estimated: f64, predicate_id: u64) -> f64;
+}
+
+/// Converts a `selectivity`, derived from statistics with the given
+/// `selectivity_confidence`, into the number of rows it selects out of
+/// `input_count` rows. The row count is exact only if both the selectivity and
+/// the input count are exact, and the selectivity is 0.0 or 1.0, as the count
+/// is rounded (up) otherwise. It is absent if either of them is absent.
+pub fn selectivity_to_row_count(
+    selectivity: f64,
+    selectivity_confidence: Precision<()>,
+    input_count: Precision<usize>,
+) -> Precision<usize> {
+    match (selectivity_confidence, input_count) {
+        (Precision::Absent, _) | (_, Precision::Absent) => Precision::Absent,
+        (Precision::Exact(()), Precision::Exact(count))
+            if selectivity == 0.0 || selectivity == 1.0 =>
+        {
+            Precision::Exact((count as f64 * selectivity) as usize)
+        }
+        (_, Precision::Exact(count) | Precision::Inexact(count)) => {
+            Precision::Inexact((count as f64 * selectivity).ceil() as usize)
+        }
+    }
 }
 
 /// Represents the boundaries of the resulting value from a physical expression,
 /// if it were to be an expression, if it were to be evaluated.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExprBoundaries {
     pub column: Column,
     /// Minimum and maximum values this expression can have.
     pub interval: Interval,
+    /// How reliable `interval` is, i.e. the weakest of the precisions of the
+    /// minimum and maximum values it was created from.
+    pub confidence: Precision<()>,
     /// Maximum number of distinct values this expression can produce, if known.
     pub distinct_count: Precision<usize>,
+    /// Number of NULL values this expression can produce, if known.
+    pub null_count: Precision<usize>,
+    /// Histogram of the values this expression can produce, if known. Each
+    /// entry holds the upper bound of a bucket and the cumulative number of
+    /// values up to (and including) that bound. The first bucket starts at
+    /// the lower bound of `interval`.
+    pub histogram: Option<Vec<(ScalarValue, u64)>>,
+    /// The estimated fraction of values that remain after the analysis narrowed
+    /// `interval` down from its initial value, if known. This is the share of
+    /// this column in the selectivity of [`AnalysisContext`].
+    pub selectivity: Option<f64>,
 }
 
 impl ExprBoundaries {
@@ -109,12 +492,122 @@ impl ExprBoundaries {
             ),
         );
         let column = Column::new(field.name(), col_index);
+        let confidence =
+            precision_of(&col_stats.min_value).min(&precision_of(&col_stats.max_value));
         Ok(ExprBoundaries {
             column,
             interval,
+            confidence,
             distinct_count: col_stats.distinct_count.clone(),
+            null_count: col_stats.null_count.clone(),
+            histogram: col_stats.histogram.clone(),
+            selectivity: None,
         })
     }
+
+    /// Creates `ExprBoundaries` for an arbitrary expression over the columns
+    /// with the given boundaries, e.g. `price * quantity` or
+    /// `date_part('year', ts)`. The interval of the expression is computed from
+    /// those of the columns via interval arithmetic, see
+    /// [`ExprIntervalGraph::evaluate_bounds`]. Supported are columns, literals,
+    /// additions, subtractions, multiplications, negations, casts, and scalar
+    /// functions that are monotonic in their arguments (e.g. `date_trunc`, or
+    /// `date_part` extracting the year). Returns `None` if `expr` contains any
+    /// other expression, or a column without boundaries.
+    ///
+    /// Unless `expr` is a column, the result is named after `expr` and has the
+    /// index of a column appended to `column_boundaries`. The distinct count
+    /// is estimated by [`estimate_distinct_count`]. If `expr` references a
+    /// single column, the null count of that column carries over inexactly.
+    pub fn try_from_physical_expr(
+        expr: &Arc<dyn PhysicalExpr>,
+        column_boundaries: &[ExprBoundaries],
+    ) -> Result<Option<ExprBoundaries>> {
+        let find = |column: &Column| {
+            column_boundaries
+                .iter()
+                .find(|bound| &bound.column == column)
+        };
+        if let Some(column) = expr.as_any().downcast_ref::<Column>() {
+            return Ok(find(column).cloned());
+        }
+        let Some((interval, referenced)) = evaluate_expr_bounds(expr, column_boundaries)?
+        else {
+            return Ok(None);
+        };
+
+        let confidence = referenced
+            .iter()
+            .fold(Precision::Exact(()), |confidence, bound| {
+                confidence.min(&bound.confidence)
+            });
+        let null_count = match referenced.as_slice() {
+            [bound] => bound.null_count.clone().to_inexact(),
+            _ => Precision::Absent,
+        };
+        Ok(Some(ExprBoundaries {
+            column: Column::new(&expr.to_string(), column_boundaries.len()),
+            interval,
+            confidence,
+            distinct_count: estimate_distinct_count(expr, column_boundaries),
+            null_count,
+            histogram: None,
+            selectivity: None,
+        }))
+    }
+
+    /// Returns the fraction of the values in the `initial` boundaries that fall
+    /// into `interval`. The computation integrates over the histogram of the
+    /// column if there is one; otherwise, it assumes a uniform distribution.
+    fn ratio_of(initial: &ExprBoundaries, interval: &Interval) -> Result<f64> {
+        match &initial.histogram {
+            Some(histogram) => histogram_ratio(histogram, &initial.interval, interval),
+            None => cardinality_ratio(&initial.interval, interval),
+        }
+    }
+
+    /// Combines these boundaries with the `other` boundaries of the same
+    /// column over a separate input. See [`AnalysisContext::merge`].
+    fn merge(&self, other: &ExprBoundaries, weights: (f64, f64)) -> ExprBoundaries {
+        let interval = self
+            .interval
+            .union(&other.interval)
+            .unwrap_or_else(|_| unbounded_like(&self.interval));
+        ExprBoundaries {
+            column: self.column.clone(),
+            interval,
+            confidence: self.confidence.min(&other.confidence),
+            distinct_count: self.distinct_count.add(&other.distinct_count),
+            null_count: self.null_count.add(&other.null_count),
+            histogram: None,
+            selectivity: weighted_average(self.selectivity, other.selectivity, weights),
+        }
+    }
+
+    /// Returns boundaries for the same column that carry no information.
+    fn to_unknown(&self) -> ExprBoundaries {
+        ExprBoundaries {
+            column: self.column.clone(),
+            interval: unbounded_like(&self.interval),
+            confidence: Precision::Absent,
+            distinct_count: Precision::Absent,
+            null_count: Precision::Absent,
+            histogram: None,
+            selectivity: None,
+        }
+    }
+
+    /// Returns the fraction of NULL values among `num_rows` rows, if both the
+    /// null count and the number of rows are known.
+    pub fn null_fraction(&self, num_rows: &Precision<usize>) -> Option<f64> {
+        match (self.null_count.get_value(), num_rows.get_value()) {
+            (_, Some(0)) => None,
+            (Some(null_count), Some(num_rows)) => {
+                Some((*null_count as f64 / *num_rows as f64).min(1.0))
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Attempts to refine column boundaries and compute a selectivity value.
@@ -122,7 +615,29 @@ impl ExprBoundaries {
 /// The function accepts boundaries of the input columns in the `context` parameter.
 /// It then tries to tighten these boundaries based on the provided `expr`.
 /// The resulting selectivity value is calculated by comparing the initial and final boundaries.
-/// The computation assumes that the data within the column is uniformly distributed and not sorted.
+/// If a column carries a histogram, the computation integrates over its buckets; otherwise,
+/// it assumes that the data within the column is uniformly distributed and not sorted.
+/// When the null count of a column and the number of rows are known, the selectivity
+/// also accounts for NULL values, which never satisfy the supported (null-intolerant)
+/// predicates. `IS NULL` and `IS NOT NULL` checks on a column are estimated directly
+/// from its null fraction, and comparisons of a boolean column with a literal from
+/// the fraction of its values that are `true`. A `LIKE` predicate with a prefix-anchored pattern, such as
+/// `name LIKE 'abc%'`, restricts the column to the range `["abc", "abd")`; other
+/// patterns leave the boundaries untouched and are assumed to have the default
+/// selectivity of the context. The distinct count of each column is scaled by
+/// the fraction of its values that remain, and capped by the number of rows
+/// that remain. The selectivity is bounded according to the precision of the
+/// statistics it is derived from, see [`SelectivityEstimate`]. The
+/// sub-expressions that defeated the estimation are reported in
+/// [`AnalysisContext::unsupported_exprs`].
+///
+/// An error is returned if `expr` references a column that has no boundaries
+/// in `context`, unless [`AnalysisContext::allow_missing_columns`] is set.
+///
+/// Disjunctions, as well as conjunctions containing disjunctions, are analyzed by
+/// analyzing their children independently. The selectivities of the children are
+/// then combined using the inclusion-exclusion formula `P(A) + P(B) - P(A ∩ B)` for
+/// `OR`, and the product `P(A) * P(B)` for `AND`.
 ///
 /// # Arguments
 ///
@@ -136,9 +651,234 @@ pub fn analyze(
     expr: &Arc<dyn PhysicalExpr>,
     context: AnalysisContext,
 ) -> Result<AnalysisContext> {
-    let target_boundaries = context.boundaries;
+    let indices = boundary_indices(&context.boundaries);
+    analyze_checked(expr, context, &indices)
+}
 
-    let mut graph = ExprIntervalGraph::try_new(expr.clone())?;
+/// Analyzes each of the given predicates as described in [`analyze`], starting
+/// from the same `context`. The result for each predicate is identical to that
+/// of a separate [`analyze`] call, but the positions of the columns within the
+/// boundaries of `context` are only resolved once for all predicates.
+pub fn analyze_many(
+    exprs: &[Arc<dyn PhysicalExpr>],
+    context: AnalysisContext,
+) -> Result<Vec<AnalysisContext>> {
+    let indices = boundary_indices(&context.boundaries);
+    exprs
+        .iter()
+        .map(|expr| analyze_checked(expr, context.clone(), &indices))
+        .collect()
+}
+
+/// Maps each column of `boundaries` to its position in `boundaries`. The
+/// positions remain valid for the boundaries derived from them during the
+/// analysis, which keep the order of the columns.
+fn boundary_indices(boundaries: &[ExprBoundaries]) -> HashMap<Column, usize> {
+    let mut indices = HashMap::with_capacity(boundaries.len());
+    for (index, bound) in boundaries.iter().enumerate() {
+        indices.entry(bound.column.clone()).or_insert(index);
+    }
+    indices
+}
+
+/// Analyzes `expr` as described in [`analyze`], given the positions of the
+/// columns within the boundaries of `context`, after checking that all the
+/// columns referenced by `expr` have boundaries.
+fn analyze_checked(
+    expr: &Arc<dyn PhysicalExpr>,
+    context: AnalysisContext,
+    indices: &HashMap<Column, usize>,
+) -> Result<AnalysisContext> {
+    let mut missing = collect_columns(expr)
+        .into_iter()
+        .filter(|column| !indices.contains_key(column))
+        .map(|column| column.to_string())
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return analyze_with_indices(expr, context, indices);
+    }
+    if !context.allow_missing_columns {
+        missing.sort();
+        return plan_err!(
+            "Can not analyze {expr}, as there are no boundaries for the column(s) {}",
+            missing.join(", ")
+        );
+    }
+    // The conjuncts referencing missing columns are left unconstrained:
+    let default_selectivity = context.default_selectivity;
+    let constrained = split_conjunction(expr)
+        .into_iter()
+        .filter(|conjunct| {
+            collect_columns(conjunct)
+                .iter()
+                .all(|column| indices.contains_key(column))
+        })
+        .cloned()
+        .reduce(|left, right| {
+            Arc::new(BinaryExpr::new(left, Operator::And, right)) as Arc<dyn PhysicalExpr>
+        });
+    let result = match constrained {
+        Some(constrained) => analyze_with_indices(&constrained, context, indices)?,
+        None => AnalysisContext {
+            selectivity: None,
+            ..context
+        },
+    };
+    let point = result
+        .selectivity
+        .as_ref()
+        .map_or(default_selectivity, |selectivity| {
+            selectivity.point.min(default_selectivity)
+        });
+    Ok(result
+        .with_selectivity(SelectivityEstimate::new(point, Precision::Absent))
+        .with_selectivity_precision(Precision::Absent))
+}
+
+/// Analyzes `expr` as described in [`analyze`], given the positions of the
+/// columns within the boundaries of `context`.
+fn analyze_with_indices(
+    expr: &Arc<dyn PhysicalExpr>,
+    context: AnalysisContext,
+    indices: &HashMap<Column, usize>,
+) -> Result<AnalysisContext> {
+    let default_selectivity = context.default_selectivity;
+    let allow_missing_columns = context.allow_missing_columns;
+    let correlations = context.correlations.clone();
+    analyze_predicate(expr, context, indices).map(|result| AnalysisContext {
+        default_selectivity,
+        allow_missing_columns,
+        ..result.with_correlations(correlations)
+    })
+}
+
+/// Analyzes `expr` as described in [`analyze`]. The resulting context does not
+/// retain the default selectivity and the correlations of `context`.
+fn analyze_predicate(
+    expr: &Arc<dyn PhysicalExpr>,
+    context: AnalysisContext,
+    indices: &HashMap<Column, usize>,
+) -> Result<AnalysisContext> {
+    if let Some(negated) = negate_comparison(expr) {
+        return analyze_predicate(&negated, context, indices);
+    }
+    if let Some(not) = expr.as_any().downcast_ref::<NotExpr>() {
+        return analyze_negation(not, context, indices);
+    }
+    if let Some(binary) = expr.as_any().downcast_ref::<BinaryExpr>() {
+        let decompose = match binary.op() {
+            Operator::Or => true,
+            Operator::And => {
+                contains_disjunction(binary.left())
+                    || contains_disjunction(binary.right())
+            }
+            _ => false,
+        };
+        if decompose {
+            if let Some(regrouped) = group_conjuncts(expr) {
+                return analyze_connective(&regrouped, context, indices);
+            }
+            return analyze_connective(binary, context, indices);
+        }
+    }
+
+    let AnalysisContext {
+        boundaries: target_boundaries,
+        num_rows,
+        default_selectivity,
+        correlations,
+        ..
+    } = context;
+
+    // The selectivity of a null check is unknown unless the null fraction is.
+    if let Some((column, negated)) = as_column_null_check(expr) {
+        let estimate = indices.get(column).and_then(|&index| {
+            let bound = &target_boundaries[index];
+            let fraction = bound.null_fraction(&num_rows)?;
+            let point = if negated { 1.0 - fraction } else { fraction };
+            let confidence =
+                precision_of(&bound.null_count).min(&precision_of(&num_rows));
+            Some(SelectivityEstimate::new(point, confidence))
+        });
+        let context = AnalysisContext::new(target_boundaries).with_num_rows(num_rows);
+        // The null fraction determines the selectivity as exactly as it is known:
+        return Ok(match estimate {
+            Some(estimate) => {
+                let precision = estimate.confidence.clone();
+                context
+                    .with_selectivity(estimate)
+                    .with_selectivity_precision(precision)
+            }
+            None => context,
+        });
+    }
+
+    // Interval arithmetic does not support booleans, so comparisons of boolean
+    // columns with a literal are estimated from the fraction of `true` values.
+    if let Some((column, value)) = as_boolean_column_comparison(expr) {
+        if let Some(&index) = indices.get(column) {
+            let mut target_boundaries = target_boundaries;
+            let bound = &mut target_boundaries[index];
+            let (true_fraction, confidence) = true_fraction(bound);
+            let fraction = if value {
+                true_fraction
+            } else {
+                1.0 - true_fraction
+            };
+            let null_fraction = bound.null_fraction(&num_rows);
+            let confidence = match null_fraction {
+                Some(_) => confidence
+                    .min(&precision_of(&bound.null_count))
+                    .min(&precision_of(&num_rows)),
+                None => confidence.min(&Precision::Inexact(())),
+            };
+            let point = fraction * (1.0 - null_fraction.unwrap_or(0.0));
+            if fraction > 0.0 {
+                let value = ScalarValue::Boolean(Some(value));
+                bound.interval = Interval::new(
+                    IntervalBound::new_closed(value.clone()),
+                    IntervalBound::new_closed(value),
+                );
+            }
+            bound.selectivity = Some(fraction);
+            let precision = confidence.clone();
+            return Ok(AnalysisContext::new(target_boundaries)
+                .with_num_rows(num_rows)
+                .with_selectivity(SelectivityEstimate::new(point, confidence))
+                .with_selectivity_precision(precision));
+        }
+    }
+
+    // The selectivity of an IN list on a column is estimated from the distinct
+    // count of the column, if known, rather than from the interval covering
+    // the list items.
+    let in_list_estimate = match as_column_in_list(expr) {
+        Some((in_list, column)) => match indices.get(column) {
+            Some(&index) => in_list_selectivity(in_list, &target_boundaries[index])?
+                .map(|selectivity| (column.clone(), selectivity)),
+            None => None,
+        },
+        None => None,
+    };
+
+    // Expressions that interval arithmetic does not support are assumed to
+    // have the default selectivity:
+    let unknown = |target_boundaries: Vec<ExprBoundaries>, num_rows: Precision<usize>| {
+        AnalysisContext::new(target_boundaries)
+            .with_num_rows(num_rows)
+            .with_selectivity(SelectivityEstimate::new(
+                default_selectivity,
+                Precision::Absent,
+            ))
+            .with_selectivity_precision(Precision::Absent)
+            .with_unsupported_exprs(unsupported_sub_exprs(expr))
+    };
+    let mut graph = match ExprIntervalGraph::try_new(expr.clone()) {
+        Err(DataFusionError::NotImplemented(_)) => {
+            return Ok(unknown(target_boundaries, num_rows))
+        }
+        graph => graph?,
+    };
 
     let columns: Vec<Arc<dyn PhysicalExpr>> = collect_columns(expr)
         .into_iter()
@@ -147,106 +887,3717 @@ pub fn analyze(
 
     let target_expr_and_indices: Vec<(Arc<dyn PhysicalExpr>, usize)> =
         graph.gather_node_indices(columns.as_slice());
+    let referenced = referenced_indices(&target_expr_and_indices, indices);
 
     let mut target_indices_and_boundaries: Vec<(usize, Interval)> =
         target_expr_and_indices
             .iter()
             .filter_map(|(expr, i)| {
-                target_boundaries.iter().find_map(|bound| {
-                    expr.as_any()
-                        .downcast_ref::<Column>()
-                        .filter(|expr_column| bound.column.eq(*expr_column))
-                        .map(|_| (*i, bound.interval.clone()))
-                })
+                let column = expr.as_any().downcast_ref::<Column>()?;
+                let index = indices.get(column)?;
+                Some((*i, target_boundaries[*index].interval.clone()))
             })
             .collect();
-    Ok(
-        match graph.update_ranges(&mut target_indices_and_boundaries)? {
-            PropagationResult::Success => shrink_boundaries(
+    // Columns whose boundaries hold a single value are constants, which may
+    // decide the predicate without propagating any constraints:
+    let propagation = if fold_point_intervals(&mut graph, &target_boundaries) > 0 {
+        graph.assign_intervals(&target_indices_and_boundaries);
+        match graph.try_evaluate_constant() {
+            Ok(Some(true)) => Ok(PropagationResult::CannotPropagate),
+            Ok(Some(false)) => Ok(PropagationResult::Infeasible),
+            Ok(None) => graph.update_ranges(&mut target_indices_and_boundaries),
+            Err(e) => Err(e),
+        }
+    } else {
+        graph.update_ranges(&mut target_indices_and_boundaries)
+    };
+    let propagation = match propagation {
+        Err(DataFusionError::NotImplemented(_)) => {
+            return Ok(unknown(target_boundaries, num_rows))
+        }
+        propagation => propagation?,
+    };
+    Ok(match propagation {
+        PropagationResult::Success => {
+            let context = shrink_boundaries(
                 expr,
                 graph,
                 target_boundaries,
                 target_expr_and_indices,
-            )?,
-            PropagationResult::Infeasible => {
-                AnalysisContext::new(target_boundaries).with_selectivity(0.0)
-            }
-            PropagationResult::CannotPropagate => {
-                AnalysisContext::new(target_boundaries).with_selectivity(1.0)
+                &num_rows,
+                &correlations,
+                indices,
+            )?;
+            let context = match in_list_estimate {
+                Some((column, selectivity)) => with_column_selectivity(
+                    context,
+                    &column,
+                    selectivity,
+                    &num_rows,
+                    indices,
+                ),
+                None => context,
+            };
+            // Interval arithmetic does not account for the selectivity of
+            // patterns that can not be turned into ranges, which is a guess:
+            match (&context.selectivity, count_unestimated_patterns(expr)?) {
+                (Some(selectivity), count) if count > 0 => {
+                    let selectivity = selectivity
+                        .scale(default_selectivity.powi(count), &Precision::Absent);
+                    context
+                        .with_selectivity(selectivity)
+                        .with_selectivity_precision(Precision::Inexact(()))
+                }
+                _ => context,
             }
-        },
-    )
+        }
+        PropagationResult::Infeasible => {
+            let confidence = bounds_confidence(&target_boundaries, &referenced);
+            let precision = limit_to_bounds_confidence(
+                Precision::Exact(()),
+                &target_boundaries,
+                &referenced,
+            );
+            AnalysisContext::new(target_boundaries)
+                .with_selectivity(SelectivityEstimate::new(0.0, confidence))
+                .with_selectivity_precision(precision)
+        }
+        // The predicate holds for all non-NULL values if it is certainly
+        // true. Otherwise, its selectivity is unknown:
+        PropagationResult::CannotPropagate => {
+            let root = graph.gather_node_indices(std::slice::from_ref(expr));
+            let certainly_true = root.first().is_some_and(|(_, index)| {
+                graph.get_interval(*index) == Interval::CERTAINLY_TRUE
+            });
+            let confidence = bounds_confidence(&target_boundaries, &referenced);
+            let (selectivity, precision, unsupported_exprs) = if certainly_true {
+                let precision = limit_to_bounds_confidence(
+                    non_null_precision(&target_boundaries, &referenced, &num_rows),
+                    &target_boundaries,
+                    &referenced,
+                );
+                (SelectivityEstimate::new(1.0, confidence), precision, vec![])
+            } else {
+                let selectivity =
+                    SelectivityEstimate::new(default_selectivity, Precision::Absent);
+                let unsupported_exprs = unsupported_sub_exprs(expr);
+                (selectivity, Precision::Absent, unsupported_exprs)
+            };
+            let selectivity = selectivity.scale(
+                non_null_fraction(&target_boundaries, &referenced, &num_rows),
+                &Precision::Exact(()),
+            );
+            AnalysisContext::new(target_boundaries)
+                .with_selectivity(selectivity)
+                .with_selectivity_precision(precision)
+                .with_unsupported_exprs(unsupported_exprs)
+        }
+    }
+    .with_num_rows(num_rows))
 }
 
-/// If the `PropagationResult` indicates success, this function calculates the
-/// selectivity value by comparing the initial and final column boundaries.
-/// Following this, it constructs and returns a new `AnalysisContext` with the
-/// updated parameters.
-fn shrink_boundaries(
+/// Replaces the columns of `graph` whose boundaries (among `boundaries`) are a
+/// single value, such as partition columns within a partition, with literals
+/// holding that value. [`ExprIntervalGraph::try_evaluate_constant`] can then
+/// tell whether these constants decide the expression. Returns the number of
+/// replaced columns.
+pub fn fold_point_intervals(
+    graph: &mut ExprIntervalGraph,
+    boundaries: &[ExprBoundaries],
+) -> usize {
+    graph
+        .fold_leaves(|expr| {
+            let column = expr.as_any().downcast_ref::<Column>()?;
+            let interval = &boundaries
+                .iter()
+                .find(|bound| &bound.column == column)?
+                .interval;
+            let is_point = !interval.lower.is_unbounded()
+                && !interval.lower.open
+                && !interval.upper.open
+                && interval.lower.value == interval.upper.value;
+            is_point.then(|| interval.lower.value.clone())
+        })
+        .len()
+}
+
+/// Determines which partitions may contain rows satisfying the predicate `expr`,
+/// given the column statistics of each partition (ordered by the index of the
+/// column in `schema`). Each partition is analyzed with [`analyze`], and is
+/// pruned if no row can satisfy the predicate, i.e. the resulting selectivity is
+/// zero.
+///
+/// Like `PruningPredicate::prune`, the result holds one flag per partition, which
+/// is `true` if the partition must be kept and `false` if it can be skipped.
+/// No partition is pruned if the predicate is not supported by the analysis.
+pub fn prune_partitions(
     expr: &Arc<dyn PhysicalExpr>,
-    mut graph: ExprIntervalGraph,
-    mut target_boundaries: Vec<ExprBoundaries>,
-    target_expr_and_indices: Vec<(Arc<dyn PhysicalExpr>, usize)>,
-) -> Result<AnalysisContext> {
-    let initial_boundaries = target_boundaries.clone();
-    target_expr_and_indices.iter().for_each(|(expr, i)| {
-        if let Some(column) = expr.as_any().downcast_ref::<Column>() {
-            if let Some(bound) = target_boundaries
-                .iter_mut()
-                .find(|bound| bound.column.eq(column))
-            {
-                bound.interval = graph.get_interval(*i);
+    partition_statistics: &[Vec<ColumnStatistics>],
+    schema: &Schema,
+) -> Result<Vec<bool>> {
+    if !check_support(expr, &Arc::new(schema.clone()))
+        && as_column_null_check(expr).is_none()
+    {
+        return Ok(vec![true; partition_statistics.len()]);
+    }
+    partition_statistics
+        .iter()
+        .map(|statistics| {
+            let context = AnalysisContext::try_from_statistics(schema, statistics)?;
+            let selectivity = analyze(expr, context)?.selectivity;
+            Ok(selectivity.map(|selectivity| selectivity.point) != Some(0.0))
+        })
+        .collect()
+}
+
+/// Estimates the number of rows produced by an inner equi-join of the inputs
+/// described by `left_ctx` and `right_ctx` on the given pairs of join keys.
+///
+/// The intervals of the keys in each pair are intersected. If they are
+/// disjoint for any pair, no row can find a match and the estimate is zero.
+/// Otherwise, only rows whose keys fall into the overlap can match: assuming
+/// uniformly distributed values, the rows and the distinct count of each side
+/// are scaled by the fraction of its interval that overlaps, and the pair is
+/// estimated to produce `left_rows * right_rows / max(left_ndv, right_ndv)`
+/// rows. A key without a distinct count uses the cardinality of the overlap
+/// instead, if it can be computed. Each pair bounds the output independently, so the smallest
+/// estimate among the pairs is returned.
+///
+/// The rows of each side are its `num_rows`, scaled by its selectivity if
+/// known. Returns `None` if the number of rows of either side is unknown, if
+/// a join key has no boundaries in its context, or if the distinct counts of
+/// no pair can be estimated.
+pub fn estimate_join_cardinality(
+    left_ctx: &AnalysisContext,
+    right_ctx: &AnalysisContext,
+    join_keys: &[(Column, Column)],
+) -> Option<usize> {
+    let num_rows = |context: &AnalysisContext| {
+        let selectivity = context.selectivity.as_ref().map_or(1.0, |s| s.point);
+        context
+            .num_rows
+            .get_value()
+            .map(|num_rows| *num_rows as f64 * selectivity)
+    };
+    let (left_rows, right_rows) = (num_rows(left_ctx)?, num_rows(right_ctx)?);
+
+    let mut estimate: Option<f64> = None;
+    for (left_key, right_key) in join_keys {
+        let left = find_boundaries(left_ctx, left_key)?;
+        let right = find_boundaries(right_ctx, right_key)?;
+        let Some(overlap) = left.interval.intersect(&right.interval).ok()? else {
+            return Some(0);
+        };
+        let overlap_cardinality = overlap.cardinality().ok().flatten();
+        // Returns the number of rows and distinct values of a side that fall
+        // into the overlap.
+        let restrict = |bound: &ExprBoundaries, num_rows: f64| {
+            let fraction = cardinality_ratio(&bound.interval, &overlap)
+                .ok()
+                .filter(|fraction| fraction.is_finite())
+                .unwrap_or(1.0);
+            let num_rows = num_rows * fraction;
+            let distinct_count = match bound.distinct_count.get_value() {
+                Some(distinct_count) => Some(*distinct_count as f64 * fraction),
+                None => overlap_cardinality.map(|cardinality| cardinality as f64),
             };
+            (
+                num_rows,
+                distinct_count.map(|distinct_count| distinct_count.min(num_rows)),
+            )
+        };
+        let (left_rows, left_ndv) = restrict(left, left_rows);
+        let (right_rows, right_ndv) = restrict(right, right_rows);
+        let max_ndv = match (left_ndv, right_ndv) {
+            (Some(left_ndv), Some(right_ndv)) => left_ndv.max(right_ndv),
+            (Some(ndv), None) | (None, Some(ndv)) => ndv,
+            (None, None) => continue,
+        };
+        let pair_estimate = left_rows * right_rows / max_ndv.max(1.0);
+        estimate = Some(estimate.map_or(pair_estimate, |e| e.min(pair_estimate)));
+    }
+    estimate.map(|estimate| estimate.round() as usize)
+}
+
+/// Returns the boundaries of `column` in `context`, if any.
+fn find_boundaries<'a>(
+    context: &'a AnalysisContext,
+    column: &Column,
+) -> Option<&'a ExprBoundaries> {
+    context
+        .boundaries
+        .iter()
+        .find(|bound| &bound.column == column)
+}
+
+/// Returns the number of `LIKE` predicates in `expr` whose patterns do not
+/// translate into a range of strings (see [`LikeExpr::matching_interval`]).
+fn count_unestimated_patterns(expr: &Arc<dyn PhysicalExpr>) -> Result<i32> {
+    let mut count = 0;
+    expr.apply(&mut |expr| {
+        if let Some(like) = expr.as_any().downcast_ref::<LikeExpr>() {
+            let matching = match like.pattern().as_any().downcast_ref::<Literal>() {
+                Some(pattern) => like.matching_interval(pattern.value())?,
+                None => None,
+            };
+            if matching.is_none() {
+                count += 1;
+            }
         }
-    });
-    let graph_nodes = graph.gather_node_indices(&[expr.clone()]);
-    let Some((_, root_index)) = graph_nodes.get(0) else {
-        return internal_err!(
-            "The ExprIntervalGraph under investigation does not have any nodes."
-        );
+        Ok(VisitRecursion::Continue)
+    })?;
+    Ok(count)
+}
+
+/// Returns whether the bounds of `expr` can be evaluated from those of the
+/// columns it references, see [`ExprBoundaries::try_from_physical_expr`].
+fn is_bounds_evaluable(expr: &Arc<dyn PhysicalExpr>) -> bool {
+    let expr_any = expr.as_any();
+    let supported = if let Some(binary) = expr_any.downcast_ref::<BinaryExpr>() {
+        matches!(
+            binary.op(),
+            Operator::Plus | Operator::Minus | Operator::Multiply
+        )
+    } else if let Some(function) = expr_any.downcast_ref::<ScalarFunctionExpr>() {
+        function.monotonicity().is_some()
+    } else {
+        expr_any.is::<Column>()
+            || expr_any.is::<Literal>()
+            || expr_any.is::<CastExpr>()
+            || expr_any.is::<NegativeExpr>()
     };
-    let final_result = graph.get_interval(*root_index);
+    supported && expr.children().iter().all(is_bounds_evaluable)
+}
 
-    let selectivity = calculate_selectivity(
-        &final_result.lower.value,
-        &final_result.upper.value,
-        &target_boundaries,
-        &initial_boundaries,
-    )?;
+/// Evaluates the interval of `expr` from the boundaries of the columns it
+/// references, along with the boundaries of these columns. Returns `None` if
+/// the bounds of `expr` can not be evaluated (see [`is_bounds_evaluable`]), or
+/// a referenced column has no boundaries.
+fn evaluate_expr_bounds<'a>(
+    expr: &Arc<dyn PhysicalExpr>,
+    column_boundaries: &'a [ExprBoundaries],
+) -> Result<Option<(Interval, Vec<&'a ExprBoundaries>)>> {
+    if !is_bounds_evaluable(expr) {
+        return Ok(None);
+    }
+    let Some(referenced) = collect_columns(expr)
+        .iter()
+        .map(|column| {
+            column_boundaries
+                .iter()
+                .find(|bound| &bound.column == column)
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(None);
+    };
 
-    Ok(AnalysisContext::new(target_boundaries).with_selectivity(selectivity))
+    let mut graph = ExprIntervalGraph::try_new(expr.clone())?;
+    let leaves = referenced
+        .iter()
+        .map(|bound| Arc::new(bound.column.clone()) as Arc<dyn PhysicalExpr>)
+        .collect::<Vec<_>>();
+    let assignments = graph
+        .gather_node_indices(&leaves)
+        .into_iter()
+        .zip(&referenced)
+        .map(|((_, index), bound)| (index, bound.interval.clone()))
+        .collect::<Vec<_>>();
+    graph.assign_intervals(&assignments);
+    let interval = graph.evaluate_bounds()?.clone();
+    Ok(Some((interval, referenced)))
 }
 
-/// This function calculates the filter predicate's selectivity by comparing
-/// the initial and pruned column boundaries. Selectivity is defined as the
-/// ratio of rows in a table that satisfy the filter's predicate.
+/// Estimates the number of distinct values of `expr` over the columns with the
+/// given boundaries, e.g. to estimate the number of groups of `GROUP BY a + b`.
+/// The estimate is derived as follows:
+/// - A column has its own distinct count, and a literal a single value.
+/// - A cast preserves the distinct count of its input if it is lossless, e.g.
+///   from `Int32` to `Int64`. Otherwise, the count is also bounded by the
+///   number of values in the range of the result.
+/// - A negation, as well as the addition or subtraction of a literal,
+///   preserves the distinct count of its input.
+/// - The sum or difference of two inputs has `NDV(a) + NDV(b) - 1` distinct
+///   values, and their product `NDV(a) * NDV(b)`, but no more than there are
+///   values in the range of the result.
+/// - `date_trunc` and `date_part` are bounded by the number of periods that
+///   the range of their input spans, e.g. 3 for months from January 31 to
+///   March 1, counted in UTC. `date_part` is also bounded by the number of
+///   values the part can take, e.g. 12 for months.
+/// - Any other expression of a single column has at most as many distinct
+///   values as that column.
 ///
-/// An exact propagation result at the root, i.e. `[true, true]` or `[false, false]`,
-/// leads to early exit (returning a selectivity value of either 1.0 or 0.0). In such
-/// a case, `[true, true]` indicates that all data values satisfy the predicate (hence,
-/// selectivity is 1.0), and `[false, false]` suggests that no data value meets the
-/// predicate (therefore, selectivity is 0.0).
-fn calculate_selectivity(
-    lower_value: &ScalarValue,
-    upper_value: &ScalarValue,
-    target_boundaries: &[ExprBoundaries],
-    initial_boundaries: &[ExprBoundaries],
-) -> Result<f64> {
-    match (lower_value, upper_value) {
-        (ScalarValue::Boolean(Some(true)), ScalarValue::Boolean(Some(true))) => Ok(1.0),
-        (ScalarValue::Boolean(Some(false)), ScalarValue::Boolean(Some(false))) => Ok(0.0),
-        _ => {
-            // Since the intervals are assumed uniform and the values
-            // are not correlated, we need to multiply the selectivities
-            // of multiple columns to get the overall selectivity.
-            target_boundaries.iter().enumerate().try_fold(
-                1.0,
-                |acc, (i, ExprBoundaries { interval, .. })| {
-                    let temp =
-                        cardinality_ratio(&initial_boundaries[i].interval, interval)?;
-                    Ok(acc * temp)
-                },
-            )
+/// Bounds on the distinct count also apply if the distinct counts of the
+/// inputs are unknown. The estimate is exact only if the expression preserves
+/// the exact distinct count of its input.
+pub fn estimate_distinct_count(
+    expr: &Arc<dyn PhysicalExpr>,
+    column_boundaries: &[ExprBoundaries],
+) -> Precision<usize> {
+    let expr_any = expr.as_any();
+    if let Some(column) = expr_any.downcast_ref::<Column>() {
+        return column_boundaries
+            .iter()
+            .find(|bound| &bound.column == column)
+            .map_or(Precision::Absent, |bound| bound.distinct_count.clone());
+    }
+    if expr_any.is::<Literal>() {
+        return Precision::Exact(1);
+    }
+    let estimate =
+        |expr: &Arc<dyn PhysicalExpr>| estimate_distinct_count(expr, column_boundaries);
+    let interval_of =
+        |expr: &Arc<dyn PhysicalExpr>| match expr.as_any().downcast_ref::<Column>() {
+            Some(column) => column_boundaries
+                .iter()
+                .find(|bound| &bound.column == column)
+                .map(|bound| bound.interval.clone()),
+            None => evaluate_expr_bounds(expr, column_boundaries)
+                .ok()
+                .flatten()
+                .map(|(interval, _)| interval),
+        };
+    let range_width = || {
+        let width = interval_of(expr)?.cardinality().ok()??;
+        Some(usize::try_from(width).unwrap_or(usize::MAX))
+    };
+
+    if let Some(cast) = expr_any.downcast_ref::<CastExpr>() {
+        let input = estimate(cast.expr());
+        let lossless = interval_of(cast.expr())
+            .and_then(|interval| interval.get_datatype().ok())
+            .is_some_and(|from| is_lossless_cast(&from, cast.cast_type()));
+        return if lossless {
+            input
+        } else {
+            bound_distinct_count(input.to_inexact(), range_width())
+        };
+    }
+    if let Some(negative) = expr_any.downcast_ref::<NegativeExpr>() {
+        return estimate(negative.arg());
+    }
+    if let Some(binary) = expr_any.downcast_ref::<BinaryExpr>() {
+        let (left, right) = (binary.left(), binary.right());
+        match binary.op() {
+            Operator::Plus | Operator::Minus if right.as_any().is::<Literal>() => {
+                return estimate(left);
+            }
+            Operator::Plus | Operator::Minus if left.as_any().is::<Literal>() => {
+                return estimate(right);
+            }
+            Operator::Plus | Operator::Minus | Operator::Multiply => {
+                let (left, right) = (estimate(left), estimate(right));
+                let combined = match (left.get_value(), right.get_value()) {
+                    (Some(left), Some(right)) if binary.op() == &Operator::Multiply => {
+                        Precision::Inexact(left.saturating_mul(*right))
+                    }
+                    (Some(left), Some(right)) => {
+                        Precision::Inexact(left.saturating_add(*right).saturating_sub(1))
+                    }
+                    _ => Precision::Absent,
+                };
+                return bound_distinct_count(combined, range_width());
+            }
+            _ => {}
+        }
+    }
+    if let Some(function) = expr_any.downcast_ref::<ScalarFunctionExpr>() {
+        let is_date_part = function.name() == BuiltinScalarFunction::DatePart.to_string();
+        let is_date_trunc =
+            function.name() == BuiltinScalarFunction::DateTrunc.to_string();
+        if let ([granularity, input], true) =
+            (function.args(), is_date_part || is_date_trunc)
+        {
+            let Some(ScalarValue::Utf8(Some(granularity))) = granularity
+                .as_any()
+                .downcast_ref::<Literal>()
+                .map(|literal| literal.value())
+            else {
+                return Precision::Absent;
+            };
+            let granularity = granularity.to_lowercase();
+            let periods = interval_of(input)
+                .and_then(|interval| count_periods(&interval, &granularity));
+            let distinct_count =
+                bound_distinct_count(estimate(input).to_inexact(), periods);
+            return if is_date_part {
+                bound_distinct_count(distinct_count, date_part_domain(&granularity))
+            } else {
+                distinct_count
+            };
+        }
+    }
+    let columns = collect_columns(expr);
+    match columns.iter().next() {
+        Some(column) if columns.len() == 1 => {
+            let column = Arc::new(column.clone()) as Arc<dyn PhysicalExpr>;
+            bound_distinct_count(estimate(&column).to_inexact(), range_width())
+        }
+        _ => Precision::Absent,
+    }
+}
+
+/// Bounds the given distinct count by `max`, if known. An unknown distinct
+/// count is estimated to be `max`.
+fn bound_distinct_count(
+    distinct_count: Precision<usize>,
+    max: Option<usize>,
+) -> Precision<usize> {
+    match (distinct_count, max) {
+        (Precision::Absent, Some(max)) => Precision::Inexact(max),
+        (distinct_count, Some(max))
+            if distinct_count.get_value().is_some_and(|count| *count > max) =>
+        {
+            Precision::Inexact(max)
         }
+        (distinct_count, _) => distinct_count,
+    }
+}
+
+/// Returns whether casting from `from` to `to` maps distinct values to distinct
+/// values.
+fn is_lossless_cast(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+    from == to
+        || matches!(
+            (from, to),
+            (Int8, Int16 | Int32 | Int64 | Float32 | Float64)
+                | (Int16, Int32 | Int64 | Float32 | Float64)
+                | (Int32, Int64 | Float64)
+                | (
+                    UInt8,
+                    UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64 | Float32 | Float64
+                )
+                | (UInt16, UInt32 | UInt64 | Int32 | Int64 | Float32 | Float64)
+                | (UInt32, UInt64 | Int64 | Float64)
+                | (Float32, Float64)
+                | (Date32, Date64)
+                | (Utf8, LargeUtf8)
+                | (LargeUtf8, Utf8)
+        )
+        || (from.is_integer() && matches!(to, Utf8 | LargeUtf8))
+}
+
+/// Returns the number of periods of the given `date_trunc` granularity (or
+/// `date_part` part) that the dates or timestamps in `interval` fall into, in
+/// UTC. Returns `None` if `interval` is unbounded or does not hold dates or
+/// timestamps, or the granularity is not supported.
+fn count_periods(interval: &Interval, granularity: &str) -> Option<usize> {
+    const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+    // Days from 0001-01-01 (day 1 of the common era) to 1970-01-01:
+    const EPOCH_DAYS_FROM_CE: i128 = 719_163;
+    let period = |value: &ScalarValue| -> Option<i128> {
+        let nanos = match value {
+            ScalarValue::TimestampSecond(Some(v), _) => *v as i128 * 1_000_000_000,
+            ScalarValue::TimestampMillisecond(Some(v), _) => *v as i128 * 1_000_000,
+            ScalarValue::TimestampMicrosecond(Some(v), _) => *v as i128 * 1_000,
+            ScalarValue::TimestampNanosecond(Some(v), _) => *v as i128,
+            ScalarValue::Date32(Some(v)) => *v as i128 * NANOS_PER_DAY,
+            ScalarValue::Date64(Some(v)) => *v as i128 * 1_000_000,
+            _ => return None,
+        };
+        let days = nanos.div_euclid(NANOS_PER_DAY);
+        let months = || {
+            let days = i32::try_from(days + EPOCH_DAYS_FROM_CE).ok()?;
+            let date = NaiveDate::from_num_days_from_ce_opt(days)?;
+            Some(date.year() as i128 * 12 + date.month0() as i128)
+        };
+        Some(match granularity {
+            "year" => months()?.div_euclid(12),
+            "quarter" => months()?.div_euclid(3),
+            "month" => months()?,
+            // Weeks start on Mondays, and 1970-01-01 was a Thursday:
+            "week" => (days + 3).div_euclid(7),
+            "day" | "doy" | "dow" => days,
+            "hour" => nanos.div_euclid(3_600_000_000_000),
+            "minute" => nanos.div_euclid(60_000_000_000),
+            "second" => nanos.div_euclid(1_000_000_000),
+            "millisecond" => nanos.div_euclid(1_000_000),
+            "microsecond" => nanos.div_euclid(1_000),
+            _ => return None,
+        })
+    };
+    let count = period(&interval.upper.value)? - period(&interval.lower.value)? + 1;
+    usize::try_from(count.max(0)).ok()
+}
+
+/// Returns the number of values the given `date_part` part can take, if it is
+/// bounded, e.g. 12 for `month`.
+fn date_part_domain(part: &str) -> Option<usize> {
+    match part {
+        "quarter" => Some(4),
+        "month" => Some(12),
+        "week" => Some(53),
+        "day" => Some(31),
+        "doy" => Some(366),
+        "dow" => Some(7),
+        "hour" => Some(24),
+        "minute" => Some(60),
+        _ => None,
+    }
+}
+
+/// Returns whether `expr` is a disjunction, or a conjunction that contains a
+/// disjunction.
+/// IN lists count as disjunctions of equalities, and negations that can not be
+/// rewritten into comparisons as disjunctions of the negated children.
+fn contains_disjunction(expr: &Arc<dyn PhysicalExpr>) -> bool {
+    expr.as_any().is::<InListExpr>()
+        || (expr.as_any().is::<NotExpr>() && negate_comparison(expr).is_none())
+        || expr
+            .as_any()
+            .downcast_ref::<BinaryExpr>()
+            .is_some_and(|binary| match binary.op() {
+                Operator::Or => true,
+                Operator::And => {
+                    contains_disjunction(binary.left())
+                        || contains_disjunction(binary.right())
+                }
+                _ => false,
+            })
+}
+
+/// Regroups the conjuncts of `expr` into `A AND B`, where `A` is the conjunction
+/// of those that do not contain a disjunction and `B` that of the others, so
+/// that [`analyze_connective`] analyzes the former together. This way, the
+/// comparisons on the same column (e.g. the two sides of a `BETWEEN`) shrink a
+/// single interval, and count once towards the selectivity, even if other
+/// conjuncts separate them. Returns `None` if there is nothing to regroup.
+fn group_conjuncts(expr: &Arc<dyn PhysicalExpr>) -> Option<BinaryExpr> {
+    let (plain, disjunctive): (Vec<_>, Vec<_>) = split_conjunction(expr)
+        .into_iter()
+        .partition(|conjunct| !contains_disjunction(conjunct));
+    if plain.len() < 2 || disjunctive.is_empty() {
+        return None;
+    }
+    let conjunction = |conjuncts: Vec<&Arc<dyn PhysicalExpr>>| {
+        conjuncts
+            .into_iter()
+            .cloned()
+            .reduce(|left, right| {
+                Arc::new(BinaryExpr::new(left, Operator::And, right))
+                    as Arc<dyn PhysicalExpr>
+            })
+            .unwrap()
+    };
+    Some(BinaryExpr::new(
+        conjunction(plain),
+        Operator::And,
+        conjunction(disjunctive),
+    ))
+}
+
+/// Analyzes the children of the given conjunction or disjunction independently
+/// and combines the results. The selectivity of a disjunction `A OR B` is
+/// computed with the inclusion-exclusion formula `P(A) + P(B) - P(A ∩ B)`, and
+/// that of a conjunction is the product of the selectivities of its children.
+/// If neither child contains a disjunction, `P(A ∩ B)` is the selectivity of
+/// `A AND B`, which accounts for predicates on the same columns (e.g. it is zero
+/// for `a < 20 OR a > 80`); otherwise, the children are assumed independent.
+/// The resulting column boundaries are the unions (for `OR`) or intersections
+/// (for `AND`) of those of the children. If the selectivity of either child is
+/// unknown, so is the combined one.
+fn analyze_connective(
+    binary: &BinaryExpr,
+    context: AnalysisContext,
+    indices: &HashMap<Column, usize>,
+) -> Result<AnalysisContext> {
+    let left = analyze_with_indices(binary.left(), context.clone(), indices)?;
+    let right = analyze_with_indices(binary.right(), context.clone(), indices)?;
+    let unsupported_exprs =
+        union_exprs(&left.unsupported_exprs, &right.unsupported_exprs);
+    combine_connective(binary, left, right, context, indices)
+        .map(|result| result.with_unsupported_exprs(unsupported_exprs))
+}
+
+/// Combines the results of analyzing the children of the given conjunction or
+/// disjunction, see [`analyze_connective`].
+fn combine_connective(
+    binary: &BinaryExpr,
+    left: AnalysisContext,
+    right: AnalysisContext,
+    context: AnalysisContext,
+    indices: &HashMap<Column, usize>,
+) -> Result<AnalysisContext> {
+    let is_disjunction = binary.op() == &Operator::Or;
+    let AnalysisContext {
+        boundaries: initial_boundaries,
+        num_rows,
+        default_selectivity,
+        correlations,
+        ..
+    } = context;
+
+    let (Some(left_estimate), Some(right_estimate)) =
+        (&left.selectivity, &right.selectivity)
+    else {
+        return Ok(AnalysisContext::new(initial_boundaries).with_num_rows(num_rows));
+    };
+    let (left_selectivity, right_selectivity) =
+        (left_estimate.point, right_estimate.point);
+    // Combining the selectivities assumes that the children are independent,
+    // unless a child selects everything exactly. Such a child decides a
+    // disjunction, and does not change a conjunction.
+    let is_exact =
+        |context: &AnalysisContext| context.selectivity_precision == Precision::Exact(());
+    let selects_all = |context: &AnalysisContext, selectivity: f64| {
+        is_exact(context) && selectivity == 1.0
+    };
+    let precision = match (
+        selects_all(&left, left_selectivity),
+        selects_all(&right, right_selectivity),
+    ) {
+        (true, _) if is_disjunction || is_exact(&right) => Precision::Exact(()),
+        (_, true) if is_disjunction || is_exact(&left) => Precision::Exact(()),
+        _ => Precision::Inexact(()),
+    };
+    let mut confidence = left_estimate.confidence.min(&right_estimate.confidence);
+    // A child that selects nothing does not contribute to a disjunction, and
+    // makes a conjunction select nothing.
+    if left_selectivity == 0.0 {
+        return Ok(if is_disjunction { right } else { left });
+    }
+    if right_selectivity == 0.0 {
+        return Ok(if is_disjunction { left } else { right });
+    }
+
+    let independent_selectivity = left_selectivity * right_selectivity;
+    let selectivity = if !is_disjunction {
+        independent_selectivity
+    } else if [binary.left(), binary.right()]
+        .into_iter()
+        .any(|child| contains_disjunction(child) || as_column_null_check(child).is_some())
+    {
+        left_selectivity + right_selectivity - independent_selectivity
+    } else {
+        let conjunction = Arc::new(BinaryExpr::new(
+            binary.left().clone(),
+            Operator::And,
+            binary.right().clone(),
+        )) as Arc<dyn PhysicalExpr>;
+        let context = AnalysisContext {
+            default_selectivity,
+            ..AnalysisContext::new(initial_boundaries.clone())
+                .with_num_rows(num_rows.clone())
+                .with_correlations(correlations)
+        };
+        // An intersection that can not be estimated falls back to independence:
+        let intersection = analyze_with_indices(&conjunction, context, indices)?;
+        let intersection_selectivity = match intersection
+            .selectivity
+            .filter(|_| intersection.selectivity_precision != Precision::Absent)
+        {
+            Some(intersection) => {
+                confidence = confidence.min(&intersection.confidence);
+                intersection.point
+            }
+            None => independent_selectivity,
+        };
+        (left_selectivity + right_selectivity - intersection_selectivity)
+            .clamp(left_selectivity.max(right_selectivity), 1.0)
+    };
+
+    let mut boundaries = Vec::with_capacity(initial_boundaries.len());
+    for (initial, (left_bound, right_bound)) in initial_boundaries
+        .into_iter()
+        .zip(left.boundaries.iter().zip(right.boundaries.iter()))
+    {
+        let interval = if is_disjunction {
+            left_bound.interval.union(&right_bound.interval)?
+        } else if let Some(interval) =
+            left_bound.interval.intersect(&right_bound.interval)?
+        {
+            interval
+        } else {
+            // The children can not be satisfied simultaneously, as far as the
+            // boundaries of the column tell:
+            let precision = limit_to_bounds_confidence(
+                Precision::Exact(()),
+                std::slice::from_ref(&initial),
+                &[0],
+            );
+            return Ok(left
+                .with_selectivity(SelectivityEstimate::new(0.0, confidence))
+                .with_selectivity_precision(precision));
+        };
+        let histogram = initial
+            .histogram
+            .as_ref()
+            .map(|histogram| restrict_histogram(histogram, &initial.interval, &interval))
+            .transpose()?;
+        let selectivity = Some(ExprBoundaries::ratio_of(&initial, &interval)?);
+        boundaries.push(ExprBoundaries {
+            interval,
+            histogram,
+            selectivity,
+            ..initial
+        });
+    }
+
+    Ok(AnalysisContext::new(boundaries)
+        .with_num_rows(num_rows)
+        .with_selectivity(SelectivityEstimate::new(selectivity, confidence))
+        .with_selectivity_precision(precision))
+}
+
+/// Analyzes the negation of a predicate that can not be rewritten into a
+/// comparison, such as `NOT (a BETWEEN 10 AND 20)`. Its selectivity is the
+/// fraction of the non-NULL rows that do not satisfy the predicate, i.e.
+/// `1 - selectivity(a BETWEEN 10 AND 20)` if `a` has no NULL values. The
+/// column boundaries are only shrunk where propagating the constraints of the
+/// negation yields intervals, e.g. for `NOT (a < 10 OR a > 20)`. Otherwise, the
+/// remaining values may not form an interval, as for `NOT BETWEEN`. If the
+/// selectivity of the predicate is unknown or only the default guess, the
+/// negation is not estimated either.
+fn analyze_negation(
+    not: &NotExpr,
+    context: AnalysisContext,
+    indices: &HashMap<Column, usize>,
+) -> Result<AnalysisContext> {
+    let inner = analyze_with_indices(not.arg(), context.clone(), indices)?;
+    let AnalysisContext {
+        boundaries,
+        num_rows,
+        default_selectivity,
+        ..
+    } = context;
+    let unsupported_exprs = inner.unsupported_exprs;
+    let Some(estimate) = inner.selectivity else {
+        return Ok(AnalysisContext::new(boundaries)
+            .with_num_rows(num_rows)
+            .with_unsupported_exprs(unsupported_exprs));
+    };
+    if inner.selectivity_precision == Precision::Absent {
+        return Ok(AnalysisContext::new(boundaries)
+            .with_num_rows(num_rows)
+            .with_selectivity(SelectivityEstimate::new(
+                default_selectivity,
+                Precision::Absent,
+            ))
+            .with_selectivity_precision(Precision::Absent)
+            .with_unsupported_exprs(unsupported_exprs));
+    }
+    let mut referenced = collect_columns(not.arg())
+        .iter()
+        .filter_map(|column| indices.get(column).copied())
+        .collect::<Vec<_>>();
+    referenced.sort_unstable();
+    let non_null = non_null_fraction(&boundaries, &referenced, &num_rows);
+    let precision = inner.selectivity_precision.min(&non_null_precision(
+        &boundaries,
+        &referenced,
+        &num_rows,
+    ));
+    let selectivity = SelectivityEstimate::new(
+        (non_null - estimate.point).max(0.0),
+        estimate.confidence,
+    );
+    let negation: Arc<dyn PhysicalExpr> = Arc::new(NotExpr::new(not.arg().clone()));
+    let boundaries = shrink_negated_boundaries(&negation, boundaries, indices)?;
+    Ok(AnalysisContext::new(boundaries)
+        .with_num_rows(num_rows)
+        .with_selectivity(selectivity)
+        .with_selectivity_precision(precision)
+        .with_unsupported_exprs(unsupported_exprs))
+}
+
+/// Shrinks the column boundaries to the intervals that the negation `expr`
+/// constrains the columns to, see [`analyze_negation`]. The boundaries are
+/// returned unchanged if the constraints can not be propagated.
+fn shrink_negated_boundaries(
+    expr: &Arc<dyn PhysicalExpr>,
+    mut boundaries: Vec<ExprBoundaries>,
+    indices: &HashMap<Column, usize>,
+) -> Result<Vec<ExprBoundaries>> {
+    let mut graph = match ExprIntervalGraph::try_new(expr.clone()) {
+        Err(DataFusionError::NotImplemented(_)) => return Ok(boundaries),
+        graph => graph?,
+    };
+    let columns: Vec<Arc<dyn PhysicalExpr>> = collect_columns(expr)
+        .into_iter()
+        .map(|c| Arc::new(c) as Arc<dyn PhysicalExpr>)
+        .collect();
+    let (positions, mut target_indices_and_boundaries): (Vec<_>, Vec<_>) = graph
+        .gather_node_indices(columns.as_slice())
+        .into_iter()
+        .filter_map(|(expr, i)| {
+            let column = expr.as_any().downcast_ref::<Column>()?;
+            let index = *indices.get(column)?;
+            Some((index, (i, boundaries[index].interval.clone())))
+        })
+        .unzip();
+    match graph.update_ranges(&mut target_indices_and_boundaries) {
+        Ok(PropagationResult::Success) => {}
+        Ok(_) | Err(DataFusionError::NotImplemented(_)) => return Ok(boundaries),
+        Err(e) => return Err(e),
+    }
+    for (index, (_, interval)) in positions.into_iter().zip(target_indices_and_boundaries)
+    {
+        let bound = &mut boundaries[index];
+        if bound.interval == interval {
+            continue;
+        }
+        bound.selectivity = Some(ExprBoundaries::ratio_of(bound, &interval)?);
+        if let Some(histogram) = &bound.histogram {
+            bound.histogram =
+                Some(restrict_histogram(histogram, &bound.interval, &interval)?);
+        }
+        bound.interval = interval;
+    }
+    Ok(boundaries)
+}
+
+/// Returns the sub-expressions of `expr` that interval arithmetic does not
+/// support, see [`find_unsupported_exprs`]. If there are none, `expr` itself is
+/// unsupported, e.g. because of the data types involved.
+fn unsupported_sub_exprs(expr: &Arc<dyn PhysicalExpr>) -> Vec<Arc<dyn PhysicalExpr>> {
+    let exprs = find_unsupported_exprs(expr);
+    if exprs.is_empty() {
+        vec![expr.clone()]
+    } else {
+        exprs
+    }
+}
+
+/// Returns the expressions in `left` followed by those in `right` that are not
+/// in `left`.
+fn union_exprs(
+    left: &[Arc<dyn PhysicalExpr>],
+    right: &[Arc<dyn PhysicalExpr>],
+) -> Vec<Arc<dyn PhysicalExpr>> {
+    let mut exprs = left.to_vec();
+    for expr in right {
+        if !exprs.iter().any(|existing| existing.eq(expr)) {
+            exprs.push(expr.clone());
+        }
+    }
+    exprs
+}
+
+/// If `expr` is an `IS NULL` or `IS NOT NULL` check on a column, returns the
+/// column along with a flag indicating whether the check is `IS NOT NULL`.
+/// Otherwise, returns `None`.
+fn as_column_null_check(expr: &Arc<dyn PhysicalExpr>) -> Option<(&Column, bool)> {
+    let (arg, negated) = if let Some(is_null) = expr.as_any().downcast_ref::<IsNullExpr>()
+    {
+        (is_null.arg(), false)
+    } else {
+        let is_not_null = expr.as_any().downcast_ref::<IsNotNullExpr>()?;
+        (is_not_null.arg(), true)
+    };
+    arg.as_any()
+        .downcast_ref::<Column>()
+        .map(|column| (column, negated))
+}
+
+/// If `expr` compares a column with a boolean literal by `=` or `!=`, returns
+/// the column along with the value that satisfies the comparison. Otherwise,
+/// returns `None`.
+fn as_boolean_column_comparison(expr: &Arc<dyn PhysicalExpr>) -> Option<(&Column, bool)> {
+    let binary = expr.as_any().downcast_ref::<BinaryExpr>()?;
+    let negated = match binary.op() {
+        Operator::Eq => false,
+        Operator::NotEq => true,
+        _ => return None,
+    };
+    let as_literal = |expr: &Arc<dyn PhysicalExpr>| match expr
+        .as_any()
+        .downcast_ref::<Literal>()?
+        .value()
+    {
+        ScalarValue::Boolean(Some(value)) => Some(*value),
+        _ => None,
+    };
+    let (column, value) = match (
+        binary.left().as_any().downcast_ref::<Column>(),
+        binary.right().as_any().downcast_ref::<Column>(),
+    ) {
+        (Some(column), None) => (column, as_literal(binary.right())?),
+        (None, Some(column)) => (column, as_literal(binary.left())?),
+        _ => return None,
+    };
+    Some((column, value != negated))
+}
+
+/// Estimates the fraction of the non-NULL values described by the boolean
+/// `bound` that are `true`, along with the confidence of the estimate. A
+/// histogram holds the cumulative counts of `false` and `true` values. Without
+/// one, the minimum and maximum values tell whether all values are equal, and
+/// half of the values are assumed to be `true` otherwise.
+fn true_fraction(bound: &ExprBoundaries) -> (f64, Precision<()>) {
+    let counts = bound.histogram.as_ref().and_then(|histogram| {
+        let false_count = histogram.iter().find_map(|(value, count)| {
+            (value == &ScalarValue::Boolean(Some(false))).then_some(*count)
+        });
+        let total_count = histogram.last().map(|(_, count)| *count)?;
+        Some((false_count.unwrap_or(0), total_count))
+    });
+    if let Some((false_count, total_count)) = counts.filter(|(_, total)| *total > 0) {
+        let true_count = total_count.saturating_sub(false_count);
+        return (
+            true_count as f64 / total_count as f64,
+            Precision::Inexact(()),
+        );
+    }
+    match (&bound.interval.lower.value, &bound.interval.upper.value) {
+        (ScalarValue::Boolean(Some(true)), _) => (1.0, bound.confidence.clone()),
+        (_, ScalarValue::Boolean(Some(false))) => (0.0, bound.confidence.clone()),
+        _ => (0.5, Precision::Absent),
+    }
+}
+
+/// If `expr` is an IN list on a column, returns the IN list along with the
+/// column. Otherwise, returns `None`.
+fn as_column_in_list(expr: &Arc<dyn PhysicalExpr>) -> Option<(&InListExpr, &Column)> {
+    let in_list = expr.as_any().downcast_ref::<InListExpr>()?;
+    in_list
+        .expr()
+        .as_any()
+        .downcast_ref::<Column>()
+        .map(|column| (in_list, column))
+}
+
+/// Estimates the fraction of the values described by `bound` that satisfy
+/// `in_list`, assuming that each of the distinct values occurs equally often:
+/// The list items within the interval of `bound` select
+/// `min(list_len, distinct_count) / distinct_count` of the values. Returns
+/// `None` if the distinct count is unknown or a list item is not a literal.
+fn in_list_selectivity(
+    in_list: &InListExpr,
+    bound: &ExprBoundaries,
+) -> Result<Option<f64>> {
+    let Some(distinct_count) = bound.distinct_count.get_value().filter(|&&n| n > 0)
+    else {
+        return Ok(None);
+    };
+    let mut values = Vec::with_capacity(in_list.list().len());
+    for item in in_list.list() {
+        let Some(literal) = item.as_any().downcast_ref::<Literal>() else {
+            return Ok(None);
+        };
+        let value = literal.value();
+        let point = Interval::new(
+            IntervalBound::new_closed(value.clone()),
+            IntervalBound::new_closed(value.clone()),
+        );
+        if !value.is_null()
+            && !values.contains(value)
+            && bound.interval.intersect(&point)?.is_some()
+        {
+            values.push(value.clone());
+        }
+    }
+    let ratio = (values.len().min(*distinct_count) as f64) / (*distinct_count as f64);
+    Ok(Some(if in_list.negated() {
+        1.0 - ratio
+    } else {
+        ratio
+    }))
+}
+
+/// Overrides the selectivity of `column` in `context`, which is the result of
+/// analyzing a predicate on this column only, with the given `selectivity`.
+/// The selectivity of the predicate also accounts for NULL values, which never
+/// satisfy it.
+fn with_column_selectivity(
+    mut context: AnalysisContext,
+    column: &Column,
+    selectivity: f64,
+    num_rows: &Precision<usize>,
+    indices: &HashMap<Column, usize>,
+) -> AnalysisContext {
+    let Some(bound) = indices
+        .get(column)
+        .map(|&index| &mut context.boundaries[index])
+    else {
+        return context;
+    };
+    bound.selectivity = Some(selectivity);
+    let null_fraction = bound.null_fraction(num_rows).unwrap_or(0.0);
+    let confidence = bound.confidence.min(&precision_of(&bound.distinct_count));
+    context
+        .with_selectivity(SelectivityEstimate::new(
+            selectivity * (1.0 - null_fraction),
+            confidence,
+        ))
+        .with_selectivity_precision(Precision::Inexact(()))
+}
+
+/// If the `PropagationResult` indicates success, this function calculates the
+/// selectivity value by comparing the initial and final column boundaries.
+/// Following this, it constructs and returns a new `AnalysisContext` with the
+/// updated parameters.
+fn shrink_boundaries(
+    expr: &Arc<dyn PhysicalExpr>,
+    mut graph: ExprIntervalGraph,
+    mut target_boundaries: Vec<ExprBoundaries>,
+    target_expr_and_indices: Vec<(Arc<dyn PhysicalExpr>, usize)>,
+    num_rows: &Precision<usize>,
+    correlations: &[(Column, Column, f64)],
+    indices: &HashMap<Column, usize>,
+) -> Result<AnalysisContext> {
+    let initial_boundaries = target_boundaries.clone();
+    for (expr, i) in target_expr_and_indices.iter() {
+        if let Some(column) = expr.as_any().downcast_ref::<Column>() {
+            if let Some(bound) = indices
+                .get(column)
+                .map(|&index| &mut target_boundaries[index])
+            {
+                let interval = graph.get_interval(*i);
+                if let Some(histogram) = &bound.histogram {
+                    bound.histogram =
+                        Some(restrict_histogram(histogram, &bound.interval, &interval)?);
+                }
+                bound.interval = interval;
+            };
+        }
+    }
+    let graph_nodes = graph.gather_node_indices(&[expr.clone()]);
+    let Some((_, root_index)) = graph_nodes.get(0) else {
+        return internal_err!(
+            "The ExprIntervalGraph under investigation does not have any nodes."
+        );
+    };
+    let final_result = graph.get_interval(*root_index);
+    // The propagation proves the predicate to be always true or always false:
+    let is_constant = matches!(
+        (&final_result.lower.value, &final_result.upper.value),
+        (ScalarValue::Boolean(Some(lower)), ScalarValue::Boolean(Some(upper)))
+            if lower == upper
+    );
+
+    for (bound, initial) in target_boundaries.iter_mut().zip(&initial_boundaries) {
+        bound.selectivity = Some(ExprBoundaries::ratio_of(initial, &bound.interval)?);
+    }
+    let referenced = referenced_indices(&target_expr_and_indices, indices);
+    let selectivity = calculate_selectivity(
+        &final_result.lower.value,
+        &final_result.upper.value,
+        &target_boundaries,
+        &referenced,
+        correlations,
+    )
+    .scale(
+        non_null_fraction(&initial_boundaries, &referenced, num_rows),
+        &Precision::Exact(()),
+    );
+
+    // No column can have more distinct values than there are remaining rows:
+    let max_rows = num_rows
+        .get_value()
+        .map(|num_rows| (*num_rows as f64 * selectivity.point).ceil() as usize);
+    for bound in target_boundaries.iter_mut() {
+        bound.distinct_count = refine_distinct_count(bound, selectivity.point, max_rows);
+    }
+
+    // An always true predicate selects the non-NULL rows, whose fraction is as
+    // exact as the null counts of the columns. Either way, the proof is only
+    // as exact as the boundaries of the columns:
+    let precision = if !is_constant {
+        Precision::Inexact(())
+    } else if selectivity.point == 0.0 {
+        limit_to_bounds_confidence(Precision::Exact(()), &initial_boundaries, &referenced)
+    } else {
+        limit_to_bounds_confidence(
+            non_null_precision(&initial_boundaries, &referenced, num_rows),
+            &initial_boundaries,
+            &referenced,
+        )
+    };
+
+    Ok(AnalysisContext::new(target_boundaries)
+        .with_selectivity(selectivity)
+        .with_selectivity_precision(precision))
+}
+
+/// Scales the distinct count of `bound` by the fraction of the values of the
+/// column that remain in its (shrunk) interval, given by the selectivity of
+/// `bound`. The result never exceeds `max_rows`, if known, and is at least 1
+/// if the interval is not empty. A single point interval has exactly one
+/// distinct value. The distinct count stays exact only if the predicate with
+/// the given `selectivity` retains all rows.
+fn refine_distinct_count(
+    bound: &ExprBoundaries,
+    selectivity: f64,
+    max_rows: Option<usize>,
+) -> Precision<usize> {
+    let Some(&distinct_count) = bound.distinct_count.get_value() else {
+        return Precision::Absent;
+    };
+    let Interval { lower, upper } = &bound.interval;
+    let is_empty = bound.interval.is_empty();
+    if !is_empty && !lower.is_unbounded() && lower.value == upper.value {
+        return Precision::Exact(1);
+    }
+    let ratio = bound.selectivity.unwrap_or(1.0);
+    let mut refined = (distinct_count as f64 * ratio).round() as usize;
+    if let Some(max_rows) = max_rows {
+        refined = refined.min(max_rows);
+    }
+    if !is_empty {
+        refined = refined.max(1);
+    }
+    if refined == distinct_count && selectivity == 1.0 {
+        bound.distinct_count.clone()
+    } else {
+        Precision::Inexact(refined)
+    }
+}
+
+/// Returns the positions of the columns referenced by the predicate (given in
+/// `target_expr_and_indices`) within the boundaries, in ascending order.
+fn referenced_indices(
+    target_expr_and_indices: &[(Arc<dyn PhysicalExpr>, usize)],
+    indices: &HashMap<Column, usize>,
+) -> Vec<usize> {
+    let mut referenced = target_expr_and_indices
+        .iter()
+        .filter_map(|(expr, _)| {
+            let column = expr.as_any().downcast_ref::<Column>()?;
+            indices.get(column).copied()
+        })
+        .collect::<Vec<_>>();
+    referenced.sort_unstable();
+    referenced.dedup();
+    referenced
+}
+
+/// Returns the fraction of rows where none of the columns at the `referenced`
+/// positions of `boundaries` are NULL. NULL values of the columns referenced
+/// by a predicate make it evaluate to NULL, so only such rows can pass.
+fn non_null_fraction(
+    boundaries: &[ExprBoundaries],
+    referenced: &[usize],
+    num_rows: &Precision<usize>,
+) -> f64 {
+    // Multiply in the order of the boundaries, so that the result does not
+    // depend on the order of the columns in the predicate:
+    referenced
+        .iter()
+        .filter_map(|&index| boundaries[index].null_fraction(num_rows))
+        .map(|null_fraction| 1.0 - null_fraction)
+        .product()
+}
+
+/// Returns how exactly [`non_null_fraction`] is known, given the precisions of
+/// the null counts and of the number of rows. Unknown null counts are assumed
+/// to be zero, which is inexact.
+fn non_null_precision(
+    boundaries: &[ExprBoundaries],
+    referenced: &[usize],
+    num_rows: &Precision<usize>,
+) -> Precision<()> {
+    referenced
+        .iter()
+        .fold(Precision::Exact(()), |precision, &index| {
+            let null_count = &boundaries[index].null_count;
+            let column_precision = match null_count {
+                Precision::Absent => Precision::Inexact(()),
+                _ => precision_of(null_count).min(&precision_of(num_rows)),
+            };
+            precision.min(&column_precision)
+        })
+}
+
+/// Returns the weakest confidence among the boundaries at the `referenced`
+/// positions of `boundaries`, see [`ExprBoundaries::confidence`].
+fn bounds_confidence(
+    boundaries: &[ExprBoundaries],
+    referenced: &[usize],
+) -> Precision<()> {
+    referenced
+        .iter()
+        .fold(Precision::Exact(()), |confidence, &index| {
+            confidence.min(&boundaries[index].confidence)
+        })
+}
+
+/// Limits `precision`, which the analysis proved from the boundaries at the
+/// `referenced` positions of `boundaries`, to the confidence of these
+/// boundaries: a predicate is only certainly true or false if the minimum and
+/// maximum values of the columns are exact. Boundaries without minimum or
+/// maximum values make the result inexact, as it is still estimated rather
+/// than merely assumed.
+fn limit_to_bounds_confidence(
+    precision: Precision<()>,
+    boundaries: &[ExprBoundaries],
+    referenced: &[usize],
+) -> Precision<()> {
+    match bounds_confidence(boundaries, referenced) {
+        Precision::Absent => precision.to_inexact(),
+        confidence => precision.min(&confidence),
+    }
+}
+
+/// Returns the precision of `value`, regardless of the value itself.
+fn precision_of<T: Debug + Clone + PartialEq + Eq + PartialOrd>(
+    value: &Precision<T>,
+) -> Precision<()> {
+    match value.is_exact() {
+        Some(true) => Precision::Exact(()),
+        Some(false) => Precision::Inexact(()),
+        None => Precision::Absent,
+    }
+}
+
+/// This function calculates the filter predicate's selectivity by combining
+/// the selectivities of the pruned column boundaries. Selectivity is defined
+/// as the ratio of rows in a table that satisfy the filter's predicate.
+///
+/// An exact propagation result at the root, i.e. `[true, true]` or `[false, false]`,
+/// leads to early exit (returning a selectivity value of either 1.0 or 0.0). In such
+/// a case, `[true, true]` indicates that all data values satisfy the predicate (hence,
+/// selectivity is 1.0), and `[false, false]` suggests that no data value meets the
+/// predicate (therefore, selectivity is 0.0).
+///
+/// Otherwise, the column selectivities are combined with an exponential backoff
+/// that accounts for the given `correlations`. With the column selectivities
+/// sorted in ascending order as `s_1 <= s_2 <= ... <= s_n`, the result is
+///
+/// ```text
+/// s_1 * s_2 ^ (1 - c_2) * ... * s_n ^ (1 - c_n)
+/// ```
+///
+/// where `c_i` is the largest correlation coefficient between the column of `s_i`
+/// and any of the columns of `s_1, ..., s_(i-1)`. Independent columns (`c_i = 0`)
+/// thus multiply their selectivities, while a column fully correlated with a more
+/// selective one (`c_i = 1`) does not change the result.
+///
+/// The confidence of the result is the weakest confidence among the boundaries
+/// of the columns at the `referenced` positions, i.e. those of the predicate.
+fn calculate_selectivity(
+    lower_value: &ScalarValue,
+    upper_value: &ScalarValue,
+    target_boundaries: &[ExprBoundaries],
+    referenced: &[usize],
+    correlations: &[(Column, Column, f64)],
+) -> SelectivityEstimate {
+    let confidence = bounds_confidence(target_boundaries, referenced);
+    let point = match (lower_value, upper_value) {
+        (ScalarValue::Boolean(Some(true)), ScalarValue::Boolean(Some(true))) => 1.0,
+        (ScalarValue::Boolean(Some(false)), ScalarValue::Boolean(Some(false))) => 0.0,
+        // Since the values are assumed not to be correlated, we need to
+        // multiply the selectivities of multiple columns to get the overall
+        // selectivity.
+        _ if correlations.is_empty() => target_boundaries
+            .iter()
+            .filter_map(|bound| bound.selectivity)
+            .product(),
+        _ => {
+            let mut selectivities = target_boundaries
+                .iter()
+                .filter_map(|bound| bound.selectivity.map(|s| (&bound.column, s)))
+                .collect::<Vec<_>>();
+            selectivities.sort_by(|(_, left), (_, right)| left.total_cmp(right));
+            let correlation = |left: &Column, right: &Column| {
+                correlations
+                    .iter()
+                    .filter(|(a, b, _)| {
+                        (a == left && b == right) || (a == right && b == left)
+                    })
+                    .map(|(_, _, coefficient)| coefficient.clamp(0.0, 1.0))
+                    .fold(0.0, f64::max)
+            };
+            selectivities
+                .iter()
+                .enumerate()
+                .map(|(idx, (column, selectivity))| {
+                    let backoff = selectivities[..idx]
+                        .iter()
+                        .map(|(previous, _)| correlation(column, previous))
+                        .fold(0.0, f64::max);
+                    selectivity.powf(1.0 - backoff)
+                })
+                .product()
+        }
+    };
+    SelectivityEstimate::new(point, confidence)
+}
+
+/// This function computes the ratio of the values in `histogram` that fall
+/// into `final_interval` to those that fall into `initial_interval`. Values
+/// are assumed to be uniformly distributed within each bucket, so a bucket
+/// partially overlapping with an interval contributes proportionally to the
+/// overlap. If the histogram has no values inside `initial_interval`, falls
+/// back to the uniform assumption over the whole interval.
+fn histogram_ratio(
+    histogram: &[(ScalarValue, u64)],
+    initial_interval: &Interval,
+    final_interval: &Interval,
+) -> Result<f64> {
+    let initial_mass = histogram_mass(histogram, initial_interval, initial_interval)?;
+    if initial_mass == 0.0 {
+        return cardinality_ratio(initial_interval, final_interval);
+    }
+    let final_mass = histogram_mass(histogram, initial_interval, final_interval)?;
+    Ok(final_mass / initial_mass)
+}
+
+/// This function restricts `histogram`, whose first bucket starts at the lower
+/// bound of `domain`, to the given `interval`. Buckets outside `interval` are
+/// dropped, and the counts of partially overlapping buckets are scaled down in
+/// proportion to the overlap. The first bucket of the resulting histogram
+/// starts at the lower bound of `interval`.
+fn restrict_histogram(
+    histogram: &[(ScalarValue, u64)],
+    domain: &Interval,
+    interval: &Interval,
+) -> Result<Vec<(ScalarValue, u64)>> {
+    let mut mass = 0.0;
+    let mut restricted = vec![];
+    for (bucket, count) in histogram_buckets(histogram, domain) {
+        if let Some(overlap) = bucket.intersect(interval)? {
+            mass += count as f64 * cardinality_ratio(&bucket, &overlap)?;
+            restricted.push((overlap.upper.value, mass.round() as u64));
+        }
+    }
+    Ok(restricted)
+}
+
+/// This function estimates the number of values in `histogram` that fall into
+/// `interval`, where the first bucket of the histogram starts at the lower
+/// bound of `domain`.
+fn histogram_mass(
+    histogram: &[(ScalarValue, u64)],
+    domain: &Interval,
+    interval: &Interval,
+) -> Result<f64> {
+    histogram_buckets(histogram, domain).into_iter().try_fold(
+        0.0,
+        |acc, (bucket, count)| {
+            Ok(match bucket.intersect(interval)? {
+                Some(overlap) => {
+                    acc + count as f64 * cardinality_ratio(&bucket, &overlap)?
+                }
+                None => acc,
+            })
+        },
+    )
+}
+
+/// Converts the cumulative representation of `histogram` into a list of bucket
+/// intervals and their individual counts. The first bucket starts at the lower
+/// bound of `domain`, and every subsequent bucket starts (exclusively) at the
+/// upper bound of its predecessor.
+fn histogram_buckets(
+    histogram: &[(ScalarValue, u64)],
+    domain: &Interval,
+) -> Vec<(Interval, u64)> {
+    let mut lower = domain.lower.clone();
+    let mut previous_count = 0;
+    histogram
+        .iter()
+        .map(|(upper, cumulative_count)| {
+            let bucket = Interval::new(
+                std::mem::replace(&mut lower, IntervalBound::new_open(upper.clone())),
+                IntervalBound::new_closed(upper.clone()),
+            );
+            let count = cumulative_count.saturating_sub(previous_count);
+            previous_count = *cumulative_count;
+            (bucket, count)
+        })
+        .collect()
+}
+
+/// Averages the two (optional) values with the given weights. The result is
+/// unknown if either value is, or if the weights sum to zero.
+fn weighted_average(
+    left: Option<f64>,
+    right: Option<f64>,
+    (left_weight, right_weight): (f64, f64),
+) -> Option<f64> {
+    let total_weight = left_weight + right_weight;
+    match (left, right) {
+        (Some(left), Some(right)) if total_weight > 0.0 => {
+            Some((left * left_weight + right * right_weight) / total_weight)
+        }
+        _ => None,
+    }
+}
+
+/// Returns an unbounded interval with the same data type as `interval`.
+fn unbounded_like(interval: &Interval) -> Interval {
+    interval
+        .get_datatype()
+        .and_then(|data_type| {
+            Ok(Interval::new(
+                IntervalBound::make_unbounded(&data_type)?,
+                IntervalBound::make_unbounded(&data_type)?,
+            ))
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{
+        analyze, analyze_many, estimate_distinct_count, estimate_join_cardinality,
+        fold_point_intervals, prune_partitions, selectivity_to_row_count,
+        AnalysisContext, ExprBoundaries, SelectivityEstimate, SelectivityFeedback,
+    };
+    use crate::execution_props::ExecutionProps;
+    use crate::expressions::{
+        binary, cast, col, in_list, is_not_null, is_null, like, lit, negative, not,
+        BinaryExpr, Column,
+    };
+    use crate::functions::create_physical_expr;
+    use crate::intervals::utils::check_support;
+    use crate::intervals::{ExprIntervalGraph, Interval, IntervalBound};
+    use crate::PhysicalExpr;
+
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+    use datafusion_common::stats::Precision;
+    use datafusion_common::{ColumnStatistics, DataFusionError, Result, ScalarValue};
+    use datafusion_expr::{BuiltinScalarFunction, Operator};
+
+    /// Exact statistics of an `Int64` column with values in `[min, max]`
+    fn int64_stats(min: i64, max: i64) -> ColumnStatistics {
+        ColumnStatistics {
+            min_value: Precision::Exact(ScalarValue::Int64(Some(min))),
+            max_value: Precision::Exact(ScalarValue::Int64(Some(max))),
+            ..Default::default()
+        }
+    }
+
+    /// Analyzes `expr` with the given column statistics and number of rows,
+    /// and returns the point estimate of its selectivity and the precision
+    /// of the selectivity
+    fn analyze_selectivity(
+        expr: &Arc<dyn PhysicalExpr>,
+        schema: &Schema,
+        stats: &[ColumnStatistics],
+        num_rows: Precision<usize>,
+    ) -> Result<(f64, Precision<()>)> {
+        let context =
+            AnalysisContext::try_from_statistics(schema, stats)?.with_num_rows(num_rows);
+        let context = analyze(expr, context)?;
+        Ok((
+            context.selectivity.unwrap().point,
+            context.selectivity_precision,
+        ))
+    }
+
+    #[test]
+    fn test_histogram_selectivity_on_skewed_data() -> Result<()> {
+        // Column 'a' has 1000 rows in [1, 1000] with a power-law-like
+        // distribution: 500 rows in [1, 10], 300 rows in (10, 100] and
+        // 200 rows in (100, 1000].
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let uniform_stats = int64_stats(1, 1000);
+        let histogram_stats = ColumnStatistics {
+            histogram: Some(vec![
+                (ScalarValue::Int64(Some(10)), 500),
+                (ScalarValue::Int64(Some(100)), 800),
+                (ScalarValue::Int64(Some(1000)), 1000),
+            ]),
+            ..uniform_stats.clone()
+        };
+
+        // a <= 50
+        let expr = binary(col("a", &schema)?, Operator::LtEq, lit(50i64), &schema)?;
+        // The first bucket is fully selected, and 40 of the 90 distinct values
+        // in the second bucket are selected: (500 + 300 * 40 / 90) / 1000
+        let actual_selectivity = (500.0 + 300.0 * 40.0 / 90.0) / 1000.0;
+
+        let (uniform, _) =
+            analyze_selectivity(&expr, &schema, &[uniform_stats], Precision::Absent)?;
+        let (histogram, _) =
+            analyze_selectivity(&expr, &schema, &[histogram_stats], Precision::Absent)?;
+
+        assert!((uniform - 0.05).abs() < 1e-9);
+        assert!((histogram - actual_selectivity).abs() < 1e-9);
+        assert!(
+            (histogram - actual_selectivity).abs() < (uniform - actual_selectivity).abs()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_with_null_count() -> Result<()> {
+        // Column 'a' has 100 rows in [1, 100], 40 of which are NULL.
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let stats = [ColumnStatistics {
+            null_count: Precision::Exact(40),
+            ..int64_stats(1, 100)
+        }];
+        let selectivity = |expr: Arc<dyn PhysicalExpr>, num_rows| -> Result<f64> {
+            Ok(analyze_selectivity(&expr, &schema, &stats, num_rows)?.0)
+        };
+
+        // a > 50
+        let expr = binary(col("a", &schema)?, Operator::Gt, lit(50i64), &schema)?;
+        assert!((selectivity(expr.clone(), Precision::Exact(100))? - 0.3).abs() < 1e-9);
+        // Without the number of rows, NULL values are not accounted for.
+        assert!((selectivity(expr, Precision::Absent)? - 0.5).abs() < 1e-9);
+
+        // a = 50
+        let expr = binary(col("a", &schema)?, Operator::Eq, lit(50i64), &schema)?;
+        assert!((selectivity(expr, Precision::Exact(100))? - 0.006).abs() < 1e-9);
+
+        // a BETWEEN 20 AND 70, and a range covering all values: NULL values
+        // are filtered out either way.
+        let between = |low: i64, high: i64| -> Result<Arc<dyn PhysicalExpr>> {
+            binary(
+                binary(col("a", &schema)?, Operator::GtEq, lit(low), &schema)?,
+                Operator::And,
+                binary(col("a", &schema)?, Operator::LtEq, lit(high), &schema)?,
+                &schema,
+            )
+        };
+        assert!(
+            (selectivity(between(20, 70)?, Precision::Exact(100))? - 0.306).abs() < 1e-9
+        );
+        assert!(selectivity(between(0, 200)?, Precision::Exact(100))? <= 0.6);
+
+        // a IS NULL
+        let expr = is_null(col("a", &schema)?)?;
+        assert!((selectivity(expr, Precision::Inexact(100))? - 0.4).abs() < 1e-9);
+
+        // a IS NOT NULL
+        let expr = is_not_null(col("a", &schema)?)?;
+        assert!((selectivity(expr, Precision::Inexact(100))? - 0.6).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_on_string_column() -> Result<()> {
+        // Column 'name' has values in ["A", "Z"].
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("name", DataType::Utf8, false)]));
+        let stats = [ColumnStatistics {
+            min_value: Precision::Exact(ScalarValue::from("A")),
+            max_value: Precision::Exact(ScalarValue::from("Z")),
+            ..Default::default()
+        }];
+
+        // name >= 'A' AND name <= 'M'
+        let expr = binary(
+            binary(col("name", &schema)?, Operator::GtEq, lit("A"), &schema)?,
+            Operator::And,
+            binary(col("name", &schema)?, Operator::LtEq, lit("M"), &schema)?,
+            &schema,
+        )?;
+        let context = analyze(
+            &expr,
+            AnalysisContext::try_from_statistics(&schema, &stats)?,
+        )?;
+        let selectivity = context.selectivity.unwrap().point;
+        assert!((selectivity - 12.0 / 25.0).abs() < 1e-9);
+        assert_eq!(
+            context.boundaries[0].interval.upper.value,
+            ScalarValue::from("M")
+        );
+
+        // name > 'Z'
+        let expr = binary(col("name", &schema)?, Operator::Gt, lit("Z"), &schema)?;
+        let context = analyze(
+            &expr,
+            AnalysisContext::try_from_statistics(&schema, &stats)?,
+        )?;
+        assert_eq!(context.selectivity.unwrap().point, 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_disjunctions() -> Result<()> {
+        // Column 'a' has values in [1, 100] and column 'b' in [1, 1000].
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let stats = [int64_stats(1, 100), int64_stats(1, 1000)];
+        let analyze_expr = |expr: &Arc<dyn PhysicalExpr>| {
+            analyze(expr, AnalysisContext::try_from_statistics(&schema, &stats)?)
+        };
+        let a_le_10 = binary(col("a", &schema)?, Operator::LtEq, lit(10i64), &schema)?;
+        let b_gt_900 = binary(col("b", &schema)?, Operator::Gt, lit(900i64), &schema)?;
+        let a_ge_5 = binary(col("a", &schema)?, Operator::GtEq, lit(5i64), &schema)?;
+
+        // a <= 10 AND b > 900 keeps using the product of the column ratios
+        let expr = binary(a_le_10.clone(), Operator::And, b_gt_900.clone(), &schema)?;
+        let context = analyze_expr(&expr)?;
+        assert!((context.selectivity.unwrap().point - 0.01).abs() < 1e-9);
+
+        // a <= 10 OR b > 900
+        let disjunction = binary(a_le_10, Operator::Or, b_gt_900, &schema)?;
+        let context = analyze_expr(&disjunction)?;
+        assert!((context.selectivity.unwrap().point - 0.19).abs() < 1e-9);
+        // Either branch may be satisfied, so neither column is restricted.
+        assert_eq!(
+            context.boundaries[0].interval,
+            Interval::make(Some(1i64), Some(100i64), (false, false))
+        );
+        assert_eq!(
+            context.boundaries[1].interval,
+            Interval::make(Some(1i64), Some(1000i64), (false, false))
+        );
+
+        // (a <= 10 OR b > 900) AND a >= 5
+        let expr = binary(disjunction.clone(), Operator::And, a_ge_5, &schema)?;
+        let context = analyze_expr(&expr)?;
+        assert!((context.selectivity.unwrap().point - 0.19 * 0.96).abs() < 1e-9);
+        assert_eq!(
+            context.boundaries[0].interval,
+            Interval::make(Some(5i64), Some(100i64), (false, false))
+        );
+
+        // (a <= 10 OR b > 900) OR a > 1000, where the last branch is infeasible
+        let a_gt_1000 = binary(col("a", &schema)?, Operator::Gt, lit(1000i64), &schema)?;
+        let expr = binary(disjunction, Operator::Or, a_gt_1000, &schema)?;
+        let context = analyze_expr(&expr)?;
+        assert!((context.selectivity.unwrap().point - 0.19).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_disjunctions_on_same_column() -> Result<()> {
+        // Column 'a' has values in [1, 100].
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let stats = [int64_stats(1, 100)];
+        let analyze_expr = |expr: &Arc<dyn PhysicalExpr>| {
+            analyze(expr, AnalysisContext::try_from_statistics(&schema, &stats)?)
+        };
+        let a_le_10 = binary(col("a", &schema)?, Operator::LtEq, lit(10i64), &schema)?;
+        let a_le_20 = binary(col("a", &schema)?, Operator::LtEq, lit(20i64), &schema)?;
+        let a_gt_90 = binary(col("a", &schema)?, Operator::Gt, lit(90i64), &schema)?;
+
+        // a <= 10 OR a > 90: disjoint branches add up
+        let expr = binary(a_le_10.clone(), Operator::Or, a_gt_90, &schema)?;
+        let context = analyze_expr(&expr)?;
+        assert!((context.selectivity.unwrap().point - 0.2).abs() < 1e-9);
+        assert_eq!(
+            context.boundaries[0].interval,
+            Interval::make(Some(1i64), Some(100i64), (false, false))
+        );
+
+        // a <= 20 OR a <= 10: the overlap is only counted once
+        let expr = binary(a_le_20, Operator::Or, a_le_10, &schema)?;
+        let context = analyze_expr(&expr)?;
+        assert!((context.selectivity.unwrap().point - 0.2).abs() < 1e-9);
+        assert_eq!(
+            context.boundaries[0].interval,
+            Interval::make(Some(1i64), Some(20i64), (false, false))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_per_column() -> Result<()> {
+        // Column 'a' has values in [1, 100] and column 'b' in [1, 1000].
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+            Field::new("c", DataType::Int64, false),
+        ]));
+        let stats = [
+            int64_stats(1, 100),
+            int64_stats(1, 1000),
+            int64_stats(1, 10),
+        ];
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?;
+        assert!(context.boundaries.iter().all(|b| b.selectivity.is_none()));
+
+        // a > 10 AND b <= 500
+        let expr = binary(
+            binary(col("a", &schema)?, Operator::Gt, lit(10i64), &schema)?,
+            Operator::And,
+            binary(col("b", &schema)?, Operator::LtEq, lit(500i64), &schema)?,
+            &schema,
+        )?;
+        let context = analyze(&expr, context)?;
+        let selectivities = context
+            .boundaries
+            .iter()
+            .map(|bound| bound.selectivity.unwrap())
+            .collect::<Vec<_>>();
+        assert!((selectivities[0] - 0.9).abs() < 1e-9);
+        assert!((selectivities[1] - 0.5).abs() < 1e-9);
+        // The unreferenced column keeps all of its values.
+        assert_eq!(selectivities[2], 1.0);
+        // The overall selectivity is the product of the column selectivities.
+        assert!((context.selectivity.unwrap().point - 0.45).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_confidence() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let estimate = |min_value: Precision<ScalarValue>,
+                        max_value: Precision<ScalarValue>|
+         -> Result<SelectivityEstimate> {
+            let stats = ColumnStatistics {
+                min_value,
+                max_value,
+                ..Default::default()
+            };
+            let context = AnalysisContext::try_from_statistics(&schema, &[stats])?;
+            // a <= 25
+            let expr = binary(col("a", &schema)?, Operator::LtEq, lit(25i64), &schema)?;
+            Ok(analyze(&expr, context)?.selectivity.unwrap())
+        };
+        let int = |value: i64| ScalarValue::Int64(Some(value));
+
+        // Exact statistics yield tight bounds:
+        let exact = estimate(Precision::Exact(int(1)), Precision::Exact(int(100)))?;
+        assert_eq!(exact, SelectivityEstimate::exact(0.25));
+
+        // Inexact statistics yield bounds within 10% of the estimate:
+        let inexact = estimate(Precision::Exact(int(1)), Precision::Inexact(int(100)))?;
+        assert_eq!(inexact.confidence, Precision::Inexact(()));
+        assert!((inexact.point - 0.25).abs() < 1e-9);
+        assert!((inexact.lower_bound - 0.225).abs() < 1e-9);
+        assert!((inexact.upper_bound - 0.275).abs() < 1e-9);
+
+        // Without a maximum, nothing is known about the selectivity:
+        let absent = estimate(Precision::Inexact(int(1)), Precision::Absent)?;
+        assert_eq!(absent.confidence, Precision::Absent);
+        assert_eq!((absent.lower_bound, absent.upper_bound), (0.0, 1.0));
+
+        // The bounds of inexact estimates stay within [0, 1]:
+        let estimate = SelectivityEstimate::new(0.95, Precision::Inexact(()));
+        assert!((estimate.lower_bound - 0.855).abs() < 1e-9);
+        assert_eq!(estimate.upper_bound, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_precision() -> Result<()> {
+        // Column 'a' has values in [1, 100] and no NULL values.
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let stats = [ColumnStatistics {
+            null_count: Precision::Exact(0),
+            ..int64_stats(1, 100)
+        }];
+        let analyze_expr = |expr: Arc<dyn PhysicalExpr>| {
+            analyze_selectivity(&expr, &schema, &stats, Precision::Exact(100))
+        };
+        let a_op = |op: Operator, value: i64| {
+            binary(col("a", &schema)?, op, lit(value), &schema)
+        };
+
+        // Provably constant predicates have exact selectivities:
+        assert_eq!(
+            analyze_expr(a_op(Operator::GtEq, 1)?)?,
+            (1.0, Precision::Exact(()))
+        );
+        assert_eq!(
+            analyze_expr(a_op(Operator::Gt, 100)?)?,
+            (0.0, Precision::Exact(()))
+        );
+        let disjoint = binary(
+            a_op(Operator::Lt, 10)?,
+            Operator::And,
+            a_op(Operator::Gt, 20)?,
+            &schema,
+        )?;
+        assert_eq!(analyze_expr(disjoint)?, (0.0, Precision::Exact(())));
+        let always_true = binary(
+            a_op(Operator::Lt, 10)?,
+            Operator::Or,
+            a_op(Operator::LtEq, 100)?,
+            &schema,
+        )?;
+        assert_eq!(analyze_expr(always_true)?, (1.0, Precision::Exact(())));
+        // So do null checks with exact null counts:
+        assert_eq!(
+            analyze_expr(is_not_null(col("a", &schema)?)?)?,
+            (1.0, Precision::Exact(()))
+        );
+
+        // Ranges assume uniformly distributed values:
+        let (selectivity, precision) = analyze_expr(a_op(Operator::LtEq, 50)?)?;
+        assert!((selectivity - 0.5).abs() < 1e-9);
+        assert_eq!(precision, Precision::Inexact(()));
+        let either = binary(
+            a_op(Operator::Lt, 10)?,
+            Operator::Or,
+            a_op(Operator::Gt, 90)?,
+            &schema,
+        )?;
+        assert_eq!(analyze_expr(either)?.1, Precision::Inexact(()));
+
+        // Without a null count, the selectivity of a null check is unknown:
+        let context = AnalysisContext::try_from_statistics(
+            &schema,
+            &[ColumnStatistics {
+                null_count: Precision::Absent,
+                ..stats[0].clone()
+            }],
+        )?;
+        let context = analyze(&is_null(col("a", &schema)?)?, context)?;
+        assert_eq!(context.selectivity, None);
+        assert_eq!(context.selectivity_precision, Precision::Absent);
+
+        // Inexact minimum and maximum values prove nothing:
+        let inexact_stats = [ColumnStatistics {
+            min_value: Precision::Inexact(ScalarValue::Int64(Some(1))),
+            max_value: Precision::Inexact(ScalarValue::Int64(Some(100))),
+            ..stats[0].clone()
+        }];
+        let analyze_inexact = |expr: Arc<dyn PhysicalExpr>| {
+            analyze_selectivity(&expr, &schema, &inexact_stats, Precision::Exact(100))
+        };
+        assert_eq!(
+            analyze_inexact(a_op(Operator::GtEq, 1)?)?,
+            (1.0, Precision::Inexact(()))
+        );
+        assert_eq!(
+            analyze_inexact(a_op(Operator::Gt, 100)?)?,
+            (0.0, Precision::Inexact(()))
+        );
+        let disjoint = binary(
+            a_op(Operator::Lt, 10)?,
+            Operator::And,
+            a_op(Operator::Gt, 20)?,
+            &schema,
+        )?;
+        assert_eq!(analyze_inexact(disjoint)?, (0.0, Precision::Inexact(())));
+        // Null checks do not depend on the minimum and maximum values:
+        assert_eq!(
+            analyze_inexact(is_not_null(col("a", &schema)?)?)?,
+            (1.0, Precision::Exact(()))
+        );
+
+        // Setting the selectivity keeps its precision:
+        let context = AnalysisContext::new(vec![])
+            .with_selectivity_precision(Precision::Exact(()))
+            .with_selectivity(SelectivityEstimate::exact(0.5));
+        assert_eq!(context.selectivity_precision, Precision::Exact(()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_join_cardinality() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "order_id",
+            DataType::Int64,
+            false,
+        )]));
+        let context = |min: i64, max: i64, distinct_count: Option<usize>, num_rows| {
+            let stats = [ColumnStatistics {
+                distinct_count: distinct_count
+                    .map(Precision::Exact)
+                    .unwrap_or(Precision::Absent),
+                ..int64_stats(min, max)
+            }];
+            AnalysisContext::try_from_statistics(&schema, &stats)
+                .map(|context| context.with_num_rows(num_rows))
+        };
+        let keys = [(Column::new("order_id", 0), Column::new("order_id", 0))];
+
+        // Disjoint key ranges can not produce any row.
+        let left = context(1, 1_000, Some(1_000), Precision::Exact(1_000))?;
+        let right = context(50_000, 100_000, Some(50_001), Precision::Exact(50_001))?;
+        assert_eq!(estimate_join_cardinality(&left, &right, &keys), Some(0));
+
+        // Identical key ranges use the plain NDV formula: 1000 * 500 / 100.
+        let left = context(1, 100, Some(100), Precision::Exact(1_000))?;
+        let right = context(1, 100, Some(100), Precision::Inexact(500))?;
+        assert_eq!(estimate_join_cardinality(&left, &right, &keys), Some(5_000));
+
+        // Only the keys in the overlap [901, 1000] can match, i.e. 100 rows
+        // with 100 distinct values on the left and 200 rows with 100 distinct
+        // values on the right.
+        let left = context(1, 1_000, Some(1_000), Precision::Exact(1_000))?;
+        let right = context(901, 1_900, Some(1_000), Precision::Exact(2_000))?;
+        assert_eq!(estimate_join_cardinality(&left, &right, &keys), Some(200));
+
+        // Without distinct counts, the overlap [51, 100] holds 50 distinct
+        // values and half of the rows of each side.
+        let left = context(1, 100, None, Precision::Exact(1_000))?;
+        let right = context(51, 150, None, Precision::Exact(1_000))?;
+        assert_eq!(estimate_join_cardinality(&left, &right, &keys), Some(5_000));
+
+        // The selectivity of analyzed contexts scales the number of rows:
+        // order_id <= 100 keeps 100 rows with the keys [1, 100].
+        let filtered = |value: i64| {
+            let expr = binary(
+                col("order_id", &schema)?,
+                Operator::LtEq,
+                lit(value),
+                &schema,
+            )?;
+            analyze(
+                &expr,
+                context(1, 1_000, Some(1_000), Precision::Exact(1_000))?,
+            )
+        };
+        let right = context(51, 150, Some(100), Precision::Exact(100))?;
+        assert_eq!(
+            estimate_join_cardinality(&filtered(100)?, &right, &keys),
+            Some(50)
+        );
+        assert_eq!(
+            estimate_join_cardinality(&filtered(10)?, &right, &keys),
+            Some(0)
+        );
+
+        // Unknown row counts and unknown keys can not be estimated.
+        let left = context(1, 100, Some(100), Precision::Absent)?;
+        assert_eq!(estimate_join_cardinality(&left, &right, &keys), None);
+        let left = context(1, 100, Some(100), Precision::Exact(100))?;
+        let unknown_key = [(Column::new("order_id", 0), Column::new("other", 1))];
+        assert_eq!(estimate_join_cardinality(&left, &right, &unknown_key), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct_count_after_predicate() -> Result<()> {
+        // Column 'a' has values in [1, 100] and column 'b' in [1, 10].
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let distinct_counts = |expr: Arc<dyn PhysicalExpr>,
+                               distinct_count: Precision<usize>,
+                               num_rows: usize|
+         -> Result<Vec<Precision<usize>>> {
+            let stats = [
+                ColumnStatistics {
+                    distinct_count,
+                    ..int64_stats(1, 100)
+                },
+                ColumnStatistics {
+                    distinct_count: Precision::Exact(10),
+                    ..int64_stats(1, 10)
+                },
+            ];
+            let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+                .with_num_rows(Precision::Exact(num_rows));
+            let context = analyze(&expr, context)?;
+            Ok(context
+                .boundaries
+                .into_iter()
+                .map(|bound| bound.distinct_count)
+                .collect())
+        };
+        let a_op = |op: Operator, value: i64| {
+            binary(col("a", &schema)?, op, lit(value), &schema)
+        };
+
+        // a <= 50 keeps half of the values of 'a'. The distinct count of the
+        // other column is unchanged, but no longer exact.
+        assert_eq!(
+            distinct_counts(a_op(Operator::LtEq, 50)?, Precision::Exact(80), 1000)?,
+            vec![Precision::Inexact(40), Precision::Inexact(10)]
+        );
+        // a <= 25 keeps a quarter of the values of 'a':
+        assert_eq!(
+            distinct_counts(a_op(Operator::LtEq, 25)?, Precision::Inexact(80), 1000)?,
+            vec![Precision::Inexact(20), Precision::Inexact(10)]
+        );
+        // With 30 rows, only 15 rows remain after a <= 50:
+        assert_eq!(
+            distinct_counts(a_op(Operator::LtEq, 50)?, Precision::Exact(80), 30)?,
+            vec![Precision::Inexact(15), Precision::Inexact(10)]
+        );
+        // a < 3 keeps at least one distinct value:
+        assert_eq!(
+            distinct_counts(a_op(Operator::Lt, 3)?, Precision::Exact(10), 1000)?,
+            vec![Precision::Inexact(1), Precision::Inexact(10)]
+        );
+        // a = 42 leaves a single point:
+        assert_eq!(
+            distinct_counts(a_op(Operator::Eq, 42)?, Precision::Inexact(80), 1000)?,
+            vec![Precision::Exact(1), Precision::Inexact(10)]
+        );
+        // Unknown distinct counts stay unknown:
+        assert_eq!(
+            distinct_counts(a_op(Operator::LtEq, 50)?, Precision::Absent, 1000)?,
+            vec![Precision::Absent, Precision::Inexact(10)]
+        );
+        // A predicate that retains all rows keeps exact distinct counts:
+        assert_eq!(
+            distinct_counts(a_op(Operator::GtEq, 1)?, Precision::Exact(80), 1000)?,
+            vec![Precision::Exact(80), Precision::Exact(10)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_union_branches() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]));
+        let column_stats = |min: i64, max: i64, distinct_count| ColumnStatistics {
+            distinct_count,
+            null_count: Precision::Exact(1),
+            ..int64_stats(min, max)
+        };
+        // The first branch has 100 rows with 'a' in [1, 10], the second one
+        // has 300 rows with 'a' in [5, 20]. Only the first branch knows 'b'.
+        let left = AnalysisContext::try_from_statistics(
+            &schema,
+            &[
+                column_stats(1, 10, Precision::Exact(10)),
+                column_stats(0, 5, Precision::Exact(6)),
+            ],
+        )?
+        .with_selectivity(SelectivityEstimate::exact(0.5))
+        .with_num_rows(Precision::Exact(100));
+        let right = AnalysisContext::try_from_statistics(
+            &schema,
+            &[column_stats(5, 20, Precision::Inexact(4))],
+        )?
+        .with_selectivity(SelectivityEstimate::exact(0.2))
+        .with_num_rows(Precision::Exact(300));
+
+        let merged = left.merge(&right, 100, 300);
+        assert_eq!(merged.boundaries.len(), 2);
+        assert_eq!(
+            merged.boundaries[0].interval,
+            Interval::make(Some(1i64), Some(20i64), (false, false))
+        );
+        assert_eq!(merged.boundaries[0].distinct_count, Precision::Inexact(14));
+        assert_eq!(merged.boundaries[0].null_count, Precision::Exact(2));
+        assert_eq!(
+            merged.boundaries[1].interval,
+            Interval::make(None::<i64>, None, (true, true))
+        );
+        assert_eq!(merged.boundaries[1].distinct_count, Precision::Absent);
+        assert_eq!(merged.boundaries[1].null_count, Precision::Absent);
+        assert_eq!(merged.num_rows, Precision::Exact(400));
+        // (0.5 * 100 + 0.2 * 300) / 400
+        assert!((merged.selectivity.unwrap().point - 0.275).abs() < 1e-9);
+
+        // Exact distinct counts stay exact:
+        let merged = left.merge(&left, 100, 100);
+        assert_eq!(merged.boundaries[0].distinct_count, Precision::Exact(20));
+        assert!((merged.selectivity.unwrap().point - 0.5).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_in_list() -> Result<()> {
+        // Column 'a' has values in [1, 100] and column 'b' in [0, 10].
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Float64, false),
+        ]));
+        let stats = |distinct_count: Precision<usize>| {
+            [
+                ColumnStatistics {
+                    distinct_count: distinct_count.clone(),
+                    ..int64_stats(1, 100)
+                },
+                ColumnStatistics {
+                    min_value: Precision::Exact(ScalarValue::Float64(Some(0.0))),
+                    max_value: Precision::Exact(ScalarValue::Float64(Some(10.0))),
+                    distinct_count,
+                    ..Default::default()
+                },
+            ]
+        };
+        let analyze_in_list = |column: &str,
+                               list: Vec<ScalarValue>,
+                               negated: bool,
+                               distinct_count: Precision<usize>|
+         -> Result<(f64, Interval)> {
+            let list = list.into_iter().map(lit).collect();
+            let expr = in_list(col(column, &schema)?, list, &negated, &schema)?;
+            let context = analyze(
+                &expr,
+                AnalysisContext::try_from_statistics(&schema, &stats(distinct_count))?,
+            )?;
+            let index = schema.index_of(column)?;
+            Ok((
+                context.selectivity.unwrap().point,
+                context.boundaries[index].interval.clone(),
+            ))
+        };
+        let ints = |values: &[i64]| -> Vec<ScalarValue> {
+            values
+                .iter()
+                .map(|v| ScalarValue::Int64(Some(*v)))
+                .collect()
+        };
+
+        // a IN (10, 20, 30): without a distinct count, the selectivity is the
+        // ratio of the interval covering the list.
+        let (selectivity, interval) =
+            analyze_in_list("a", ints(&[30, 10, 20]), false, Precision::Absent)?;
+        assert!((selectivity - 0.21).abs() < 1e-9);
+        assert_eq!(
+            interval,
+            Interval::make(Some(10i64), Some(30i64), (false, false))
+        );
+        // With a known distinct count, each list item selects one of them.
+        let (selectivity, interval) =
+            analyze_in_list("a", ints(&[30, 10, 20]), false, Precision::Exact(50))?;
+        assert!((selectivity - 0.06).abs() < 1e-9);
+        assert_eq!(
+            interval,
+            Interval::make(Some(10i64), Some(30i64), (false, false))
+        );
+
+        // a IN (1, 100, 200): the list contains the minimum and the maximum,
+        // and 200 can not match.
+        let (selectivity, interval) =
+            analyze_in_list("a", ints(&[1, 100, 200]), false, Precision::Exact(50))?;
+        assert!((selectivity - 0.04).abs() < 1e-9);
+        assert_eq!(
+            interval,
+            Interval::make(Some(1i64), Some(100i64), (false, false))
+        );
+
+        // a IN (200, 300): no value can match.
+        let (selectivity, _) =
+            analyze_in_list("a", ints(&[200, 300]), false, Precision::Exact(50))?;
+        assert_eq!(selectivity, 0.0);
+
+        // a NOT IN (10, 20, 30): the interval is not narrowed.
+        let (selectivity, interval) =
+            analyze_in_list("a", ints(&[30, 10, 20]), true, Precision::Exact(50))?;
+        assert!((selectivity - 0.94).abs() < 1e-9);
+        assert_eq!(
+            interval,
+            Interval::make(Some(1i64), Some(100i64), (false, false))
+        );
+        let (selectivity, _) =
+            analyze_in_list("a", ints(&[30, 10, 20]), true, Precision::Absent)?;
+        assert_eq!(selectivity, 1.0);
+
+        // b IN (2.5, 7.5)
+        let floats = vec![
+            ScalarValue::Float64(Some(7.5)),
+            ScalarValue::Float64(Some(2.5)),
+        ];
+        let (selectivity, interval) =
+            analyze_in_list("b", floats.clone(), false, Precision::Absent)?;
+        assert!(selectivity > 0.0 && selectivity < 1.0);
+        assert_eq!(
+            interval,
+            Interval::make(Some(2.5), Some(7.5), (false, false))
+        );
+        let (selectivity, _) =
+            analyze_in_list("b", floats, false, Precision::Inexact(100))?;
+        assert!((selectivity - 0.02).abs() < 1e-9);
+
+        // a IN (NULL, 5): the NULL item never matches, so it neither adds to
+        // the selectivity nor keeps the interval from being narrowed.
+        let (selectivity, interval) = analyze_in_list(
+            "a",
+            vec![ScalarValue::Int64(None), ScalarValue::Int64(Some(5))],
+            false,
+            Precision::Exact(50),
+        )?;
+        assert!(selectivity > 0.0 && selectivity < 1.0);
+        assert_eq!(
+            interval,
+            Interval::make(Some(5i64), Some(5i64), (false, false))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_string_in_list() -> Result<()> {
+        // Column 'status' has values in ["closed", "review"].
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "status",
+            DataType::Utf8,
+            false,
+        )]));
+        let analyze_in_list = |list: &[&str],
+                               distinct_count: Precision<usize>|
+         -> Result<(f64, Interval)> {
+            let stats = [ColumnStatistics {
+                min_value: Precision::Exact(ScalarValue::from("closed")),
+                max_value: Precision::Exact(ScalarValue::from("review")),
+                distinct_count,
+                ..Default::default()
+            }];
+            let list = list.iter().map(|v| lit(*v)).collect();
+            let expr = in_list(col("status", &schema)?, list, &false, &schema)?;
+            let context = analyze(
+                &expr,
+                AnalysisContext::try_from_statistics(&schema, &stats)?,
+            )?;
+            Ok((
+                context.selectivity.unwrap().point,
+                context.boundaries[0].interval.clone(),
+            ))
+        };
+
+        // status IN ('open', 'pending', 'review')
+        let list = ["open", "pending", "review"];
+        let (selectivity, interval) = analyze_in_list(&list, Precision::Exact(10))?;
+        assert!((selectivity - 0.3).abs() < 1e-9);
+        assert_eq!(
+            interval,
+            Interval::make(Some("open"), Some("review"), (false, false))
+        );
+        // Without a distinct count, the selectivity falls back to the ratio of
+        // the interval covering the list.
+        let (selectivity, _) = analyze_in_list(&list, Precision::Absent)?;
+        assert!(selectivity > 0.0 && selectivity < 1.0);
+        // A list longer than the distinct count selects every row.
+        let (selectivity, _) = analyze_in_list(&list, Precision::Inexact(2))?;
+        assert_eq!(selectivity, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_like() -> Result<()> {
+        // Column 'name' has values in ["aaa", "zzz"].
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("name", DataType::Utf8, false)]));
+        let stats = [ColumnStatistics {
+            min_value: Precision::Exact(ScalarValue::from("aaa")),
+            max_value: Precision::Exact(ScalarValue::from("zzz")),
+            ..Default::default()
+        }];
+        let analyze_like = |pattern: &str,
+                            negated: bool,
+                            default_selectivity: f64|
+         -> Result<(f64, Interval)> {
+            let expr = like(
+                negated,
+                false,
+                col("name", &schema)?,
+                lit(pattern),
+                None,
+                &schema,
+            )?;
+            let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+                .with_default_selectivity(default_selectivity)?;
+            let context = analyze(&expr, context)?;
+            assert_eq!(context.default_selectivity, default_selectivity);
+            Ok((
+                context.selectivity.unwrap().point,
+                context.boundaries[0].interval.clone(),
+            ))
+        };
+        let initial = Interval::make(Some("aaa"), Some("zzz"), (false, false));
+
+        // A prefix-anchored pattern restricts the column to a range.
+        let (selectivity, interval) = analyze_like("abc%", false, 0.2)?;
+        assert!(selectivity > 0.0 && selectivity < 0.2);
+        assert_eq!(
+            interval,
+            Interval::make(Some("abc"), Some("abd"), (false, true))
+        );
+        // A pattern without wildcards matches a single value.
+        let (_, interval) = analyze_like("abc", false, 0.2)?;
+        assert_eq!(
+            interval,
+            Interval::make(Some("abc"), Some("abc"), (false, false))
+        );
+        // No value can match a prefix outside of the column range.
+        let (selectivity, _) = analyze_like("zzzz%", false, 0.2)?;
+        assert_eq!(selectivity, 0.0);
+
+        // Other patterns fall back to the default selectivity.
+        for pattern in ["%abc", "a_c%", "ab%c"] {
+            let (selectivity, interval) = analyze_like(pattern, false, 0.2)?;
+            assert_eq!(selectivity, 0.2);
+            assert_eq!(interval, initial);
+            let (selectivity, _) = analyze_like(pattern, false, 1.0)?;
+            assert_eq!(selectivity, 1.0);
+        }
+
+        // A negated pattern does not narrow the column.
+        let (selectivity, interval) = analyze_like("abc%", true, 0.2)?;
+        assert_eq!(selectivity, 1.0);
+        assert_eq!(interval, initial);
+        let (selectivity, _) = analyze_like("zzzz%", true, 0.2)?;
+        assert_eq!(selectivity, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_selectivity() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let stats = [int64_stats(1, 100)];
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?;
+
+        for invalid in [-0.1, 1.5, f64::NAN] {
+            let err = context
+                .clone()
+                .with_default_selectivity(invalid)
+                .unwrap_err();
+            assert!(err.to_string().contains("between 0.0 and 1.0"), "{err}");
+        }
+        let context = context.with_default_selectivity(0.2)?;
+
+        // Interval arithmetic does not support `(a + 1) IS NULL`:
+        let a_plus_one = binary(col("a", &schema)?, Operator::Plus, lit(1i64), &schema)?;
+        let unsupported = is_null(a_plus_one)?;
+        let result = analyze(&unsupported, context.clone())?;
+        let selectivity = result.selectivity.unwrap();
+        assert_eq!(selectivity.point, 0.2);
+        assert_eq!(selectivity.confidence, Precision::Absent);
+        assert_eq!(result.selectivity_precision, Precision::Absent);
+        assert_eq!(result.boundaries, context.boundaries);
+        // The default also applies to the unsupported children of disjunctions:
+        let either = binary(
+            binary(col("a", &schema)?, Operator::LtEq, lit(10i64), &schema)?,
+            Operator::Or,
+            unsupported,
+            &schema,
+        )?;
+        let selectivity = analyze(&either, context.clone())?.selectivity.unwrap();
+        assert!((selectivity.point - (0.1 + 0.2 - 0.1 * 0.2)).abs() < 1e-9);
+
+        // A predicate that certainly holds still selects everything:
+        let always = binary(col("a", &schema)?, Operator::GtEq, lit(1i64), &schema)?;
+        assert_eq!(analyze(&always, context)?.selectivity.unwrap().point, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_columns_without_boundaries() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        // The boundaries only cover column a:
+        let stats = [int64_stats(1, 100)];
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+            .with_default_selectivity(0.5)?;
+
+        let a_le_25 = binary(col("a", &schema)?, Operator::LtEq, lit(25i64), &schema)?;
+        let b_gt_10 = binary(col("b", &schema)?, Operator::Gt, lit(10i64), &schema)?;
+        let both = binary(a_le_25.clone(), Operator::And, b_gt_10.clone(), &schema)?;
+        // A column index that is out of range for the boundaries:
+        let out_of_range = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("a", 5)),
+            Operator::Gt,
+            lit(10i64),
+        )) as Arc<dyn PhysicalExpr>;
+
+        for (expr, missing) in [(&b_gt_10, "b@1"), (&both, "b@1"), (&out_of_range, "a@5")]
+        {
+            let err = analyze(expr, context.clone()).unwrap_err();
+            assert!(matches!(err, DataFusionError::Plan(_)), "{err}");
+            assert!(err.to_string().contains(missing), "{err}");
+            assert!(
+                analyze_many(&[a_le_25.clone(), expr.clone()], context.clone()).is_err()
+            );
+        }
+
+        // The missing columns may be treated as unconstrained instead, which caps
+        // the selectivity at the default:
+        let context = context.with_allow_missing_columns(true);
+        let selectivity_of = |expr: &Arc<dyn PhysicalExpr>| -> Result<_> {
+            let analyzed = analyze(expr, context.clone())?;
+            assert!(analyzed.allow_missing_columns);
+            assert_eq!(analyzed.selectivity_precision, Precision::Absent);
+            Ok(analyzed.selectivity.unwrap())
+        };
+        assert_eq!(selectivity_of(&out_of_range)?.point, 0.5);
+        assert_eq!(selectivity_of(&b_gt_10)?.point, 0.5);
+        // a <= 25 alone selects a quarter of the rows:
+        let selectivity = selectivity_of(&both)?;
+        assert_eq!(selectivity.point, 0.25);
+        assert_eq!(selectivity.confidence, Precision::Absent);
+        let analyzed = analyze(&both, context.clone())?;
+        assert_eq!(
+            analyzed.boundaries[0].interval,
+            Interval::make(Some(1i64), Some(25i64), (false, false))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_strict_comparisons() -> Result<()> {
+        let selectivity = |data_type: DataType,
+                           (min, max): (ScalarValue, ScalarValue),
+                           op: Operator,
+                           value: ScalarValue|
+         -> Result<f64> {
+            let schema = Schema::new(vec![Field::new("x", data_type, false)]);
+            let stats = [ColumnStatistics {
+                min_value: Precision::Exact(min),
+                max_value: Precision::Exact(max),
+                ..Default::default()
+            }];
+            let expr = binary(col("x", &schema)?, op, lit(value), &schema)?;
+            let context = AnalysisContext::try_from_statistics(&schema, &stats)?;
+            Ok(analyze(&expr, context)?.selectivity.unwrap().point)
+        };
+
+        // Column 'x' is an integer in [1, 10]: `x < 5` and `x <= 4` (as well as
+        // `x > 4` and `x >= 5`) select the same values.
+        let int = |value: i64| ScalarValue::Int64(Some(value));
+        let range = (int(1), int(10));
+        let lt = selectivity(DataType::Int64, range.clone(), Operator::Lt, int(5))?;
+        let lt_eq = selectivity(DataType::Int64, range.clone(), Operator::LtEq, int(4))?;
+        assert_eq!(lt, 0.4);
+        assert_eq!(lt, lt_eq);
+        let gt = selectivity(DataType::Int64, range.clone(), Operator::Gt, int(4))?;
+        let gt_eq = selectivity(DataType::Int64, range, Operator::GtEq, int(5))?;
+        assert_eq!(gt, 0.6);
+        assert_eq!(gt, gt_eq);
+
+        // Column 'x' is a float in [0, 10]: `x < 5` and `x <= 5` are equivalent.
+        let float = |value: f64| ScalarValue::Float64(Some(value));
+        let range = (float(0.0), float(10.0));
+        let lt = selectivity(DataType::Float64, range.clone(), Operator::Lt, float(5.0))?;
+        let lt_eq = selectivity(DataType::Float64, range, Operator::LtEq, float(5.0))?;
+        assert_eq!(lt, lt_eq);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_negations() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Int64, true)]));
+        let stats = |null_count: usize| {
+            [ColumnStatistics {
+                null_count: Precision::Exact(null_count),
+                ..int64_stats(1, 100)
+            }]
+        };
+        let analyze_with = |expr: &Arc<dyn PhysicalExpr>,
+                            null_count: usize|
+         -> Result<AnalysisContext> {
+            let context =
+                AnalysisContext::try_from_statistics(&schema, &stats(null_count))?
+                    .with_num_rows(Precision::Exact(100))
+                    .with_default_selectivity(0.3)?;
+            analyze(expr, context)
+        };
+        let x = col("x", &schema)?;
+        let compare =
+            |op: Operator, value: i64| binary(x.clone(), op, lit(value), &schema);
+        let between = binary(
+            compare(Operator::GtEq, 10)?,
+            Operator::And,
+            compare(Operator::LtEq, 20)?,
+            &schema,
+        )?;
+
+        // `NOT (x < 5)` is analyzed as `x >= 5`.
+        let negated = not(compare(Operator::Lt, 5)?)?;
+        assert!(check_support(&negated, &schema));
+        let expected = analyze_with(&compare(Operator::GtEq, 5)?, 0)?;
+        let actual = analyze_with(&negated, 0)?;
+        assert_eq!(actual.selectivity, expected.selectivity);
+        assert_eq!(actual.boundaries, expected.boundaries);
+        assert_eq!(
+            actual.boundaries[0].interval,
+            Interval::make(Some(5_i64), Some(100_i64), (false, false))
+        );
+
+        // `NOT BETWEEN` selects the complement of `BETWEEN` without shrinking
+        // the column boundaries.
+        let negated = not(between.clone())?;
+        assert!(check_support(&negated, &schema));
+        let selectivity = analyze_with(&between, 0)?.selectivity.unwrap().point;
+        let actual = analyze_with(&negated, 0)?;
+        assert_eq!(actual.selectivity.unwrap().point, 1.0 - selectivity);
+        assert_eq!(
+            actual.boundaries[0].interval,
+            Interval::make(Some(1_i64), Some(100_i64), (false, false))
+        );
+        // NULL values satisfy neither of them.
+        let selectivity = analyze_with(&between, 20)?.selectivity.unwrap().point;
+        let actual = analyze_with(&negated, 20)?.selectivity.unwrap().point;
+        assert!((actual - (0.8 - selectivity)).abs() < 1e-9);
+
+        // Negations are combined with other predicates like disjunctions.
+        let conjunction = binary(
+            negated.clone(),
+            Operator::And,
+            compare(Operator::Lt, 50)?,
+            &schema,
+        )?;
+        let actual = analyze_with(&conjunction, 0)?.selectivity.unwrap().point;
+        let negated_selectivity = analyze_with(&negated, 0)?.selectivity.unwrap().point;
+        let lt_selectivity = analyze_with(&compare(Operator::Lt, 50)?, 0)?
+            .selectivity
+            .unwrap()
+            .point;
+        assert_eq!(actual, negated_selectivity * lt_selectivity);
+
+        // The negation of a predicate whose selectivity is only guessed is
+        // guessed as well.
+        let negated = not(is_null(binary(
+            x.clone(),
+            Operator::Plus,
+            lit(1_i64),
+            &schema,
+        )?)?)?;
+        let actual = analyze_with(&negated, 0)?;
+        assert_eq!(actual.selectivity.unwrap().point, 0.3);
+        assert_eq!(actual.selectivity_precision, Precision::Absent);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shrink_negated_boundaries() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Int64, true)]));
+        let stats = [ColumnStatistics {
+            null_count: Precision::Exact(0),
+            ..int64_stats(1, 100)
+        }];
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+            .with_num_rows(Precision::Exact(100));
+        let x = col("x", &schema)?;
+        let compare =
+            |op: Operator, value: i64| binary(x.clone(), op, lit(value), &schema);
+        let outside = |low: i64, high: i64| {
+            binary(
+                compare(Operator::Lt, low)?,
+                Operator::Or,
+                compare(Operator::Gt, high)?,
+                &schema,
+            )
+        };
+
+        // NOT (x < 10 OR x > 20) shrinks `x` to [10, 20].
+        let negated = not(outside(10, 20)?)?;
+        assert!(check_support(&negated, &schema));
+        let analyzed = analyze(&negated, context.clone())?;
+        assert_eq!(
+            analyzed.boundaries[0].interval,
+            Interval::make(Some(10_i64), Some(20_i64), (false, false))
+        );
+        assert_eq!(analyzed.boundaries[0].selectivity, Some(11.0 / 100.0));
+        let selectivity = analyze(&outside(10, 20)?, context.clone())?
+            .selectivity
+            .unwrap()
+            .point;
+        assert_eq!(analyzed.selectivity.unwrap().point, 1.0 - selectivity);
+
+        // NOT (x < 150 OR x > 200) can not hold for values up to 100, which
+        // leaves the boundaries unchanged.
+        let negated = not(outside(150, 200)?)?;
+        let analyzed = analyze(&negated, context.clone())?;
+        assert_eq!(analyzed.boundaries, context.boundaries);
+
+        // The selectivities of a predicate and its negation add up to one for
+        // all ranges between values below, at, inside and above the bounds of
+        // `x`.
+        let values = [-20, 0, 1, 2, 10, 50, 99, 100, 101, 140];
+        let ranges = values.iter().flat_map(|low| {
+            values
+                .iter()
+                .filter(move |high| *high >= low)
+                .map(move |high| (*low, *high))
+        });
+        for (low, high) in ranges {
+            let predicates = [
+                outside(low, high)?,
+                binary(
+                    compare(Operator::GtEq, low)?,
+                    Operator::And,
+                    compare(Operator::LtEq, high)?,
+                    &schema,
+                )?,
+                compare(Operator::Gt, low)?,
+            ];
+            for predicate in predicates {
+                let selectivity = |expr: &Arc<dyn PhysicalExpr>| -> Result<f64> {
+                    Ok(analyze(expr, context.clone())?.selectivity.unwrap().point)
+                };
+                let sum = selectivity(&predicate)? + selectivity(&not(predicate)?)?;
+                assert!((sum - 1.0).abs() < 1e-9, "{low} {high}: {sum}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_between() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        // Column 'a' is in [1, 100], column 'b' is in [1, 10].
+        let stats = [(1, 100), (1, 10)].map(|(min, max)| ColumnStatistics {
+            null_count: Precision::Exact(0),
+            ..int64_stats(min, max)
+        });
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?;
+        let selectivity = |expr: &Arc<dyn PhysicalExpr>| -> Result<f64> {
+            Ok(analyze(expr, context.clone())?.selectivity.unwrap().point)
+        };
+        let compare = |name: &str, op: Operator, value: i64| {
+            binary(col(name, &schema)?, op, lit(value), &schema)
+        };
+        let and = |left, right| binary(left, Operator::And, right, &schema);
+        // b = 1 OR b = 2
+        let disjunction = binary(
+            compare("b", Operator::Eq, 1)?,
+            Operator::Or,
+            compare("b", Operator::Eq, 2)?,
+            &schema,
+        )?;
+        let disjunction_selectivity = selectivity(&disjunction)?;
+
+        for ((low, high), expected) in [
+            // The range contains all values of the column:
+            ((0, 200), 1.0),
+            // The range overlaps with the values of the column:
+            ((10, 20), 0.11),
+            ((-10, 10), 0.1),
+            // The range is disjoint with the values of the column:
+            ((150, 200), 0.0),
+        ] {
+            let lower = compare("a", Operator::GtEq, low)?;
+            let upper = compare("a", Operator::LtEq, high)?;
+            // a BETWEEN low AND high
+            let between = and(lower.clone(), upper.clone())?;
+            assert_eq!(selectivity(&between)?, expected);
+            // Both sides of the range shrink the same interval even if a
+            // disjunction separates them:
+            // a >= low AND (b = 1 OR b = 2) AND a <= high
+            let expr = and(and(lower, disjunction.clone())?, upper)?;
+            let analyzed = analyze(&expr, context.clone())?;
+            assert_eq!(
+                analyzed.selectivity.unwrap().point,
+                expected * disjunction_selectivity
+            );
+            if expected > 0.0 {
+                assert_eq!(
+                    analyzed.boundaries[0].interval,
+                    Interval::make(Some(low.max(1)), Some(high.min(100)), (false, false))
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_correlated_columns() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Int64, false),
+            Field::new("c", DataType::Int64, false),
+        ]);
+        let stats = vec![int64_stats(1, 100); 3];
+        // `a <= 50` selects half of the values, and `b <= 20` one fifth.
+        let a_and_b = binary(
+            binary(col("a", &schema)?, Operator::LtEq, lit(50i64), &schema)?,
+            Operator::And,
+            binary(col("b", &schema)?, Operator::LtEq, lit(20i64), &schema)?,
+            &schema,
+        )?;
+        let selectivity = |correlations: Vec<(Column, Column, f64)>| -> Result<f64> {
+            let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+                .with_correlations(correlations);
+            let result = analyze(&a_and_b, context)?;
+            Ok(result.selectivity.unwrap().point)
+        };
+        let a = Column::new("a", 0);
+        let b = Column::new("b", 1);
+        let c = Column::new("c", 2);
+
+        // Independent columns multiply their selectivities:
+        assert_eq!(selectivity(vec![])?, 0.5 * 0.2);
+        assert_eq!(selectivity(vec![(a.clone(), c.clone(), 1.0)])?, 0.5 * 0.2);
+        // Fully correlated columns are as selective as the more selective one:
+        assert_eq!(selectivity(vec![(a.clone(), b.clone(), 1.0)])?, 0.2);
+        assert_eq!(selectivity(vec![(b.clone(), a.clone(), 1.0)])?, 0.2);
+        // Partially correlated columns are in between:
+        let partial = selectivity(vec![(a.clone(), b.clone(), 0.5)])?;
+        assert!((partial - 0.2 * 0.5f64.sqrt()).abs() < 1e-9);
+        // The strongest correlation is used:
+        assert_eq!(
+            selectivity(vec![(a.clone(), b.clone(), 0.5), (a, b, 1.0)])?,
+            0.2
+        );
+
+        // Correlated columns can be given by their positions:
+        let correlated = |pairs: Vec<(usize, usize)>| -> Result<f64> {
+            let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+                .with_correlated_columns(pairs);
+            Ok(analyze(&a_and_b, context)?.selectivity.unwrap().point)
+        };
+        assert_eq!(correlated(vec![(1, 0)])?, 0.2);
+        assert_eq!(correlated(vec![(0, 2)])?, 0.5 * 0.2);
+        assert_eq!(correlated(vec![(0, 3)])?, 0.5 * 0.2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prune_partitions() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        // Column 'a' has values in [1, 10], [11, 20] and [21, 30] in the
+        // respective partitions.
+        let partition_statistics = [(1, 10), (11, 20), (21, 30)]
+            .into_iter()
+            .map(|(min, max)| {
+                vec![
+                    ColumnStatistics {
+                        null_count: Precision::Exact(0),
+                        ..int64_stats(min, max)
+                    },
+                    ColumnStatistics::new_unknown(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        // a > 15 AND a < 25
+        let expr = binary(
+            binary(
+                col("a", &schema)?,
+                Operator::Gt,
+                lit(ScalarValue::Int64(Some(15))),
+                &schema,
+            )?,
+            Operator::And,
+            binary(
+                col("a", &schema)?,
+                Operator::Lt,
+                lit(ScalarValue::Int64(Some(25))),
+                &schema,
+            )?,
+            &schema,
+        )?;
+        assert_eq!(
+            prune_partitions(&expr, &partition_statistics, &schema)?,
+            vec![false, true, true]
+        );
+
+        // a = 5 OR a = 25
+        let expr = binary(
+            binary(
+                col("a", &schema)?,
+                Operator::Eq,
+                lit(ScalarValue::Int64(Some(5))),
+                &schema,
+            )?,
+            Operator::Or,
+            binary(
+                col("a", &schema)?,
+                Operator::Eq,
+                lit(ScalarValue::Int64(Some(25))),
+                &schema,
+            )?,
+            &schema,
+        )?;
+        assert_eq!(
+            prune_partitions(&expr, &partition_statistics, &schema)?,
+            vec![true, false, true]
+        );
+
+        // a IS NULL: no partition has NULL values, but the number of rows is
+        // unknown.
+        let expr = is_null(col("a", &schema)?)?;
+        assert_eq!(
+            prune_partitions(&expr, &partition_statistics, &schema)?,
+            vec![true, true, true]
+        );
+
+        // Predicates that can not be analyzed do not prune any partition.
+        let expr = binary(
+            col("a", &schema)?,
+            Operator::Modulo,
+            lit(ScalarValue::Int64(Some(2))),
+            &schema,
+        )?;
+        assert_eq!(
+            prune_partitions(&expr, &partition_statistics, &schema)?,
+            vec![true, true, true]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_point_intervals() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("part", DataType::Int64, false),
+            Field::new("x", DataType::Int64, false),
+        ]));
+        // Column 'part' is 3 in all rows, column 'x' is in [1, 100].
+        let stats = [(3, 3), (1, 100)].map(|(min, max)| ColumnStatistics {
+            null_count: Precision::Exact(0),
+            ..int64_stats(min, max)
+        });
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+            .with_num_rows(Precision::Exact(100));
+        let predicate = |part: i64| -> Result<Arc<dyn PhysicalExpr>> {
+            binary(
+                binary(col("part", &schema)?, Operator::Eq, lit(part), &schema)?,
+                Operator::And,
+                binary(col("x", &schema)?, Operator::Gt, lit(50_i64), &schema)?,
+                &schema,
+            )
+        };
+
+        // Only the constant column is folded; it decides the predicate when it
+        // does not match, regardless of the range of 'x'.
+        let mut graph = ExprIntervalGraph::try_new(predicate(2)?)?;
+        assert_eq!(fold_point_intervals(&mut graph, &context.boundaries), 1);
+        assert_eq!(graph.try_evaluate_constant()?, Some(false));
+        let mut graph = ExprIntervalGraph::try_new(predicate(3)?)?;
+        assert_eq!(fold_point_intervals(&mut graph, &context.boundaries), 1);
+        assert_eq!(graph.try_evaluate_constant()?, None);
+
+        // The results of the analysis are the same as without folding.
+        let analyzed = analyze(&predicate(2)?, context.clone())?;
+        assert_eq!(analyzed.selectivity.unwrap().point, 0.0);
+        assert_eq!(analyzed.selectivity_precision, Precision::Exact(()));
+        let analyzed = analyze(&predicate(3)?, context.clone())?;
+        assert_eq!(analyzed.selectivity.unwrap().point, 0.5);
+        assert_eq!(
+            analyzed.boundaries[1].interval,
+            Interval::make(Some(50_i64), Some(100_i64), (true, false))
+        );
+
+        // A predicate on the constant column alone holds for every row.
+        let expr = binary(col("part", &schema)?, Operator::GtEq, lit(3_i64), &schema)?;
+        let analyzed = analyze(&expr, context)?;
+        assert_eq!(analyzed.selectivity.unwrap().point, 1.0);
+        assert_eq!(analyzed.selectivity_precision, Precision::Exact(()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_constant_column() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("i", DataType::Int64, false),
+            Field::new("f", DataType::Float64, false),
+        ]));
+        // Column 'i' is 5 and column 'f' is 2.5 in all rows.
+        let stats =
+            [ScalarValue::Int64(Some(5)), ScalarValue::Float64(Some(2.5))].map(|value| {
+                ColumnStatistics {
+                    min_value: Precision::Exact(value.clone()),
+                    max_value: Precision::Exact(value),
+                    null_count: Precision::Exact(0),
+                    ..Default::default()
+                }
+            });
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+            .with_num_rows(Precision::Exact(100));
+        let selectivity = |name: &str, op: Operator, value: ScalarValue| {
+            let expr = binary(col(name, &schema)?, op, lit(value), &schema)?;
+            let analyzed = analyze(&expr, context.clone())?;
+            for bound in &analyzed.boundaries {
+                assert!(bound.selectivity.map_or(true, f64::is_finite));
+            }
+            Ok::<_, DataFusionError>(analyzed.selectivity.unwrap().point)
+        };
+        let int = |value: i64| ScalarValue::Int64(Some(value));
+        let float = |value: f64| ScalarValue::Float64(Some(value));
+        let cases = [
+            ("i", Operator::Eq, int(5), 1.0),
+            ("i", Operator::Eq, int(6), 0.0),
+            ("i", Operator::NotEq, int(5), 0.0),
+            ("i", Operator::NotEq, int(6), 1.0),
+            ("i", Operator::Gt, int(4), 1.0),
+            ("i", Operator::Gt, int(5), 0.0),
+            ("f", Operator::Eq, float(2.5), 1.0),
+            ("f", Operator::NotEq, float(2.5), 0.0),
+            ("f", Operator::Gt, float(2.5), 0.0),
+            ("f", Operator::Gt, float(2.0), 1.0),
+        ];
+        for (name, op, value, expected) in cases {
+            assert_eq!(
+                selectivity(name, op, value.clone())?,
+                expected,
+                "{name} {op} {value}"
+            );
+        }
+
+        // A conjunction of predicates on constant columns holds for every row.
+        let expr = binary(
+            binary(col("i", &schema)?, Operator::Eq, lit(int(5)), &schema)?,
+            Operator::And,
+            binary(col("f", &schema)?, Operator::LtEq, lit(float(2.5)), &schema)?,
+            &schema,
+        )?;
+        let analyzed = analyze(&expr, context)?;
+        assert_eq!(analyzed.selectivity.unwrap().point, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_many_matches_analyze() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+            Field::new("c", DataType::Utf8, true),
+        ]));
+        let int_stats = |max: i64| ColumnStatistics {
+            null_count: Precision::Exact(10),
+            distinct_count: Precision::Inexact(50),
+            ..int64_stats(0, max)
+        };
+        let stats = [
+            int_stats(100),
+            int_stats(1000),
+            ColumnStatistics {
+                min_value: Precision::Exact(ScalarValue::from("aaa")),
+                max_value: Precision::Exact(ScalarValue::from("zzz")),
+                ..Default::default()
+            },
+        ];
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+            .with_num_rows(Precision::Exact(200))
+            .with_default_selectivity(0.5)?
+            .with_correlations(vec![(Column::new("a", 0), Column::new("b", 1), 0.5)]);
+
+        let a = col("a", &schema)?;
+        let b = col("b", &schema)?;
+        let c = col("c", &schema)?;
+        let int = |v: i64| lit(ScalarValue::Int64(Some(v)));
+        let cmp = |expr: &Arc<dyn PhysicalExpr>, op: Operator, v: i64| {
+            binary(expr.clone(), op, int(v), &schema)
+        };
+        let and = |l, r| binary(l, Operator::And, r, &schema);
+        let or = |l, r| binary(l, Operator::Or, r, &schema);
+        let exprs = vec![
+            cmp(&a, Operator::Gt, 10)?,
+            cmp(&a, Operator::LtEq, 50)?,
+            cmp(&b, Operator::Eq, 500)?,
+            cmp(&b, Operator::GtEq, 500)?,
+            cmp(&a, Operator::Gt, 200)?,
+            and(cmp(&a, Operator::Gt, 10)?, cmp(&b, Operator::Lt, 100)?)?,
+            and(cmp(&a, Operator::GtEq, 20)?, cmp(&a, Operator::Lt, 30)?)?,
+            or(cmp(&a, Operator::Lt, 10)?, cmp(&b, Operator::Gt, 900)?)?,
+            or(cmp(&a, Operator::Lt, 10)?, cmp(&a, Operator::Gt, 90)?)?,
+            and(
+                cmp(&b, Operator::Gt, 10)?,
+                or(cmp(&a, Operator::Eq, 1)?, cmp(&a, Operator::Eq, 2)?)?,
+            )?,
+            binary(a.clone(), Operator::Lt, b.clone(), &schema)?,
+            in_list(a.clone(), vec![int(1), int(5), int(7)], &false, &schema)?,
+            in_list(b.clone(), vec![int(1), int(5000)], &true, &schema)?,
+            is_null(a.clone())?,
+            is_not_null(b.clone())?,
+            like(false, false, c.clone(), lit("abc%"), None, &schema)?,
+            like(false, false, c.clone(), lit("%abc%"), None, &schema)?,
+            and(
+                like(false, false, c.clone(), lit("m%"), None, &schema)?,
+                cmp(&a, Operator::Gt, 50)?,
+            )?,
+            binary(
+                binary(a.clone(), Operator::Plus, b.clone(), &schema)?,
+                Operator::Gt,
+                int(1000),
+                &schema,
+            )?,
+            cmp(&a, Operator::Gt, 10)?,
+        ];
+        assert_eq!(exprs.len(), 20);
+
+        let results = analyze_many(&exprs, context.clone())?;
+        assert_eq!(results.len(), exprs.len());
+        for (expr, result) in exprs.iter().zip(results) {
+            assert_eq!(result, analyze(expr, context.clone())?, "{expr}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_of_temporal_predicates() -> Result<()> {
+        // Returns the selectivity of `t >= value` and the resulting interval of
+        // `t`, for a column `t` of the given type with values in [min, max].
+        let analyze_gt_eq = |data_type: DataType,
+                             min: ScalarValue,
+                             max: ScalarValue,
+                             value: ScalarValue|
+         -> Result<(f64, Interval)> {
+            let schema = Arc::new(Schema::new(vec![Field::new("t", data_type, false)]));
+            let stats = [ColumnStatistics {
+                min_value: Precision::Exact(min),
+                max_value: Precision::Exact(max),
+                ..Default::default()
+            }];
+            let expr = binary(col("t", &schema)?, Operator::GtEq, lit(value), &schema)?;
+            assert!(check_support(&expr, &schema));
+            let context = analyze(
+                &expr,
+                AnalysisContext::try_from_statistics(&schema, &stats)?,
+            )?;
+            Ok((
+                context.selectivity.unwrap().point,
+                context.boundaries[0].interval.clone(),
+            ))
+        };
+        // The last month of a year with 360 days selects roughly 1/12 of it.
+        let assert_last_month =
+            |data_type: DataType, day: &dyn Fn(i64) -> ScalarValue| {
+                let (selectivity, interval) =
+                    analyze_gt_eq(data_type, day(0), day(359), day(330))?;
+                assert!((selectivity - 1.0 / 12.0).abs() < 5e-3, "{selectivity}");
+                assert_eq!(
+                    interval,
+                    Interval::new(
+                        IntervalBound::new_closed(day(330)),
+                        IntervalBound::new_closed(day(359)),
+                    )
+                );
+                Ok::<_, DataFusionError>(())
+            };
+
+        assert_last_month(DataType::Date32, &|day| {
+            ScalarValue::Date32(Some(day as i32))
+        })?;
+        assert_last_month(DataType::Date64, &|day| {
+            ScalarValue::Date64(Some(day * 86_400_000))
+        })?;
+        assert_last_month(DataType::Timestamp(TimeUnit::Second, None), &|day| {
+            ScalarValue::TimestampSecond(Some(day * 86_400), None)
+        })?;
+        let tz: Option<Arc<str>> = Some("+05:00".into());
+        assert_last_month(
+            DataType::Timestamp(TimeUnit::Nanosecond, tz.clone()),
+            &|day| {
+                ScalarValue::TimestampNanosecond(
+                    Some(day * 86_400_000_000_000),
+                    tz.clone(),
+                )
+            },
+        )?;
+
+        // A millisecond bound on a microsecond column is compared in microseconds.
+        let micros = |day: i64| {
+            ScalarValue::TimestampMicrosecond(Some(day * 86_400_000_000), None)
+        };
+        let (selectivity, interval) = analyze_gt_eq(
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            micros(0),
+            micros(359),
+            ScalarValue::TimestampMillisecond(Some(330 * 86_400_000 + 1), None),
+        )?;
+        assert!((selectivity - 1.0 / 12.0).abs() < 5e-3, "{selectivity}");
+        assert_eq!(
+            interval,
+            Interval::new(
+                IntervalBound::new_closed(ScalarValue::TimestampMicrosecond(
+                    Some(330 * 86_400_000_000 + 1_000),
+                    None
+                )),
+                IntervalBound::new_closed(micros(359)),
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_boundaries_of_derived_expressions() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("price", DataType::Int64, false),
+            Field::new("quantity", DataType::Int64, false),
+            Field::new("ts", DataType::Timestamp(TimeUnit::Nanosecond, None), false),
+        ]));
+        // 2020-03-01T00:00:00 and 2022-06-01T00:00:00:
+        let (min_ts, max_ts) = (1_583_020_800_000_000_000, 1_654_041_600_000_000_000);
+        let stats = [
+            (ScalarValue::Int64(Some(2)), ScalarValue::Int64(Some(10))),
+            (ScalarValue::Int64(Some(1)), ScalarValue::Int64(Some(5))),
+            (
+                ScalarValue::TimestampNanosecond(Some(min_ts), None),
+                ScalarValue::TimestampNanosecond(Some(max_ts), None),
+            ),
+        ]
+        .map(|(min, max)| ColumnStatistics {
+            min_value: Precision::Exact(min),
+            max_value: Precision::Exact(max),
+            distinct_count: Precision::Exact(4),
+            ..Default::default()
+        });
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?;
+        let boundaries_of = |expr: &Arc<dyn PhysicalExpr>| {
+            ExprBoundaries::try_from_physical_expr(expr, &context.boundaries)
+        };
+        let date_part = |part: &str| {
+            create_physical_expr(
+                &BuiltinScalarFunction::DatePart,
+                &[lit(part), col("ts", &schema)?],
+                &schema,
+                &ExecutionProps::new(),
+            )
+        };
+        let price = col("price", &schema)?;
+        let quantity = col("quantity", &schema)?;
+
+        // price * quantity
+        let total = binary(price.clone(), Operator::Multiply, quantity.clone(), &schema)?;
+        let bounds = boundaries_of(&total)?.unwrap();
+        assert_eq!(
+            bounds.interval,
+            Interval::make(Some(2_i64), Some(50), (false, false))
+        );
+        assert_eq!(bounds.column, Column::new("price@0 * quantity@1", 3));
+        assert_eq!(bounds.confidence, Precision::Exact(()));
+        assert_eq!(bounds.distinct_count, Precision::Inexact(16));
+
+        // -CAST(price AS Float64) + 1
+        let negated = binary(
+            negative(cast(price.clone(), &schema, DataType::Float64)?, &schema)?,
+            Operator::Plus,
+            lit(1.0),
+            &schema,
+        )?;
+        let bounds = boundaries_of(&negated)?.unwrap();
+        assert_eq!(
+            bounds.interval,
+            Interval::make(Some(-9.0), Some(-1.0), (false, false))
+        );
+        assert_eq!(bounds.distinct_count, Precision::Inexact(4));
+
+        // date_part('year', ts)
+        let bounds = boundaries_of(&date_part("year")?)?.unwrap();
+        assert_eq!(
+            bounds.interval,
+            Interval::make(Some(2020.0), Some(2022.0), (false, false))
+        );
+        assert_eq!(bounds.distinct_count, Precision::Inexact(3));
+
+        // Columns carry over their own boundaries:
+        assert_eq!(
+            boundaries_of(&price)?.as_ref(),
+            Some(&context.boundaries[0])
+        );
+
+        // The following expressions are not supported:
+        let unsupported = [
+            date_part("month")?,
+            binary(price, Operator::Divide, quantity, &schema)?,
+            Arc::new(Column::new("unknown", 3)),
+        ];
+        for expr in unsupported {
+            assert_eq!(boundaries_of(&expr)?, None);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate_distinct_count() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("f", DataType::Float64, false),
+            Field::new("ts", DataType::Timestamp(TimeUnit::Nanosecond, None), false),
+        ]));
+        // 2020-01-01T00:00:00 and 2020-03-15T00:00:00:
+        let (min_ts, max_ts) = (1_577_836_800_000_000_000, 1_584_230_400_000_000_000);
+        let stats = [
+            (
+                ScalarValue::Int32(Some(1)),
+                ScalarValue::Int32(Some(100)),
+                Some(50),
+            ),
+            (
+                ScalarValue::Int32(Some(1)),
+                ScalarValue::Int32(Some(10)),
+                Some(10),
+            ),
+            (
+                ScalarValue::Float64(Some(0.0)),
+                ScalarValue::Float64(Some(1.0)),
+                Some(1000),
+            ),
+            (
+                ScalarValue::TimestampNanosecond(Some(min_ts), None),
+                ScalarValue::TimestampNanosecond(Some(max_ts), None),
+                None,
+            ),
+        ]
+        .map(|(min, max, distinct_count)| ColumnStatistics {
+            min_value: Precision::Exact(min),
+            max_value: Precision::Exact(max),
+            distinct_count: distinct_count.map_or(Precision::Absent, Precision::Exact),
+            ..Default::default()
+        });
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?;
+        let estimate = |expr: Arc<dyn PhysicalExpr>| {
+            estimate_distinct_count(&expr, &context.boundaries)
+        };
+        let function = |fun: BuiltinScalarFunction, granularity: &str| {
+            create_physical_expr(
+                &fun,
+                &[lit(granularity), col("ts", &schema)?],
+                &schema,
+                &ExecutionProps::new(),
+            )
+        };
+        let a = col("a", &schema)?;
+        let b = col("b", &schema)?;
+        let f = col("f", &schema)?;
+        let arithmetic = |left: &Arc<dyn PhysicalExpr>, op, right| {
+            binary(left.clone(), op, right, &schema)
+        };
+
+        assert_eq!(estimate(a.clone()), Precision::Exact(50));
+        assert_eq!(estimate(lit(1_i32)), Precision::Exact(1));
+        assert_eq!(
+            estimate(negative(a.clone(), &schema)?),
+            Precision::Exact(50)
+        );
+
+        // Lossless casts preserve the distinct count, lossy ones are bounded by
+        // the range of the result.
+        let widened = cast(a.clone(), &schema, DataType::Int64)?;
+        assert_eq!(estimate(widened), Precision::Exact(50));
+        let truncated = cast(f.clone(), &schema, DataType::Int8)?;
+        assert_eq!(estimate(truncated), Precision::Inexact(2));
+
+        // Arithmetic
+        let sum = arithmetic(&a, Operator::Plus, b.clone())?;
+        assert_eq!(estimate(sum), Precision::Inexact(59));
+        let shifted = arithmetic(&a, Operator::Minus, lit(1_i32))?;
+        assert_eq!(estimate(shifted), Precision::Exact(50));
+        let product = arithmetic(&a, Operator::Multiply, b.clone())?;
+        assert_eq!(estimate(product), Precision::Inexact(500));
+        // b * b is in [1, 100], so it has at most 100 distinct values.
+        let square = arithmetic(&b, Operator::Multiply, b.clone())?;
+        assert_eq!(estimate(square), Precision::Inexact(100));
+
+        // Dates: January, February and March, 31 + 29 + 15 days.
+        let truncated = function(BuiltinScalarFunction::DateTrunc, "month")?;
+        assert_eq!(estimate(truncated), Precision::Inexact(3));
+        let truncated = function(BuiltinScalarFunction::DateTrunc, "day")?;
+        assert_eq!(estimate(truncated), Precision::Inexact(75));
+        let part = function(BuiltinScalarFunction::DatePart, "month")?;
+        assert_eq!(estimate(part), Precision::Inexact(3));
+        let part = function(BuiltinScalarFunction::DatePart, "dow")?;
+        assert_eq!(estimate(part), Precision::Inexact(7));
+
+        // Other expressions of a single column have at most as many distinct
+        // values as the column, those of several columns are unknown.
+        let sqrt = create_physical_expr(
+            &BuiltinScalarFunction::Sqrt,
+            &[f],
+            &schema,
+            &ExecutionProps::new(),
+        )?;
+        assert_eq!(estimate(sqrt), Precision::Inexact(1000));
+        let quotient = arithmetic(&a, Operator::Divide, b)?;
+        assert_eq!(estimate(quotient), Precision::Absent);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_exprs() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Float64, false),
+            Field::new("b", DataType::Float64, false),
+        ]));
+        let stats = ColumnStatistics {
+            min_value: Precision::Exact(ScalarValue::Float64(Some(1.0))),
+            max_value: Precision::Exact(ScalarValue::Float64(Some(100.0))),
+            ..Default::default()
+        };
+        let context =
+            AnalysisContext::try_from_statistics(&schema, &[stats.clone(), stats])?
+                .with_default_selectivity(0.2)?;
+        let sqrt = create_physical_expr(
+            &BuiltinScalarFunction::Sqrt,
+            &[col("a", &schema)?],
+            &schema,
+            &ExecutionProps::new(),
+        )?;
+        let compare = |left, op, value: f64| binary(left, op, lit(value), &schema);
+
+        // sqrt(a) > 3
+        let expr = compare(sqrt.clone(), Operator::Gt, 3.0)?;
+        let analyzed = analyze(&expr, context.clone())?;
+        assert_eq!(analyzed.selectivity.unwrap().point, 0.2);
+        assert_eq!(analyzed.selectivity_precision, Precision::Absent);
+        assert_eq!(analyzed.unsupported_exprs.len(), 1);
+        assert!(analyzed.unsupported_exprs[0].eq(&sqrt));
+
+        // The unsupported expressions of the children of a disjunction are
+        // reported together:
+        // sqrt(a) > 3 OR b < 50
+        let disjunction = binary(
+            expr.clone(),
+            Operator::Or,
+            compare(col("b", &schema)?, Operator::Lt, 50.0)?,
+            &schema,
+        )?;
+        let analyzed = analyze(&disjunction, context.clone())?;
+        assert_eq!(analyzed.unsupported_exprs.len(), 1);
+        assert!(analyzed.unsupported_exprs[0].eq(&sqrt));
+        // NOT (sqrt(a) > 3 OR b < 50)
+        let negation = not(disjunction)?;
+        let analyzed = analyze(&negation, context.clone())?;
+        assert_eq!(analyzed.unsupported_exprs.len(), 1);
+
+        // Supported predicates report nothing:
+        let expr = compare(col("a", &schema)?, Operator::Gt, 3.0)?;
+        assert!(analyze(&expr, context)?.unsupported_exprs.is_empty());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Utf8, true),
+            Field::new("d", DataType::Date32, true),
+            Field::new("e", DataType::Decimal128(10, 2), true),
+        ]));
+        let stats = vec![
+            ColumnStatistics {
+                null_count: Precision::Exact(10),
+                distinct_count: Precision::Inexact(50),
+                histogram: Some(vec![
+                    (ScalarValue::Int64(Some(50)), 60),
+                    (ScalarValue::Int64(Some(100)), 90),
+                ]),
+                ..int64_stats(1, 100)
+            },
+            ColumnStatistics {
+                min_value: Precision::Inexact(ScalarValue::Float64(Some(-1.5))),
+                max_value: Precision::Inexact(ScalarValue::Float64(Some(2.5))),
+                ..Default::default()
+            },
+            ColumnStatistics {
+                min_value: Precision::Exact(ScalarValue::Utf8(Some("a".to_string()))),
+                max_value: Precision::Exact(ScalarValue::Utf8(Some("z".to_string()))),
+                ..Default::default()
+            },
+            ColumnStatistics {
+                min_value: Precision::Inexact(ScalarValue::Date32(Some(19000))),
+                max_value: Precision::Absent,
+                ..Default::default()
+            },
+            ColumnStatistics {
+                min_value: Precision::Exact(ScalarValue::Decimal128(Some(100), 10, 2)),
+                max_value: Precision::Exact(ScalarValue::Decimal128(Some(9999), 10, 2)),
+                ..Default::default()
+            },
+        ];
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+            .with_num_rows(Precision::Exact(100))
+            .with_default_selectivity(0.2)?
+            .with_correlated_columns(vec![(0, 1)]);
+        let round_trip = |context: &AnalysisContext| {
+            let serialized = serde_json::to_string(context).unwrap();
+            serde_json::from_str::<AnalysisContext>(&serialized).unwrap()
+        };
+        assert_eq!(round_trip(&context), context);
+
+        // a > 10
+        let expr = binary(
+            col("a", &schema)?,
+            Operator::Gt,
+            lit(ScalarValue::Int64(Some(10))),
+            &schema,
+        )?;
+        let analyzed = analyze(&expr, context)?;
+        assert!(analyzed.selectivity.is_some());
+        assert_eq!(round_trip(&analyzed), analyzed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_selectivity() -> Result<()> {
+        // price: min=0.01, max=100.00
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "price",
+            DataType::Decimal128(10, 2),
+            false,
+        )]));
+        let price = |cents: i128| ScalarValue::Decimal128(Some(cents), 10, 2);
+        let stats = ColumnStatistics {
+            min_value: Precision::Exact(price(1)),
+            max_value: Precision::Exact(price(10_000)),
+            ..Default::default()
+        };
+        let context = AnalysisContext::try_from_statistics(&schema, &[stats])?;
+
+        // price <= 50.00
+        let expr = binary(
+            col("price", &schema)?,
+            Operator::LtEq,
+            lit(price(5_000)),
+            &schema,
+        )?;
+        let analyzed = analyze(&expr, context)?;
+        assert_eq!(analyzed.selectivity.unwrap().point, 0.5);
+        assert_eq!(
+            analyzed.boundaries[0].interval,
+            Interval::new(
+                IntervalBound::new_closed(price(1)),
+                IntervalBound::new_closed(price(5_000)),
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_on_unsigned_and_boolean_columns() -> Result<()> {
+        // Column 'a' spans close to the whole range of `UInt64`.
+        let schema =
+            Arc::new(Schema::new(vec![Field::new("a", DataType::UInt64, false)]));
+        let stats = ColumnStatistics {
+            min_value: Precision::Exact(ScalarValue::UInt64(Some(0))),
+            max_value: Precision::Exact(ScalarValue::UInt64(Some(u64::MAX))),
+            ..Default::default()
+        };
+        let context = AnalysisContext::try_from_statistics(&schema, &[stats])?;
+        // a > 9223372036854775808
+        let expr = binary(
+            col("a", &schema)?,
+            Operator::Gt,
+            lit(ScalarValue::UInt64(Some(u64::MAX / 2 + 1))),
+            &schema,
+        )?;
+        let selectivity = analyze(&expr, context)?.selectivity.unwrap().point;
+        assert!((selectivity - 0.5).abs() < 1e-9);
+
+        // Column 'flag' has 100 rows, 40 of which are NULL.
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "flag",
+            DataType::Boolean,
+            true,
+        )]));
+        let selectivity = |value: bool, stats: &ColumnStatistics| -> Result<f64> {
+            let expr = binary(col("flag", &schema)?, Operator::Eq, lit(value), &schema)?;
+            // Interval arithmetic does not support booleans, so the comparison
+            // is estimated from the fraction of `true` values:
+            assert!(!check_support(&expr, &schema));
+            let context =
+                AnalysisContext::try_from_statistics(&schema, &[stats.clone()])?
+                    .with_num_rows(Precision::Exact(100));
+            Ok(analyze(&expr, context)?.selectivity.unwrap().point)
+        };
+
+        // Without any statistics, either value is selected by half of the rows.
+        let stats = ColumnStatistics::new_unknown();
+        assert!((selectivity(true, &stats)? - 0.5).abs() < 1e-9);
+        assert!((selectivity(false, &stats)? - 0.5).abs() < 1e-9);
+
+        // NULL values are selected by neither.
+        let stats = ColumnStatistics {
+            min_value: Precision::Exact(ScalarValue::Boolean(Some(false))),
+            max_value: Precision::Exact(ScalarValue::Boolean(Some(true))),
+            null_count: Precision::Exact(40),
+            ..Default::default()
+        };
+        assert!((selectivity(true, &stats)? - 0.3).abs() < 1e-9);
+        assert!((selectivity(false, &stats)? - 0.3).abs() < 1e-9);
+
+        // 15 of the other values are `false`, and 45 are `true`.
+        let stats = ColumnStatistics {
+            histogram: Some(vec![
+                (ScalarValue::Boolean(Some(false)), 15),
+                (ScalarValue::Boolean(Some(true)), 60),
+            ]),
+            ..stats
+        };
+        assert!((selectivity(true, &stats)? - 0.45).abs() < 1e-9);
+        assert!((selectivity(false, &stats)? - 0.15).abs() < 1e-9);
+        // `true != flag` selects the `false` values.
+        let expr = binary(lit(true), Operator::NotEq, col("flag", &schema)?, &schema)?;
+        let context = AnalysisContext::try_from_statistics(&schema, &[stats.clone()])?
+            .with_num_rows(Precision::Exact(100));
+        let analyzed = analyze(&expr, context)?;
+        assert!((analyzed.selectivity.unwrap().point - 0.15).abs() < 1e-9);
+        assert_eq!(
+            analyzed.boundaries[0].interval,
+            Interval::make(Some(false), Some(false), (false, false))
+        );
+
+        // All of the other values are `true`.
+        let stats = ColumnStatistics {
+            min_value: Precision::Exact(ScalarValue::Boolean(Some(true))),
+            histogram: None,
+            ..stats
+        };
+        assert!((selectivity(true, &stats)? - 0.6).abs() < 1e-9);
+        assert_eq!(selectivity(false, &stats)?, 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectivity_to_row_count() {
+        let cases = [
+            (
+                0.5,
+                Precision::Exact(()),
+                Precision::Exact(100),
+                Precision::Inexact(50),
+            ),
+            (
+                0.0,
+                Precision::Exact(()),
+                Precision::Exact(100),
+                Precision::Exact(0),
+            ),
+            (
+                1.0,
+                Precision::Exact(()),
+                Precision::Exact(100),
+                Precision::Exact(100),
+            ),
+            (
+                1.0,
+                Precision::Inexact(()),
+                Precision::Exact(100),
+                Precision::Inexact(100),
+            ),
+            (
+                0.0,
+                Precision::Exact(()),
+                Precision::Inexact(100),
+                Precision::Inexact(0),
+            ),
+            (
+                0.255,
+                Precision::Inexact(()),
+                Precision::Inexact(100),
+                Precision::Inexact(26),
+            ),
+            (
+                0.5,
+                Precision::Absent,
+                Precision::Exact(100),
+                Precision::Absent,
+            ),
+            (
+                0.5,
+                Precision::Exact(()),
+                Precision::Absent,
+                Precision::Absent,
+            ),
+        ];
+        for (selectivity, confidence, input_count, expected) in cases {
+            assert_eq!(
+                selectivity_to_row_count(selectivity, confidence, input_count),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_statistics() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+            Field::new("c", DataType::Int64, true),
+        ]));
+        let stats = vec![
+            ColumnStatistics {
+                null_count: Precision::Exact(10),
+                distinct_count: Precision::Exact(80),
+                ..int64_stats(1, 100)
+            },
+            ColumnStatistics {
+                null_count: Precision::Inexact(0),
+                min_value: Precision::Inexact(ScalarValue::Int64(Some(-5))),
+                max_value: Precision::Inexact(ScalarValue::Int64(Some(5))),
+                distinct_count: Precision::Inexact(11),
+                histogram: None,
+            },
+            ColumnStatistics::new_unknown(),
+        ];
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?
+            .with_num_rows(Precision::Exact(100));
+
+        // Without a predicate, converting back yields the original statistics:
+        assert_eq!(context.to_statistics(&Precision::Exact(100)), stats);
+
+        // a <= 50 narrows the interval of 'a', and selects 45% of the rows:
+        let expr = binary(col("a", &schema)?, Operator::LtEq, lit(50i64), &schema)?;
+        let analyzed = analyze(&expr, context.clone())?;
+        let output = analyzed.to_statistics(&Precision::Exact(100));
+        assert_eq!(
+            output[0].min_value,
+            Precision::Inexact(ScalarValue::Int64(Some(1)))
+        );
+        assert_eq!(
+            output[0].max_value,
+            Precision::Inexact(ScalarValue::Int64(Some(50)))
+        );
+        assert_eq!(output[0].null_count, Precision::Inexact(5));
+        assert_eq!(
+            output[0].distinct_count,
+            analyzed.boundaries[0].distinct_count
+        );
+        assert_eq!(output[1].min_value, stats[1].min_value);
+        assert_eq!(output[1].max_value, stats[1].max_value);
+        assert_eq!(output[1].null_count, Precision::Inexact(0));
+        assert_eq!(output[2], ColumnStatistics::new_unknown());
+
+        // a > 200 can not be satisfied, so no rows remain:
+        let expr = binary(col("a", &schema)?, Operator::Gt, lit(200i64), &schema)?;
+        let output = analyze(&expr, context)?.to_statistics(&Precision::Exact(100));
+        for column in output {
+            assert_eq!(column.null_count, Precision::Exact(0));
+            assert_eq!(column.distinct_count, Precision::Exact(0));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_feedback() -> Result<()> {
+        // Feedback that the predicate with id 1 selects twice as many rows as
+        // estimated:
+        struct Doubling;
+        impl SelectivityFeedback for Doubling {
+            fn adjust_selectivity(&self, estimated: f64, predicate_id: u64) -> f64 {
+                match predicate_id {
+                    1 => (estimated * 2.0).min(1.0),
+                    _ => estimated,
+                }
+            }
+        }
+
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+        let stats = [ColumnStatistics {
+            min_value: Precision::Inexact(ScalarValue::Int64(Some(1))),
+            max_value: Precision::Inexact(ScalarValue::Int64(Some(100))),
+            ..Default::default()
+        }];
+        let context = AnalysisContext::try_from_statistics(&schema, &stats)?;
+        let expr = binary(col("a", &schema)?, Operator::LtEq, lit(25i64), &schema)?;
+        let analyzed = analyze(&expr, context)?;
+        assert_eq!(analyzed.selectivity.as_ref().unwrap().point, 0.25);
+
+        let corrected = analyzed.clone().with_feedback(&Doubling, 1);
+        assert_eq!(
+            corrected.selectivity,
+            Some(SelectivityEstimate::new(0.5, Precision::Inexact(())))
+        );
+        assert_eq!(corrected.boundaries, analyzed.boundaries);
+        assert_eq!(analyzed.clone().with_feedback(&Doubling, 2), analyzed);
+
+        // Exact selectivities are not corrected:
+        let exact = AnalysisContext::new(vec![])
+            .with_selectivity(SelectivityEstimate::exact(0.25))
+            .with_selectivity_precision(Precision::Exact(()));
+        assert_eq!(exact.clone().with_feedback(&Doubling, 1), exact);
+
+        Ok(())
     }
 }
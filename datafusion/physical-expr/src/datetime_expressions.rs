@@ -26,7 +26,7 @@ use arrow::{
     temporal_conversions::{as_datetime_with_timezone, timestamp_ns_to_datetime},
 };
 use arrow::{
-    array::{Array, ArrayRef, Float64Array, OffsetSizeTrait, PrimitiveArray},
+    array::{Array, ArrayRef, Float64Array, Int64Array, OffsetSizeTrait, PrimitiveArray},
     compute::kernels::cast_utils::string_to_timestamp_nanos,
     datatypes::{
         ArrowNumericType, ArrowPrimitiveType, ArrowTemporalType, DataType,
@@ -41,7 +41,7 @@ use arrow_array::{
 use chrono::prelude::*;
 use chrono::{Duration, Months, NaiveDate};
 use datafusion_common::cast::{
-    as_date32_array, as_date64_array, as_generic_string_array,
+    as_date32_array, as_date64_array, as_generic_string_array, as_int64_array,
     as_timestamp_microsecond_array, as_timestamp_millisecond_array,
     as_timestamp_nanosecond_array, as_timestamp_second_array,
 };
@@ -163,6 +163,110 @@ pub fn to_timestamp_seconds(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     )
 }
 
+/// Number of ticks of the given unit in one second
+fn ticks_per_second(unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+/// Applies an array function to a [`ColumnarValue`], preserving scalars
+fn apply_to_columnar(
+    value: &ColumnarValue,
+    f: impl Fn(&ArrayRef) -> Result<ArrayRef>,
+) -> Result<ColumnarValue> {
+    match value {
+        ColumnarValue::Array(array) => Ok(ColumnarValue::Array(f(array)?)),
+        ColumnarValue::Scalar(scalar) => Ok(ColumnarValue::Scalar(
+            ScalarValue::try_from_array(&f(&scalar.to_array())?, 0)?,
+        )),
+    }
+}
+
+/// to_unixtime SQL function
+///
+/// Returns the number of whole seconds since the unix epoch as `Int64`,
+/// rounding towards negative infinity for timestamps finer than seconds.
+/// The timezone of the input is irrelevant as timestamps are stored as UTC.
+pub fn to_unixtime(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.len() != 1 {
+        return exec_err!(
+            "to_unixtime function requires 1 argument, got {}",
+            args.len()
+        );
+    }
+    let ticks = match args[0].data_type() {
+        DataType::Timestamp(unit, _) => ticks_per_second(&unit),
+        other => {
+            return exec_err!("Unsupported data type {other:?} for function to_unixtime")
+        }
+    };
+    apply_to_columnar(&args[0], |array| {
+        let values = cast(array, &DataType::Int64)?;
+        let seconds: Int64Array =
+            as_int64_array(&values)?.unary(|value| value.div_euclid(ticks));
+        Ok(Arc::new(seconds))
+    })
+}
+
+/// from_unixtime SQL function
+///
+/// Interprets an `Int64` as seconds since the unix epoch and returns a
+/// `Timestamp(Second, None)`. If a unit (`second`, `millisecond`, `microsecond`
+/// or `nanosecond`) is given as second argument, the value is read in that unit
+/// and a `Timestamp(Nanosecond, None)` is returned instead.
+pub fn from_unixtime(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    let unit = match args {
+        [_] => None,
+        [_, ColumnarValue::Scalar(ScalarValue::Utf8(Some(unit)))] => {
+            Some(match unit.to_lowercase().as_str() {
+                "second" | "s" => TimeUnit::Second,
+                "millisecond" | "ms" => TimeUnit::Millisecond,
+                "microsecond" | "us" => TimeUnit::Microsecond,
+                "nanosecond" | "ns" => TimeUnit::Nanosecond,
+                _ => return exec_err!("Unit '{unit}' not supported by from_unixtime"),
+            })
+        }
+        [_, _] => {
+            return exec_err!(
+                "Second argument of `from_unixtime` must be non-null scalar Utf8"
+            )
+        }
+        _ => {
+            return exec_err!(
+                "from_unixtime function requires 1 or 2 arguments, got {}",
+                args.len()
+            )
+        }
+    };
+    let Some(unit) = unit else {
+        return apply_to_columnar(&args[0], |array| {
+            Ok(cast(array, &DataType::Timestamp(TimeUnit::Second, None))?)
+        });
+    };
+    let scale = ticks_per_second(&TimeUnit::Nanosecond) / ticks_per_second(&unit);
+    apply_to_columnar(&args[0], |array| {
+        let nanos = as_int64_array(array)?
+            .iter()
+            .map(|value| {
+                value
+                    .map(|value| {
+                        value.checked_mul(scale).ok_or_else(|| {
+                            DataFusionError::Execution(format!(
+                                "Overflow converting {value} {unit:?}s to nanoseconds"
+                            ))
+                        })
+                    })
+                    .transpose()
+            })
+            .collect::<Result<TimestampNanosecondArray>>()?;
+        Ok(Arc::new(nanos))
+    })
+}
+
 /// Create an implementation of `now()` that always returns the
 /// specified timestamp.
 ///
@@ -1549,4 +1653,69 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn to_unixtime_and_from_unixtime_round_trip() -> Result<()> {
+        // 2020-09-13T12:26:40 and 1969-12-31T23:59:59.5
+        let micros: ArrayRef = Arc::new(
+            TimestampMicrosecondArray::from(vec![
+                Some(1_600_000_000_000_000),
+                Some(-500_000),
+                None,
+            ])
+            .with_timezone("+05:00"),
+        );
+        let seconds = to_unixtime(&[ColumnarValue::Array(micros.clone())])?.into_array(3);
+        assert_eq!(
+            as_int64_array(&seconds)?,
+            &Int64Array::from(vec![Some(1_600_000_000), Some(-1), None])
+        );
+
+        // seconds round trip, losing the sub-second part
+        let timestamps =
+            from_unixtime(&[ColumnarValue::Array(seconds.clone())])?.into_array(3);
+        assert_eq!(
+            as_timestamp_second_array(&timestamps)?,
+            &TimestampSecondArray::from(vec![Some(1_600_000_000), Some(-1), None])
+        );
+
+        // microseconds round trip through the native value of the timestamp
+        let values = cast(&micros, &DataType::Int64)?;
+        let unit = ColumnarValue::Scalar(ScalarValue::Utf8(Some("microsecond".into())));
+        let timestamps =
+            from_unixtime(&[ColumnarValue::Array(values), unit])?.into_array(3);
+        assert_eq!(
+            as_timestamp_nanosecond_array(&timestamps)?,
+            &TimestampNanosecondArray::from(vec![
+                Some(1_600_000_000_000_000_000),
+                Some(-500_000_000),
+                None
+            ])
+        );
+
+        // scalars
+        let seconds = to_unixtime(&[ColumnarValue::Scalar(
+            ScalarValue::TimestampNanosecond(Some(1_999_999_999), None),
+        )])?;
+        let ColumnarValue::Scalar(seconds) = seconds else {
+            panic!("expected a scalar")
+        };
+        assert_eq!(seconds, ScalarValue::Int64(Some(1)));
+        Ok(())
+    }
+
+    #[test]
+    fn from_unixtime_invalid_unit() {
+        let value = ColumnarValue::Scalar(ScalarValue::Int64(Some(1)));
+        let unit = ColumnarValue::Scalar(ScalarValue::Utf8(Some("fortnight".into())));
+        let err = from_unixtime(&[value.clone(), unit]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Unit 'fortnight' not supported by from_unixtime"));
+
+        let value = ColumnarValue::Scalar(ScalarValue::Int64(Some(i64::MAX)));
+        let unit = ColumnarValue::Scalar(ScalarValue::Utf8(Some("second".into())));
+        let err = from_unixtime(&[value, unit]).unwrap_err();
+        assert!(err.to_string().contains("Overflow"));
+    }
 }
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::expressions::ArithmeticOverflowMode;
 use crate::var_provider::{VarProvider, VarType};
 use chrono::{DateTime, TimeZone, Utc};
 use datafusion_common::alias::AliasGenerator;
@@ -39,6 +40,8 @@ pub struct ExecutionProps {
     pub alias_generator: Arc<AliasGenerator>,
     /// Providers for scalar variables
     pub var_providers: Option<HashMap<VarType, Arc<dyn VarProvider + Send + Sync>>>,
+    /// How integer arithmetic handles overflows
+    pub arithmetic_overflow_mode: ArithmeticOverflowMode,
 }
 
 impl Default for ExecutionProps {
@@ -56,6 +59,7 @@ impl ExecutionProps {
             query_execution_start_time: Utc.timestamp_nanos(0),
             alias_generator: Arc::new(AliasGenerator::new()),
             var_providers: None,
+            arithmetic_overflow_mode: ArithmeticOverflowMode::default(),
         }
     }
 
@@ -68,6 +72,15 @@ impl ExecutionProps {
         self
     }
 
+    /// Set how integer arithmetic handles overflows
+    pub fn with_arithmetic_overflow_mode(
+        mut self,
+        arithmetic_overflow_mode: ArithmeticOverflowMode,
+    ) -> Self {
+        self.arithmetic_overflow_mode = arithmetic_overflow_mode;
+        self
+    }
+
     /// Marks the execution of query started timestamp.
     /// This also instantiates a new alias generator.
     pub fn start_execution(&mut self) -> &Self {
@@ -109,6 +122,6 @@ mod test {
     #[test]
     fn debug() {
         let props = ExecutionProps::new();
-        assert_eq!("ExecutionProps { query_execution_start_time: 1970-01-01T00:00:00Z, alias_generator: AliasGenerator { next_id: 1 }, var_providers: None }", format!("{props:?}"));
+        assert_eq!("ExecutionProps { query_execution_start_time: 1970-01-01T00:00:00Z, alias_generator: AliasGenerator { next_id: 1 }, var_providers: None, arithmetic_overflow_mode: Wrap }", format!("{props:?}"));
     }
 }
@@ -18,6 +18,7 @@
 mod kernels;
 
 use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 use std::{any::Any, sync::Arc};
 
 use crate::array_expressions::{
@@ -39,7 +40,7 @@ use arrow::compute::kernels::concat_elements::concat_elements_utf8;
 use arrow::datatypes::*;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::cast::as_boolean_array;
-use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
+use datafusion_common::{internal_err, plan_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::type_coercion::binary::get_result_type;
 use datafusion_expr::{ColumnarValue, Operator};
 
@@ -48,14 +49,54 @@ use kernels::{
     bitwise_and_dyn, bitwise_and_dyn_scalar, bitwise_or_dyn, bitwise_or_dyn_scalar,
     bitwise_shift_left_dyn, bitwise_shift_left_dyn_scalar, bitwise_shift_right_dyn,
     bitwise_shift_right_dyn_scalar, bitwise_xor_dyn, bitwise_xor_dyn_scalar,
+    is_temporal_scaling, scale_temporal,
 };
 
+/// Determines how integer arithmetic (`+`, `-` and `*`) in a [`BinaryExpr`]
+/// handles results that do not fit into the result type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArithmeticOverflowMode {
+    /// Return an error.
+    Error,
+    /// Wrap around, using two's complement arithmetic.
+    #[default]
+    Wrap,
+    /// Return NULL for the overflowing values.
+    Null,
+}
+
+impl std::fmt::Display for ArithmeticOverflowMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Wrap => write!(f, "wrap"),
+            Self::Null => write!(f, "null"),
+        }
+    }
+}
+
+impl FromStr for ArithmeticOverflowMode {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "error" => Ok(Self::Error),
+            "wrap" => Ok(Self::Wrap),
+            "null" => Ok(Self::Null),
+            _ => plan_err!(
+                "Invalid arithmetic overflow mode '{s}', expected one of 'error', 'wrap' or 'null'"
+            ),
+        }
+    }
+}
+
 /// Binary expression
 #[derive(Debug, Hash, Clone)]
 pub struct BinaryExpr {
     left: Arc<dyn PhysicalExpr>,
     op: Operator,
     right: Arc<dyn PhysicalExpr>,
+    overflow_mode: ArithmeticOverflowMode,
 }
 
 impl BinaryExpr {
@@ -65,7 +106,18 @@ impl BinaryExpr {
         op: Operator,
         right: Arc<dyn PhysicalExpr>,
     ) -> Self {
-        Self { left, op, right }
+        Self {
+            left,
+            op,
+            right,
+            overflow_mode: ArithmeticOverflowMode::default(),
+        }
+    }
+
+    /// Sets how integer arithmetic in this expression handles overflows
+    pub fn with_overflow_mode(mut self, overflow_mode: ArithmeticOverflowMode) -> Self {
+        self.overflow_mode = overflow_mode;
+        self
     }
 
     /// Get the left side of the binary expression
@@ -82,6 +134,11 @@ impl BinaryExpr {
     pub fn op(&self) -> &Operator {
         &self.op
     }
+
+    /// Get the way integer arithmetic in this expression handles overflows
+    pub fn overflow_mode(&self) -> ArithmeticOverflowMode {
+        self.overflow_mode
+    }
 }
 
 impl std::fmt::Display for BinaryExpr {
@@ -268,6 +325,33 @@ impl PhysicalExpr for BinaryExpr {
         let schema = batch.schema();
         let input_schema = schema.as_ref();
 
+        if matches!(
+            self.op,
+            Operator::Plus | Operator::Minus | Operator::Multiply
+        ) && left_data_type.is_integer()
+            && left_data_type == right_data_type
+        {
+            match self.overflow_mode {
+                ArithmeticOverflowMode::Error => {
+                    let checked = match self.op {
+                        Operator::Plus => add,
+                        Operator::Minus => sub,
+                        _ => mul,
+                    };
+                    return apply(&lhs, &rhs, checked);
+                }
+                ArithmeticOverflowMode::Null => {
+                    return self.evaluate_null_on_overflow(&lhs, &rhs, batch.num_rows())
+                }
+                ArithmeticOverflowMode::Wrap => {}
+            }
+        }
+
+        if is_temporal_scaling(&self.op, &left_data_type, &right_data_type) {
+            let op = self.op;
+            return apply(&lhs, &rhs, |l, r| scale_temporal(l, op, r));
+        }
+
         match self.op {
             Operator::Plus => return apply(&lhs, &rhs, add_wrapping),
             Operator::Minus => return apply(&lhs, &rhs, sub_wrapping),
@@ -319,11 +403,10 @@ impl PhysicalExpr for BinaryExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(BinaryExpr::new(
-            children[0].clone(),
-            self.op,
-            children[1].clone(),
-        )))
+        Ok(Arc::new(
+            BinaryExpr::new(children[0].clone(), self.op, children[1].clone())
+                .with_overflow_mode(self.overflow_mode),
+        ))
     }
 
     fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
@@ -344,18 +427,42 @@ impl PhysicalExpr for BinaryExpr {
         let right_interval = children[1];
 
         let (left, right) = if self.op.is_logic_operator() {
-            // TODO: Currently, this implementation only supports the AND operator
-            //       and does not require any further propagation. In the future,
-            //       upon adding support for additional logical operators, this
-            //       method will require modification to support propagating the
-            //       changes accordingly.
-            return Ok(vec![]);
+            // The children of an AND must both hold, and those of an OR are
+            // only constrained by the `ExprIntervalGraph` when the other child
+            // is certainly false, which requires no further propagation. When
+            // the expression is certainly false, the children of an OR must
+            // both be false, and a child of an AND must be false if the other
+            // child is certainly true.
+            if interval != &Interval::CERTAINLY_FALSE {
+                return Ok(vec![]);
+            }
+            let false_unless = |child: &Interval, other: &Interval| {
+                if self.op == Operator::Or || other == &Interval::CERTAINLY_TRUE {
+                    child.intersect(Interval::CERTAINLY_FALSE)
+                } else {
+                    Ok(Some(child.clone()))
+                }
+            };
+            (
+                false_unless(left_interval, right_interval)?,
+                false_unless(right_interval, left_interval)?,
+            )
         } else if self.op.is_comparison_operator() {
             if interval == &Interval::CERTAINLY_FALSE {
-                // TODO: We will handle strictly false clauses by negating
-                //       the comparison operator (e.g. GT to LE, LT to GE)
-                //       once open/closed intervals are supported.
-                return Ok(vec![]);
+                // A false ordering comparison is equivalent to the comparison
+                // with the negated operator (e.g. GT to LE, LT to GE). Other
+                // comparisons, such as inequalities, do not yield a range.
+                return match self.op {
+                    Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+                        let (left, right) = propagate_comparison(
+                            &self.op.negate().unwrap(),
+                            left_interval,
+                            right_interval,
+                        )?;
+                        Ok(vec![left, right])
+                    }
+                    _ => Ok(vec![]),
+                };
             }
             // Propagate the comparison operator.
             propagate_comparison(&self.op, left_interval, right_interval)?
@@ -390,7 +497,12 @@ impl PartialEq<dyn Any> for BinaryExpr {
     fn eq(&self, other: &dyn Any) -> bool {
         down_cast_any_ref(other)
             .downcast_ref::<Self>()
-            .map(|x| self.left.eq(&x.left) && self.op == x.op && self.right.eq(&x.right))
+            .map(|x| {
+                self.left.eq(&x.left)
+                    && self.op == x.op
+                    && self.right.eq(&x.right)
+                    && self.overflow_mode == x.overflow_mode
+            })
             .unwrap_or(false)
     }
 }
@@ -425,7 +537,67 @@ fn to_result_type_array(
     }
 }
 
+/// Applies the checked integer operation `$OP` to each pair of values of the
+/// arrays `$LEFT` and `$RIGHT` of type `$TYPE`, producing NULL on overflow.
+macro_rules! null_on_overflow {
+    ($LEFT:expr, $RIGHT:expr, $OP:ident, $TYPE:ty) => {{
+        let result: PrimitiveArray<$TYPE> = $LEFT
+            .as_primitive::<$TYPE>()
+            .iter()
+            .zip($RIGHT.as_primitive::<$TYPE>().iter())
+            .map(|(left, right)| left?.$OP(right?).ok())
+            .collect();
+        Ok(Arc::new(result) as ArrayRef)
+    }};
+}
+
+macro_rules! null_on_overflow_by_type {
+    ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
+        match $LEFT.data_type() {
+            DataType::Int8 => null_on_overflow!($LEFT, $RIGHT, $OP, Int8Type),
+            DataType::Int16 => null_on_overflow!($LEFT, $RIGHT, $OP, Int16Type),
+            DataType::Int32 => null_on_overflow!($LEFT, $RIGHT, $OP, Int32Type),
+            DataType::Int64 => null_on_overflow!($LEFT, $RIGHT, $OP, Int64Type),
+            DataType::UInt8 => null_on_overflow!($LEFT, $RIGHT, $OP, UInt8Type),
+            DataType::UInt16 => null_on_overflow!($LEFT, $RIGHT, $OP, UInt16Type),
+            DataType::UInt32 => null_on_overflow!($LEFT, $RIGHT, $OP, UInt32Type),
+            DataType::UInt64 => null_on_overflow!($LEFT, $RIGHT, $OP, UInt64Type),
+            other => internal_err!(
+                "Data type {other:?} not supported for arithmetic with NULL on overflow"
+            ),
+        }
+    }};
+}
+
 impl BinaryExpr {
+    /// Evaluates the integer arithmetic of this expression on `lhs` and `rhs`,
+    /// returning NULL for the values that overflow.
+    fn evaluate_null_on_overflow(
+        &self,
+        lhs: &ColumnarValue,
+        rhs: &ColumnarValue,
+        num_rows: usize,
+    ) -> Result<ColumnarValue> {
+        let scalar_result = matches!(
+            (lhs, rhs),
+            (ColumnarValue::Scalar(_), ColumnarValue::Scalar(_))
+        );
+        let num_rows = if scalar_result { 1 } else { num_rows };
+        let left = lhs.clone().into_array(num_rows);
+        let right = rhs.clone().into_array(num_rows);
+        let result: ArrayRef = match self.op {
+            Operator::Plus => null_on_overflow_by_type!(left, right, add_checked),
+            Operator::Minus => null_on_overflow_by_type!(left, right, sub_checked),
+            Operator::Multiply => null_on_overflow_by_type!(left, right, mul_checked),
+            op => internal_err!("Operator {op} can not overflow"),
+        }?;
+        if scalar_result {
+            ScalarValue::try_from_array(&result, 0).map(ColumnarValue::Scalar)
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
     /// Evaluate the expression of the left input is an array and
     /// right is literal - use scalar operations
     fn evaluate_array_scalar(
@@ -1092,6 +1264,82 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn plus_op_overflow_modes() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+        ]));
+        let a = Int32Array::from(vec![Some(i32::MAX), Some(1), None, Some(i32::MIN)]);
+        let b = Int32Array::from(vec![Some(1), Some(2), Some(3), Some(-1)]);
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])?;
+        let evaluate = |left: Arc<dyn PhysicalExpr>,
+                        right: Arc<dyn PhysicalExpr>,
+                        mode: ArithmeticOverflowMode|
+         -> Result<ColumnarValue> {
+            BinaryExpr::new(left, Operator::Plus, right)
+                .with_overflow_mode(mode)
+                .evaluate(&batch)
+        };
+        let a = col("a", &schema)?;
+        let b = col("b", &schema)?;
+
+        // a + b
+        let result = evaluate(a.clone(), b.clone(), ArithmeticOverflowMode::Error);
+        assert!(result.unwrap_err().to_string().contains("Overflow"));
+        let result = evaluate(a.clone(), b.clone(), ArithmeticOverflowMode::Wrap)?
+            .into_array(batch.num_rows());
+        let expected =
+            Int32Array::from(vec![Some(i32::MIN), Some(3), None, Some(i32::MAX)]);
+        assert_eq!(result.as_ref(), &expected);
+        let result = evaluate(a.clone(), b, ArithmeticOverflowMode::Null)?
+            .into_array(batch.num_rows());
+        let expected = Int32Array::from(vec![None, Some(3), None, None]);
+        assert_eq!(result.as_ref(), &expected);
+
+        // a + 1
+        let one = lit(ScalarValue::Int32(Some(1)));
+        let result = evaluate(a.clone(), one.clone(), ArithmeticOverflowMode::Null)?
+            .into_array(batch.num_rows());
+        let expected = Int32Array::from(vec![None, Some(2), None, Some(i32::MIN + 1)]);
+        assert_eq!(result.as_ref(), &expected);
+
+        // i32::MAX + 1
+        let max = lit(ScalarValue::Int32(Some(i32::MAX)));
+        let result = evaluate(max.clone(), one.clone(), ArithmeticOverflowMode::Error);
+        assert!(result.unwrap_err().to_string().contains("Overflow"));
+        let result = evaluate(max.clone(), one.clone(), ArithmeticOverflowMode::Wrap)?;
+        assert!(matches!(
+            result,
+            ColumnarValue::Scalar(ScalarValue::Int32(Some(i32::MIN)))
+        ));
+        let result = evaluate(max, one, ArithmeticOverflowMode::Null)?;
+        assert!(matches!(
+            result,
+            ColumnarValue::Scalar(ScalarValue::Int32(None))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_arithmetic_overflow_mode() -> Result<()> {
+        assert_eq!(
+            "error".parse::<ArithmeticOverflowMode>()?,
+            ArithmeticOverflowMode::Error
+        );
+        assert_eq!(
+            "WRAP".parse::<ArithmeticOverflowMode>()?,
+            ArithmeticOverflowMode::Wrap
+        );
+        assert_eq!(
+            "null".parse::<ArithmeticOverflowMode>()?,
+            ArithmeticOverflowMode::Null
+        );
+        assert!("saturate".parse::<ArithmeticOverflowMode>().is_err());
+        Ok(())
+    }
+
     #[test]
     fn plus_op_dict() -> Result<()> {
         let schema = Schema::new(vec![
@@ -1722,6 +1970,128 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn scale_interval_and_duration_op() -> Result<()> {
+        let interval = |months, days, nanos| {
+            Some(IntervalMonthDayNanoType::make_value(months, days, nanos))
+        };
+        let hour = 3_600_000_000_000;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("i", DataType::Interval(IntervalUnit::MonthDayNano), true),
+            Field::new("d", DataType::Duration(TimeUnit::Second), true),
+            Field::new("n", DataType::Int32, true),
+            Field::new("f", DataType::Float64, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    interval(1, 1, 0),
+                    interval(-3, 0, 5),
+                    interval(0, 2, hour),
+                    None,
+                ])),
+                Arc::new(DurationSecondArray::from(vec![
+                    Some(10),
+                    Some(-7),
+                    Some(1),
+                    None,
+                ])),
+                Arc::new(Int32Array::from(vec![Some(2), Some(-2), None, Some(4)])),
+                Arc::new(Float64Array::from(vec![0.5, 1.5, 4.0, 1.0])),
+            ],
+        )?;
+        let evaluate = |left: &str, op, right: &str| -> Result<ArrayRef> {
+            let expr = binary_op(col(left, &schema)?, op, col(right, &schema)?, &schema)?;
+            Ok(expr.evaluate(&batch)?.into_array(batch.num_rows()))
+        };
+        let intervals =
+            |values| -> ArrayRef { Arc::new(IntervalMonthDayNanoArray::from(values)) };
+        let durations =
+            |values| -> ArrayRef { Arc::new(DurationSecondArray::from(values)) };
+
+        // Scaling up, with the interval on either side:
+        let expected =
+            intervals(vec![interval(2, 2, 0), interval(6, 0, -10), None, None]);
+        assert_eq!(&evaluate("i", Operator::Multiply, "n")?, &expected);
+        assert_eq!(&evaluate("n", Operator::Multiply, "i")?, &expected);
+        assert_eq!(
+            &evaluate("f", Operator::Multiply, "i")?,
+            &intervals(vec![
+                interval(0, 15, 12 * hour),
+                interval(-4, -15, 8),
+                interval(0, 8, 4 * hour),
+                None
+            ])
+        );
+        assert_eq!(
+            &evaluate("d", Operator::Multiply, "n")?,
+            &durations(vec![Some(20), Some(14), None, None])
+        );
+
+        // Scaling down, where fractions are carried into the smaller
+        // components and nanoseconds are rounded:
+        assert_eq!(
+            &evaluate("i", Operator::Divide, "n")?,
+            &intervals(vec![
+                interval(0, 15, 12 * hour),
+                interval(1, 15, -3),
+                None,
+                None
+            ])
+        );
+        assert_eq!(
+            &evaluate("i", Operator::Divide, "f")?,
+            &intervals(vec![
+                interval(2, 2, 0),
+                interval(-2, 0, 3),
+                interval(0, 0, 12 * hour + hour / 4),
+                None
+            ])
+        );
+        assert_eq!(
+            &evaluate("d", Operator::Divide, "f")?,
+            &durations(vec![Some(20), Some(-5), Some(0), None])
+        );
+
+        // Numbers can not be divided by intervals.
+        assert!(evaluate("n", Operator::Divide, "i").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn scale_interval_errors() -> Result<()> {
+        let schema = Schema::new(vec![Field::new(
+            "i",
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            false,
+        )]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(IntervalMonthDayNanoArray::from(vec![
+                IntervalMonthDayNanoType::make_value(0, 1, i64::MAX / 2),
+            ]))],
+        )?;
+        let evaluate = |op, factor: ScalarValue| -> Result<ArrayRef> {
+            let expr = binary_op(col("i", &schema)?, op, lit(factor), &schema)?;
+            Ok(expr.evaluate(&batch)?.into_array(batch.num_rows()))
+        };
+
+        for factor in [ScalarValue::Int64(Some(0)), ScalarValue::Float64(Some(0.0))] {
+            let err = evaluate(Operator::Divide, factor).unwrap_err();
+            assert_eq!(err.to_string(), "Arrow error: Divide by zero error");
+        }
+        let err = evaluate(Operator::Multiply, ScalarValue::Int64(Some(3))).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow error: Compute error: Overflow of the nanoseconds of interval \
+             0 months 1 days 4611686018427387903 nanoseconds * 3"
+        );
+        assert!(evaluate(Operator::Multiply, ScalarValue::Float64(Some(2.5))).is_err());
+        Ok(())
+    }
+
     #[test]
     fn divide_op_dict() -> Result<()> {
         let schema = Schema::new(vec![
@@ -18,14 +18,22 @@
 //! This module contains computation kernels that are specific to
 //! datafusion and not (yet) targeted to  port upstream to arrow
 use arrow::array::*;
+use arrow::compute::cast;
 use arrow::compute::kernels::bitwise::{
     bitwise_and, bitwise_and_scalar, bitwise_or, bitwise_or_scalar, bitwise_shift_left,
     bitwise_shift_left_scalar, bitwise_shift_right, bitwise_shift_right_scalar,
     bitwise_xor, bitwise_xor_scalar,
 };
-use arrow::datatypes::DataType;
+use arrow::datatypes::{
+    DataType, DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType,
+    DurationSecondType, Float64Type, Int64Type, IntervalMonthDayNanoType, IntervalUnit,
+    TimeUnit,
+};
+use arrow::error::ArrowError;
+use arrow_array::cast::AsArray;
 use datafusion_common::internal_err;
 use datafusion_common::{DataFusionError, Result, ScalarValue};
+use datafusion_expr::Operator;
 
 use std::sync::Arc;
 
@@ -131,3 +139,221 @@ create_dyn_scalar_kernel!(bitwise_or_dyn_scalar, bitwise_or_scalar);
 create_dyn_scalar_kernel!(bitwise_xor_dyn_scalar, bitwise_xor_scalar);
 create_dyn_scalar_kernel!(bitwise_shift_right_dyn_scalar, bitwise_shift_right_scalar);
 create_dyn_scalar_kernel!(bitwise_shift_left_dyn_scalar, bitwise_shift_left_scalar);
+
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+const DAYS_PER_MONTH: i128 = 30;
+
+/// Returns whether multiplying or dividing values of the given types scales an
+/// interval or a duration by a number, see [`scale_temporal`].
+pub(crate) fn is_temporal_scaling(op: &Operator, lhs: &DataType, rhs: &DataType) -> bool {
+    let is_temporal = |data_type: &DataType| {
+        matches!(data_type, DataType::Interval(_) | DataType::Duration(_))
+    };
+    match op {
+        Operator::Multiply => {
+            (is_temporal(lhs) && rhs.is_numeric())
+                || (lhs.is_numeric() && is_temporal(rhs))
+        }
+        Operator::Divide => is_temporal(lhs) && rhs.is_numeric(),
+        _ => false,
+    }
+}
+
+/// The number by which an interval or a duration is scaled
+#[derive(Debug, Clone, Copy)]
+enum Factor {
+    Int(i64),
+    Float(f64),
+}
+
+impl std::fmt::Display for Factor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Factor::Int(factor) => write!(f, "{factor}"),
+            Factor::Float(factor) => write!(f, "{factor}"),
+        }
+    }
+}
+
+/// Multiplies intervals or durations by numbers, in either order, or divides
+/// them by numbers, depending on `op`. Intervals are returned as
+/// `Interval(MonthDayNano)`, and durations in their unit.
+///
+/// The months, days and nanoseconds of an interval are scaled separately.
+/// Multiplying by an integer is exact. Otherwise, the fractional part of the
+/// scaled months is carried into the days, assuming months of 30 days, and that
+/// of the days into the nanoseconds, assuming days of 24 hours. For example,
+/// `INTERVAL '1 month 1 day' / 2` is `15 days 12 hours`. Nanoseconds, and the
+/// values of durations, are rounded to the nearest integer, with ties rounded
+/// away from zero.
+///
+/// Returns an error when dividing by zero, and if a scaled component does not
+/// fit into its type.
+pub(crate) fn scale_temporal(
+    lhs: &dyn Datum,
+    op: Operator,
+    rhs: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let ((left, left_is_scalar), (right, right_is_scalar)) = (lhs.get(), rhs.get());
+    let ((temporal, temporal_is_scalar), (factor, factor_is_scalar)) =
+        if left.data_type().is_numeric() {
+            ((right, right_is_scalar), (left, left_is_scalar))
+        } else {
+            ((left, left_is_scalar), (right, right_is_scalar))
+        };
+    let len = match (temporal_is_scalar, factor_is_scalar) {
+        (true, true) => 1,
+        (true, false) => factor.len(),
+        (false, _) => temporal.len(),
+    };
+
+    let factor = if factor.data_type().is_integer() {
+        cast(factor, &DataType::Int64)?
+    } else {
+        cast(factor, &DataType::Float64)?
+    };
+    let factor_at = |index: usize| {
+        let index = if factor_is_scalar { 0 } else { index };
+        factor.is_valid(index).then(|| match factor.data_type() {
+            DataType::Int64 => {
+                Factor::Int(factor.as_primitive::<Int64Type>().value(index))
+            }
+            _ => Factor::Float(factor.as_primitive::<Float64Type>().value(index)),
+        })
+    };
+
+    macro_rules! scale {
+        ($TYPE:ty, $TEMPORAL:expr, $SCALE:expr) => {{
+            let temporal = $TEMPORAL.as_primitive::<$TYPE>();
+            let result = (0..len)
+                .map(|index| {
+                    let temporal_index = if temporal_is_scalar { 0 } else { index };
+                    match factor_at(index) {
+                        Some(factor) if temporal.is_valid(temporal_index) => {
+                            $SCALE(temporal.value(temporal_index), factor, op).map(Some)
+                        }
+                        _ => Ok(None),
+                    }
+                })
+                .collect::<Result<PrimitiveArray<$TYPE>, ArrowError>>()?;
+            Ok(Arc::new(result) as ArrayRef)
+        }};
+    }
+
+    match temporal.data_type() {
+        DataType::Interval(_) => {
+            let interval =
+                cast(temporal, &DataType::Interval(IntervalUnit::MonthDayNano))?;
+            scale!(IntervalMonthDayNanoType, interval, scale_interval)
+        }
+        DataType::Duration(TimeUnit::Second) => {
+            scale!(DurationSecondType, temporal, scale_duration)
+        }
+        DataType::Duration(TimeUnit::Millisecond) => {
+            scale!(DurationMillisecondType, temporal, scale_duration)
+        }
+        DataType::Duration(TimeUnit::Microsecond) => {
+            scale!(DurationMicrosecondType, temporal, scale_duration)
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            scale!(DurationNanosecondType, temporal, scale_duration)
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "Can not scale values of type {other} by numbers"
+        ))),
+    }
+}
+
+/// Scales the interval `value`, see [`scale_temporal`]
+fn scale_interval(value: i128, factor: Factor, op: Operator) -> Result<i128, ArrowError> {
+    let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(value);
+    let overflow = |component: &str| {
+        ArrowError::ComputeError(format!(
+            "Overflow of the {component} of interval {months} months {days} days \
+             {nanos} nanoseconds {op} {factor}"
+        ))
+    };
+    let (months, days, nanos) = match (factor, op) {
+        (Factor::Int(0), Operator::Divide) => return Err(ArrowError::DivideByZero),
+        (Factor::Float(factor), Operator::Divide) if factor == 0.0 => {
+            return Err(ArrowError::DivideByZero)
+        }
+        (Factor::Int(factor), Operator::Multiply) => {
+            let factor = factor as i128;
+            (
+                months as i128 * factor,
+                days as i128 * factor,
+                nanos as i128 * factor,
+            )
+        }
+        (Factor::Int(factor), _) => {
+            let factor = factor as i128;
+            let days = days as i128 + months as i128 % factor * DAYS_PER_MONTH;
+            let nanos = nanos as i128 + days % factor * NANOS_PER_DAY;
+            (
+                months as i128 / factor,
+                days / factor,
+                div_round(nanos, factor),
+            )
+        }
+        (Factor::Float(factor), _) => {
+            let scale = |value: f64| match op {
+                Operator::Multiply => value * factor,
+                _ => value / factor,
+            };
+            let months = scale(months as f64);
+            let days = scale(days as f64) + months.fract() * DAYS_PER_MONTH as f64;
+            let nanos = scale(nanos as f64) + days.fract() * NANOS_PER_DAY as f64;
+            (
+                f64_to_i128(months.trunc()).ok_or_else(|| overflow("months"))?,
+                f64_to_i128(days.trunc()).ok_or_else(|| overflow("days"))?,
+                f64_to_i128(nanos.round()).ok_or_else(|| overflow("nanoseconds"))?,
+            )
+        }
+    };
+    Ok(IntervalMonthDayNanoType::make_value(
+        i32::try_from(months).map_err(|_| overflow("months"))?,
+        i32::try_from(days).map_err(|_| overflow("days"))?,
+        i64::try_from(nanos).map_err(|_| overflow("nanoseconds"))?,
+    ))
+}
+
+/// Scales the duration `value`, see [`scale_temporal`]
+fn scale_duration(value: i64, factor: Factor, op: Operator) -> Result<i64, ArrowError> {
+    let scaled = match (factor, op) {
+        (Factor::Int(0), Operator::Divide) => return Err(ArrowError::DivideByZero),
+        (Factor::Float(factor), Operator::Divide) if factor == 0.0 => {
+            return Err(ArrowError::DivideByZero)
+        }
+        (Factor::Int(factor), Operator::Multiply) => Some(value as i128 * factor as i128),
+        (Factor::Int(factor), _) => Some(div_round(value as i128, factor as i128)),
+        (Factor::Float(factor), Operator::Multiply) => {
+            f64_to_i128((value as f64 * factor).round())
+        }
+        (Factor::Float(factor), _) => f64_to_i128((value as f64 / factor).round()),
+    };
+    scaled
+        .and_then(|scaled| i64::try_from(scaled).ok())
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Overflow of duration {value} {op} {factor}"
+            ))
+        })
+}
+
+/// Divides `dividend` by `divisor`, rounding to the nearest integer with ties
+/// rounded away from zero. `divisor` must not be zero.
+fn div_round(dividend: i128, divisor: i128) -> i128 {
+    let (quotient, remainder) = (dividend / divisor, dividend % divisor);
+    if 2 * remainder.abs() >= divisor.abs() {
+        quotient + dividend.signum() * divisor.signum()
+    } else {
+        quotient
+    }
+}
+
+/// Converts `value` to an `i128`, if it is finite and within the range of
+/// `i64`, which is enough for all components of intervals and durations.
+fn f64_to_i128(value: f64) -> Option<i128> {
+    (value >= i64::MIN as f64 && value < i64::MAX as f64).then_some(value as i128)
+}
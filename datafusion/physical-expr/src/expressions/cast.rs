@@ -20,6 +20,7 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use crate::intervals::cp_solver::{propagate_cast_backward, propagate_cast_forward};
 use crate::intervals::Interval;
 use crate::physical_expr::down_cast_any_ref;
 use crate::sort_properties::SortProperties;
@@ -40,6 +41,10 @@ const DEFAULT_CAST_OPTIONS: CastOptions<'static> = CastOptions {
 };
 
 /// CAST expression casts an expression to a specific data type and returns a runtime error on invalid cast
+///
+/// Casts between `Timestamp(unit, tz)` and `Int64` preserve the stored value,
+/// i.e. the number of `unit`s since the unix epoch, in both directions. The
+/// timezone of a timestamp is dropped when it is cast to `Int64`.
 #[derive(Debug, Clone)]
 pub struct CastExpr {
     /// The expression to cast
@@ -116,8 +121,7 @@ impl PhysicalExpr for CastExpr {
     }
 
     fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
-        // Cast current node's interval to the right type:
-        children[0].cast_to(&self.cast_type, &self.cast_options)
+        propagate_cast_forward(children[0], &self.cast_type, &self.cast_options)
     }
 
     fn propagate_constraints(
@@ -125,12 +129,11 @@ impl PhysicalExpr for CastExpr {
         interval: &Interval,
         children: &[&Interval],
     ) -> Result<Vec<Option<Interval>>> {
-        let child_interval = children[0];
-        // Get child's datatype:
-        let cast_type = child_interval.get_datatype()?;
-        Ok(vec![Some(
-            interval.cast_to(&cast_type, &self.cast_options)?,
-        )])
+        Ok(vec![propagate_cast_backward(
+            interval,
+            children[0],
+            &self.cast_options,
+        )?])
     }
 
     fn dyn_hash(&self, state: &mut dyn Hasher) {
@@ -225,10 +228,12 @@ mod tests {
         array::{
             Array, Decimal128Array, Float32Array, Float64Array, Int16Array, Int32Array,
             Int64Array, Int8Array, StringArray, Time64NanosecondArray,
-            TimestampNanosecondArray, UInt32Array,
+            TimestampMicrosecondArray, TimestampNanosecondArray, TimestampSecondArray,
+            UInt32Array,
         },
         datatypes::*,
     };
+    use datafusion_common::cast::as_int64_array;
     use datafusion_common::Result;
 
     // runs an end-to-end test of physical type cast
@@ -664,6 +669,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cast_timestamp_to_i64() -> Result<()> {
+        // the value is kept in the native unit of the timestamp
+        generic_test_cast!(
+            TimestampMicrosecondArray,
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            vec![Some(1_000_000_i64), Some(-1), None],
+            Int64Array,
+            DataType::Int64,
+            [Some(1_000_000_i64), Some(-1), None],
+            None
+        );
+        generic_test_cast!(
+            TimestampSecondArray,
+            DataType::Timestamp(TimeUnit::Second, None),
+            vec![Some(1_600_000_000_i64)],
+            Int64Array,
+            DataType::Int64,
+            [Some(1_600_000_000_i64)],
+            None
+        );
+
+        // the timezone is dropped
+        let array = TimestampMicrosecondArray::from(vec![Some(1_000_000_i64)])
+            .with_timezone("+05:00");
+        let schema = Schema::new(vec![Field::new("a", array.data_type().clone(), true)]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array)])?;
+        let expression = cast(col("a", &schema)?, &schema, DataType::Int64)?;
+        let result = expression.evaluate(&batch)?.into_array(batch.num_rows());
+        assert_eq!(as_int64_array(&result)?.value(0), 1_000_000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_i64_to_timestamp() -> Result<()> {
+        generic_test_cast!(
+            Int64Array,
+            DataType::Int64,
+            vec![Some(1_600_000_000_i64), Some(-1), None],
+            TimestampSecondArray,
+            DataType::Timestamp(TimeUnit::Second, None),
+            [Some(1_600_000_000_i64), Some(-1), None],
+            None
+        );
+        generic_test_cast!(
+            Int64Array,
+            DataType::Int64,
+            vec![Some(1_600_000_000_000_000_i64)],
+            TimestampMicrosecondArray,
+            DataType::Timestamp(TimeUnit::Microsecond, Some("+05:00".into())),
+            [Some(1_600_000_000_000_000_i64)],
+            None
+        );
+        Ok(())
+    }
+
     #[test]
     fn invalid_cast() {
         // Ensure a useful error happens at plan time if invalid casts are used
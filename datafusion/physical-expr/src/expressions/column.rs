@@ -34,6 +34,7 @@ use datafusion_expr::ColumnarValue;
 
 /// Represents the column at a given index in a RecordBatch
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Column {
     name: String,
     index: usize,
@@ -22,6 +22,8 @@ use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use crate::expressions::Literal;
+use crate::intervals::Interval;
 use crate::physical_expr::down_cast_any_ref;
 use crate::utils::expr_list_eq_any_order;
 use crate::PhysicalExpr;
@@ -395,6 +397,64 @@ impl PhysicalExpr for InListExpr {
         )))
     }
 
+    fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
+        // The value is in the list if it is equal to any of the list items.
+        // A `NULL` item never equals the value, but it turns a `NOT IN` that
+        // would otherwise be true into `NULL`, so it is only skipped for `IN`:
+        let (value, list) = (children[0], &children[1..]);
+        let found = self
+            .list
+            .iter()
+            .zip(list)
+            .filter(|(expr, _)| self.negated || !is_null_literal(expr))
+            .try_fold(Interval::CERTAINLY_FALSE, |found, (_, item)| {
+                found.or(value.equal(*item))
+            })?;
+        if self.negated {
+            found.not()
+        } else {
+            Ok(found)
+        }
+    }
+
+    fn propagate_constraints(
+        &self,
+        interval: &Interval,
+        children: &[&Interval],
+    ) -> Result<Vec<Option<Interval>>> {
+        // The value can only be constrained when it must be in the list. A
+        // value that must not be in the list can still lie between (or even
+        // around) the list items, so a `NOT IN` does not narrow it.
+        let must_be_found = if self.negated {
+            interval == &Interval::CERTAINLY_FALSE
+        } else {
+            interval != &Interval::CERTAINLY_FALSE
+        };
+        if !must_be_found {
+            return Ok(vec![]);
+        }
+        // The value then lies within the smallest interval covering the list
+        // items it may be equal to. If there are no such items, the constraint
+        // is infeasible. `NULL` items are never equal to the value, and their
+        // unbounded intervals would otherwise widen the covering interval.
+        let (value, list) = (children[0], &children[1..]);
+        let mut covering: Option<Interval> = None;
+        for (_, item) in self
+            .list
+            .iter()
+            .zip(list)
+            .filter(|(expr, _)| !is_null_literal(expr))
+        {
+            if let Some(overlap) = value.intersect(*item)? {
+                covering = Some(match covering {
+                    Some(covering) => covering.union(overlap)?,
+                    None => overlap,
+                });
+            }
+        }
+        Ok(vec![covering])
+    }
+
     fn dyn_hash(&self, state: &mut dyn Hasher) {
         let mut s = state;
         self.expr.hash(&mut s);
@@ -417,6 +477,14 @@ impl PartialEq<dyn Any> for InListExpr {
     }
 }
 
+/// Returns true if `expr` is a `NULL` literal
+fn is_null_literal(expr: &Arc<dyn PhysicalExpr>) -> bool {
+    expr.as_any()
+        .downcast_ref::<Literal>()
+        .map(|literal| literal.value().is_null())
+        .unwrap_or(false)
+}
+
 /// Creates a unary expression InList
 pub fn in_list(
     expr: Arc<dyn PhysicalExpr>,
@@ -18,12 +18,15 @@
 use std::hash::{Hash, Hasher};
 use std::{any::Any, sync::Arc};
 
+use crate::intervals::{Interval, IntervalBound};
 use crate::{physical_expr::down_cast_any_ref, PhysicalExpr};
 
 use crate::expressions::datum::apply_cmp;
+use arrow::array::{Array, ArrayRef, BooleanArray, GenericStringArray, OffsetSizeTrait};
 use arrow::record_batch::RecordBatch;
 use arrow_schema::{DataType, Schema};
-use datafusion_common::{internal_err, DataFusionError, Result};
+use datafusion_common::cast::as_generic_string_array;
+use datafusion_common::{exec_err, internal_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::ColumnarValue;
 
 // Like expression
@@ -33,6 +36,7 @@ pub struct LikeExpr {
     case_insensitive: bool,
     expr: Arc<dyn PhysicalExpr>,
     pattern: Arc<dyn PhysicalExpr>,
+    escape_char: Option<char>,
 }
 
 impl LikeExpr {
@@ -47,9 +51,18 @@ impl LikeExpr {
             case_insensitive,
             expr,
             pattern,
+            escape_char: None,
         }
     }
 
+    /// Sets the character that makes the following character of the pattern
+    /// match literally, as in `LIKE 'a#_b' ESCAPE '#'`. Without one, `\`
+    /// escapes `%` and `_`.
+    pub fn with_escape_char(mut self, escape_char: Option<char>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Is negated
     pub fn negated(&self) -> bool {
         self.negated
@@ -70,6 +83,56 @@ impl LikeExpr {
         &self.pattern
     }
 
+    /// The escape character of the pattern, if any
+    pub fn escape_char(&self) -> Option<char> {
+        self.escape_char
+    }
+
+    /// Returns the interval of the strings matching `pattern`, if they form a
+    /// contiguous range. This is the case for case-sensitive patterns that
+    /// contain no wildcards, or that are anchored to a prefix such as `abc%`,
+    /// which matches the strings in `["abc", "abd")`. Returns `None` for other
+    /// patterns (e.g. `%abc` or `a_c`).
+    pub(crate) fn matching_interval(
+        &self,
+        pattern: &ScalarValue,
+    ) -> Result<Option<Interval>> {
+        if self.case_insensitive {
+            return Ok(None);
+        }
+        let (ScalarValue::Utf8(Some(value)) | ScalarValue::LargeUtf8(Some(value))) =
+            pattern
+        else {
+            return Ok(None);
+        };
+        let escape_char = self.escape_char.unwrap_or('\\');
+        let Some(wildcard) = value.find(['%', '_', escape_char]) else {
+            return Ok(Some(Interval::new(
+                IntervalBound::new_closed(pattern.clone()),
+                IntervalBound::new_closed(pattern.clone()),
+            )));
+        };
+        if !value[wildcard..].chars().all(|c| c == '%') {
+            return Ok(None);
+        }
+        let prefix = value[..wildcard].to_string();
+        let prefix = match pattern {
+            ScalarValue::LargeUtf8(_) => ScalarValue::LargeUtf8(Some(prefix)),
+            _ => ScalarValue::Utf8(Some(prefix)),
+        };
+        Interval::make_prefix(&prefix).map(Some)
+    }
+
+    /// Returns the interval of the strings matching the pattern whose interval
+    /// is `pattern`, if `pattern` holds a single value. See
+    /// [`Self::matching_interval`].
+    fn matching_interval_of(&self, pattern: &Interval) -> Result<Option<Interval>> {
+        if pattern.lower.is_unbounded() || pattern.lower.value != pattern.upper.value {
+            return Ok(None);
+        }
+        self.matching_interval(&pattern.lower.value)
+    }
+
     /// Operator name
     fn op_name(&self) -> &str {
         match (self.negated, self.case_insensitive) {
@@ -79,11 +142,174 @@ impl LikeExpr {
             (true, true) => "NOT ILIKE",
         }
     }
+
+    /// Evaluates the expression for a pattern with the given escape character,
+    /// which the `LIKE` kernels of arrow do not support.
+    fn evaluate_escaped(
+        &self,
+        lhs: &ColumnarValue,
+        rhs: &ColumnarValue,
+        escape_char: char,
+    ) -> Result<ColumnarValue> {
+        let len = match (lhs, rhs) {
+            (ColumnarValue::Array(array), _) | (_, ColumnarValue::Array(array)) => {
+                array.len()
+            }
+            _ => 1,
+        };
+        let (values, patterns) =
+            (lhs.clone().into_array(len), rhs.clone().into_array(len));
+        let result = match values.data_type() {
+            DataType::Utf8 => self.like_escaped::<i32>(&values, &patterns, escape_char),
+            DataType::LargeUtf8 => {
+                self.like_escaped::<i64>(&values, &patterns, escape_char)
+            }
+            other => {
+                internal_err!("Unsupported data type {other:?} for {}", self.op_name())
+            }
+        }?;
+        Ok(match (lhs, rhs) {
+            (ColumnarValue::Scalar(_), ColumnarValue::Scalar(_)) => {
+                ColumnarValue::Scalar(ScalarValue::try_from_array(&result, 0)?)
+            }
+            _ => ColumnarValue::Array(Arc::new(result)),
+        })
+    }
+
+    fn like_escaped<O: OffsetSizeTrait>(
+        &self,
+        values: &ArrayRef,
+        patterns: &ArrayRef,
+        escape_char: char,
+    ) -> Result<BooleanArray> {
+        let values: &GenericStringArray<O> = as_generic_string_array(values)?;
+        let patterns: &GenericStringArray<O> = as_generic_string_array(patterns)?;
+        // Patterns are usually the same for all rows, so only parse changes:
+        let mut parsed: Option<(&str, Vec<PatternToken>)> = None;
+        values
+            .iter()
+            .zip(patterns.iter())
+            .map(|(value, pattern)| {
+                let (Some(value), Some(pattern)) = (value, pattern) else {
+                    return Ok(None);
+                };
+                let tokens = match &parsed {
+                    Some((previous, tokens)) if *previous == pattern => tokens,
+                    _ => {
+                        let tokens =
+                            parse_pattern(pattern, escape_char, self.case_insensitive)?;
+                        &parsed.insert((pattern, tokens)).1
+                    }
+                };
+                let matched = if self.case_insensitive {
+                    matches_pattern(tokens, &value.to_lowercase())
+                } else {
+                    matches_pattern(tokens, value)
+                };
+                Ok(Some(matched != self.negated))
+            })
+            .collect()
+    }
+}
+
+/// A token of a `LIKE` pattern
+#[derive(Debug, PartialEq)]
+enum PatternToken {
+    /// Matches the given character
+    Char(char),
+    /// `_`, which matches any single character
+    AnyChar,
+    /// `%`, which matches any sequence of characters
+    AnyString,
+}
+
+/// Parses a `LIKE` pattern in which `escape_char` makes the following
+/// character match literally, including `%`, `_` and `escape_char` itself.
+/// Like in PostgreSQL, escaping any other character matches that character,
+/// and a pattern must not end with the escape character. The characters of
+/// case-insensitive patterns are lowercased.
+fn parse_pattern(
+    pattern: &str,
+    escape_char: char,
+    case_insensitive: bool,
+) -> Result<Vec<PatternToken>> {
+    let mut tokens = vec![];
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        let c = match c {
+            '%' if c != escape_char => {
+                // Consecutive wildcards match the same strings as one:
+                if tokens.last() != Some(&PatternToken::AnyString) {
+                    tokens.push(PatternToken::AnyString);
+                }
+                continue;
+            }
+            '_' if c != escape_char => {
+                tokens.push(PatternToken::AnyChar);
+                continue;
+            }
+            c if c == escape_char => match chars.next() {
+                Some(escaped) => escaped,
+                None => {
+                    return exec_err!("LIKE pattern must not end with escape character")
+                }
+            },
+            c => c,
+        };
+        if case_insensitive {
+            tokens.extend(c.to_lowercase().map(PatternToken::Char));
+        } else {
+            tokens.push(PatternToken::Char(c));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Returns whether `value` matches the pattern given by `tokens`. A mismatch
+/// after a `%` retries with the `%` matching one more character, which finds
+/// a match if there is one since any later `%` can match whatever the earlier
+/// one would cover.
+fn matches_pattern(tokens: &[PatternToken], value: &str) -> bool {
+    let value = value.chars().collect::<Vec<_>>();
+    let (mut token, mut position) = (0, 0);
+    // The token following the last `%` and the position it was tried at:
+    let mut retry = None;
+    while position < value.len() {
+        match tokens.get(token) {
+            Some(PatternToken::AnyString) => {
+                token += 1;
+                retry = Some((token, position));
+            }
+            Some(PatternToken::AnyChar) => {
+                token += 1;
+                position += 1;
+            }
+            Some(PatternToken::Char(c)) if *c == value[position] => {
+                token += 1;
+                position += 1;
+            }
+            _ => match retry {
+                Some((retry_token, retry_position)) => {
+                    token = retry_token;
+                    position = retry_position + 1;
+                    retry = Some((retry_token, position));
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[token..]
+        .iter()
+        .all(|token| token == &PatternToken::AnyString)
 }
 
 impl std::fmt::Display for LikeExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{} {} {}", self.expr, self.op_name(), self.pattern)
+        write!(f, "{} {} {}", self.expr, self.op_name(), self.pattern)?;
+        if let Some(escape_char) = self.escape_char {
+            write!(f, " ESCAPE '{escape_char}'")?;
+        }
+        Ok(())
     }
 }
 
@@ -104,6 +330,9 @@ impl PhysicalExpr for LikeExpr {
         use arrow::compute::*;
         let lhs = self.expr.evaluate(batch)?;
         let rhs = self.pattern.evaluate(batch)?;
+        if let Some(escape_char) = self.escape_char {
+            return self.evaluate_escaped(&lhs, &rhs, escape_char);
+        }
         match (self.negated, self.case_insensitive) {
             (false, false) => apply_cmp(&lhs, &rhs, like),
             (false, true) => apply_cmp(&lhs, &rhs, ilike),
@@ -120,12 +349,48 @@ impl PhysicalExpr for LikeExpr {
         self: Arc<Self>,
         children: Vec<Arc<dyn PhysicalExpr>>,
     ) -> Result<Arc<dyn PhysicalExpr>> {
-        Ok(Arc::new(LikeExpr::new(
-            self.negated,
-            self.case_insensitive,
-            children[0].clone(),
-            children[1].clone(),
-        )))
+        Ok(Arc::new(
+            LikeExpr::new(
+                self.negated,
+                self.case_insensitive,
+                children[0].clone(),
+                children[1].clone(),
+            )
+            .with_escape_char(self.escape_char),
+        ))
+    }
+
+    fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
+        let (value, pattern) = (children[0], children[1]);
+        let matched = match self.matching_interval_of(pattern)? {
+            Some(matching) if value.intersect(&matching)?.is_none() => {
+                Interval::CERTAINLY_FALSE
+            }
+            _ => Interval::UNCERTAIN,
+        };
+        if self.negated {
+            matched.not()
+        } else {
+            Ok(matched)
+        }
+    }
+
+    fn propagate_constraints(
+        &self,
+        interval: &Interval,
+        children: &[&Interval],
+    ) -> Result<Vec<Option<Interval>>> {
+        // The value can only be constrained when it must match the pattern:
+        let must_match = if self.negated {
+            interval == &Interval::CERTAINLY_FALSE
+        } else {
+            interval != &Interval::CERTAINLY_FALSE
+        };
+        let (value, pattern) = (children[0], children[1]);
+        match self.matching_interval_of(pattern)? {
+            Some(matching) if must_match => Ok(vec![value.intersect(matching)?]),
+            _ => Ok(vec![]),
+        }
     }
 
     fn dyn_hash(&self, state: &mut dyn Hasher) {
@@ -141,6 +406,7 @@ impl PartialEq<dyn Any> for LikeExpr {
             .map(|x| {
                 self.negated == x.negated
                     && self.case_insensitive == x.case_insensitive
+                    && self.escape_char == x.escape_char
                     && self.expr.eq(&x.expr)
                     && self.pattern.eq(&x.pattern)
             })
@@ -154,6 +420,7 @@ pub fn like(
     case_insensitive: bool,
     expr: Arc<dyn PhysicalExpr>,
     pattern: Arc<dyn PhysicalExpr>,
+    escape_char: Option<char>,
     input_schema: &Schema,
 ) -> Result<Arc<dyn PhysicalExpr>> {
     let expr_type = &expr.data_type(input_schema)?;
@@ -163,12 +430,10 @@ pub fn like(
             "The type of {expr_type} AND {pattern_type} of like physical should be same"
         );
     }
-    Ok(Arc::new(LikeExpr::new(
-        negated,
-        case_insensitive,
-        expr,
-        pattern,
-    )))
+    Ok(Arc::new(
+        LikeExpr::new(negated, case_insensitive, expr, pattern)
+            .with_escape_char(escape_char),
+    ))
 }
 
 #[cfg(test)]
@@ -193,6 +458,7 @@ mod test {
                 $CASE_INSENSITIVE,
                 col("a", &schema)?,
                 col("b", &schema)?,
+                None,
                 &schema,
             )?;
             let batch = RecordBatch::try_new(
@@ -246,4 +512,140 @@ mod test {
 
         Ok(())
     }
+
+    fn evaluate_escaped(
+        values: Vec<Option<&str>>,
+        pattern: &str,
+        escape_char: Option<char>,
+        negated: bool,
+        case_insensitive: bool,
+    ) -> Result<ArrayRef> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(StringArray::from(values))],
+        )?;
+        let expression = like(
+            negated,
+            case_insensitive,
+            col("a", &schema)?,
+            crate::expressions::lit(pattern),
+            escape_char,
+            &schema,
+        )?;
+        Ok(expression.evaluate(&batch)?.into_array(batch.num_rows()))
+    }
+
+    #[test]
+    fn like_escape() -> Result<()> {
+        let values = vec![Some("a_b"), Some("axb"), Some("a%b"), Some("a\\b"), None];
+        let expected = |matches: Vec<Option<bool>>| -> ArrayRef {
+            Arc::new(BooleanArray::from(matches))
+        };
+
+        // Without an escape character, `_` matches any character.
+        let result = evaluate_escaped(values.clone(), "a_b", None, false, false)?;
+        assert_eq!(
+            &result,
+            &expected(vec![Some(true), Some(true), Some(true), Some(true), None])
+        );
+
+        // A backslash escape makes the wildcards literal.
+        let result = evaluate_escaped(values.clone(), "a\\_b", Some('\\'), false, false)?;
+        assert_eq!(
+            &result,
+            &expected(vec![
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                None
+            ])
+        );
+        let result = evaluate_escaped(values.clone(), "a\\%b", Some('\\'), false, false)?;
+        assert_eq!(
+            &result,
+            &expected(vec![
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false),
+                None
+            ])
+        );
+        // Two escape characters match the escape character itself.
+        let result =
+            evaluate_escaped(values.clone(), "a\\\\b", Some('\\'), false, false)?;
+        assert_eq!(
+            &result,
+            &expected(vec![
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                None
+            ])
+        );
+
+        // A custom escape character, after which a backslash is an ordinary
+        // character that may be followed by a wildcard.
+        let result = evaluate_escaped(values.clone(), "a#_%", Some('#'), false, false)?;
+        assert_eq!(
+            &result,
+            &expected(vec![
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                None
+            ])
+        );
+        let result = evaluate_escaped(values.clone(), "a\\%", Some('#'), false, false)?;
+        assert_eq!(
+            &result,
+            &expected(vec![
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                None
+            ])
+        );
+        // NOT ILIKE
+        let result = evaluate_escaped(values.clone(), "A#%B", Some('#'), true, true)?;
+        assert_eq!(
+            &result,
+            &expected(vec![Some(true), Some(true), Some(false), Some(true), None])
+        );
+        // An escaped ordinary character matches itself, like in PostgreSQL.
+        let result = evaluate_escaped(values.clone(), "#a%", Some('#'), false, false)?;
+        assert_eq!(
+            &result,
+            &expected(vec![Some(true), Some(true), Some(true), Some(true), None])
+        );
+
+        // A pattern must not end with the escape character.
+        let err = evaluate_escaped(values, "a#", Some('#'), false, false).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Execution error: LIKE pattern must not end with escape character"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn like_escape_backtracking() {
+        let matches = |pattern: &str, value: &str| {
+            matches_pattern(&parse_pattern(pattern, '#', false).unwrap(), value)
+        };
+        assert!(matches("%a%b%", "xxaxxbxx"));
+        assert!(matches("%ab", "aab"));
+        assert!(matches("a%a_", "abaaab"));
+        assert!(matches("%", ""));
+        assert!(matches("%%#%", "100%"));
+        assert!(!matches("%ab", "aba"));
+        assert!(!matches("_", ""));
+        assert!(!matches("a%b", "a"));
+    }
 }
@@ -22,6 +22,7 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use crate::intervals::Interval;
 use crate::physical_expr::down_cast_any_ref;
 use crate::PhysicalExpr;
 use arrow::datatypes::{DataType, Schema};
@@ -113,6 +114,28 @@ impl PhysicalExpr for NotExpr {
         let mut s = state;
         self.hash(&mut s);
     }
+
+    /// Given the interval of the child of a `NotExpr`, calculates the interval
+    /// of the `NotExpr`, i.e. the logical negation of the child's interval.
+    fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
+        children[0].not()
+    }
+
+    /// The child must be false if the `NotExpr` holds, and true if it does not.
+    /// As elsewhere in constraint propagation, an uncertain `NotExpr` is
+    /// assumed to hold.
+    fn propagate_constraints(
+        &self,
+        interval: &Interval,
+        children: &[&Interval],
+    ) -> Result<Vec<Option<Interval>>> {
+        let complement = if interval == &Interval::CERTAINLY_FALSE {
+            Interval::CERTAINLY_TRUE
+        } else {
+            Interval::CERTAINLY_FALSE
+        };
+        Ok(vec![children[0].intersect(complement)?])
+    }
 }
 
 impl PartialEq<dyn Any> for NotExpr {
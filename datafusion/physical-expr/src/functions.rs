@@ -34,7 +34,7 @@ use crate::execution_props::ExecutionProps;
 use crate::sort_properties::SortProperties;
 use crate::{
     array_expressions, conditional_expressions, datetime_expressions,
-    expressions::{cast_column, nullif_func},
+    expressions::{cast_column, nullif_func, CastExpr, Literal, TryCastExpr},
     math_expressions, string_expressions, struct_expressions, PhysicalExpr,
     ScalarFunctionExpr,
 };
@@ -65,7 +65,11 @@ pub fn create_physical_expr(
         .map(|e| e.data_type(input_schema))
         .collect::<Result<Vec<_>>>()?;
 
-    let data_type = fun.return_type(&input_expr_types)?;
+    let literal_args = input_phy_exprs
+        .iter()
+        .map(literal_value)
+        .collect::<Vec<_>>();
+    let data_type = fun.return_type_from_literals(&input_expr_types, &literal_args)?;
 
     let fun_expr: ScalarFunctionImplementation = match fun {
         // These functions need args and input schema to pick an implementation
@@ -150,13 +154,7 @@ pub fn create_physical_expr(
         }),
         BuiltinScalarFunction::FromUnixtime => Arc::new({
             match input_phy_exprs[0].data_type(input_schema) {
-                Ok(DataType::Int64) => |col_values: &[ColumnarValue]| {
-                    cast_column(
-                        &col_values[0],
-                        &DataType::Timestamp(TimeUnit::Second, None),
-                        None,
-                    )
-                },
+                Ok(DataType::Int64) => datetime_expressions::from_unixtime,
                 other => {
                     return internal_err!(
                         "Unsupported data type {other:?} for function from_unixtime"
@@ -177,11 +175,31 @@ pub fn create_physical_expr(
             let abs_fun = math_expressions::create_abs_function(&input_data_type)?;
             Arc::new(move |args| make_scalar_function(abs_fun)(args))
         }
+        // Decimals are rounded with the scale of the input, and then cast to
+        // the scale of the literal number of decimal places, which is exact
+        // as the dropped digits are zero
+        BuiltinScalarFunction::Ceil
+        | BuiltinScalarFunction::Floor
+        | BuiltinScalarFunction::Round
+        | BuiltinScalarFunction::Trunc
+            if input_expr_types[0] != data_type
+                && matches!(
+                    data_type,
+                    DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
+                ) =>
+        {
+            let round_fun = create_physical_fun(fun, execution_props)?;
+            let data_type = data_type.clone();
+            Arc::new(move |args| cast_column(&round_fun(args)?, &data_type, None))
+        }
         // These don't need args and input schema
         _ => create_physical_fun(fun, execution_props)?,
     };
 
-    let monotonicity = fun.monotonicity();
+    let monotonicity = match fun {
+        BuiltinScalarFunction::DatePart => date_part_monotonicity(input_phy_exprs),
+        _ => fun.monotonicity(),
+    };
 
     Ok(Arc::new(ScalarFunctionExpr::new(
         &format!("{fun}"),
@@ -192,6 +210,36 @@ pub fn create_physical_expr(
     )))
 }
 
+/// Returns the value of `expr` if it is a literal, which may have been cast by
+/// type coercion, see [`BuiltinScalarFunction::return_type_from_literals`].
+fn literal_value(expr: &Arc<dyn PhysicalExpr>) -> Option<ScalarValue> {
+    let expr = expr.as_any();
+    if let Some(literal) = expr.downcast_ref::<Literal>() {
+        Some(literal.value().clone())
+    } else if let Some(cast) = expr.downcast_ref::<CastExpr>() {
+        literal_value(cast.expr())
+    } else if let Some(try_cast) = expr.downcast_ref::<TryCastExpr>() {
+        literal_value(try_cast.expr())
+    } else {
+        None
+    }
+}
+
+/// Returns the monotonicity of `date_part` for the given arguments. The year
+/// and the epoch never decrease as the date or time increases, whereas other
+/// parts (e.g. the month) wrap around.
+fn date_part_monotonicity(args: &[Arc<dyn PhysicalExpr>]) -> Option<FuncMonotonicity> {
+    let part = args.first()?.as_any().downcast_ref::<Literal>()?;
+    match part.value() {
+        ScalarValue::Utf8(Some(part))
+            if matches!(part.to_lowercase().as_str(), "year" | "epoch") =>
+        {
+            Some(vec![None, Some(true)])
+        }
+        _ => None,
+    }
+}
+
 #[cfg(feature = "encoding_expressions")]
 macro_rules! invoke_if_encoding_expressions_feature_flag {
     ($FUNC:ident, $NAME:expr) => {{
@@ -570,6 +618,7 @@ pub fn create_physical_fun(
         BuiltinScalarFunction::DatePart => Arc::new(datetime_expressions::date_part),
         BuiltinScalarFunction::DateTrunc => Arc::new(datetime_expressions::date_trunc),
         BuiltinScalarFunction::DateBin => Arc::new(datetime_expressions::date_bin),
+        BuiltinScalarFunction::ToUnixtime => Arc::new(datetime_expressions::to_unixtime),
         BuiltinScalarFunction::Now => {
             // bind value for now at plan time
             Arc::new(datetime_expressions::make_now(
@@ -17,28 +17,37 @@
 
 //! Constraint propagator/solver for custom PhysicalExpr graphs.
 
-use std::collections::HashSet;
-use std::fmt::{Display, Formatter};
+use std::cmp::Ordering;
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{self, Display, Formatter};
 use std::sync::Arc;
 
 use super::utils::{
     convert_duration_type_to_interval, convert_interval_type_to_duration, get_inverse_op,
+    negate_comparison,
 };
 use super::IntervalBound;
-use crate::expressions::Literal;
-use crate::intervals::interval_aritmetic::{apply_operator, Interval};
+use crate::expressions::{BinaryExpr, Literal};
+use crate::intervals::interval_aritmetic::{
+    align_timestamp_units, apply_operator, cast_scalar_value, next_value,
+    restore_timestamp_unit, Interval,
+};
 use crate::utils::{build_dag, ExprTreeNode};
 use crate::PhysicalExpr;
 
+use arrow::compute::CastOptions;
 use arrow_schema::DataType;
-use datafusion_common::{DataFusionError, Result, ScalarValue};
+use datafusion_common::display::GraphvizBuilder;
+use datafusion_common::format::DEFAULT_FORMAT_OPTIONS;
+use datafusion_common::tree_node::{Transformed, TreeNode};
+use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::type_coercion::binary::get_result_type;
 use datafusion_expr::Operator;
 
 use petgraph::graph::NodeIndex;
 use petgraph::stable_graph::{DefaultIx, StableGraph};
 use petgraph::visit::{Bfs, Dfs, DfsPostOrder, EdgeRef};
-use petgraph::Outgoing;
+use petgraph::{Incoming, Outgoing};
 
 // Interval arithmetic provides a way to perform mathematical operations on
 // intervals, which represent a range of possible values rather than a single
@@ -153,6 +162,17 @@ pub enum PropagationResult {
 pub struct ExprIntervalGraphNode {
     expr: Arc<dyn PhysicalExpr>,
     interval: Interval,
+    /// The range of the expression as computed by the last bottom-up
+    /// evaluation, before any constraints are propagated to it. For leaf
+    /// expressions, this is the range they were last assigned.
+    bounds: Interval,
+    /// Whether the range of the expression or one of its descendants has been
+    /// reset since the last propagation. The ancestors of a dirty node are also
+    /// dirty.
+    dirty: bool,
+    /// Whether the ranges of the children were derived from `interval` in the
+    /// last top-down propagation.
+    propagated: bool,
 }
 
 impl Display for ExprIntervalGraphNode {
@@ -164,15 +184,18 @@ impl Display for ExprIntervalGraphNode {
 impl ExprIntervalGraphNode {
     /// Constructs a new DAEG node with an [-∞, ∞] range.
     pub fn new(expr: Arc<dyn PhysicalExpr>) -> Self {
-        ExprIntervalGraphNode {
-            expr,
-            interval: Interval::default(),
-        }
+        Self::new_with_interval(expr, Interval::default())
     }
 
     /// Constructs a new DAEG node with the given range.
     pub fn new_with_interval(expr: Arc<dyn PhysicalExpr>, interval: Interval) -> Self {
-        ExprIntervalGraphNode { expr, interval }
+        ExprIntervalGraphNode {
+            expr,
+            bounds: interval.clone(),
+            interval,
+            dirty: true,
+            propagated: false,
+        }
     }
 
     /// Get the interval object representing the range of the expression.
@@ -303,8 +326,40 @@ pub fn propagate_comparison(
     left_child: &Interval,
     right_child: &Interval,
 ) -> Result<(Option<Interval>, Option<Interval>)> {
+    // Timestamps with different time units are compared in the finer unit:
+    if let Some((left, right)) = align_timestamp_units(left_child, right_child)? {
+        let (left, right) = propagate_comparison(op, &left, &right)?;
+        let restore = |interval: Option<Interval>, original: &Interval| {
+            interval
+                .map(|interval| {
+                    restore_timestamp_unit(&interval, &original.get_datatype()?)
+                })
+                .transpose()
+        };
+        return Ok((restore(left, left_child)?, restore(right, right_child)?));
+    }
     let left_type = left_child.get_datatype()?;
     let right_type = right_child.get_datatype()?;
+    // Strings and binary values do not support arithmetic, so we can not use
+    // the inequality x - y > 0. Instead, we use the ordering of the values
+    // directly. The difference of two dates is not a date, so dates are
+    // handled likewise. The difference of two decimals has a wider precision
+    // than the decimals, so their ordering is used as well.
+    if matches!(
+        (&left_type, &right_type),
+        (DataType::Utf8, DataType::Utf8)
+            | (DataType::LargeUtf8, DataType::LargeUtf8)
+            | (DataType::Binary, DataType::Binary)
+            | (DataType::LargeBinary, DataType::LargeBinary)
+            | (DataType::Date32, DataType::Date32)
+            | (DataType::Date64, DataType::Date64)
+    ) || (matches!(
+        left_type,
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
+    ) && left_type == right_type)
+    {
+        return propagate_ordering(op, left_child, right_child);
+    }
     let parent = comparison_operator_target(&left_type, op, &right_type)?;
     match (&left_type, &right_type) {
         // We can not compare a Duration type with a time interval type
@@ -327,8 +382,305 @@ pub fn propagate_comparison(
     }
 }
 
+/// This function propagates constraints arising from comparison operators by
+/// directly using the ordering of the values, which is required for types that
+/// do not support arithmetic (e.g. strings). Assuming that x and y has ranges
+/// [xL, xU] and [yL, yU], for x > y we would first do
+///     - [xL, xU] <- (yL, ∞) ∩ [xL, xU], and then
+///     - [yL, yU] <- (-∞, xU) ∩ [yL, yU].
+fn propagate_ordering(
+    op: &Operator,
+    left_child: &Interval,
+    right_child: &Interval,
+) -> Result<(Option<Interval>, Option<Interval>)> {
+    let left_unbounded = IntervalBound::make_unbounded(left_child.get_datatype()?)?;
+    let right_unbounded = IntervalBound::make_unbounded(right_child.get_datatype()?)?;
+    // Returns the given bound, which is made open if the comparison is strict.
+    let bound = |bound: &IntervalBound| {
+        let strict = matches!(op, Operator::Gt | Operator::Lt);
+        IntervalBound::new(bound.value.clone(), bound.open || strict)
+    };
+    let (left_target, right_target) = match op {
+        Operator::Gt | Operator::GtEq => (
+            Interval::new(bound(&right_child.lower), left_unbounded),
+            Interval::new(right_unbounded, bound(&left_child.upper)),
+        ),
+        Operator::Lt | Operator::LtEq => (
+            Interval::new(left_unbounded, bound(&right_child.upper)),
+            Interval::new(bound(&left_child.lower), right_unbounded),
+        ),
+        Operator::Eq => (right_child.clone(), left_child.clone()),
+        _ => return internal_err!("Unsupported comparison operator {op}"),
+    };
+    match left_target.intersect(left_child)? {
+        Some(left) => Ok((Some(left), right_target.intersect(right_child)?)),
+        None => Ok((None, None)),
+    }
+}
+
+/// This function computes the interval of `CAST(child AS cast_type)` from the
+/// interval of `child`.
+///
+/// Casts between integer and floating point types preserve the order of values,
+/// so the bounds of `child` are cast individually. Bounds that overflow the
+/// range of `cast_type` are clamped to it, and open bounds are closed unless
+/// the cast maps distinct values to distinct values. Strings do not sort like
+/// the numbers they represent (e.g. `'10' < '9'`), so casting a string interval
+/// to a numeric type gives an unbounded interval unless it holds a single value.
+/// Other casts are applied to both bounds using `cast_options`.
+pub fn propagate_cast_forward(
+    child: &Interval,
+    cast_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<Interval> {
+    let child_type = child.get_datatype()?;
+    if is_order_preserving_cast(&child_type, cast_type) {
+        let injective = is_injective_cast(&child_type, cast_type);
+        let cast_bound = |bound: &IntervalBound| {
+            if bound.is_unbounded() {
+                return IntervalBound::make_unbounded(cast_type);
+            }
+            let (value, overflow) = cast_saturating(&bound.value, cast_type)?;
+            Ok(IntervalBound::new(
+                value,
+                bound.open && injective && overflow.is_eq(),
+            ))
+        };
+        Ok(Interval::new(
+            cast_bound(&child.lower)?,
+            cast_bound(&child.upper)?,
+        ))
+    } else if is_string_to_numeric_cast(&child_type, cast_type) {
+        let is_single_value = child.lower.value == child.upper.value
+            && !child.lower.open
+            && !child.upper.open;
+        if is_single_value {
+            // Values that can not be parsed become NULL, i.e. unbounded.
+            child.cast_to(cast_type, &SAFE_CAST_OPTIONS)
+        } else {
+            let unbounded = IntervalBound::make_unbounded(cast_type)?;
+            Ok(Interval::new(unbounded.clone(), unbounded))
+        }
+    } else {
+        child.cast_to(cast_type, cast_options)
+    }
+}
+
+/// This function refines the interval `child` of the child of a cast, given
+/// the interval `parent` of the casted value. It returns `None` if no value in
+/// `child` can be cast into `parent`.
+///
+/// For casts between integer and floating point types, the bounds of `parent`
+/// are cast back to the type of `child`, rounding so that no value satisfying
+/// the constraint is excluded. For example, `CAST(x AS DOUBLE) > 3.5` implies
+/// `x >= 4` for an integer `x`. When the cast itself loses precision, e.g. when
+/// a floating point value is truncated towards zero to an integer, the bounds
+/// are widened to cover every value that is cast into `parent`. Bounds beyond
+/// the range of the type of `child` are clamped to it. Constraints on numbers
+/// parsed from strings do not refine the string interval, and other casts cast
+/// `parent` to the type of `child` using `cast_options`.
+pub fn propagate_cast_backward(
+    parent: &Interval,
+    child: &Interval,
+    cast_options: &CastOptions,
+) -> Result<Option<Interval>> {
+    let child_type = child.get_datatype()?;
+    let cast_type = parent.get_datatype()?;
+    let target = if is_order_preserving_cast(&child_type, &cast_type) {
+        let inverse = InverseCast {
+            source_type: &child_type,
+            injective: is_injective_cast(&child_type, &cast_type),
+            truncating: child_type.is_floating() && cast_type.is_integer(),
+        };
+        let (Some(lower), Some(upper)) = (
+            inverse.cast_bound::<false>(&parent.lower)?,
+            inverse.cast_bound::<true>(&parent.upper)?,
+        ) else {
+            return Ok(None);
+        };
+        Interval::new(lower, upper)
+    } else if is_string_to_numeric_cast(&child_type, &cast_type) {
+        return Ok(Some(child.clone()));
+    } else {
+        parent.cast_to(&child_type, cast_options)?
+    };
+    target.intersect(child)
+}
+
+/// Cast options that produce NULL instead of an error for invalid values.
+const SAFE_CAST_OPTIONS: CastOptions<'static> = CastOptions {
+    safe: true,
+    format_options: DEFAULT_FORMAT_OPTIONS,
+};
+
+/// Indicates whether casting from `from` to `to` preserves the order of values,
+/// which holds for casts between integer and floating point types.
+fn is_order_preserving_cast(from: &DataType, to: &DataType) -> bool {
+    let is_ordered_numeric = |t: &DataType| t.is_integer() || t.is_floating();
+    is_ordered_numeric(from) && is_ordered_numeric(to)
+}
+
+/// Indicates whether casting from `from` to `to` maps distinct values to
+/// distinct values. This holds for casts between integer types, since values
+/// outside the range of `to` do not produce a result, and for casts that do not
+/// lose precision.
+fn is_injective_cast(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+    match to {
+        _ if from == to || (from.is_integer() && to.is_integer()) => true,
+        Float32 => matches!(from, Int8 | Int16 | UInt8 | UInt16),
+        Float64 => matches!(
+            from,
+            Int8 | Int16 | Int32 | UInt8 | UInt16 | UInt32 | Float32
+        ),
+        _ => false,
+    }
+}
+
+/// Indicates whether `from` -> `to` parses numbers from strings.
+fn is_string_to_numeric_cast(from: &DataType, to: &DataType) -> bool {
+    matches!(from, DataType::Utf8 | DataType::LargeUtf8) && to.is_numeric()
+}
+
+/// Casts `value` to the integer or floating point type `data_type`. Values
+/// outside the range of `data_type` are clamped to its minimum or maximum, in
+/// which case the returned ordering tells whether `value` is below (`Less`) or
+/// above (`Greater`) the range. Otherwise, the ordering is `Equal`.
+fn cast_saturating(
+    value: &ScalarValue,
+    data_type: &DataType,
+) -> Result<(ScalarValue, Ordering)> {
+    let result = cast_scalar_value(value, data_type, &SAFE_CAST_OPTIONS)?;
+    if !result.is_null() {
+        return Ok((result, Ordering::Equal));
+    }
+    let (min, max) = numeric_range(data_type)?;
+    if value > &ScalarValue::new_zero(&value.data_type())? {
+        Ok((max, Ordering::Greater))
+    } else {
+        Ok((min, Ordering::Less))
+    }
+}
+
+/// Returns the minimum and maximum values of the given integer or floating
+/// point type.
+fn numeric_range(data_type: &DataType) -> Result<(ScalarValue, ScalarValue)> {
+    macro_rules! range {
+        ($variant:ident, $min:expr, $max:expr) => {
+            (
+                ScalarValue::$variant(Some($min)),
+                ScalarValue::$variant(Some($max)),
+            )
+        };
+    }
+    Ok(match data_type {
+        DataType::Int8 => range!(Int8, i8::MIN, i8::MAX),
+        DataType::Int16 => range!(Int16, i16::MIN, i16::MAX),
+        DataType::Int32 => range!(Int32, i32::MIN, i32::MAX),
+        DataType::Int64 => range!(Int64, i64::MIN, i64::MAX),
+        DataType::UInt8 => range!(UInt8, u8::MIN, u8::MAX),
+        DataType::UInt16 => range!(UInt16, u16::MIN, u16::MAX),
+        DataType::UInt32 => range!(UInt32, u32::MIN, u32::MAX),
+        DataType::UInt64 => range!(UInt64, u64::MIN, u64::MAX),
+        DataType::Float32 => range!(Float32, f32::NEG_INFINITY, f32::INFINITY),
+        DataType::Float64 => range!(Float64, f64::NEG_INFINITY, f64::INFINITY),
+        _ => {
+            return internal_err!("{data_type} is not an integer or floating point type")
+        }
+    })
+}
+
+/// Casts interval bounds back to the type of the child of a cast.
+struct InverseCast<'a> {
+    /// The type of the child of the cast.
+    source_type: &'a DataType,
+    /// Whether the cast maps distinct values to distinct values.
+    injective: bool,
+    /// Whether the cast truncates floating point values towards zero.
+    truncating: bool,
+}
+
+impl InverseCast<'_> {
+    /// Returns a bound of the source type that includes every value whose cast
+    /// satisfies `bound`, which is a lower bound unless `UPPER` is set. Returns
+    /// `None` if there is no such value.
+    fn cast_bound<const UPPER: bool>(
+        &self,
+        bound: &IntervalBound,
+    ) -> Result<Option<IntervalBound>> {
+        let unbounded = IntervalBound::make_unbounded(self.source_type)?;
+        if bound.is_unbounded() {
+            return Ok(Some(unbounded));
+        }
+        // First, find a bound of the cast type that every such value satisfies:
+        let (value, open) = if self.injective {
+            (bound.value.clone(), bound.open)
+        } else if self.truncating {
+            // Only integers can satisfy the bound, so it can be closed:
+            let value = if bound.open {
+                if UPPER {
+                    next_value::<false>(bound.value.clone())
+                } else {
+                    next_value::<true>(bound.value.clone())
+                }
+            } else {
+                bound.value.clone()
+            };
+            // Values between zero and the bound truncate towards zero, so
+            // values within one of the bound away from zero satisfy it.
+            let zero = ScalarValue::new_zero(&value.data_type())?;
+            let away_from_zero = if UPPER { value >= zero } else { value <= zero };
+            if !away_from_zero {
+                (value, false)
+            } else {
+                let next = next_value::<UPPER>(value.clone());
+                if next == value {
+                    return Ok(Some(unbounded));
+                }
+                (next, true)
+            }
+        } else {
+            // Rounding to the nearest value maps only values beyond its
+            // neighbour to the bound or further from it:
+            (next_value::<UPPER>(bound.value.clone()), true)
+        };
+        // Then, cast this bound to the source type without excluding any value:
+        let (source_value, overflow) = cast_saturating(&value, self.source_type)?;
+        match (overflow, UPPER) {
+            (Ordering::Equal, _) => {}
+            (Ordering::Greater, false) | (Ordering::Less, true) => return Ok(None),
+            _ => return Ok(Some(unbounded)),
+        }
+        let (round_trip, overflow) = cast_saturating(&source_value, &value.data_type())?;
+        let ordering = if overflow.is_eq() {
+            round_trip.partial_cmp(&value)
+        } else {
+            Some(overflow)
+        };
+        // A rounded bound is open if it was rounded away from the values that
+        // satisfy it, and closed otherwise, since no value of the source type
+        // lies between the rounded and the original bound.
+        let open = match ordering {
+            Some(Ordering::Equal) => open,
+            Some(Ordering::Less) => !UPPER,
+            Some(Ordering::Greater) => UPPER,
+            None => return Ok(Some(unbounded)),
+        };
+        Ok(Some(IntervalBound::new(source_value, open)))
+    }
+}
+
 impl ExprIntervalGraph {
+    /// Builds the graph of `expr`. Negated comparisons are replaced by the
+    /// equivalent comparisons with inverted operators (see [`negate_comparison`]),
+    /// so nodes must be looked up by the rewritten expressions.
     pub fn try_new(expr: Arc<dyn PhysicalExpr>) -> Result<Self> {
+        let expr = expr.transform_up(&|expr| {
+            Ok(match negate_comparison(&expr) {
+                Some(negated) => Transformed::Yes(negated),
+                None => Transformed::No(expr),
+            })
+        })?;
         // Build the full graph:
         let (root, graph) = build_dag(expr, &ExprIntervalGraphNode::make_node)?;
         Ok(Self { graph, root })
@@ -423,6 +775,11 @@ impl ExprIntervalGraph {
         // Remove nodes not connected to the root node:
         self.graph
             .retain_nodes(|_, index| connected_nodes.contains(&index));
+        // The structure of the graph has changed, so all nodes have to be
+        // evaluated again:
+        for node in self.graph.node_weights_mut() {
+            node.dirty = true;
+        }
         expr_node_indices
     }
 
@@ -441,9 +798,31 @@ impl ExprIntervalGraph {
     /// The argument `assignments` associates indices of sought expressions
     /// with their corresponding new ranges.
     pub fn assign_intervals(&mut self, assignments: &[(usize, Interval)]) {
-        for (index, interval) in assignments {
+        self.reset_intervals(assignments)
+    }
+
+    /// Resets the ranges of the given (leaf) expressions, and marks them and
+    /// their ancestors as dirty so that a subsequent [`Self::propagate_dirty`]
+    /// only re-evaluates the affected parts of the DAEG. The argument `updates`
+    /// associates indices of expressions with their new ranges.
+    pub fn reset_intervals(&mut self, updates: &[(usize, Interval)]) {
+        let mut stack = vec![];
+        for (index, interval) in updates {
             let node_index = NodeIndex::from(*index as DefaultIx);
-            self.graph[node_index].interval = interval.clone();
+            let node = &mut self.graph[node_index];
+            node.interval = interval.clone();
+            node.bounds = interval.clone();
+            stack.push(node_index);
+        }
+        // Mark the ancestors as dirty. Since the ancestors of a dirty node are
+        // dirty as well, there is no need to look beyond a dirty ancestor.
+        while let Some(node) = stack.pop() {
+            self.graph[node].dirty = true;
+            for parent in self.graph.neighbors_directed(node, Incoming) {
+                if !self.graph[parent].dirty {
+                    stack.push(parent);
+                }
+            }
         }
     }
 
@@ -507,18 +886,63 @@ impl ExprIntervalGraph {
             if !children_intervals.is_empty() {
                 // Reverse to align with [PhysicalExpr]'s children:
                 children_intervals.reverse();
-                self.graph[node].interval =
+                let interval =
                     self.graph[node].expr.evaluate_bounds(&children_intervals)?;
+                self.graph[node].bounds = interval.clone();
+                self.graph[node].interval = interval;
             }
+            self.graph[node].dirty = false;
+            self.graph[node].propagated = false;
         }
         Ok(&self.graph[self.root].interval)
     }
 
+    /// Re-evaluates the bounds of the dirty expressions (see
+    /// [`Self::reset_intervals`]) via a bottom-up traversal that does not visit
+    /// any clean subgraphs, whose bounds are still up to date. The expressions
+    /// stay dirty until constraints are propagated to them.
+    fn evaluate_dirty_bounds(&mut self) -> Result<()> {
+        let mut stack = vec![(self.root, false)];
+        while let Some((node, children_done)) = stack.pop() {
+            if !self.graph[node].dirty {
+                continue;
+            }
+            if !children_done {
+                stack.push((node, true));
+                for child in self.graph.neighbors_directed(node, Outgoing) {
+                    stack.push((child, false));
+                }
+                continue;
+            }
+            let neighbors = self.graph.neighbors_directed(node, Outgoing);
+            let mut children_bounds = neighbors
+                .map(|child| &self.graph[child].bounds)
+                .collect::<Vec<_>>();
+            let bounds = if children_bounds.is_empty() {
+                self.graph[node].bounds.clone()
+            } else {
+                // Reverse to align with [PhysicalExpr]'s children:
+                children_bounds.reverse();
+                self.graph[node].expr.evaluate_bounds(&children_bounds)?
+            };
+            let node = &mut self.graph[node];
+            node.interval = bounds.clone();
+            node.bounds = bounds;
+        }
+        Ok(())
+    }
+
     /// Updates/shrinks bounds for leaf expressions using interval arithmetic
-    /// via a top-down traversal.
+    /// via a top-down traversal. The children of a disjunction are only
+    /// traversed if one of them is certainly false, since the other one must
+    /// then hold. Otherwise, either child may be false and no constraint can
+    /// be propagated through the disjunction. Likewise, the children of a
+    /// conjunction that does not hold, e.g. below a `NOT`, are only traversed
+    /// if one of them is certainly true. See [`constrained_children`].
     fn propagate_constraints(&mut self) -> Result<PropagationResult> {
-        let mut bfs = Bfs::new(&self.graph, self.root);
-        while let Some(node) = bfs.next(&self.graph) {
+        let mut queue = VecDeque::from([self.root]);
+        let mut discovered = HashSet::from([self.root]);
+        while let Some(node) = queue.pop_front() {
             let neighbors = self.graph.neighbors_directed(node, Outgoing);
             let mut children = neighbors.collect::<Vec<_>>();
             // If the current expression is a leaf, its range is now final.
@@ -533,21 +957,179 @@ impl ExprIntervalGraph {
                 .map(|child| self.graph[*child].interval())
                 .collect::<Vec<_>>();
             let node_interval = self.graph[node].interval();
+            let constrained_children = children
+                .iter()
+                .zip(constrained_children(
+                    &self.graph[node].expr,
+                    node_interval,
+                    &children_intervals,
+                ))
+                .filter_map(|(child, constrained)| constrained.then_some(*child))
+                .collect::<Vec<_>>();
             let propagated_intervals = self.graph[node]
                 .expr
                 .propagate_constraints(node_interval, &children_intervals)?;
+            self.graph[node].propagated = true;
             for (child, interval) in children.into_iter().zip(propagated_intervals) {
                 if let Some(interval) = interval {
                     self.graph[child].interval = interval;
                 } else {
-                    // The constraint is infeasible, report:
+                    // The constraint is infeasible, report. The propagation
+                    // stopped halfway, so the graph has to be evaluated again
+                    // from scratch:
+                    self.mark_all_dirty();
                     return Ok(PropagationResult::Infeasible);
                 }
             }
+            for child in constrained_children {
+                if discovered.insert(child) {
+                    queue.push_back(child);
+                }
+            }
+        }
+        Ok(PropagationResult::Success)
+    }
+
+    /// Updates the intervals of the expressions in the DAEG after the ranges of
+    /// some leaf expressions were changed by [`Self::reset_intervals`]. This has
+    /// the same effect as [`Self::update_ranges`] with the ranges of all leaf
+    /// expressions, but reuses the results of the previous call where possible:
+    /// Bounds are only evaluated again for the dirty expressions, and constraints
+    /// are only propagated to an expression if it is dirty or the range
+    /// propagated to it has changed. If the DAEG is not a tree, i.e. some
+    /// expressions have several parents, constraints are propagated to all
+    /// expressions.
+    ///
+    /// Use [`Self::get_interval`] to retrieve the updated ranges.
+    pub fn propagate_dirty(&mut self) -> Result<PropagationResult> {
+        let result = self.try_propagate_dirty();
+        if result.is_err() {
+            // The propagation stopped halfway, so the graph has to be
+            // evaluated again from scratch:
+            self.mark_all_dirty();
+        }
+        result
+    }
+
+    fn try_propagate_dirty(&mut self) -> Result<PropagationResult> {
+        self.evaluate_dirty_bounds()?;
+        let root_bounds = &self.graph[self.root].bounds;
+        let result = if root_bounds == &Interval::CERTAINLY_FALSE {
+            PropagationResult::Infeasible
+        } else if root_bounds != &Interval::UNCERTAIN {
+            PropagationResult::CannotPropagate
+        } else if self.graph.edge_count() + 1 != self.graph.node_count() {
+            // Constraints propagated through an expression with several parents
+            // depend on the order of propagation, so start from scratch:
+            for node in self.graph.node_weights_mut() {
+                node.interval = node.bounds.clone();
+                node.dirty = false;
+                node.propagated = false;
+            }
+            return self.propagate_constraints();
+        } else {
+            return self.propagate_tree_constraints();
+        };
+        // No constraints are propagated, so every expression is at its bounds:
+        self.reset_to_bounds(vec![self.root]);
+        Ok(result)
+    }
+
+    /// Propagates constraints from the root of a DAEG that is a tree, skipping
+    /// the subtrees that are not dirty and whose propagated ranges are the same
+    /// as in the last propagation. See [`Self::propagate_constraints`] for the
+    /// semantics of the propagation.
+    fn propagate_tree_constraints(&mut self) -> Result<PropagationResult> {
+        let mut stack = vec![];
+        let root = &mut self.graph[self.root];
+        if root.dirty || !root.propagated || root.interval != root.bounds {
+            root.interval = root.bounds.clone();
+            stack.push(self.root);
+        }
+        let mut detached = vec![];
+        while let Some(node) = stack.pop() {
+            self.graph[node].dirty = false;
+            self.graph[node].propagated = true;
+            let neighbors = self.graph.neighbors_directed(node, Outgoing);
+            let mut children = neighbors.collect::<Vec<_>>();
+            if children.is_empty() {
+                continue;
+            }
+            // Reverse to align with [PhysicalExpr]'s children:
+            children.reverse();
+            // In a tree, the ranges of the children are not constrained by any
+            // other expression, so they start at their bounds:
+            let children_bounds = children
+                .iter()
+                .map(|child| &self.graph[*child].bounds)
+                .collect::<Vec<_>>();
+            let constrained = constrained_children(
+                &self.graph[node].expr,
+                self.graph[node].interval(),
+                &children_bounds,
+            );
+            let mut propagated_intervals = self.graph[node]
+                .expr
+                .propagate_constraints(self.graph[node].interval(), &children_bounds)?
+                .into_iter();
+            for (child, constrained) in children.into_iter().zip(constrained) {
+                let interval = match propagated_intervals.next() {
+                    Some(Some(interval)) => interval,
+                    // An empty result does not change the ranges:
+                    None => self.graph[child].bounds.clone(),
+                    Some(None) => {
+                        // The constraint is infeasible, report. The propagation
+                        // stopped halfway, so the graph has to be evaluated
+                        // again from scratch:
+                        self.mark_all_dirty();
+                        return Ok(PropagationResult::Infeasible);
+                    }
+                };
+                let child_node = &mut self.graph[child];
+                if !constrained {
+                    // The range is not propagated any further, so the
+                    // descendants stay at their bounds:
+                    child_node.interval = interval;
+                    if child_node.dirty || child_node.propagated {
+                        child_node.dirty = false;
+                        child_node.propagated = false;
+                        detached.extend(self.graph.neighbors_directed(child, Outgoing));
+                    }
+                } else if child_node.dirty
+                    || !child_node.propagated
+                    || child_node.interval != interval
+                {
+                    child_node.interval = interval;
+                    stack.push(child);
+                }
+            }
         }
+        self.reset_to_bounds(detached);
         Ok(PropagationResult::Success)
     }
 
+    /// Restores the ranges of the given expressions and their descendants to
+    /// their bounds, undoing the effects of previous propagations.
+    fn reset_to_bounds(&mut self, mut stack: Vec<NodeIndex>) {
+        while let Some(node) = stack.pop() {
+            let node_weight = &mut self.graph[node];
+            node_weight.interval = node_weight.bounds.clone();
+            if node_weight.dirty || node_weight.propagated {
+                node_weight.dirty = false;
+                node_weight.propagated = false;
+                stack.extend(self.graph.neighbors_directed(node, Outgoing));
+            }
+        }
+    }
+
+    /// Marks all expressions in the DAEG as dirty, so that the next call to
+    /// [`Self::propagate_dirty`] starts from scratch.
+    fn mark_all_dirty(&mut self) {
+        for node in self.graph.node_weights_mut() {
+            node.dirty = true;
+        }
+    }
+
     /// Updates intervals for all expressions in the DAEG by successive
     /// bottom-up and top-down traversals.
     pub fn update_ranges(
@@ -567,10 +1149,121 @@ impl ExprIntervalGraph {
         }
     }
 
+    /// Replaces the leaf expressions for which `constant` returns a value with
+    /// literals holding that value, whose ranges are the single value. The
+    /// ancestors of the replaced expressions are marked as dirty (see
+    /// [`Self::reset_intervals`]). Returns the indices of the replaced
+    /// expressions, which keep their indices.
+    pub fn fold_leaves(
+        &mut self,
+        constant: impl Fn(&Arc<dyn PhysicalExpr>) -> Option<ScalarValue>,
+    ) -> Vec<usize> {
+        let leaves = self
+            .graph
+            .node_indices()
+            .filter(|node| {
+                self.graph
+                    .neighbors_directed(*node, Outgoing)
+                    .next()
+                    .is_none()
+            })
+            .collect::<Vec<_>>();
+        let mut updates = vec![];
+        for node in leaves {
+            let expr = &self.graph[node].expr;
+            if expr.as_any().is::<Literal>() {
+                continue;
+            }
+            let Some(value) = constant(expr) else {
+                continue;
+            };
+            let interval = Interval::new(
+                IntervalBound::new_closed(value.clone()),
+                IntervalBound::new_closed(value.clone()),
+            );
+            self.graph[node].expr = Arc::new(Literal::new(value));
+            updates.push((node.index(), interval));
+        }
+        self.reset_intervals(&updates);
+        updates.into_iter().map(|(index, _)| index).collect()
+    }
+
+    /// Evaluates the bounds of all expressions for the current ranges of the
+    /// leaf expressions, and returns the value of the expression if it is
+    /// certainly true or certainly false. In that case, no constraints can be
+    /// propagated, so [`Self::update_ranges`] is not needed.
+    pub fn try_evaluate_constant(&mut self) -> Result<Option<bool>> {
+        let bounds = self.evaluate_bounds()?;
+        Ok(if bounds == &Interval::CERTAINLY_TRUE {
+            Some(true)
+        } else if bounds == &Interval::CERTAINLY_FALSE {
+            Some(false)
+        } else {
+            None
+        })
+    }
+
     /// Returns the interval associated with the node at the given `index`.
     pub fn get_interval(&self, index: usize) -> Interval {
         self.graph[NodeIndex::new(index)].interval.clone()
     }
+
+    /// Returns a Graphviz DOT representation of the graph for debugging. Each
+    /// node is labeled with its expression and its current interval, and each
+    /// edge points from an expression to one of its children. Comparing the
+    /// output before and after [`Self::update_ranges`] shows how the intervals
+    /// were propagated.
+    pub fn to_dot(&self) -> String {
+        self.to_dot_with_highlight(&[])
+    }
+
+    /// Like [`Self::to_dot`], but colors the nodes with the given `nodes`
+    /// indices red, e.g. to mark the nodes whose intervals have changed.
+    pub fn to_dot_with_highlight(&self, nodes: &[usize]) -> String {
+        struct Wrapper<'a> {
+            graph: &'a StableGraph<ExprIntervalGraphNode, usize>,
+            highlight: &'a [usize],
+        }
+
+        impl<'a> Display for Wrapper<'a> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let mut builder = GraphvizBuilder::default();
+                builder.start_graph(f)?;
+                for index in self.graph.node_indices() {
+                    let node = &self.graph[index];
+                    let label = format!("{}\\n{}", node.expr, node.interval);
+                    let color = if self.highlight.contains(&index.index()) {
+                        ", color=red"
+                    } else {
+                        ""
+                    };
+                    writeln!(
+                        f,
+                        "    {}[shape=box label={}{color}]",
+                        index.index(),
+                        GraphvizBuilder::quoted(&label),
+                    )?;
+                }
+                for index in self.graph.node_indices() {
+                    // Neighbors are listed in the reverse order of insertion:
+                    let children = self
+                        .graph
+                        .neighbors_directed(index, Outgoing)
+                        .collect::<Vec<_>>();
+                    for child in children.into_iter().rev() {
+                        builder.add_edge(f, index.index(), child.index())?;
+                    }
+                }
+                builder.end_graph(f)
+            }
+        }
+
+        Wrapper {
+            graph: &self.graph,
+            highlight: nodes,
+        }
+        .to_string()
+    }
 }
 
 /// During the propagation of [`Interval`] values on an [`ExprIntervalGraph`], if there exists a `timestamp - timestamp`
@@ -651,6 +1344,37 @@ fn propagate_right(
     .intersect(right)
 }
 
+/// Returns, for each child of `expr`, whether the constraint that `expr` lies
+/// within `interval` constrains the child, given the intervals of the children
+/// (`children_intervals`). Uncertain expressions are assumed to hold, so:
+/// - A child of a disjunction that holds is only constrained if the other
+///   child is certainly false, since it must then hold itself.
+/// - A child of a conjunction that is certainly false is only constrained if
+///   the other child is certainly true, since it must then be false itself.
+///
+/// The children of all other expressions are always constrained.
+fn constrained_children(
+    expr: &Arc<dyn PhysicalExpr>,
+    interval: &Interval,
+    children_intervals: &[&Interval],
+) -> Vec<bool> {
+    let other_must_be = match expr.as_any().downcast_ref::<BinaryExpr>() {
+        Some(binary) if binary.op() == &Operator::Or => {
+            (interval != &Interval::CERTAINLY_FALSE).then_some(Interval::CERTAINLY_FALSE)
+        }
+        Some(binary) if binary.op() == &Operator::And => {
+            (interval == &Interval::CERTAINLY_FALSE).then_some(Interval::CERTAINLY_TRUE)
+        }
+        _ => None,
+    };
+    match other_must_be {
+        Some(other_must_be) => (0..children_intervals.len())
+            .map(|idx| children_intervals[1 - idx] == &other_must_be)
+            .collect(),
+        None => vec![true; children_intervals.len()],
+    }
+}
+
 /// Converts the `time interval` (as the left child) to duration, then performs the propagation rule for comparison operators.
 pub fn propagate_comparison_to_time_interval_at_left(
     left_child: &Interval,
@@ -686,7 +1410,7 @@ mod tests {
     use super::*;
     use itertools::Itertools;
 
-    use crate::expressions::{BinaryExpr, Column};
+    use crate::expressions::{BinaryExpr, CastExpr, Column, InListExpr, NotExpr};
     use crate::intervals::test_utils::gen_conjunctive_numerical_expr;
     use arrow::datatypes::TimeUnit;
     use datafusion_common::ScalarValue;
@@ -1209,6 +1933,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_to_dot() -> Result<()> {
+        // Expression: a@0 > 10
+        let expr = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new("a", 0)),
+            Operator::Gt,
+            Arc::new(Literal::new(ScalarValue::Int64(Some(10)))),
+        ));
+        let mut graph = ExprIntervalGraph::try_new(expr)?;
+        let expected = [
+            "digraph {",
+            r#"    0[shape=box label="a@0\n(NULL, NULL)"]"#,
+            r#"    1[shape=box label="10\n[10, 10]"]"#,
+            r#"    2[shape=box label="a@0 > 10\n(NULL, NULL)"]"#,
+            "    2 -> 0 [arrowhead=none, arrowtail=normal, dir=back]",
+            "    2 -> 1 [arrowhead=none, arrowtail=normal, dir=back]",
+            "}",
+        ];
+        assert!(graph.to_dot().contains(&expected.join("\n")));
+
+        let column: Arc<dyn PhysicalExpr> = Arc::new(Column::new("a", 0));
+        let index = graph.gather_node_indices(&[column])[0].1;
+        graph.update_ranges(&mut [(
+            index,
+            Interval::make(Some(0_i64), Some(20_i64), (false, false)),
+        )])?;
+        let dot = graph.to_dot_with_highlight(&[index]);
+        assert!(dot.contains(r#"    0[shape=box label="a@0\n(10, 20]", color=red]"#));
+        assert!(dot.contains(r#"    2[shape=box label="a@0 > 10\n[false, true]"]"#));
+
+        Ok(())
+    }
+
     #[test]
     fn test_gather_node_indices_cannot_provide() -> Result<()> {
         // Expression: a@0 + 1 + b@1 > y@0 - z@1 -> provide a@0 + b@1
@@ -1545,4 +2302,538 @@ mod tests {
             propagate_comparison(&Operator::Lt, &left, &right).unwrap()
         );
     }
+
+    #[test]
+    fn test_propagate_comparison_strings() -> Result<()> {
+        // left: ["apple", "melon"], right: ["banana", "zucchini"]
+        let left = Interval::make(Some("apple"), Some("melon"), (false, false));
+        let right = Interval::make(Some("banana"), Some("zucchini"), (false, false));
+
+        // left > right
+        assert_eq!(
+            propagate_comparison(&Operator::Gt, &left, &right)?,
+            (
+                Some(Interval::make(Some("banana"), Some("melon"), (true, false))),
+                Some(Interval::make(Some("banana"), Some("melon"), (false, true))),
+            )
+        );
+        // left <= right
+        assert_eq!(
+            propagate_comparison(&Operator::LtEq, &left, &right)?,
+            (Some(left.clone()), Some(right.clone()))
+        );
+        // left = right
+        assert_eq!(
+            propagate_comparison(&Operator::Eq, &left, &right)?,
+            (
+                Some(Interval::make(
+                    Some("banana"),
+                    Some("melon"),
+                    (false, false)
+                )),
+                Some(Interval::make(
+                    Some("banana"),
+                    Some("melon"),
+                    (false, false)
+                )),
+            )
+        );
+        // left >= "zz" is infeasible
+        let literal = Interval::make(Some("zz"), Some("zz"), (false, false));
+        assert_eq!(
+            propagate_comparison(&Operator::GtEq, &left, &literal)?,
+            (None, None)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagate_comparison_binary() -> Result<()> {
+        let binary = |lower: &[u8], upper: &[u8], open: (bool, bool)| {
+            Interval::new(
+                IntervalBound::new(ScalarValue::Binary(Some(lower.to_vec())), open.0),
+                IntervalBound::new(ScalarValue::Binary(Some(upper.to_vec())), open.1),
+            )
+        };
+        let left = binary(&[1], &[5], (false, false));
+        let right = binary(&[3], &[9], (false, false));
+
+        // left > right
+        assert_eq!(
+            propagate_comparison(&Operator::Gt, &left, &right)?,
+            (
+                Some(binary(&[3], &[5], (true, false))),
+                Some(binary(&[3], &[5], (false, true))),
+            )
+        );
+        // left >= [10] is infeasible
+        let literal = binary(&[10], &[10], (false, false));
+        assert_eq!(
+            propagate_comparison(&Operator::GtEq, &left, &literal)?,
+            (None, None)
+        );
+        // Other operators are not comparisons
+        assert!(propagate_comparison(&Operator::Plus, &left, &right).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagate_disjunction() -> Result<()> {
+        // Expression: a@0 > 10 OR b@1 < 5
+        let a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let expr = Arc::new(BinaryExpr::new(
+            Arc::new(BinaryExpr::new(
+                a.clone(),
+                Operator::Gt,
+                Arc::new(Literal::new(ScalarValue::Int64(Some(10)))),
+            )),
+            Operator::Or,
+            Arc::new(BinaryExpr::new(
+                b.clone(),
+                Operator::Lt,
+                Arc::new(Literal::new(ScalarValue::Int64(Some(5)))),
+            )),
+        ));
+
+        // Both branches may hold, so neither column can be shrunk.
+        let interval = Interval::make(Some(0_i64), Some(100_i64), (false, false));
+        experiment(
+            expr.clone(),
+            (a.clone(), b.clone()),
+            interval.clone(),
+            interval.clone(),
+            interval.clone(),
+            interval.clone(),
+            PropagationResult::Success,
+        )?;
+
+        // The left branch is certainly false, so the right branch must hold.
+        let a_interval = Interval::make(Some(0_i64), Some(8_i64), (false, false));
+        experiment(
+            expr.clone(),
+            (a.clone(), b.clone()),
+            a_interval.clone(),
+            interval.clone(),
+            a_interval,
+            Interval::make(Some(0_i64), Some(5_i64), (false, true)),
+            PropagationResult::Success,
+        )?;
+
+        // Neither branch can hold.
+        experiment(
+            expr,
+            (a, b),
+            Interval::make(Some(0_i64), Some(8_i64), (false, false)),
+            Interval::make(Some(20_i64), Some(100_i64), (false, false)),
+            Interval::make(Some(0_i64), Some(8_i64), (false, false)),
+            Interval::make(Some(20_i64), Some(100_i64), (false, false)),
+            PropagationResult::Infeasible,
+        )
+    }
+
+    #[test]
+    fn test_propagate_negation() -> Result<()> {
+        let a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let compare = |column: &Arc<dyn PhysicalExpr>, op: Operator, value: i64| {
+            Arc::new(BinaryExpr::new(
+                column.clone(),
+                op,
+                Arc::new(Literal::new(ScalarValue::Int64(Some(value)))),
+            )) as Arc<dyn PhysicalExpr>
+        };
+        // Expression: NOT (a@0 > 10 <op> b@1 < 5)
+        let negation = |op: Operator| {
+            Arc::new(NotExpr::new(Arc::new(BinaryExpr::new(
+                compare(&a, Operator::Gt, 10),
+                op,
+                compare(&b, Operator::Lt, 5),
+            )))) as Arc<dyn PhysicalExpr>
+        };
+        let propagate = |expr: Arc<dyn PhysicalExpr>,
+                         a_interval: Interval,
+                         b_interval: Interval|
+         -> Result<(PropagationResult, Vec<Interval>)> {
+            let mut graph = ExprIntervalGraph::try_new(expr)?;
+            let mut leaves = graph
+                .gather_node_indices(&[a.clone(), b.clone()])
+                .into_iter()
+                .zip([a_interval, b_interval])
+                .map(|((_, index), interval)| (index, interval))
+                .collect_vec();
+            let result = graph.update_ranges(&mut leaves)?;
+            Ok((result, leaves.into_iter().map(|(_, i)| i).collect()))
+        };
+        let interval = Interval::make(Some(0_i64), Some(100_i64), (false, false));
+
+        // Neither branch of the disjunction may hold.
+        let (result, intervals) =
+            propagate(negation(Operator::Or), interval.clone(), interval.clone())?;
+        assert_eq!(result, PropagationResult::Success);
+        assert_eq!(
+            intervals,
+            vec![
+                Interval::make(Some(0_i64), Some(10_i64), (false, false)),
+                Interval::make(Some(5_i64), Some(100_i64), (false, false)),
+            ]
+        );
+        let (result, _) = propagate(
+            negation(Operator::Or),
+            Interval::make(Some(20_i64), Some(100_i64), (false, false)),
+            interval.clone(),
+        )?;
+        assert_eq!(result, PropagationResult::Infeasible);
+
+        // Either branch of the conjunction may be false, so neither column can
+        // be shrunk.
+        let (result, intervals) =
+            propagate(negation(Operator::And), interval.clone(), interval.clone())?;
+        assert_eq!(result, PropagationResult::Success);
+        assert_eq!(intervals, vec![interval.clone(), interval.clone()]);
+
+        // The right branch is certainly true, so the left branch must be false.
+        let b_interval = Interval::make(Some(0_i64), Some(4_i64), (false, false));
+        let (result, intervals) =
+            propagate(negation(Operator::And), interval, b_interval.clone())?;
+        assert_eq!(result, PropagationResult::Success);
+        assert_eq!(
+            intervals,
+            vec![
+                Interval::make(Some(0_i64), Some(10_i64), (false, false)),
+                b_interval,
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagate_in_list() -> Result<()> {
+        // Expression: a@0 IN (10, 20) AND b@1 [NOT] IN (1, 3, 9)
+        let a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let list = |values: &[i64]| {
+            values
+                .iter()
+                .map(|v| {
+                    Arc::new(Literal::new(ScalarValue::Int64(Some(*v))))
+                        as Arc<dyn PhysicalExpr>
+                })
+                .collect::<Vec<_>>()
+        };
+        let expr = |negated: bool| {
+            Arc::new(BinaryExpr::new(
+                Arc::new(InListExpr::new(a.clone(), list(&[10, 20]), false, None)),
+                Operator::And,
+                Arc::new(InListExpr::new(b.clone(), list(&[1, 3, 9]), negated, None)),
+            )) as Arc<dyn PhysicalExpr>
+        };
+
+        // Each column shrinks to the list items it may be equal to.
+        experiment(
+            expr(false),
+            (a.clone(), b.clone()),
+            Interval::make(Some(0_i64), Some(15_i64), (false, false)),
+            Interval::make(Some(0_i64), Some(5_i64), (false, false)),
+            Interval::make(Some(10_i64), Some(10_i64), (false, false)),
+            Interval::make(Some(1_i64), Some(3_i64), (false, false)),
+            PropagationResult::Success,
+        )?;
+
+        // A negated list does not shrink its column.
+        experiment(
+            expr(true),
+            (a.clone(), b.clone()),
+            Interval::make(Some(0_i64), Some(15_i64), (false, false)),
+            Interval::make(Some(0_i64), Some(5_i64), (false, false)),
+            Interval::make(Some(10_i64), Some(10_i64), (false, false)),
+            Interval::make(Some(0_i64), Some(5_i64), (false, false)),
+            PropagationResult::Success,
+        )?;
+
+        // No list item is within the interval of `a`.
+        experiment(
+            expr(false),
+            (a, b),
+            Interval::make(Some(30_i64), Some(40_i64), (false, false)),
+            Interval::make(Some(0_i64), Some(5_i64), (false, false)),
+            Interval::make(Some(30_i64), Some(40_i64), (false, false)),
+            Interval::make(Some(0_i64), Some(5_i64), (false, false)),
+            PropagationResult::Infeasible,
+        )
+    }
+
+    #[test]
+    fn test_propagate_cast_forward() -> Result<()> {
+        let options = CastOptions::default();
+        // Widening keeps the bounds, including open ones.
+        assert_eq!(
+            propagate_cast_forward(
+                &Interval::make(Some(1_i32), Some(10_i32), (true, false)),
+                &DataType::Float64,
+                &options
+            )?,
+            Interval::make(Some(1_f64), Some(10_f64), (true, false))
+        );
+        // Truncation may map values next to an open bound onto it.
+        assert_eq!(
+            propagate_cast_forward(
+                &Interval::make(Some(-3.5_f64), Some(3.5_f64), (true, true)),
+                &DataType::Int64,
+                &options
+            )?,
+            Interval::make(Some(-3_i64), Some(3_i64), (false, false))
+        );
+        // Narrowing clamps overflowing bounds.
+        assert_eq!(
+            propagate_cast_forward(
+                &Interval::make(Some(-1_i64), Some(1_i64 << 40), (false, true)),
+                &DataType::UInt32,
+                &options
+            )?,
+            Interval::make(Some(0_u32), Some(u32::MAX), (false, false))
+        );
+        assert_eq!(
+            propagate_cast_forward(
+                &Interval::make(Some(0_f64), Some(1e300), (false, false)),
+                &DataType::Int32,
+                &options
+            )?,
+            Interval::make(Some(0_i32), Some(i32::MAX), (false, false))
+        );
+        assert_eq!(
+            propagate_cast_forward(
+                &Interval::make::<i64>(None, Some(5), (false, false)),
+                &DataType::Int8,
+                &options
+            )?,
+            Interval::make(None, Some(5_i8), (true, false))
+        );
+        // Strings do not sort like numbers.
+        assert_eq!(
+            propagate_cast_forward(
+                &Interval::make(Some("10"), Some("9"), (false, false)),
+                &DataType::Int64,
+                &options
+            )?,
+            Interval::make::<i64>(None, None, (true, true))
+        );
+        assert_eq!(
+            propagate_cast_forward(
+                &Interval::make(Some("42"), Some("42"), (false, false)),
+                &DataType::Int64,
+                &options
+            )?,
+            Interval::make(Some(42_i64), Some(42_i64), (false, false))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagate_cast_backward() -> Result<()> {
+        let options = CastOptions::default();
+        let int_child = Interval::make(Some(-100_i64), Some(100_i64), (false, false));
+        // CAST(x AS DOUBLE) > 3.5 implies x > 3, and < -3.5 implies x < -3.
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(Some(3.5_f64), None, (true, false)),
+                &int_child,
+                &options
+            )?,
+            Some(Interval::make(Some(3_i64), Some(100_i64), (true, false)))
+        );
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(None, Some(-3.5_f64), (false, true)),
+                &int_child,
+                &options
+            )?,
+            Some(Interval::make(Some(-100_i64), Some(-3_i64), (false, true)))
+        );
+        // CAST(x AS DOUBLE) in [-3.5, 3.5] implies x in [-3, 3].
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(Some(-3.5_f64), Some(3.5_f64), (false, false)),
+                &int_child,
+                &options
+            )?,
+            Some(Interval::make(Some(-3_i64), Some(3_i64), (false, false)))
+        );
+
+        // Casting a double to an integer truncates towards zero, so
+        // CAST(x AS BIGINT) in [-3, 3] implies x in (-4, 4).
+        let float_child = Interval::make(Some(-100_f64), Some(100_f64), (false, false));
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(Some(-3_i64), Some(3_i64), (false, false)),
+                &float_child,
+                &options
+            )?,
+            Some(Interval::make(Some(-4_f64), Some(4_f64), (true, true)))
+        );
+        // CAST(x AS BIGINT) > 3 implies x >= 4, and < -3 implies x <= -4.
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(Some(3_i64), None, (true, false)),
+                &float_child,
+                &options
+            )?,
+            Some(Interval::make(Some(4_f64), Some(100_f64), (false, false)))
+        );
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(None, Some(-3_i64), (false, true)),
+                &float_child,
+                &options
+            )?,
+            Some(Interval::make(Some(-100_f64), Some(-4_f64), (false, false)))
+        );
+
+        // Bounds beyond the range of the child type are clamped, or make the
+        // constraint infeasible.
+        let small_child = Interval::make(Some(0_i8), Some(10_i8), (false, false));
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(Some(-1000_i64), Some(5_i64), (false, false)),
+                &small_child,
+                &options
+            )?,
+            Some(Interval::make(Some(0_i8), Some(5_i8), (false, false)))
+        );
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(Some(1000_i64), None, (false, false)),
+                &small_child,
+                &options
+            )?,
+            None
+        );
+
+        // Constraints on numbers parsed from strings do not refine the strings.
+        let string_child = Interval::make(Some("1"), Some("9"), (false, false));
+        assert_eq!(
+            propagate_cast_backward(
+                &Interval::make(Some(5_i64), None, (false, false)),
+                &string_child,
+                &options
+            )?,
+            Some(string_child)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagate_through_cast() -> Result<()> {
+        // Expression: CAST(a@0 AS Float64) > 3.5 AND CAST(b@1 AS Int64) < -3
+        let a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let expr = Arc::new(BinaryExpr::new(
+            Arc::new(BinaryExpr::new(
+                Arc::new(CastExpr::new(a.clone(), DataType::Float64, None)),
+                Operator::Gt,
+                Arc::new(Literal::new(ScalarValue::Float64(Some(3.5)))),
+            )),
+            Operator::And,
+            Arc::new(BinaryExpr::new(
+                Arc::new(CastExpr::new(b.clone(), DataType::Int64, None)),
+                Operator::Lt,
+                Arc::new(Literal::new(ScalarValue::Int64(Some(-3)))),
+            )),
+        ));
+        experiment(
+            expr,
+            (a, b),
+            Interval::make(Some(0_i32), Some(10_i32), (false, false)),
+            Interval::make(Some(-10_f64), Some(10_f64), (false, false)),
+            Interval::make(Some(3_i32), Some(10_i32), (true, false)),
+            Interval::make(Some(-10_f64), Some(-4_f64), (false, false)),
+            PropagationResult::Success,
+        )
+    }
+
+    #[rstest]
+    // Expression: (a@0 > 10 AND b@1 + c@2 < 50) AND (d@3 < 5 OR e@4 > 90)
+    #[case::tree(false)]
+    // Expression: (a@0 > 10 AND b@1 + c@2 < 50) AND (d@3 < 5 OR a@0 > 90)
+    #[case::dag(true)]
+    fn test_propagate_dirty(#[case] shared_column: bool) -> Result<()> {
+        let columns = ["a", "b", "c", "d", "e"]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| Arc::new(Column::new(name, idx)) as Arc<dyn PhysicalExpr>)
+            .collect::<Vec<_>>();
+        let binary = |left, op, right| {
+            Arc::new(BinaryExpr::new(left, op, right)) as Arc<dyn PhysicalExpr>
+        };
+        let lit = |value: i64| {
+            Arc::new(Literal::new(ScalarValue::Int64(Some(value))))
+                as Arc<dyn PhysicalExpr>
+        };
+        let last = if shared_column { 0 } else { 4 };
+        let expr = binary(
+            binary(
+                binary(columns[0].clone(), Operator::Gt, lit(10)),
+                Operator::And,
+                binary(
+                    binary(columns[1].clone(), Operator::Plus, columns[2].clone()),
+                    Operator::Lt,
+                    lit(50),
+                ),
+            ),
+            Operator::And,
+            binary(
+                binary(columns[3].clone(), Operator::Lt, lit(5)),
+                Operator::Or,
+                binary(columns[last].clone(), Operator::Gt, lit(90)),
+            ),
+        );
+        let columns = &columns[..if shared_column { 4 } else { 5 }];
+
+        let mut graph = ExprIntervalGraph::try_new(expr.clone())?;
+        let indices = graph
+            .gather_node_indices(columns)
+            .into_iter()
+            .map(|(_, index)| index)
+            .collect::<Vec<_>>();
+        let mut intervals =
+            vec![
+                Interval::make(Some(0_i64), Some(100_i64), (false, false));
+                columns.len()
+            ];
+        graph.reset_intervals(
+            &indices.iter().cloned().zip(intervals.clone()).collect_vec(),
+        );
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..500 {
+            // Change the range of a random column:
+            let column = rng.gen_range(0..columns.len());
+            let lower = rng.gen_range(0..100_i64);
+            let upper = rng.gen_range(lower..=100_i64);
+            intervals[column] = Interval::make(Some(lower), Some(upper), (false, false));
+            graph.reset_intervals(&[(indices[column], intervals[column].clone())]);
+            let result = graph.propagate_dirty()?;
+
+            // Compare with the results of a graph built from scratch:
+            let mut expected_graph = ExprIntervalGraph::try_new(expr.clone())?;
+            let expected_indices = expected_graph.gather_node_indices(columns);
+            let mut expected_intervals = expected_indices
+                .iter()
+                .zip(&intervals)
+                .map(|((_, index), interval)| (*index, interval.clone()))
+                .collect_vec();
+            let expected_result =
+                expected_graph.update_ranges(&mut expected_intervals)?;
+            assert_eq!(result, expected_result);
+            if result == PropagationResult::Infeasible {
+                continue;
+            }
+            for (index, (_, expected)) in indices.iter().zip(&expected_intervals) {
+                assert_eq!(&graph.get_interval(*index), expected);
+            }
+        }
+        Ok(())
+    }
 }
@@ -18,6 +18,7 @@
 //! Interval arithmetic library
 
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::fmt::{self, Display, Formatter};
 use std::ops::{AddAssign, SubAssign};
 
@@ -25,8 +26,10 @@ use crate::aggregate::min_max::{max, min};
 use crate::intervals::rounding::{alter_fp_rounding_mode, next_down, next_up};
 
 use arrow::compute::{cast_with_options, CastOptions};
-use arrow::datatypes::DataType;
+use arrow::datatypes::{DataType, TimeUnit};
+use arrow::temporal_conversions::MILLISECONDS_IN_DAY;
 use arrow_array::ArrowNativeTypeOp;
+use arrow_buffer::i256;
 use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::type_coercion::binary::get_result_type;
 use datafusion_expr::Operator;
@@ -35,6 +38,7 @@ use datafusion_expr::Operator;
 /// endpoint can be open (does not include the endpoint) or closed
 /// (includes the endpoint).
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntervalBound {
     pub value: ScalarValue,
     /// If true, interval does not include `value`
@@ -173,6 +177,32 @@ impl IntervalBound {
             IntervalBound::new(second.value.clone(), first.open || second.open)
         })
     }
+
+    /// This function chooses the outer one of the given `IntervalBound`s
+    /// according to the given function `decide`, which is the counterpart of
+    /// [`IntervalBound::choose`] for unions. The result is unbounded if either
+    /// argument is; otherwise, the function `decide` is used. If both bounds
+    /// have the same value, the result is open only if both arguments are.
+    fn choose_outer(
+        first: &IntervalBound,
+        second: &IntervalBound,
+        decide: fn(&ScalarValue, &ScalarValue) -> Result<ScalarValue>,
+    ) -> Result<IntervalBound> {
+        Ok(if first.is_unbounded() {
+            first.clone()
+        } else if second.is_unbounded() {
+            second.clone()
+        } else if first.value != second.value {
+            let chosen = decide(&first.value, &second.value)?;
+            if chosen.eq(&first.value) {
+                first.clone()
+            } else {
+                second.clone()
+            }
+        } else {
+            IntervalBound::new(second.value.clone(), first.open && second.open)
+        })
+    }
 }
 
 impl Display for IntervalBound {
@@ -205,6 +235,7 @@ impl Display for IntervalBound {
 /// An `Interval` of `(-∞, ∞)` represents that the range is entirely unknown.
 ///
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Interval {
     pub lower: IntervalBound,
     pub upper: IntervalBound,
@@ -288,6 +319,34 @@ impl Interval {
         )
     }
 
+    /// Creates the interval of all strings starting with the given (`Utf8` or
+    /// `LargeUtf8`) prefix. The upper bound is obtained by incrementing the last
+    /// character of the prefix, which is the same trick Parquet uses for prefix
+    /// matching. For example, the prefix `foo` results in `["foo", "fop")`. If
+    /// no such string exists (e.g. for an empty prefix), the interval is
+    /// unbounded from above.
+    pub fn make_prefix(prefix: &ScalarValue) -> Result<Interval> {
+        let (lower, upper) = match prefix {
+            ScalarValue::Utf8(Some(prefix)) => (
+                ScalarValue::Utf8(Some(prefix.clone())),
+                ScalarValue::Utf8(next_prefix(prefix)),
+            ),
+            ScalarValue::LargeUtf8(Some(prefix)) => (
+                ScalarValue::LargeUtf8(Some(prefix.clone())),
+                ScalarValue::LargeUtf8(next_prefix(prefix)),
+            ),
+            _ => {
+                return internal_err!(
+                    "Prefix intervals can only be created from non-null strings, got {prefix:?}"
+                )
+            }
+        };
+        Ok(Interval::new(
+            IntervalBound::new_closed(lower),
+            IntervalBound::new_open(upper),
+        ))
+    }
+
     /// Casts this interval to `data_type` using `cast_options`.
     pub(crate) fn cast_to(
         &self,
@@ -432,6 +491,33 @@ impl Interval {
         }
     }
 
+    /// Compute the logical disjunction of this (boolean) interval with the given boolean interval.
+    pub(crate) fn or<T: Borrow<Interval>>(&self, other: T) -> Result<Interval> {
+        let rhs = other.borrow();
+        match (
+            &self.lower.value,
+            &self.upper.value,
+            &rhs.lower.value,
+            &rhs.upper.value,
+        ) {
+            (
+                ScalarValue::Boolean(Some(self_lower)),
+                ScalarValue::Boolean(Some(self_upper)),
+                ScalarValue::Boolean(Some(other_lower)),
+                ScalarValue::Boolean(Some(other_upper)),
+            ) => {
+                let lower = *self_lower || *other_lower;
+                let upper = *self_upper || *other_upper;
+
+                Ok(Interval {
+                    lower: IntervalBound::new(ScalarValue::Boolean(Some(lower)), false),
+                    upper: IntervalBound::new(ScalarValue::Boolean(Some(upper)), false),
+                })
+            }
+            _ => internal_err!("Incompatible types for logical disjunction"),
+        }
+    }
+
     /// Compute the logical negation of this (boolean) interval.
     pub(crate) fn not(&self) -> Result<Self> {
         if !matches!(self.get_datatype()?, DataType::Boolean) {
@@ -448,12 +534,37 @@ impl Interval {
         }
     }
 
-    /// Compute the intersection of the interval with the given interval.
-    /// If the intersection is empty, return None.
-    pub(crate) fn intersect<T: Borrow<Interval>>(
-        &self,
-        other: T,
-    ) -> Result<Option<Interval>> {
+    /// Returns whether this interval contains no values, i.e. its lower bound
+    /// is greater than its upper bound, or equal to it while one of them is
+    /// open.
+    pub fn is_empty(&self) -> bool {
+        let Interval { lower, upper } = self;
+        match lower.value.partial_cmp(&upper.value) {
+            _ if lower.is_unbounded() || upper.is_unbounded() => false,
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Equal) => lower.open || upper.open,
+            _ => false,
+        }
+    }
+
+    /// Returns the value of this interval if it is a closed interval holding
+    /// a single value.
+    fn single_value(&self) -> Option<&ScalarValue> {
+        let Interval { lower, upper } = self;
+        (!lower.is_unbounded()
+            && !lower.open
+            && !upper.open
+            && lower.value == upper.value)
+            .then_some(&lower.value)
+    }
+
+    /// Compute the intersection of the interval with the given interval, i.e.
+    /// the largest interval contained in both. The result takes the tighter
+    /// of the two bounds on each side; if both bounds have the same value, the
+    /// result is open if either of them is. If the intersection is empty,
+    /// e.g. because the intervals only share an endpoint that one of them
+    /// excludes, return None.
+    pub fn intersect<T: Borrow<Interval>>(&self, other: T) -> Result<Option<Interval>> {
         let rhs = other.borrow();
         // If it is evident that the result is an empty interval,
         // do not make any calculation and directly return None.
@@ -478,6 +589,19 @@ impl Interval {
         Ok(non_empty.then_some(Interval::new(lower, upper)))
     }
 
+    /// Compute the union of the interval with the given interval, i.e. the
+    /// smallest interval containing both. The result takes the looser of the
+    /// two bounds on each side; if both bounds have the same value, the result
+    /// is open only if both of them are. The result is unbounded from a side
+    /// if either interval is. Since an interval can not have gaps, the union
+    /// of disjoint intervals also contains the values between them.
+    pub fn union<T: Borrow<Interval>>(&self, other: T) -> Result<Interval> {
+        let rhs = other.borrow();
+        let lower = IntervalBound::choose_outer(&self.lower, &rhs.lower, min)?;
+        let upper = IntervalBound::choose_outer(&self.upper, &rhs.upper, max)?;
+        Ok(Interval::new(lower, upper))
+    }
+
     /// Decide if this interval is certainly contains, possibly contains,
     /// or can't can't `other` by returning [true, true],
     /// [false, true] or [false, false] respectively.
@@ -519,6 +643,56 @@ impl Interval {
         ))
     }
 
+    /// Multiply this interval with the given interval (`other`). Say we have
+    /// intervals [a1, b1] and [a2, b2], then their product is [min(P), max(P)],
+    /// where P = {a1 * a2, a1 * b2, b1 * a2, b1 * b2}. The bounds of the result
+    /// are closed, and it is unbounded if either interval is unbounded or one of
+    /// the products overflows.
+    pub fn mul<T: Borrow<Interval>>(&self, other: T) -> Result<Interval> {
+        let rhs = other.borrow();
+        let data_type = get_result_type(
+            &self.get_datatype()?,
+            &Operator::Multiply,
+            &rhs.get_datatype()?,
+        )?;
+        let bounds = [&self.lower, &self.upper, &rhs.lower, &rhs.upper];
+        if bounds.iter().any(|bound| bound.is_unbounded()) {
+            return Ok(Interval::new(
+                IntervalBound::make_unbounded(&data_type)?,
+                IntervalBound::make_unbounded(&data_type)?,
+            ));
+        }
+        let product = |lhs: &ScalarValue, rhs: &ScalarValue| lhs.mul_checked(rhs);
+        let mut lower: Option<ScalarValue> = None;
+        let mut upper: Option<ScalarValue> = None;
+        for left in [&self.lower.value, &self.upper.value] {
+            for right in [&rhs.lower.value, &rhs.upper.value] {
+                let (Ok(low), Ok(high)) = (
+                    alter_fp_rounding_mode::<false, _>(left, right, product),
+                    alter_fp_rounding_mode::<true, _>(left, right, product),
+                ) else {
+                    return Ok(Interval::new(
+                        IntervalBound::make_unbounded(&data_type)?,
+                        IntervalBound::make_unbounded(&data_type)?,
+                    ));
+                };
+                lower = Some(match lower {
+                    Some(lower) => min(&lower, &low)?,
+                    None => low,
+                });
+                upper = Some(match upper {
+                    Some(upper) => max(&upper, &high)?,
+                    None => high,
+                });
+            }
+        }
+        // Both options are set by the loop above:
+        Ok(Interval::new(
+            IntervalBound::new_closed(lower.unwrap()),
+            IntervalBound::new_closed(upper.unwrap()),
+        ))
+    }
+
     pub const CERTAINLY_FALSE: Interval = Interval {
         lower: IntervalBound::new_closed(ScalarValue::Boolean(Some(false))),
         upper: IntervalBound::new_closed(ScalarValue::Boolean(Some(false))),
@@ -545,13 +719,17 @@ impl Interval {
     pub fn cardinality(&self) -> Result<Option<u64>> {
         let data_type = self.get_datatype()?;
         if data_type.is_integer() {
-            Ok(self.upper.value.distance(&self.lower.value).map(|diff| {
-                calculate_cardinality_based_on_bounds(
-                    self.lower.open,
-                    self.upper.open,
-                    diff as u64,
-                )
-            }))
+            Ok(self
+                .upper
+                .value
+                .distance(&self.lower.value)
+                .and_then(|diff| {
+                    calculate_cardinality_based_on_bounds(
+                        self.lower.open,
+                        self.upper.open,
+                        diff as u64,
+                    )
+                }))
         }
         // Ordering floating-point numbers according to their binary representations
         // coincide with their natural ordering. Therefore, we can consider their
@@ -575,11 +753,11 @@ impl Interval {
                     else {
                         return Ok(None);
                     };
-                    Ok(Some(calculate_cardinality_based_on_bounds(
+                    Ok(calculate_cardinality_based_on_bounds(
                         self.lower.open,
                         self.upper.open,
                         count as u64,
-                    )))
+                    ))
                 }
                 (
                     ScalarValue::Float64(Some(lower)),
@@ -595,20 +773,94 @@ impl Interval {
                     else {
                         return Ok(None);
                     };
-                    Ok(Some(calculate_cardinality_based_on_bounds(
+                    Ok(calculate_cardinality_based_on_bounds(
                         self.lower.open,
                         self.upper.open,
                         count as u64,
-                    )))
+                    ))
                 }
                 _ => Ok(None),
             }
+        }
+        // Booleans are a domain of two values, so the unbounded sides of a
+        // boolean interval are `false` and `true`, respectively.
+        else if matches!(data_type, DataType::Boolean) {
+            let (ScalarValue::Boolean(lower), ScalarValue::Boolean(upper)) =
+                (&self.lower.value, &self.upper.value)
+            else {
+                return Ok(None);
+            };
+            let lower_open = lower.is_some() && self.lower.open;
+            let upper_open = upper.is_some() && self.upper.open;
+            let (lower, upper) = (lower.unwrap_or(false), upper.unwrap_or(true));
+            if lower > upper {
+                return Ok(Some(0));
+            }
+            Ok(calculate_cardinality_based_on_bounds(
+                lower_open,
+                upper_open,
+                upper as u64 - lower as u64,
+            ))
+        }
+        // Dates are discrete with a step of one day. `Date64` values are in
+        // milliseconds, but represent whole days.
+        else if matches!(data_type, DataType::Date32 | DataType::Date64) {
+            let diff = match (&self.lower.value, &self.upper.value) {
+                (ScalarValue::Date32(Some(lower)), ScalarValue::Date32(Some(upper))) => {
+                    upper.abs_diff(*lower) as u64
+                }
+                (ScalarValue::Date64(Some(lower)), ScalarValue::Date64(Some(upper))) => {
+                    upper.abs_diff(*lower) / MILLISECONDS_IN_DAY as u64
+                }
+                _ => return Ok(None),
+            };
+            Ok(calculate_cardinality_based_on_bounds(
+                self.lower.open,
+                self.upper.open,
+                diff,
+            ))
+        }
+        // Timestamps are discrete with a step of one unit. Their values are
+        // relative to the UTC epoch regardless of the time zone.
+        else if matches!(data_type, DataType::Timestamp(..)) {
+            let diff = match (&self.lower.value, &self.upper.value) {
+                (
+                    ScalarValue::TimestampSecond(Some(lower), _),
+                    ScalarValue::TimestampSecond(Some(upper), _),
+                )
+                | (
+                    ScalarValue::TimestampMillisecond(Some(lower), _),
+                    ScalarValue::TimestampMillisecond(Some(upper), _),
+                )
+                | (
+                    ScalarValue::TimestampMicrosecond(Some(lower), _),
+                    ScalarValue::TimestampMicrosecond(Some(upper), _),
+                )
+                | (
+                    ScalarValue::TimestampNanosecond(Some(lower), _),
+                    ScalarValue::TimestampNanosecond(Some(upper), _),
+                ) => upper.abs_diff(*lower),
+                _ => return Ok(None),
+            };
+            Ok(calculate_cardinality_based_on_bounds(
+                self.lower.open,
+                self.upper.open,
+                diff,
+            ))
         } else {
             // Cardinality calculations are not implemented for this data type yet:
             Ok(None)
         }
     }
 
+    /// Marks both endpoints of this interval as closed, without changing their
+    /// values.
+    fn with_closed_endpoints(mut self) -> Interval {
+        self.lower.open = false;
+        self.upper.open = false;
+        self
+    }
+
     /// This function "closes" this interval; i.e. it modifies the endpoints so
     /// that we end up with the narrowest possible closed interval containing
     /// the original interval.
@@ -661,7 +913,7 @@ macro_rules! check_infinite_bounds {
 
 /// This function returns the next/previous value depending on the `ADD` value.
 /// If `true`, it returns the next value; otherwise it returns the previous value.
-fn next_value<const INC: bool>(value: ScalarValue) -> ScalarValue {
+pub(crate) fn next_value<const INC: bool>(value: ScalarValue) -> ScalarValue {
     use ScalarValue::*;
     match value {
         Float32(Some(val)) => {
@@ -708,20 +960,73 @@ fn next_value<const INC: bool>(value: ScalarValue) -> ScalarValue {
     }
 }
 
+/// Returns the smallest string that is greater than all strings starting with
+/// `prefix`, if any, by incrementing the last character of `prefix` that can be
+/// incremented.
+fn next_prefix(prefix: &str) -> Option<String> {
+    let mut chars = prefix.chars().collect::<Vec<_>>();
+    while let Some(last) = chars.pop() {
+        let next = match last {
+            char::MAX => continue,
+            // Skip the surrogate range, which does not contain valid characters.
+            '\u{D7FF}' => '\u{E000}',
+            c => char::from_u32(c as u32 + 1)?,
+        };
+        chars.push(next);
+        return Some(chars.into_iter().collect());
+    }
+    None
+}
+
 /// This function computes the selectivity of an operation by computing the
 /// cardinality ratio of the given input/output intervals. If this can not be
 /// calculated for some reason, it returns `1.0` meaning fullly selective (no
-/// filtering).
+/// filtering). For string intervals, the ratio is the fraction of the
+/// lexicographic range of `initial_interval` covered by `final_interval`.
+/// Open bounds exclude one step of discrete (integer, date and timestamp) values, whereas
+/// open and closed bounds are treated identically for continuous
+/// (floating-point) values. The ratio is `0.0` if `final_interval` is empty.
+/// If `initial_interval` holds a single value, e.g. that of a constant column,
+/// the ratio is `1.0` if `final_interval` contains the value and `0.0` if not.
 pub fn cardinality_ratio(
     initial_interval: &Interval,
     final_interval: &Interval,
 ) -> Result<f64> {
+    if final_interval.is_empty() {
+        return Ok(0.0);
+    }
+    if let Some(value) = initial_interval.single_value() {
+        let point = Interval::new(
+            IntervalBound::new_closed(value.clone()),
+            IntervalBound::new_closed(value.clone()),
+        );
+        let contained = final_interval.intersect(point)?.is_some();
+        return Ok(if contained { 1.0 } else { 0.0 });
+    }
+    if let Some(ratio) = lexicographic_ratio(initial_interval, final_interval) {
+        return Ok(ratio);
+    }
+    if let Some(ratio) = decimal_ratio(initial_interval, final_interval) {
+        return ratio;
+    }
+    if let Some(ratio) = integer_ratio(initial_interval, final_interval) {
+        return Ok(ratio);
+    }
+    let (initial_interval, final_interval) =
+        if initial_interval.lower.value.data_type().is_floating() {
+            (
+                initial_interval.clone().with_closed_endpoints(),
+                final_interval.clone().with_closed_endpoints(),
+            )
+        } else {
+            (initial_interval.clone(), final_interval.clone())
+        };
     Ok(
         match (
             final_interval.cardinality()?,
             initial_interval.cardinality()?,
         ) {
-            (Some(final_interval), Some(initial_interval)) => {
+            (Some(final_interval), Some(initial_interval)) if initial_interval > 0 => {
                 final_interval as f64 / initial_interval as f64
             }
             _ => 1.0,
@@ -729,7 +1034,177 @@ pub fn cardinality_ratio(
     )
 }
 
+/// Computes the cardinality ratio of `final_interval` to `initial_interval`, if
+/// all of their bounds are integers. The numbers of values in the intervals are
+/// computed as `i128`s, which can not overflow for any integer type, unlike
+/// [`Interval::cardinality`] for e.g. a `UInt64` interval spanning the whole
+/// type.
+fn integer_ratio(initial_interval: &Interval, final_interval: &Interval) -> Option<f64> {
+    let initial_count = integer_count(initial_interval)?;
+    let final_count = integer_count(final_interval)?;
+    Some(if initial_count > 0 {
+        final_count as f64 / initial_count as f64
+    } else {
+        1.0
+    })
+}
+
+/// Returns the number of values in `interval`, if its bounds are integers.
+fn integer_count(interval: &Interval) -> Option<i128> {
+    let value_of = |value: &ScalarValue| match value {
+        ScalarValue::Int8(Some(v)) => Some(*v as i128),
+        ScalarValue::Int16(Some(v)) => Some(*v as i128),
+        ScalarValue::Int32(Some(v)) => Some(*v as i128),
+        ScalarValue::Int64(Some(v)) => Some(*v as i128),
+        ScalarValue::UInt8(Some(v)) => Some(*v as i128),
+        ScalarValue::UInt16(Some(v)) => Some(*v as i128),
+        ScalarValue::UInt32(Some(v)) => Some(*v as i128),
+        ScalarValue::UInt64(Some(v)) => Some(*v as i128),
+        _ => None,
+    };
+    let lower = value_of(&interval.lower.value)? + interval.lower.open as i128;
+    let upper = value_of(&interval.upper.value)? - interval.upper.open as i128;
+    Some((upper - lower + 1).max(0))
+}
+
+/// Estimates the fraction of the lexicographic range of `initial_interval`
+/// covered by `final_interval`, if both intervals have string or binary bounds
+/// and `initial_interval` is bounded. Values are mapped to numbers in `[0, 1)`
+/// by interpreting their bytes (the UTF-8 bytes of strings preserve the
+/// ordering of characters) as digits in base 256, after stripping the common
+/// prefix of the initial bounds. Whether the bounds are open or closed is
+/// ignored.
+fn lexicographic_ratio(
+    initial_interval: &Interval,
+    final_interval: &Interval,
+) -> Option<f64> {
+    let (initial_lower, initial_upper) = lexicographic_bounds(initial_interval)?;
+    let (initial_lower, initial_upper) = (initial_lower?, initial_upper?);
+    let (final_lower, final_upper) = lexicographic_bounds(final_interval)?;
+    let prefix_len = initial_lower
+        .iter()
+        .zip(initial_upper.iter())
+        .take_while(|(lhs, rhs)| lhs == rhs)
+        .count();
+    let position = |value: &[u8]| {
+        value
+            .get(prefix_len..)
+            .unwrap_or_default()
+            .iter()
+            .take(8)
+            .rev()
+            .fold(0.0, |acc, byte| (acc + *byte as f64) / 256.0)
+    };
+    let initial_width = position(initial_upper) - position(initial_lower);
+    if initial_width <= 0.0 {
+        return Some(1.0);
+    }
+    let final_width = position(final_upper.unwrap_or(initial_upper))
+        - position(final_lower.unwrap_or(initial_lower));
+    Some((final_width / initial_width).clamp(0.0, 1.0))
+}
+
+/// Computes the cardinality ratio of `final_interval` to `initial_interval`, if
+/// all of their bounds are `Decimal128` or `Decimal256` values. Decimals are
+/// discrete with a step of one unit in the last place, so the unscaled values
+/// are first rescaled to the largest scale among the bounds, which makes the
+/// ratio independent of the scales. Returns an error if rescaling overflows.
+fn decimal_ratio(
+    initial_interval: &Interval,
+    final_interval: &Interval,
+) -> Option<Result<f64>> {
+    let bounds = [
+        &initial_interval.lower,
+        &initial_interval.upper,
+        &final_interval.lower,
+        &final_interval.upper,
+    ];
+    let mut values = Vec::with_capacity(bounds.len());
+    for bound in bounds {
+        match &bound.value {
+            ScalarValue::Decimal128(Some(value), _, scale) => {
+                values.push((i256::from_i128(*value), *scale))
+            }
+            ScalarValue::Decimal256(Some(value), _, scale) => {
+                values.push((*value, *scale))
+            }
+            _ => return None,
+        }
+    }
+    let max_scale = values.iter().map(|(_, scale)| *scale).max()?;
+    let rescaled = values
+        .into_iter()
+        .map(|(value, scale)| {
+            i256::from_i128(10)
+                .checked_pow((max_scale as i32 - scale as i32) as u32)
+                .and_then(|factor| value.checked_mul(factor))
+                .ok_or_else(|| {
+                    DataFusionError::Execution(format!(
+                        "Overflow while rescaling decimal {value} from scale {scale} to {max_scale}"
+                    ))
+                })
+        })
+        .collect::<Result<Vec<_>>>();
+    let rescaled = match rescaled {
+        Ok(rescaled) => rescaled,
+        Err(e) => return Some(Err(e)),
+    };
+    let count = |lower: i256, upper: i256, lower_open: bool, upper_open: bool| {
+        let steps = i256_to_f64(upper.wrapping_sub(lower));
+        let count = match (lower_open, upper_open) {
+            (false, false) => steps + 1.0,
+            (true, true) => steps - 1.0,
+            _ => steps,
+        };
+        count.max(0.0)
+    };
+    let initial_count = count(
+        rescaled[0],
+        rescaled[1],
+        initial_interval.lower.open,
+        initial_interval.upper.open,
+    );
+    let final_count = count(
+        rescaled[2],
+        rescaled[3],
+        final_interval.lower.open,
+        final_interval.upper.open,
+    );
+    if initial_count == 0.0 {
+        return Some(Ok(1.0));
+    }
+    Some(Ok((final_count / initial_count).clamp(0.0, 1.0)))
+}
+
+/// Converts the given `i256` to the nearest `f64`.
+fn i256_to_f64(value: i256) -> f64 {
+    let (low, high) = value.to_parts();
+    high as f64 * 2_f64.powi(128) + low as f64
+}
+
+/// Returns the bytes of the bounds of the given interval if they are strings
+/// or binary values, where `None` represents an unbounded endpoint.
+fn lexicographic_bounds(interval: &Interval) -> Option<(Option<&[u8]>, Option<&[u8]>)> {
+    match (&interval.lower.value, &interval.upper.value) {
+        (ScalarValue::Utf8(lower), ScalarValue::Utf8(upper))
+        | (ScalarValue::LargeUtf8(lower), ScalarValue::LargeUtf8(upper)) => Some((
+            lower.as_deref().map(str::as_bytes),
+            upper.as_deref().map(str::as_bytes),
+        )),
+        (ScalarValue::Binary(lower), ScalarValue::Binary(upper))
+        | (ScalarValue::LargeBinary(lower), ScalarValue::LargeBinary(upper)) => {
+            Some((lower.as_deref(), upper.as_deref()))
+        }
+        _ => None,
+    }
+}
+
 pub fn apply_operator(op: &Operator, lhs: &Interval, rhs: &Interval) -> Result<Interval> {
+    if op.is_comparison_operator() {
+        if let Some((lhs, rhs)) = align_timestamp_units(lhs, rhs)? {
+            return apply_operator(op, &lhs, &rhs);
+        }
+    }
     match *op {
         Operator::Eq => Ok(lhs.equal(rhs)),
         Operator::NotEq => Ok(lhs.equal(rhs).not()?),
@@ -738,14 +1213,16 @@ pub fn apply_operator(op: &Operator, lhs: &Interval, rhs: &Interval) -> Result<I
         Operator::Lt => Ok(lhs.lt(rhs)),
         Operator::LtEq => Ok(lhs.lt_eq(rhs)),
         Operator::And => lhs.and(rhs),
+        Operator::Or => lhs.or(rhs),
         Operator::Plus => lhs.add(rhs),
         Operator::Minus => lhs.sub(rhs),
+        Operator::Multiply => lhs.mul(rhs),
         _ => Ok(Interval::default()),
     }
 }
 
 /// Cast scalar value to the given data type using an arrow kernel.
-fn cast_scalar_value(
+pub(crate) fn cast_scalar_value(
     value: &ScalarValue,
     data_type: &DataType,
     cast_options: &CastOptions,
@@ -754,16 +1231,77 @@ fn cast_scalar_value(
     ScalarValue::try_from_array(&cast_array, 0)
 }
 
+/// If `lhs` and `rhs` are timestamp intervals with different time units,
+/// returns both intervals in the finer of the two units, which represents the
+/// values of the coarser unit exactly. Otherwise, returns `None`.
+pub(crate) fn align_timestamp_units(
+    lhs: &Interval,
+    rhs: &Interval,
+) -> Result<Option<(Interval, Interval)>> {
+    let (DataType::Timestamp(lhs_unit, lhs_tz), DataType::Timestamp(rhs_unit, rhs_tz)) =
+        (lhs.get_datatype()?, rhs.get_datatype()?)
+    else {
+        return Ok(None);
+    };
+    if lhs_unit == rhs_unit {
+        return Ok(None);
+    }
+    let unit = if unit_precision(&lhs_unit) > unit_precision(&rhs_unit) {
+        lhs_unit
+    } else {
+        rhs_unit
+    };
+    let options = CastOptions::default();
+    Ok(Some((
+        lhs.cast_to(&DataType::Timestamp(unit.clone(), lhs_tz), &options)?,
+        rhs.cast_to(&DataType::Timestamp(unit, rhs_tz), &options)?,
+    )))
+}
+
+/// Casts `interval`, which was aligned to a finer time unit by
+/// [`align_timestamp_units`], back to the timestamp type `data_type`. As the
+/// cast truncates the values, bounds that are not exactly representable in
+/// the coarser unit are closed so that the result still contains every value
+/// of `interval`.
+pub(crate) fn restore_timestamp_unit(
+    interval: &Interval,
+    data_type: &DataType,
+) -> Result<Interval> {
+    let options = CastOptions::default();
+    let data_type_of_interval = interval.get_datatype()?;
+    let restore = |bound: &IntervalBound| -> Result<IntervalBound> {
+        let value = cast_scalar_value(&bound.value, data_type, &options)?;
+        let exact =
+            cast_scalar_value(&value, &data_type_of_interval, &options)? == bound.value;
+        Ok(IntervalBound::new(value, bound.open && exact))
+    };
+    Ok(Interval::new(
+        restore(&interval.lower)?,
+        restore(&interval.upper)?,
+    ))
+}
+
+/// Returns the number of fractional decimal digits of a second that the time
+/// unit represents.
+fn unit_precision(unit: &TimeUnit) -> u8 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 3,
+        TimeUnit::Microsecond => 6,
+        TimeUnit::Nanosecond => 9,
+    }
+}
+
 /// This function calculates the final cardinality result by inspecting the endpoints of the interval.
 fn calculate_cardinality_based_on_bounds(
     lower_open: bool,
     upper_open: bool,
     diff: u64,
-) -> u64 {
+) -> Option<u64> {
     match (lower_open, upper_open) {
-        (false, false) => diff + 1,
-        (true, true) => diff - 1,
-        _ => diff,
+        (false, false) => diff.checked_add(1),
+        (true, true) => Some(diff.saturating_sub(1)),
+        _ => Some(diff),
     }
 }
 
@@ -1046,10 +1584,15 @@ impl NullableInterval {
 
 #[cfg(test)]
 mod tests {
-    use super::next_value;
+    use super::{
+        align_timestamp_units, apply_operator, cardinality_ratio, next_value,
+        restore_timestamp_unit,
+    };
     use crate::intervals::{Interval, IntervalBound};
+    use arrow_buffer::i256;
     use arrow_schema::DataType;
     use datafusion_common::{Result, ScalarValue};
+    use datafusion_expr::Operator;
 
     fn open_open<T>(lower: Option<T>, upper: Option<T>) -> Interval
     where
@@ -1240,6 +1783,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn or_test() -> Result<()> {
+        let cases = vec![
+            (false, true, false, false, false, true),
+            (false, false, false, true, false, true),
+            (false, true, false, true, false, true),
+            (false, true, true, true, true, true),
+            (false, false, false, false, false, false),
+            (true, true, false, false, true, true),
+        ];
+
+        for case in cases {
+            assert_eq!(
+                closed_closed(Some(case.0), Some(case.1))
+                    .or(closed_closed(Some(case.2), Some(case.3)))?,
+                closed_closed(Some(case.4), Some(case.5))
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn add_test() -> Result<()> {
         let cases = vec![
@@ -1388,6 +1952,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn mul_test_various_bounds() -> Result<()> {
+        let cases = vec![
+            (
+                closed_closed(Some(2_i64), Some(3_i64)),
+                closed_closed(Some(10_i64), Some(20_i64)),
+                closed_closed(Some(20_i64), Some(60_i64)),
+            ),
+            (
+                closed_open(Some(-2_i64), Some(3_i64)),
+                open_closed(Some(-10_i64), Some(20_i64)),
+                closed_closed(Some(-40_i64), Some(60_i64)),
+            ),
+            (
+                closed_closed(Some(-3_i64), Some(-2_i64)),
+                closed_closed(Some(-5_i64), Some(4_i64)),
+                closed_closed(Some(-12_i64), Some(15_i64)),
+            ),
+            (
+                closed_closed(Some(1_i64), Some(2_i64)),
+                closed_open(Some(1_i64), None),
+                open_open::<i64>(None, None),
+            ),
+            (
+                closed_closed(Some(1_i64), Some(2_i64)),
+                closed_closed(Some(1_i64), Some(i64::MAX)),
+                open_open::<i64>(None, None),
+            ),
+        ];
+        for case in cases {
+            assert_eq!(case.0.mul(case.1)?, case.2)
+        }
+        // Floating point bounds are rounded outwards:
+        let product = closed_closed(Some(0.1_f64), Some(0.3))
+            .mul(closed_closed(Some(3.0_f64), Some(3.0)))?;
+        assert!(product.lower.value <= ScalarValue::Float64(Some(0.3)));
+        assert!(product.upper.value >= ScalarValue::Float64(Some(0.9)));
+        Ok(())
+    }
+
     #[test]
     fn lt_test_various_bounds() -> Result<()> {
         let cases = vec![
@@ -1593,6 +2197,97 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn union_test_various_bounds() -> Result<()> {
+        let cases = vec![
+            (
+                closed_closed(Some(1_i64), Some(5_i64)),
+                closed_closed(Some(10_i64), Some(20_i64)),
+                closed_closed(Some(1_i64), Some(20_i64)),
+            ),
+            (
+                open_open(Some(1_i64), Some(5_i64)),
+                closed_open(Some(1_i64), Some(3_i64)),
+                closed_open(Some(1_i64), Some(5_i64)),
+            ),
+            (
+                open_open(Some(1_i64), Some(5_i64)),
+                open_closed(Some(3_i64), Some(5_i64)),
+                open_closed(Some(1_i64), Some(5_i64)),
+            ),
+            (
+                open_open(None, Some(5_i64)),
+                closed_closed(Some(1_i64), Some(10_i64)),
+                open_closed(None, Some(10_i64)),
+            ),
+            (
+                closed_closed(Some(1_i64), Some(10_i64)),
+                open_open(Some(5_i64), None),
+                closed_open(Some(1_i64), None),
+            ),
+        ];
+        for case in cases {
+            assert_eq!(case.0.union(case.1)?, case.2)
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn intersect_and_union_edge_cases() -> Result<()> {
+        // (lhs, rhs, intersection, union)
+        let cases = vec![
+            // Disjoint
+            (
+                closed_closed(Some(1_i64), Some(5_i64)),
+                closed_closed(Some(10_i64), Some(20_i64)),
+                None,
+                closed_closed(Some(1_i64), Some(20_i64)),
+            ),
+            // Touching at 5, which only the left interval includes
+            (
+                closed_closed(Some(1_i64), Some(5_i64)),
+                open_closed(Some(5_i64), Some(10_i64)),
+                None,
+                closed_closed(Some(1_i64), Some(10_i64)),
+            ),
+            // Touching at 5, which both intervals include
+            (
+                closed_closed(Some(1_i64), Some(5_i64)),
+                closed_open(Some(5_i64), Some(10_i64)),
+                Some(closed_closed(Some(5_i64), Some(5_i64))),
+                closed_open(Some(1_i64), Some(10_i64)),
+            ),
+            // Sharing the upper endpoint, which only the left interval includes
+            (
+                closed_closed(Some(1_i64), Some(10_i64)),
+                open_open(Some(5_i64), Some(10_i64)),
+                Some(open_open(Some(5_i64), Some(10_i64))),
+                closed_closed(Some(1_i64), Some(10_i64)),
+            ),
+            // Fully nested
+            (
+                open_open(Some(1_i64), Some(10_i64)),
+                closed_closed(Some(3_i64), Some(4_i64)),
+                Some(closed_closed(Some(3_i64), Some(4_i64))),
+                open_open(Some(1_i64), Some(10_i64)),
+            ),
+            // Nested in an unbounded interval
+            (
+                open_open(None, Some(10_i64)),
+                closed_closed(Some(3_i64), Some(4_i64)),
+                Some(closed_closed(Some(3_i64), Some(4_i64))),
+                open_open(None, Some(10_i64)),
+            ),
+        ];
+        for (lhs, rhs, intersection, union) in cases {
+            assert_eq!(lhs.intersect(&rhs)?, intersection);
+            assert_eq!(rhs.intersect(&lhs)?, intersection);
+            assert_eq!(lhs.union(&rhs)?, union);
+            assert_eq!(rhs.union(&lhs)?, union);
+        }
+        Ok(())
+    }
+
     // This function tests if valid constructions produce standardized objects
     // ([false, false], [false, true], [true, true]) for boolean intervals.
     #[test]
@@ -1711,6 +2406,102 @@ mod tests {
         capture_mode_change_f32((lower, upper), true, true);
     }
 
+    #[test]
+    fn test_prefix_intervals() -> Result<()> {
+        let cases = [
+            ("foo", Some("fop")),
+            ("a\u{10FFFF}", Some("b")),
+            ("\u{D7FF}", Some("\u{E000}")),
+            ("ü", Some("ý")),
+            ("\u{10FFFF}", None),
+            ("", None),
+        ];
+        for (prefix, upper) in cases {
+            let expected = Interval::make(Some(prefix), upper, (false, true));
+            assert_eq!(
+                Interval::make_prefix(&ScalarValue::Utf8(Some(prefix.to_string())))?,
+                expected
+            );
+        }
+        let large = ScalarValue::LargeUtf8(Some("foo".to_string()));
+        assert_eq!(
+            Interval::make_prefix(&large)?,
+            Interval::new(
+                IntervalBound::new_closed(large),
+                IntervalBound::new_open(ScalarValue::LargeUtf8(Some("fop".to_string())))
+            )
+        );
+        assert!(Interval::make_prefix(&ScalarValue::Utf8(None)).is_err());
+        assert!(Interval::make_prefix(&ScalarValue::Int32(Some(1))).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cardinality_ratio_of_binary() -> Result<()> {
+        let binary = |lower: u8, upper: Option<u8>| {
+            Interval::new(
+                IntervalBound::new_closed(ScalarValue::Binary(Some(vec![lower]))),
+                IntervalBound::new_closed(ScalarValue::Binary(upper.map(|u| vec![u]))),
+            )
+        };
+        let initial = binary(0, Some(200));
+        let cases = [
+            (binary(0, Some(200)), 1.0),
+            (binary(0, Some(100)), 0.5),
+            (binary(50, Some(100)), 0.25),
+            (binary(150, None), 0.25),
+        ];
+        for (interval, expected) in cases {
+            assert!((cardinality_ratio(&initial, &interval)? - expected).abs() < 1e-9);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cardinality_ratio_of_strings() -> Result<()> {
+        let initial = Interval::make(Some("A"), Some("M"), (false, false));
+        let cases = [
+            (Interval::make(Some("A"), Some("M"), (false, false)), 1.0),
+            (Interval::make(Some("A"), Some("G"), (false, true)), 0.5),
+            (Interval::make(Some("D"), Some("G"), (false, false)), 0.25),
+            (Interval::make(Some("G"), None, (false, true)), 0.5),
+        ];
+        for (interval, expected) in cases {
+            assert!((cardinality_ratio(&initial, &interval)? - expected).abs() < 1e-9);
+        }
+
+        // The common prefix of the initial bounds is ignored, so long prefixes
+        // do not affect the precision.
+        let initial = Interval::make(
+            Some("a long common prefix a"),
+            Some("a long common prefix z"),
+            (false, false),
+        );
+        let interval = Interval::make(
+            Some("a long common prefix a"),
+            Some("a long common prefix m"),
+            (false, false),
+        );
+        let expected = (b'm' - b'a') as f64 / (b'z' - b'a') as f64;
+        assert!((cardinality_ratio(&initial, &interval)? - expected).abs() < 1e-9);
+
+        // Empty strings and non-ASCII characters
+        let initial = Interval::make(Some(""), Some("\u{e9}"), (false, false));
+        let interval = Interval::make(Some(""), Some("a"), (false, false));
+        let expected = b'a' as f64 / 0xC3 as f64;
+        assert!((cardinality_ratio(&initial, &interval)? - expected).abs() < 1e-2);
+
+        // Unbounded and single-point initial intervals
+        let initial = Interval::make(Some("A"), None, (false, true));
+        assert_eq!(cardinality_ratio(&initial, &initial)?, 1.0);
+        let initial = Interval::make(Some("A"), Some("A"), (false, false));
+        assert_eq!(cardinality_ratio(&initial, &initial)?, 1.0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_cardinality_of_intervals() -> Result<()> {
         // In IEEE 754 standard for floating-point arithmetic, if we keep the sign and exponent fields same,
@@ -1780,6 +2571,278 @@ mod tests {
         );
         assert_eq!(interval.cardinality()?.unwrap(), 2063597569);
 
+        // Open bounds exclude a single day:
+        let interval = Interval::new(
+            IntervalBound::new(ScalarValue::Date32(Some(1)), true),
+            IntervalBound::new(ScalarValue::Date32(Some(10)), false),
+        );
+        assert_eq!(interval.cardinality()?.unwrap(), 9);
+        let day = 86_400_000;
+        let interval = Interval::new(
+            IntervalBound::new(ScalarValue::Date64(Some(day)), false),
+            IntervalBound::new(ScalarValue::Date64(Some(10 * day)), true),
+        );
+        assert_eq!(interval.cardinality()?.unwrap(), 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cardinality_ratio_of_points_and_empty_intervals() -> Result<()> {
+        // A single-value initial interval either keeps its value or nothing.
+        let point = Interval::make(Some(5_i64), Some(5_i64), (false, false));
+        let cases = [
+            (point.clone(), 1.0),
+            (
+                Interval::make(Some(0_i64), Some(10_i64), (false, false)),
+                1.0,
+            ),
+            (Interval::make(Some(5_i64), None, (true, true)), 0.0),
+            (
+                Interval::make(Some(6_i64), Some(10_i64), (false, false)),
+                0.0,
+            ),
+        ];
+        for (interval, expected) in cases {
+            assert_eq!(cardinality_ratio(&point, &interval)?, expected);
+        }
+        let point = Interval::make(Some(2.5), Some(2.5), (false, false));
+        let excluded = Interval::make(Some(2.5), Some(3.0), (true, false));
+        assert_eq!(cardinality_ratio(&point, &point)?, 1.0);
+        assert_eq!(cardinality_ratio(&point, &excluded)?, 0.0);
+        let point = Interval::make(Some("A"), Some("A"), (false, false));
+        let excluded = Interval::make(Some("B"), None, (false, true));
+        assert_eq!(cardinality_ratio(&point, &excluded)?, 0.0);
+
+        // Empty final intervals keep nothing.
+        let initial = Interval::make(Some(1_i64), Some(10_i64), (false, false));
+        let cases = [
+            Interval::make(Some(8_i64), Some(3_i64), (false, false)),
+            Interval::make(Some(3_i64), Some(3_i64), (true, true)),
+            Interval::make(Some(3_i64), Some(3_i64), (false, true)),
+        ];
+        for interval in cases {
+            assert!(interval.is_empty());
+            assert_eq!(cardinality_ratio(&initial, &interval)?, 0.0);
+        }
+        let empty = Interval::make(Some(8.0), Some(3.0), (false, false));
+        let initial = Interval::make(Some(0.0), Some(10.0), (false, false));
+        assert_eq!(cardinality_ratio(&initial, &empty)?, 0.0);
+
+        // Initial intervals without any discrete values do not filter anything.
+        let initial = Interval::make(Some(5_i64), Some(6_i64), (true, true));
+        assert_eq!(initial.cardinality()?, Some(0));
+        assert_eq!(cardinality_ratio(&initial, &initial)?, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cardinality_ratio_with_open_bounds() -> Result<()> {
+        // Discrete values: `[1, 5)` and `[1, 4]` contain the same values.
+        let initial = Interval::make(Some(1_i64), Some(10_i64), (false, false));
+        let open = Interval::make(Some(1_i64), Some(5_i64), (false, true));
+        let closed = Interval::make(Some(1_i64), Some(4_i64), (false, false));
+        assert_eq!(cardinality_ratio(&initial, &open)?, 0.4);
+        assert_eq!(cardinality_ratio(&initial, &closed)?, 0.4);
+
+        let date = |day: i32| ScalarValue::Date32(Some(day));
+        let initial = Interval::new(
+            IntervalBound::new_closed(date(1)),
+            IntervalBound::new_closed(date(10)),
+        );
+        let open = Interval::new(
+            IntervalBound::new_open(date(4)),
+            IntervalBound::new_closed(date(10)),
+        );
+        let closed = Interval::new(
+            IntervalBound::new_closed(date(5)),
+            IntervalBound::new_closed(date(10)),
+        );
+        assert_eq!(cardinality_ratio(&initial, &open)?, 0.6);
+        assert_eq!(cardinality_ratio(&initial, &closed)?, 0.6);
+
+        // Continuous values: open and closed bounds are equivalent.
+        let initial = Interval::make(Some(0.0), Some(10.0), (false, false));
+        let open = Interval::make(Some(0.0), Some(5.0), (false, true));
+        let closed = Interval::make(Some(0.0), Some(5.0), (false, false));
+        assert_eq!(
+            cardinality_ratio(&initial, &open)?,
+            cardinality_ratio(&initial, &closed)?
+        );
+        let open = Interval::make(Some(0.0), Some(10.0), (true, true));
+        assert_eq!(cardinality_ratio(&initial, &open)?, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cardinality_of_unsigned_and_boolean_intervals() -> Result<()> {
+        // The number of values of a `UInt64` interval spanning the whole type
+        // does not fit into a `u64`, but its ratios are still computed.
+        let initial = Interval::make(Some(0_u64), Some(u64::MAX), (false, false));
+        assert_eq!(initial.cardinality()?, None);
+        let half = Interval::make(Some(0_u64), Some(u64::MAX / 2), (false, false));
+        assert_eq!(half.cardinality()?, Some(u64::MAX / 2 + 1));
+        assert!((cardinality_ratio(&initial, &half)? - 0.5).abs() < 1e-9);
+        let top = Interval::make(Some(u64::MAX - 9), Some(u64::MAX), (true, false));
+        assert_eq!(top.cardinality()?, Some(9));
+        let initial = Interval::make(Some(u64::MAX - 99), Some(u64::MAX), (false, false));
+        assert!((cardinality_ratio(&initial, &top)? - 0.09).abs() < 1e-9);
+
+        // Booleans have two values, also if the interval is unbounded.
+        let cases = [
+            (Interval::make::<bool>(None, None, (false, false)), 2),
+            (Interval::make(Some(false), Some(true), (false, false)), 2),
+            (Interval::make(Some(false), Some(true), (true, false)), 1),
+            (Interval::make(None, Some(false), (false, false)), 1),
+            (Interval::make(Some(true), Some(true), (false, false)), 1),
+            (Interval::make(Some(true), Some(false), (false, false)), 0),
+            (Interval::make(Some(false), Some(true), (true, true)), 0),
+        ];
+        for (interval, expected) in cases {
+            assert_eq!(interval.cardinality()?, Some(expected));
+        }
+        let initial = Interval::make::<bool>(None, None, (false, false));
+        let a_true = Interval::make(Some(true), Some(true), (false, false));
+        assert_eq!(cardinality_ratio(&initial, &a_true)?, 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_cardinality_ratio() -> Result<()> {
+        // A price column with two decimal places in [0.01, 100.00]:
+        let price = |cents: i128| ScalarValue::Decimal128(Some(cents), 10, 2);
+        let initial = Interval::new(
+            IntervalBound::new_closed(price(1)),
+            IntervalBound::new_closed(price(10_000)),
+        );
+        // price < 50.01
+        let interval = Interval::new(
+            IntervalBound::new_closed(price(1)),
+            IntervalBound::new_open(price(5_001)),
+        );
+        assert_eq!(cardinality_ratio(&initial, &interval)?, 0.5);
+        // 25.00 < price <= 75.00
+        let interval = Interval::new(
+            IntervalBound::new_open(price(2_500)),
+            IntervalBound::new_closed(price(7_500)),
+        );
+        assert_eq!(cardinality_ratio(&initial, &interval)?, 0.5);
+        assert_eq!(cardinality_ratio(&initial, &initial)?, 1.0);
+
+        // The ratio does not depend on the scale:
+        let large_price =
+            |millis: i128| ScalarValue::Decimal256(Some(i256::from_i128(millis)), 40, 3);
+        let initial = Interval::new(
+            IntervalBound::new_closed(large_price(0)),
+            IntervalBound::new_closed(large_price(99_999)),
+        );
+        let interval = Interval::new(
+            IntervalBound::new_closed(large_price(0)),
+            IntervalBound::new_open(large_price(50_000)),
+        );
+        assert_eq!(cardinality_ratio(&initial, &interval)?, 0.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_cardinality_ratio_with_mixed_scales() -> Result<()> {
+        // [1.0, 10.00) with the lower bound at scale 1, and the upper bound at
+        // scale 2, is rescaled to [1.000, 10.000):
+        let initial = Interval::new(
+            IntervalBound::new_closed(ScalarValue::Decimal128(Some(10), 10, 1)),
+            IntervalBound::new_open(ScalarValue::Decimal128(Some(1_000), 10, 2)),
+        );
+        // [1.000, 5.500)
+        let interval = Interval::new(
+            IntervalBound::new_closed(ScalarValue::Decimal128(Some(1_000), 10, 3)),
+            IntervalBound::new_open(ScalarValue::Decimal128(Some(5_500), 10, 3)),
+        );
+        assert_eq!(cardinality_ratio(&initial, &interval)?, 0.5);
+
+        // Rescaling a bound beyond the range of `i256` is an error:
+        let interval = Interval::new(
+            IntervalBound::new_closed(ScalarValue::Decimal256(Some(i256::MAX), 76, -10)),
+            IntervalBound::new_closed(ScalarValue::Decimal128(Some(1), 10, 2)),
+        );
+        assert!(cardinality_ratio(&initial, &interval).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_cardinality_ratio() -> Result<()> {
+        // One hour within a day, with the bounds in seconds in a time zone:
+        let second =
+            |s: i64| ScalarValue::TimestampSecond(Some(s), Some("+05:00".into()));
+        let initial = Interval::new(
+            IntervalBound::new_closed(second(0)),
+            IntervalBound::new_open(second(86_400)),
+        );
+        let hour = Interval::new(
+            IntervalBound::new_closed(second(3_600)),
+            IntervalBound::new_open(second(7_200)),
+        );
+        assert_eq!(cardinality_ratio(&initial, &hour)?, 1.0 / 24.0);
+
+        // Mixed units have no common cardinality:
+        let millisecond = Interval::new(
+            IntervalBound::new_closed(ScalarValue::TimestampMillisecond(Some(0), None)),
+            IntervalBound::new_closed(ScalarValue::TimestampSecond(Some(1), None)),
+        );
+        assert_eq!(millisecond.cardinality().ok().flatten(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_align_timestamp_units() -> Result<()> {
+        let millisecond = |v: i64| ScalarValue::TimestampMillisecond(Some(v), None);
+        let microsecond = |v: i64| ScalarValue::TimestampMicrosecond(Some(v), None);
+        let lhs = Interval::new(
+            IntervalBound::new_open(millisecond(1)),
+            IntervalBound::new_closed(millisecond(2)),
+        );
+        let rhs = Interval::new(
+            IntervalBound::new_closed(microsecond(1_500)),
+            IntervalBound::new_open(microsecond(3_000)),
+        );
+        let (aligned_lhs, aligned_rhs) = align_timestamp_units(&lhs, &rhs)?.unwrap();
+        assert_eq!(
+            aligned_lhs,
+            Interval::new(
+                IntervalBound::new_open(microsecond(1_000)),
+                IntervalBound::new_closed(microsecond(2_000)),
+            )
+        );
+        assert_eq!(aligned_rhs, rhs);
+        assert!(align_timestamp_units(&lhs, &lhs)?.is_none());
+        // Comparisons are evaluated in the finer unit:
+        assert_eq!(
+            apply_operator(&Operator::Lt, &lhs, &rhs)?,
+            Interval::UNCERTAIN
+        );
+
+        // Restoring the coarser unit truncates the values, so the bounds that
+        // are not exactly representable are closed:
+        let restored = restore_timestamp_unit(
+            &Interval::new(
+                IntervalBound::new_open(microsecond(1_500)),
+                IntervalBound::new_open(microsecond(3_000)),
+            ),
+            &lhs.get_datatype()?,
+        )?;
+        assert_eq!(
+            restored,
+            Interval::new(
+                IntervalBound::new_closed(millisecond(1)),
+                IntervalBound::new_open(millisecond(3)),
+            )
+        );
+
         Ok(())
     }
 
@@ -21,7 +21,10 @@ use std::sync::Arc;
 
 use super::{Interval, IntervalBound};
 use crate::{
-    expressions::{BinaryExpr, CastExpr, Column, Literal, NegativeExpr},
+    expressions::{
+        BinaryExpr, CastExpr, Column, InListExpr, LikeExpr, Literal, NegativeExpr,
+        NotExpr,
+    },
     PhysicalExpr,
 };
 
@@ -37,7 +40,8 @@ const DT_MS_MASK: i64 = 0xFFFF_FFFF;
 /// Currently, we do not support all [`PhysicalExpr`]s for interval calculations.
 /// We do not support every type of [`Operator`]s either. Over time, this check
 /// will relax as more types of `PhysicalExpr`s and `Operator`s are supported.
-/// Currently, [`CastExpr`], [`NegativeExpr`], [`BinaryExpr`], [`Column`] and [`Literal`] are supported.
+/// Currently, [`CastExpr`], [`NegativeExpr`], [`NotExpr`], [`BinaryExpr`],
+/// [`InListExpr`], [`LikeExpr`], [`Column`] and [`Literal`] are supported.
 pub fn check_support(expr: &Arc<dyn PhysicalExpr>, schema: &SchemaRef) -> bool {
     let expr_any = expr.as_any();
     if let Some(binary_expr) = expr_any.downcast_ref::<BinaryExpr>() {
@@ -60,11 +64,69 @@ pub fn check_support(expr: &Arc<dyn PhysicalExpr>, schema: &SchemaRef) -> bool {
         check_support(cast.expr(), schema)
     } else if let Some(negative) = expr_any.downcast_ref::<NegativeExpr>() {
         check_support(negative.arg(), schema)
+    } else if let Some(not) = expr_any.downcast_ref::<NotExpr>() {
+        check_support(not.arg(), schema)
+    } else if let Some(in_list) = expr_any.downcast_ref::<InListExpr>() {
+        check_support(in_list.expr(), schema)
+            && in_list
+                .list()
+                .iter()
+                .all(|item| check_support(item, schema))
+    } else if let Some(like) = expr_any.downcast_ref::<LikeExpr>() {
+        check_support(like.expr(), schema) && check_support(like.pattern(), schema)
     } else {
         false
     }
 }
 
+/// Returns the outermost sub-expressions of `expr` that interval arithmetic does
+/// not support, regardless of data types, e.g. the call to `sqrt` in
+/// `sqrt(a) > 3`. The supported expressions are those of [`check_support`].
+pub fn find_unsupported_exprs(
+    expr: &Arc<dyn PhysicalExpr>,
+) -> Vec<Arc<dyn PhysicalExpr>> {
+    let expr_any = expr.as_any();
+    let supported = if let Some(binary_expr) = expr_any.downcast_ref::<BinaryExpr>() {
+        is_operator_supported(binary_expr.op())
+    } else {
+        expr_any.is::<Column>()
+            || expr_any.is::<Literal>()
+            || expr_any.is::<CastExpr>()
+            || expr_any.is::<NegativeExpr>()
+            || expr_any.is::<NotExpr>()
+            || expr_any.is::<InListExpr>()
+            || expr_any.is::<LikeExpr>()
+    };
+    if supported {
+        expr.children()
+            .iter()
+            .flat_map(find_unsupported_exprs)
+            .collect()
+    } else {
+        vec![expr.clone()]
+    }
+}
+
+/// If `expr` is the negation of an ordering comparison, returns the equivalent
+/// comparison with the inverted operator, e.g. `a >= b` for `NOT (a < b)`.
+/// Both sides are `NULL` for the same inputs, so the rewrite is exact.
+/// Otherwise, returns `None`.
+pub fn negate_comparison(expr: &Arc<dyn PhysicalExpr>) -> Option<Arc<dyn PhysicalExpr>> {
+    let not = expr.as_any().downcast_ref::<NotExpr>()?;
+    let binary = not.arg().as_any().downcast_ref::<BinaryExpr>()?;
+    let op = match binary.op() {
+        Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => {
+            binary.op().negate()?
+        }
+        _ => return None,
+    };
+    Some(Arc::new(BinaryExpr::new(
+        binary.left().clone(),
+        op,
+        binary.right().clone(),
+    )))
+}
+
 // This function returns the inverse operator of the given operator.
 pub fn get_inverse_op(op: Operator) -> Operator {
     match op {
@@ -81,6 +143,7 @@ pub fn is_operator_supported(op: &Operator) -> bool {
         &Operator::Plus
             | &Operator::Minus
             | &Operator::And
+            | &Operator::Or
             | &Operator::Gt
             | &Operator::GtEq
             | &Operator::Lt
@@ -103,6 +166,15 @@ pub fn is_datatype_supported(data_type: &DataType) -> bool {
             | &DataType::UInt8
             | &DataType::Float64
             | &DataType::Float32
+            | &DataType::Utf8
+            | &DataType::LargeUtf8
+            | &DataType::Binary
+            | &DataType::LargeBinary
+            | &DataType::Date32
+            | &DataType::Date64
+            | &DataType::Timestamp(_, _)
+            | &DataType::Decimal128(_, _)
+            | &DataType::Decimal256(_, _)
     )
 }
 
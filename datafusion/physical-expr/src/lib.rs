@@ -52,7 +52,11 @@ pub use aggregate::groups_accumulator::{
     EmitTo, GroupsAccumulator, GroupsAccumulatorAdapter,
 };
 pub use aggregate::AggregateExpr;
-pub use analysis::{analyze, AnalysisContext, ExprBoundaries};
+pub use analysis::{
+    analyze, analyze_many, estimate_join_cardinality, fold_point_intervals,
+    prune_partitions, selectivity_to_row_count, AnalysisContext, ExprBoundaries,
+    SelectivityEstimate, SelectivityFeedback,
+};
 pub use equivalence::{
     add_offset_to_lex_ordering, ordering_equivalence_properties_helper,
     project_equivalence_properties, project_ordering_equivalence_properties,
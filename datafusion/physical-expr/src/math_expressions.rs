@@ -17,13 +17,18 @@
 
 //! Math expressions
 
+use crate::functions::make_scalar_function;
 use arrow::array::ArrayRef;
 use arrow::array::{
     BooleanArray, Decimal128Array, Float32Array, Float64Array, Int16Array, Int32Array,
-    Int64Array, Int8Array,
+    Int64Array, Int8Array, PrimitiveArray,
+};
+use arrow::compute::kernels::arity::try_binary;
+use arrow::datatypes::{
+    ArrowNativeTypeOp, DataType, Decimal128Type, Decimal256Type, DecimalType,
 };
-use arrow::datatypes::DataType;
 use arrow::error::ArrowError;
+use datafusion_common::cast::{as_int64_array, as_primitive_array};
 use datafusion_common::ScalarValue;
 use datafusion_common::ScalarValue::{Float32, Int64};
 use datafusion_common::{internal_err, not_impl_err};
@@ -174,8 +179,6 @@ math_unary_function!("atan", atan);
 math_unary_function!("asinh", asinh);
 math_unary_function!("acosh", acosh);
 math_unary_function!("atanh", atanh);
-math_unary_function!("floor", floor);
-math_unary_function!("ceil", ceil);
 math_unary_function!("abs", abs);
 math_unary_function!("signum", signum);
 math_unary_function!("exp", exp);
@@ -383,6 +386,28 @@ pub fn random(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     Ok(ColumnarValue::Array(Arc::new(array)))
 }
 
+/// Floor SQL function. Decimals may be rounded down to a number of decimal
+/// places given as the second argument, see [`round_decimal`].
+pub fn floor(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    match args[0].data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            make_scalar_function(|args| round_decimal(args, DecimalRounding::Floor))(args)
+        }
+        _ => unary_primitive_array_op!(&args[0], "floor", floor),
+    }
+}
+
+/// Ceil SQL function. Decimals may be rounded up to a number of decimal places
+/// given as the second argument, see [`round_decimal`].
+pub fn ceil(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    match args[0].data_type() {
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            make_scalar_function(|args| round_decimal(args, DecimalRounding::Ceil))(args)
+        }
+        _ => unary_primitive_array_op!(&args[0], "ceil", ceil),
+    }
+}
+
 /// Round SQL function
 pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
     if args.len() != 1 && args.len() != 2 {
@@ -471,6 +496,10 @@ pub fn round(args: &[ArrayRef]) -> Result<ArrayRef> {
             ),
         },
 
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            round_decimal(args, DecimalRounding::HalfAwayFromZero)
+        }
+
         other => internal_err!("Unsupported data type {other:?} for function round"),
     }
 }
@@ -659,10 +688,130 @@ pub fn trunc(args: &[ArrayRef]) -> Result<ArrayRef> {
             )) as ArrayRef),
             _ => internal_err!("trunc function requires a scalar or array for precision"),
         },
+        DataType::Decimal128(_, _) | DataType::Decimal256(_, _) => {
+            round_decimal(args, DecimalRounding::Truncate)
+        }
         other => internal_err!("Unsupported data type {other:?} for function trunc"),
     }
 }
 
+/// How [`round_decimal`] drops the digits beyond the requested decimal places.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DecimalRounding {
+    /// Round to the nearest value, and ties away from zero (`round`)
+    HalfAwayFromZero,
+    /// Round towards zero (`trunc`)
+    Truncate,
+    /// Round towards negative infinity (`floor`)
+    Floor,
+    /// Round towards positive infinity (`ceil`)
+    Ceil,
+}
+
+/// Rounds the decimals in `args[0]` to the number of decimal places in
+/// `args[1]`, or to an integer if there is no second argument. Negative places
+/// round to tens, hundreds, etc. The computation is exact, as it works on the
+/// unscaled integer values instead of converting them to floats.
+///
+/// The result has the scale of the input, so rounding only zeroes the dropped
+/// digits, e.g. `round(1.2345, 2)` is `1.2300`. A literal number of places
+/// becomes the scale of the function's return type though, so the planned
+/// function casts the result to it, see
+/// [`BuiltinScalarFunction::return_type_from_literals`]. Except for
+/// truncation, the precision grows by one digit (up to the maximum) to make
+/// room for values rounded away from zero, like `ceil(9.5)`, which is `10.0`.
+/// Values that still do not fit result in an error.
+///
+/// [`BuiltinScalarFunction::return_type_from_literals`]: datafusion_expr::BuiltinScalarFunction::return_type_from_literals
+fn round_decimal(args: &[ArrayRef], rounding: DecimalRounding) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        DataType::Decimal128(precision, scale) => {
+            round_decimal_array::<Decimal128Type>(args, *precision, *scale, rounding)
+        }
+        DataType::Decimal256(precision, scale) => {
+            round_decimal_array::<Decimal256Type>(args, *precision, *scale, rounding)
+        }
+        other => internal_err!("Unsupported data type {other:?} for decimal rounding"),
+    }
+}
+
+fn round_decimal_array<T: DecimalType>(
+    args: &[ArrayRef],
+    precision: u8,
+    scale: i8,
+    rounding: DecimalRounding,
+) -> Result<ArrayRef> {
+    let array = as_primitive_array::<T>(&args[0])?;
+    let result: PrimitiveArray<T> = match args.get(1) {
+        Some(places) => try_binary(array, as_int64_array(places)?, |value, places| {
+            round_decimal_value(value, scale, places, rounding)
+        })?,
+        None => {
+            array.try_unary(|value| round_decimal_value(value, scale, 0, rounding))?
+        }
+    };
+    // Must match the return type of the function, see `BuiltinScalarFunction`
+    let precision = match rounding {
+        DecimalRounding::Truncate => precision,
+        _ => (precision + 1).min(T::MAX_PRECISION),
+    };
+    let result = result.with_precision_and_scale(precision, scale)?;
+    result.validate_decimal_precision(precision)?;
+    Ok(Arc::new(result))
+}
+
+/// Rounds the unscaled decimal `value` with the given `scale` to `places`
+/// decimal places.
+fn round_decimal_value<N: ArrowNativeTypeOp>(
+    value: N,
+    scale: i8,
+    places: i64,
+    rounding: DecimalRounding,
+) -> Result<N, ArrowError> {
+    let dropped_digits = scale as i64 - places;
+    if dropped_digits <= 0 {
+        return Ok(value);
+    }
+    // If the factor overflows, it exceeds any value, so all digits are dropped:
+    let factor = u32::try_from(dropped_digits)
+        .ok()
+        .and_then(|digits| N::usize_as(10).pow_checked(digits).ok());
+    let (truncated, remainder) = match factor {
+        Some(factor) => {
+            let remainder = value.mod_checked(factor)?;
+            (value.sub_wrapping(remainder), remainder)
+        }
+        None => (N::ZERO, value),
+    };
+    let away_from_zero = match rounding {
+        DecimalRounding::Truncate => false,
+        DecimalRounding::Floor => remainder.is_lt(N::ZERO),
+        DecimalRounding::Ceil => remainder.is_gt(N::ZERO),
+        DecimalRounding::HalfAwayFromZero => factor.is_some_and(|factor| {
+            let magnitude = if remainder.is_lt(N::ZERO) {
+                remainder.neg_wrapping()
+            } else {
+                remainder
+            };
+            !magnitude.is_lt(factor.div_wrapping(N::usize_as(2)))
+        }),
+    };
+    if !away_from_zero {
+        return Ok(truncated);
+    }
+    let overflow = || {
+        ArrowError::ComputeError(format!(
+            "Overflow rounding decimal {value:?} to {places} places"
+        ))
+    };
+    let factor = factor.ok_or_else(overflow)?;
+    if remainder.is_lt(N::ZERO) {
+        truncated.sub_checked(factor).map_err(|_| overflow())
+    } else {
+        truncated.add_checked(factor).map_err(|_| overflow())
+    }
+}
+
 fn compute_truncate32(x: f32, y: i64) -> f32 {
     let factor = 10.0_f32.powi(y as i32);
     (x * factor).round() / factor
@@ -741,7 +890,8 @@ pub(super) fn create_abs_function(
 mod tests {
 
     use super::*;
-    use arrow::array::{Float64Array, NullArray};
+    use arrow::array::{Array, Decimal256Array, Float64Array, NullArray};
+    use arrow::datatypes::i256;
     use datafusion_common::cast::{
         as_boolean_array, as_float32_array, as_float64_array, as_int64_array,
     };
@@ -1100,6 +1250,153 @@ mod tests {
         assert_eq!(floats.value(4), -321.0);
     }
 
+    fn decimal128(values: Vec<i128>, precision: u8, scale: i8) -> ArrayRef {
+        Arc::new(
+            Decimal128Array::from(values)
+                .with_precision_and_scale(precision, scale)
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_round_decimal128() {
+        // 125.2345 and -125.2355 with a scale of 4
+        let values = decimal128(vec![1_252_345, -1_252_355], 10, 4);
+        let cases = [
+            (0, [1_250_000, -1_250_000]),
+            (1, [1_252_000, -1_252_000]),
+            (2, [1_252_300, -1_252_400]),
+            (3, [1_252_350, -1_252_360]),
+            (4, [1_252_345, -1_252_355]),
+            (5, [1_252_345, -1_252_355]),
+            (-1, [1_300_000, -1_300_000]),
+            (-2, [1_000_000, -1_000_000]),
+            (-3, [0, 0]),
+            (-50, [0, 0]),
+        ];
+        for (places, expected) in cases {
+            let args = vec![
+                values.clone(),
+                Arc::new(Int64Array::from(vec![places; 2])) as _,
+            ];
+            let result = round(&args).unwrap();
+            assert_eq!(
+                &result,
+                &decimal128(expected.to_vec(), 11, 4),
+                "round to {places} places"
+            );
+        }
+
+        // Ties are rounded away from zero:
+        let args = vec![decimal128(vec![25, -25, 35, 24], 3, 1)];
+        let result = round(&args).unwrap();
+        assert_eq!(&result, &decimal128(vec![30, -30, 40, 20], 4, 1));
+    }
+
+    #[test]
+    fn test_trunc_ceil_floor_decimal128() {
+        // 12.345 and -12.345 with a scale of 3
+        let values = decimal128(vec![12_345, -12_345], 6, 3);
+        let places =
+            |places: i64| Arc::new(Int64Array::from(vec![places; 2])) as ArrayRef;
+
+        let result = trunc(&[values.clone(), places(1)]).unwrap();
+        assert_eq!(&result, &decimal128(vec![12_300, -12_300], 6, 3));
+        let result = trunc(&[values.clone()]).unwrap();
+        assert_eq!(&result, &decimal128(vec![12_000, -12_000], 6, 3));
+        let result = trunc(&[values.clone(), places(-1)]).unwrap();
+        assert_eq!(&result, &decimal128(vec![10_000, -10_000], 6, 3));
+
+        let args = [
+            ColumnarValue::Array(values.clone()),
+            ColumnarValue::Array(places(1)),
+        ];
+        let ColumnarValue::Array(result) = ceil(&args).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(&result, &decimal128(vec![12_400, -12_300], 7, 3));
+        let ColumnarValue::Array(result) = floor(&args).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(&result, &decimal128(vec![12_300, -12_400], 7, 3));
+
+        // Without decimal places, a scalar is rounded to an integer:
+        let args = [ColumnarValue::Scalar(ScalarValue::Decimal128(
+            Some(-12_345),
+            6,
+            3,
+        ))];
+        let ColumnarValue::Array(result) = floor(&args).unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(&result, &decimal128(vec![-13_000], 7, 3));
+        let result = ceil(&[ColumnarValue::Array(decimal128(vec![-12_345, 0, 1], 6, 3))])
+            .unwrap()
+            .into_array(3);
+        assert_eq!(&result, &decimal128(vec![-12_000, 0, 1_000], 7, 3));
+    }
+
+    #[test]
+    fn test_round_decimal_without_precision_loss() {
+        // 12345678901234567890123456789012345.675, which a float can not represent
+        let value = 12_345_678_901_234_567_890_123_456_789_012_345_675_i128;
+        let values = decimal128(vec![value, -value], 38, 3);
+        let places = Arc::new(Int64Array::from(vec![2, 2])) as ArrayRef;
+
+        let result = round(&[values.clone(), places.clone()]).unwrap();
+        let expected = value + 5;
+        assert_eq!(&result, &decimal128(vec![expected, -expected], 38, 3));
+
+        let result = trunc(&[values, places]).unwrap();
+        let expected = value - 5;
+        assert_eq!(&result, &decimal128(vec![expected, -expected], 38, 3));
+    }
+
+    #[test]
+    fn test_round_decimal256() {
+        // 1701411834604692317316873037158841057270 with a scale of 10
+        let value = i256::from_i128(i128::MAX).wrapping_mul(i256::from_i128(10));
+        let args = vec![
+            Arc::new(
+                Decimal256Array::from(vec![value])
+                    .with_precision_and_scale(50, 10)
+                    .unwrap(),
+            ) as ArrayRef,
+            Arc::new(Int64Array::from(vec![-20])) as ArrayRef,
+        ];
+        let result = round(&args).unwrap();
+        let result = result.as_any().downcast_ref::<Decimal256Array>().unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal256(51, 10));
+        let expected =
+            i256::from_string("1701411835000000000000000000000000000000").unwrap();
+        assert_eq!(result.value(0), expected);
+    }
+
+    #[test]
+    fn test_round_decimal_overflow() {
+        let args = vec![
+            decimal128(vec![i128::MAX], 38, 0),
+            Arc::new(Int64Array::from(vec![-1])) as ArrayRef,
+        ];
+        let err = round(&args).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{err}");
+        // The precision is already the maximum, so rounding up does not fit:
+        let args = vec![
+            decimal128(
+                vec![99_999_999_999_999_999_999_999_999_999_999_999_995],
+                38,
+                3,
+            ),
+            Arc::new(Int64Array::from(vec![2])) as ArrayRef,
+        ];
+        assert!(round(&args).is_err());
+        let args = [
+            ColumnarValue::Array(decimal128(vec![-1], 38, 0)),
+            ColumnarValue::Array(Arc::new(Int64Array::from(vec![-40])) as ArrayRef),
+        ];
+        assert!(floor(&args).is_err());
+    }
+
     #[test]
     fn test_nanvl_f64() {
         let args: Vec<ArrayRef> = vec![
@@ -26,8 +26,7 @@ use crate::{
 };
 use arrow::datatypes::Schema;
 use datafusion_common::{
-    exec_err, internal_err, not_impl_err, plan_err, DFSchema, DataFusionError, Result,
-    ScalarValue,
+    internal_err, not_impl_err, plan_err, DFSchema, DataFusionError, Result, ScalarValue,
 };
 use datafusion_expr::expr::{Alias, Cast, InList, ScalarFunction, ScalarUDF};
 use datafusion_expr::{
@@ -189,7 +188,10 @@ pub fn create_physical_expr(
             //
             // There should be no coercion during physical
             // planning.
-            binary(lhs, *op, rhs, input_schema)
+            Ok(Arc::new(
+                expressions::BinaryExpr::new(lhs, *op, rhs)
+                    .with_overflow_mode(execution_props.arithmetic_overflow_mode),
+            ))
         }
         Expr::Like(Like {
             negated,
@@ -198,9 +200,6 @@ pub fn create_physical_expr(
             escape_char,
             case_insensitive,
         }) => {
-            if escape_char.is_some() {
-                return exec_err!("LIKE does not support escape_char");
-            }
             let physical_expr = create_physical_expr(
                 expr,
                 input_dfschema,
@@ -218,6 +217,7 @@ pub fn create_physical_expr(
                 *case_insensitive,
                 physical_expr,
                 physical_pattern,
+                *escape_char,
                 input_schema,
             )
         }
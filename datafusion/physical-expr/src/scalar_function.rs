@@ -30,13 +30,14 @@
 //! to a function that supports f64, it is coerced to f64.
 
 use crate::functions::out_ordering;
+use crate::intervals::{Interval, IntervalBound};
 use crate::physical_expr::down_cast_any_ref;
 use crate::sort_properties::SortProperties;
 use crate::utils::expr_list_eq_strict_order;
 use crate::PhysicalExpr;
 use arrow::datatypes::{DataType, Schema};
 use arrow::record_batch::RecordBatch;
-use datafusion_common::Result;
+use datafusion_common::{Result, ScalarValue};
 use datafusion_expr::expr_vec_fmt;
 use datafusion_expr::BuiltinScalarFunction;
 use datafusion_expr::ColumnarValue;
@@ -109,6 +110,29 @@ impl ScalarFunctionExpr {
     pub fn return_type(&self) -> &DataType {
         &self.return_type
     }
+
+    /// Monotonicity of the function with respect to each argument, if known
+    pub fn monotonicity(&self) -> Option<&FuncMonotonicity> {
+        self.monotonicity.as_ref()
+    }
+
+    /// Evaluates the function for a single row of scalar arguments. Returns
+    /// `None` if the evaluation fails or the result is not a number.
+    fn evaluate_scalars(&self, args: Vec<ScalarValue>) -> Option<ScalarValue> {
+        let args = args
+            .into_iter()
+            .map(ColumnarValue::Scalar)
+            .collect::<Vec<_>>();
+        let value = match (self.fun)(&args).ok()? {
+            ColumnarValue::Scalar(value) => value,
+            ColumnarValue::Array(array) => ScalarValue::try_from_array(&array, 0).ok()?,
+        };
+        match value {
+            ScalarValue::Float32(Some(value)) if value.is_nan() => None,
+            ScalarValue::Float64(Some(value)) if value.is_nan() => None,
+            value => Some(value),
+        }
+    }
 }
 
 impl fmt::Display for ScalarFunctionExpr {
@@ -185,6 +209,49 @@ impl PhysicalExpr for ScalarFunctionExpr {
             .map(|monotonicity| out_ordering(monotonicity, children))
             .unwrap_or(SortProperties::Unordered)
     }
+
+    /// If the function is monotonic with respect to each argument whose
+    /// interval is not a single value, the bounds of the result are those of
+    /// the function evaluated at the appropriate bounds of the arguments.
+    /// Since the function need not be strictly monotonic, the resulting bounds
+    /// are closed. Otherwise, or if the function can not be evaluated at the
+    /// bounds, the result is unbounded.
+    fn evaluate_bounds(&self, children: &[&Interval]) -> Result<Interval> {
+        let unbounded = || {
+            Ok(Interval::new(
+                IntervalBound::make_unbounded(&self.return_type)?,
+                IntervalBound::make_unbounded(&self.return_type)?,
+            ))
+        };
+        let Some(monotonicity) = &self.monotonicity else {
+            return unbounded();
+        };
+        let (mut lower_args, mut upper_args) = (vec![], vec![]);
+        for (index, child) in children.iter().enumerate() {
+            let (lower, upper) = (&child.lower, &child.upper);
+            if lower.is_unbounded() || upper.is_unbounded() {
+                return unbounded();
+            }
+            let (lower, upper) = match monotonicity.get(index).copied().flatten() {
+                Some(true) => (lower, upper),
+                Some(false) => (upper, lower),
+                None if lower.value == upper.value => (lower, upper),
+                None => return unbounded(),
+            };
+            lower_args.push(lower.value.clone());
+            upper_args.push(upper.value.clone());
+        }
+        match (
+            self.evaluate_scalars(lower_args),
+            self.evaluate_scalars(upper_args),
+        ) {
+            (Some(lower), Some(upper)) => Ok(Interval::new(
+                IntervalBound::new_closed(lower),
+                IntervalBound::new_closed(upper),
+            )),
+            _ => unbounded(),
+        }
+    }
 }
 
 impl PartialEq<dyn Any> for ScalarFunctionExpr {
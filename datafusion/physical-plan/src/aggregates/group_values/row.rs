@@ -17,18 +17,26 @@
 
 use crate::aggregates::group_values::GroupValues;
 use ahash::RandomState;
-use arrow::compute::cast;
+use arrow::compute::{cast, take};
 use arrow::record_batch::RecordBatch;
 use arrow::row::{RowConverter, Rows, SortField};
-use arrow_array::{Array, ArrayRef};
-use arrow_schema::{DataType, SchemaRef};
+use arrow_array::{Array, ArrayRef, ListArray, MapArray, StructArray, UInt32Array};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::{DataType, Fields, SchemaRef};
+use datafusion_common::cast::{as_list_array, as_map_array, as_struct_array};
 use datafusion_common::hash_utils::create_hashes;
 use datafusion_common::{DataFusionError, Result};
 use datafusion_execution::memory_pool::proxy::{RawTableAllocExt, VecAllocExt};
 use datafusion_physical_expr::EmitTo;
 use hashbrown::raw::RawTable;
+use std::sync::Arc;
 
 /// A [`GroupValues`] making use of [`Rows`]
+///
+/// Struct group values are compared field by field. Map group values are
+/// compared independently of the order of their entries, i.e. two maps are
+/// equal if they contain the same entries, and the entries of the emitted
+/// maps are sorted.
 pub struct GroupValuesRows {
     /// The output schema
     schema: SchemaRef,
@@ -36,6 +44,11 @@ pub struct GroupValuesRows {
     /// Converter for the group values
     row_converter: RowConverter,
 
+    /// Whether any of the group values contain maps, which are converted
+    /// to lists of their sorted entries before being converted to rows.
+    /// See [`to_row_array`].
+    has_maps: bool,
+
     /// Logically maps group values to a group_index in
     /// [`Self::group_values`] and in each accumulator
     ///
@@ -72,15 +85,20 @@ impl GroupValuesRows {
             schema
                 .fields()
                 .iter()
-                .map(|f| SortField::new(f.data_type().clone()))
+                .map(|f| SortField::new(row_data_type(f.data_type())))
                 .collect(),
         )?;
+        let has_maps = schema
+            .fields()
+            .iter()
+            .any(|f| &row_data_type(f.data_type()) != f.data_type());
 
         let map = RawTable::with_capacity(0);
 
         Ok(Self {
             schema,
             row_converter,
+            has_maps,
             map,
             map_size: 0,
             group_values: None,
@@ -94,7 +112,12 @@ impl GroupValues for GroupValuesRows {
     fn intern(&mut self, cols: &[ArrayRef], groups: &mut Vec<usize>) -> Result<()> {
         // Convert the group keys into the row format
         // Avoid reallocation when https://github.com/apache/arrow-rs/issues/4479 is available
-        let group_rows = self.row_converter.convert_columns(cols)?;
+        let group_rows = if self.has_maps {
+            let row_cols = cols.iter().map(to_row_array).collect::<Result<Vec<_>>>()?;
+            self.row_converter.convert_columns(&row_cols)?
+        } else {
+            self.row_converter.convert_columns(cols)?
+        };
         let n_rows = group_rows.num_rows();
 
         let mut group_values = match self.group_values.take() {
@@ -203,6 +226,12 @@ impl GroupValues for GroupValuesRows {
             }
         };
 
+        if self.has_maps {
+            for (field, array) in self.schema.fields.iter().zip(&mut output) {
+                *array = from_row_array(array.clone(), field.data_type())?;
+            }
+        }
+
         // TODO: Materialize dictionaries in group keys (#7647)
         for (field, array) in self.schema.fields.iter().zip(&mut output) {
             let expected = field.data_type();
@@ -234,3 +263,230 @@ impl GroupValues for GroupValuesRows {
         self.hashes_buffer.shrink_to(count);
     }
 }
+
+/// Returns the type of the arrays that the group values of the given type are
+/// converted to by [`to_row_array`], as the row format does not support maps.
+fn row_data_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Map(entries, _) => DataType::List(Arc::new(
+            entries
+                .as_ref()
+                .clone()
+                .with_data_type(row_data_type(entries.data_type())),
+        )),
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| {
+                    Arc::new(
+                        f.as_ref()
+                            .clone()
+                            .with_data_type(row_data_type(f.data_type())),
+                    )
+                })
+                .collect(),
+        ),
+        _ => data_type.clone(),
+    }
+}
+
+/// Converts the maps in the given array, including those nested in structs,
+/// to lists of their entries. The entries of each map are sorted, so that equal
+/// maps have equal rows regardless of the order of their entries.
+fn to_row_array(array: &ArrayRef) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Map(entries_field, _) => {
+            let map_array = as_map_array(array)?;
+            let entries: ArrayRef = Arc::new(map_array.entries().clone());
+            let entries = to_row_array(&entries)?;
+            let entry_rows = RowConverter::new(
+                as_struct_array(&entries)?
+                    .columns()
+                    .iter()
+                    .map(|column| SortField::new(column.data_type().clone()))
+                    .collect(),
+            )?
+            .convert_columns(as_struct_array(&entries)?.columns())?;
+
+            let value_offsets = map_array.value_offsets();
+            let mut indices = Vec::with_capacity(entries.len());
+            let mut offsets = Vec::with_capacity(value_offsets.len());
+            offsets.push(0);
+            for (start, end) in value_offsets.iter().zip(value_offsets.iter().skip(1)) {
+                let first = indices.len();
+                indices.extend(*start as u32..*end as u32);
+                indices[first..].sort_unstable_by(|a, b| {
+                    entry_rows
+                        .row(*a as usize)
+                        .cmp(&entry_rows.row(*b as usize))
+                });
+                offsets.push(indices.len() as i32);
+            }
+            let sorted_entries = take(&entries, &UInt32Array::from(indices), None)?;
+
+            let field = Arc::new(
+                entries_field
+                    .as_ref()
+                    .clone()
+                    .with_data_type(sorted_entries.data_type().clone()),
+            );
+            Ok(Arc::new(ListArray::try_new(
+                field,
+                OffsetBuffer::new(offsets.into()),
+                sorted_entries,
+                map_array.nulls().cloned(),
+            )?))
+        }
+        DataType::Struct(fields) => {
+            let struct_array = as_struct_array(array)?;
+            let columns = struct_array
+                .columns()
+                .iter()
+                .map(to_row_array)
+                .collect::<Result<Vec<_>>>()?;
+            let fields = fields
+                .iter()
+                .zip(&columns)
+                .map(|(f, column)| {
+                    Arc::new(
+                        f.as_ref()
+                            .clone()
+                            .with_data_type(column.data_type().clone()),
+                    )
+                })
+                .collect::<Fields>();
+            Ok(Arc::new(StructArray::try_new(
+                fields,
+                columns,
+                struct_array.nulls().cloned(),
+            )?))
+        }
+        _ => Ok(array.clone()),
+    }
+}
+
+/// Converts an array created by [`to_row_array`] back to the given type.
+fn from_row_array(array: ArrayRef, data_type: &DataType) -> Result<ArrayRef> {
+    match data_type {
+        DataType::Map(entries_field, ordered) => {
+            let list_array = as_list_array(&array)?;
+            let entries =
+                from_row_array(list_array.values().clone(), entries_field.data_type())?;
+            Ok(Arc::new(MapArray::try_new(
+                entries_field.clone(),
+                list_array.offsets().clone(),
+                as_struct_array(&entries)?.clone(),
+                list_array.nulls().cloned(),
+                *ordered,
+            )?))
+        }
+        DataType::Struct(fields) => {
+            let struct_array = as_struct_array(&array)?;
+            let columns = struct_array
+                .columns()
+                .iter()
+                .zip(fields.iter())
+                .map(|(column, f)| from_row_array(column.clone(), f.data_type()))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Arc::new(StructArray::try_new(
+                fields.clone(),
+                columns,
+                struct_array.nulls().cloned(),
+            )?))
+        }
+        _ => Ok(array),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_array::{Int32Array, StringArray};
+    use arrow_schema::{Field, Schema};
+
+    fn map_array(maps: &[Option<&[(&str, i32)]>]) -> ArrayRef {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for map in maps {
+            match map {
+                Some(entries) => {
+                    for (key, value) in entries.iter() {
+                        builder.keys().append_value(key);
+                        builder.values().append_value(*value);
+                    }
+                    builder.append(true).unwrap();
+                }
+                None => builder.append(false).unwrap(),
+            }
+        }
+        Arc::new(builder.finish())
+    }
+
+    #[test]
+    fn test_group_by_map() -> Result<()> {
+        let maps = map_array(&[
+            Some(&[("a", 1), ("b", 2)]),
+            Some(&[("b", 2), ("a", 1)]),
+            Some(&[("a", 1)]),
+            None,
+            Some(&[("b", 2), ("a", 1)]),
+            Some(&[("a", 2), ("b", 1)]),
+        ]);
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 1, 1, 1, 1, 1]));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("m", maps.data_type().clone(), true),
+            Field::new("i", DataType::Int32, false),
+        ]));
+
+        let mut group_values = GroupValuesRows::try_new(schema)?;
+        let mut groups = vec![];
+        group_values.intern(&[maps, ints], &mut groups)?;
+        // maps with the same entries in a different order are equal
+        assert_eq!(groups, vec![0, 0, 1, 2, 0, 3]);
+
+        // the entries of the emitted maps are sorted
+        let output = group_values.emit(EmitTo::All)?;
+        let expected = map_array(&[
+            Some(&[("a", 1), ("b", 2)]),
+            Some(&[("a", 1)]),
+            None,
+            Some(&[("a", 2), ("b", 1)]),
+        ]);
+        assert_eq!(&output[0], &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_struct_with_map() -> Result<()> {
+        let maps = map_array(&[
+            Some(&[("a", 1), ("b", 2)]),
+            Some(&[("b", 2), ("a", 1)]),
+            Some(&[("b", 2), ("a", 1)]),
+        ]);
+        let names: ArrayRef = Arc::new(StringArray::from(vec!["x", "x", "y"]));
+        let structs: ArrayRef = Arc::new(StructArray::from(vec![
+            (
+                Arc::new(Field::new("m", maps.data_type().clone(), true)),
+                maps,
+            ),
+            (Arc::new(Field::new("n", DataType::Utf8, false)), names),
+        ]));
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "s",
+            structs.data_type().clone(),
+            false,
+        )]));
+
+        let mut group_values = GroupValuesRows::try_new(schema)?;
+        let mut groups = vec![];
+        group_values.intern(&[structs.clone()], &mut groups)?;
+        assert_eq!(groups, vec![0, 0, 1]);
+
+        let output = group_values.emit(EmitTo::All)?;
+        assert_eq!(output[0].data_type(), structs.data_type());
+        assert_eq!(output[0].len(), 2);
+        Ok(())
+    }
+}
@@ -705,12 +705,14 @@ mod tests {
                     max_value: Precision::Absent,
                     min_value: Precision::Absent,
                     null_count: Precision::Exact(0),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Absent,
                     max_value: Precision::Absent,
                     min_value: Precision::Absent,
                     null_count: Precision::Exact(0),
+                    histogram: None,
                 },
             ],
         };
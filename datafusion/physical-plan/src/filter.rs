@@ -19,8 +19,9 @@
 //! include in its output batches.
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::task::{Context, Poll};
 
 use super::expressions::PhysicalSortExpr;
@@ -30,21 +31,24 @@ use super::{
 
 use crate::{
     metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet},
+    statistics_feedback::StatisticsFeedback,
     Column, DisplayFormatType, EquivalenceProperties, ExecutionPlan, Partitioning,
 };
 
 use arrow::compute::filter_record_batch;
-use arrow::datatypes::{DataType, SchemaRef};
+use arrow::datatypes::{DataType, Fields, Schema, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use datafusion_common::cast::as_boolean_array;
 use datafusion_common::stats::Precision;
 use datafusion_common::{plan_err, DataFusionError, Result};
 use datafusion_execution::TaskContext;
 use datafusion_expr::Operator;
-use datafusion_physical_expr::expressions::BinaryExpr;
+use datafusion_physical_expr::expressions::{
+    BinaryExpr, IsNotNullExpr, IsNullExpr, NotExpr,
+};
 use datafusion_physical_expr::{
-    analyze, split_conjunction, AnalysisContext, ExprBoundaries,
-    OrderingEquivalenceProperties, PhysicalExpr,
+    analyze, selectivity_to_row_count, split_conjunction, AnalysisContext,
+    ExprBoundaries, OrderingEquivalenceProperties, PhysicalExpr,
 };
 
 use datafusion_physical_expr::intervals::utils::check_support;
@@ -52,6 +56,7 @@ use datafusion_physical_expr::utils::collect_columns;
 
 use futures::stream::{Stream, StreamExt};
 use log::trace;
+use parking_lot::Mutex;
 
 /// FilterExec evaluates a boolean predicate against all input batches to determine which rows to
 /// include in its output batches.
@@ -61,8 +66,20 @@ pub struct FilterExec {
     predicate: Arc<dyn PhysicalExpr>,
     /// The input plan
     input: Arc<dyn ExecutionPlan>,
+    /// The output schema, which is the schema of the input except that the
+    /// columns checked by `IS NOT NULL` conjuncts of the predicate are not
+    /// nullable
+    schema: SchemaRef,
     /// Execution metrics
     metrics: ExecutionPlanMetricsSet,
+    /// Collects the actual selectivity of the predicate during execution, and
+    /// corrects its estimated selectivity accordingly
+    feedback: Option<Arc<Mutex<StatisticsFeedback>>>,
+    /// The selectivity of the predicate estimated from the statistics of the
+    /// input, which the feedback compares the actual selectivity to. It is
+    /// computed once, when the first partition is executed, and is `None` if
+    /// it can not be estimated.
+    estimated_selectivity: OnceLock<Option<f64>>,
 }
 
 impl FilterExec {
@@ -73,9 +90,12 @@ impl FilterExec {
     ) -> Result<Self> {
         match predicate.data_type(input.schema().as_ref())? {
             DataType::Boolean => Ok(Self {
+                schema: filter_output_schema(&predicate, &input.schema()),
                 predicate,
                 input: input.clone(),
                 metrics: ExecutionPlanMetricsSet::new(),
+                feedback: None,
+                estimated_selectivity: OnceLock::new(),
             }),
             other => {
                 plan_err!("Filter predicate must return boolean values, not {other:?}")
@@ -92,6 +112,61 @@ impl FilterExec {
     pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
         &self.input
     }
+
+    /// Records the actual selectivity of the predicate for each input batch in
+    /// `feedback`, which in turn corrects the estimated selectivity of the
+    /// predicate in the statistics of this plan. The feedback may be shared
+    /// with other plans filtering with the same predicate.
+    pub fn with_feedback(mut self, feedback: Arc<Mutex<StatisticsFeedback>>) -> Self {
+        self.feedback = Some(feedback);
+        self
+    }
+
+    /// The feedback the actual selectivity of the predicate is recorded in, if any
+    pub fn feedback(&self) -> Option<&Arc<Mutex<StatisticsFeedback>>> {
+        self.feedback.as_ref()
+    }
+
+    /// Analyzes the predicate given the statistics of the input, which are
+    /// returned along with the result. Returns `None` if the analysis does not
+    /// support the predicate.
+    fn analyze_predicate(&self) -> Result<Option<(AnalysisContext, Statistics)>> {
+        let predicate = self.predicate();
+
+        let schema = self.schema();
+        if !check_support(predicate, &schema)
+            && !is_column_null_check(predicate)
+            && !is_supported_negation(predicate, &schema)
+        {
+            return Ok(None);
+        }
+        let input_stats = self.input.statistics()?;
+
+        let input_analysis_ctx = AnalysisContext::try_from_statistics(
+            &self.input.schema(),
+            &input_stats.column_statistics,
+        )?
+        .with_num_rows(input_stats.num_rows.clone())
+        // Inputs may not have statistics for all of their columns, which must
+        // not fail the estimate:
+        .with_allow_missing_columns(true);
+        let analysis_ctx = analyze(predicate, input_analysis_ctx)?;
+        Ok(Some((analysis_ctx, input_stats)))
+    }
+
+    /// Returns the selectivity of the predicate estimated from the statistics
+    /// of the input, without any feedback, see [`Self::analyze_predicate`].
+    /// Failing to analyze the predicate leaves the selectivity unknown, as
+    /// the estimate must not fail the execution.
+    fn estimated_selectivity(&self) -> Option<f64> {
+        *self.estimated_selectivity.get_or_init(|| {
+            self.analyze_predicate()
+                .ok()
+                .flatten()
+                .and_then(|(analysis_ctx, _)| analysis_ctx.selectivity)
+                .map(|selectivity| selectivity.point)
+        })
+    }
 }
 
 impl DisplayAs for FilterExec {
@@ -116,8 +191,7 @@ impl ExecutionPlan for FilterExec {
 
     /// Get the schema for this execution plan
     fn schema(&self) -> SchemaRef {
-        // The filter operator does not make any changes to the schema of its input
-        self.input.schema()
+        self.schema.clone()
     }
 
     fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
@@ -173,10 +247,10 @@ impl ExecutionPlan for FilterExec {
         self: Arc<Self>,
         children: Vec<Arc<dyn ExecutionPlan>>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        Ok(Arc::new(FilterExec::try_new(
-            self.predicate.clone(),
-            children[0].clone(),
-        )?))
+        let mut filter =
+            FilterExec::try_new(self.predicate.clone(), children[0].clone())?;
+        filter.feedback = self.feedback.clone();
+        Ok(Arc::new(filter))
     }
 
     fn execute(
@@ -186,11 +260,26 @@ impl ExecutionPlan for FilterExec {
     ) -> Result<SendableRecordBatchStream> {
         trace!("Start FilterExec::execute for partition {} of context session_id {} and task_id {:?}", partition, context.session_id(), context.task_id());
         let baseline_metrics = BaselineMetrics::new(&self.metrics, partition);
+        // The actual selectivity can only be compared to an estimate
+        let feedback = match &self.feedback {
+            Some(feedback) => self.estimated_selectivity().map(|estimated_selectivity| {
+                FeedbackRecorder {
+                    feedback: feedback.clone(),
+                    predicate_id: StatisticsFeedback::predicate_id(
+                        self.predicate.as_ref(),
+                    ),
+                    estimated_selectivity,
+                }
+            }),
+            None => None,
+        };
         Ok(Box::pin(FilterExecStream {
-            schema: self.input.schema(),
+            schema: self.schema.clone(),
+            tightens_nullability: self.schema != self.input.schema(),
             predicate: self.predicate.clone(),
             input: self.input.execute(partition, context)?,
             baseline_metrics,
+            feedback,
         }))
     }
 
@@ -200,32 +289,36 @@ impl ExecutionPlan for FilterExec {
 
     /// The output statistics of a filtering operation can be estimated if the
     /// predicate's selectivity value can be determined for the incoming data.
+    /// If the filter has feedback, the selectivity is corrected by it.
     fn statistics(&self) -> Result<Statistics> {
-        let predicate = self.predicate();
-
-        let schema = self.schema();
-        if !check_support(predicate, &schema) {
-            return Ok(Statistics::new_unknown(&schema));
+        let Some((mut analysis_ctx, input_stats)) = self.analyze_predicate()? else {
+            return Ok(Statistics::new_unknown(&self.schema()));
+        };
+        if let Some(feedback) = &self.feedback {
+            let predicate_id = StatisticsFeedback::predicate_id(self.predicate.as_ref());
+            analysis_ctx = analysis_ctx.with_feedback(&*feedback.lock(), predicate_id);
         }
-        let input_stats = self.input.statistics()?;
 
         let num_rows = input_stats.num_rows;
         let total_byte_size = input_stats.total_byte_size;
-        let input_analysis_ctx = AnalysisContext::try_from_statistics(
-            &self.input.schema(),
-            &input_stats.column_statistics,
-        )?;
-        let analysis_ctx = analyze(predicate, input_analysis_ctx)?;
 
-        // Estimate (inexact) selectivity of predicate
-        let selectivity = analysis_ctx.selectivity.unwrap_or(1.0);
-        let num_rows = match num_rows.get_value() {
-            Some(nr) => Precision::Inexact((*nr as f64 * selectivity).ceil() as usize),
-            None => Precision::Absent,
-        };
-        let total_byte_size = match total_byte_size.get_value() {
-            Some(tbs) => Precision::Inexact((*tbs as f64 * selectivity).ceil() as usize),
-            None => Precision::Absent,
+        // Estimate selectivity of predicate. If it is unknown, or so is its
+        // confidence, the filter may select all rows of the input, which is
+        // thus an (inexact) estimate:
+        let (num_rows, total_byte_size) = match analysis_ctx.selectivity {
+            Some(selectivity) if selectivity.confidence != Precision::Absent => (
+                selectivity_to_row_count(
+                    selectivity.point,
+                    selectivity.confidence.clone(),
+                    num_rows,
+                ),
+                selectivity_to_row_count(
+                    selectivity.point,
+                    selectivity.confidence,
+                    total_byte_size,
+                ),
+            ),
+            _ => (num_rows.to_inexact(), total_byte_size.to_inexact()),
         };
 
         let column_statistics = collect_new_statistics(
@@ -240,6 +333,79 @@ impl ExecutionPlan for FilterExec {
     }
 }
 
+/// Returns the schema of the output of a filter with `predicate` on an input
+/// with `input_schema`. Columns checked by an `IS NOT NULL` (or
+/// `NOT (.. IS NULL)`) conjunct of the predicate are not nullable in it, since
+/// the filter removes all rows in which they are null.
+fn filter_output_schema(
+    predicate: &Arc<dyn PhysicalExpr>,
+    input_schema: &SchemaRef,
+) -> SchemaRef {
+    let non_null_columns = split_conjunction(predicate)
+        .into_iter()
+        .filter_map(|conjunct| {
+            let arg = if let Some(is_not_null) =
+                conjunct.as_any().downcast_ref::<IsNotNullExpr>()
+            {
+                is_not_null.arg()
+            } else {
+                let not = conjunct.as_any().downcast_ref::<NotExpr>()?;
+                not.arg().as_any().downcast_ref::<IsNullExpr>()?.arg()
+            };
+            arg.as_any().downcast_ref::<Column>().map(Column::index)
+        })
+        .filter(|index| {
+            *index < input_schema.fields().len()
+                && input_schema.field(*index).is_nullable()
+        })
+        .collect::<HashSet<_>>();
+    if non_null_columns.is_empty() {
+        return input_schema.clone();
+    }
+
+    let fields = input_schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            if non_null_columns.contains(&index) {
+                Arc::new(field.as_ref().clone().with_nullable(false))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Fields>();
+    Arc::new(Schema::new_with_metadata(
+        fields,
+        input_schema.metadata().clone(),
+    ))
+}
+
+/// Returns whether the given predicate is an `IS NULL` or `IS NOT NULL` check on
+/// a column, whose selectivity can be estimated from the null count of the column.
+fn is_column_null_check(predicate: &Arc<dyn PhysicalExpr>) -> bool {
+    let arg = if let Some(is_null) = predicate.as_any().downcast_ref::<IsNullExpr>() {
+        is_null.arg()
+    } else if let Some(is_not_null) = predicate.as_any().downcast_ref::<IsNotNullExpr>() {
+        is_not_null.arg()
+    } else {
+        return false;
+    };
+    arg.as_any().is::<Column>()
+}
+
+/// Returns whether the given predicate is the negation of a predicate whose
+/// selectivity can be estimated, such as `NOT (a BETWEEN 10 AND 20)`. Its
+/// selectivity is estimated as that of the complement.
+fn is_supported_negation(predicate: &Arc<dyn PhysicalExpr>, schema: &SchemaRef) -> bool {
+    predicate
+        .as_any()
+        .downcast_ref::<NotExpr>()
+        .is_some_and(|not| {
+            check_support(not.arg(), schema) || is_column_null_check(not.arg())
+        })
+}
+
 /// This function ensures that all bounds in the `ExprBoundaries` vector are
 /// converted to closed bounds. If a lower/upper bound is initially open, it
 /// is adjusted by using the next/previous value for its data type to convert
@@ -257,6 +423,7 @@ fn collect_new_statistics(
                 ExprBoundaries {
                     interval,
                     distinct_count,
+                    histogram,
                     ..
                 },
             )| {
@@ -272,6 +439,7 @@ fn collect_new_statistics(
                         Some(dc) => Precision::Inexact(*dc),
                         None => Precision::Absent,
                     },
+                    histogram,
                 }
             },
         )
@@ -281,14 +449,43 @@ fn collect_new_statistics(
 /// The FilterExec streams wraps the input iterator and applies the predicate expression to
 /// determine which rows to include in its output batches
 struct FilterExecStream {
-    /// Output schema, which is the same as the input schema for this operator
+    /// Output schema of the [`FilterExec`]
     schema: SchemaRef,
+    /// Whether the output schema has fewer nullable columns than the input
+    /// schema, in which case the filtered batches are converted to it
+    tightens_nullability: bool,
     /// The expression to filter on. This expression must evaluate to a boolean value.
     predicate: Arc<dyn PhysicalExpr>,
     /// The input partition to filter.
     input: SendableRecordBatchStream,
     /// runtime metrics recording
     baseline_metrics: BaselineMetrics,
+    /// Records the actual selectivity of the predicate for each batch, if any
+    feedback: Option<FeedbackRecorder>,
+}
+
+/// Records the actual selectivities of the predicate of a [`FilterExec`] in its
+/// [`StatisticsFeedback`]
+struct FeedbackRecorder {
+    feedback: Arc<Mutex<StatisticsFeedback>>,
+    predicate_id: u64,
+    /// The selectivity estimated from the statistics of the input, without
+    /// the corrections of the feedback
+    estimated_selectivity: f64,
+}
+
+impl FeedbackRecorder {
+    fn record(&self, input_rows: usize, output_rows: usize) {
+        if input_rows == 0 {
+            return;
+        }
+        let actual_selectivity = output_rows as f64 / input_rows as f64;
+        self.feedback.lock().record_actual(
+            self.predicate_id,
+            self.estimated_selectivity,
+            actual_selectivity,
+        );
+    }
 }
 
 pub(crate) fn batch_filter(
@@ -319,10 +516,21 @@ impl Stream for FilterExecStream {
                     Some(Ok(batch)) => {
                         let timer = self.baseline_metrics.elapsed_compute().timer();
                         let filtered_batch = batch_filter(&batch, &self.predicate)?;
+                        if let Some(feedback) = &self.feedback {
+                            feedback.record(batch.num_rows(), filtered_batch.num_rows());
+                        }
                         // skip entirely filtered batches
                         if filtered_batch.num_rows() == 0 {
                             continue;
                         }
+                        let filtered_batch = if self.tightens_nullability {
+                            RecordBatch::try_new(
+                                self.schema.clone(),
+                                filtered_batch.columns().to_vec(),
+                            )?
+                        } else {
+                            filtered_batch
+                        };
                         timer.done();
                         poll = Poll::Ready(Some(Ok(filtered_batch)));
                         break;
@@ -393,9 +601,10 @@ mod tests {
     use super::*;
     use crate::expressions::*;
     use crate::test;
-    use crate::test::exec::StatisticsExec;
-    use crate::ExecutionPlan;
+    use crate::test::exec::{MockExec, StatisticsExec};
+    use crate::{collect, ExecutionPlan};
 
+    use arrow::array::{Array, Int32Array};
     use arrow::datatypes::{DataType, Field, Schema};
     use datafusion_common::{ColumnStatistics, ScalarValue};
     use datafusion_expr::Operator;
@@ -929,6 +1138,198 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_filter_statistics_with_histogram() -> Result<()> {
+        // Table:
+        //      a: min=1, max=100, 90% of the values are in [1, 10]
+        //      b: min=1, max=100, no histogram
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]);
+        let input = Arc::new(StatisticsExec::new(
+            Statistics {
+                num_rows: Precision::Inexact(1000),
+                total_byte_size: Precision::Inexact(4000),
+                column_statistics: vec![
+                    ColumnStatistics {
+                        min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
+                        max_value: Precision::Inexact(ScalarValue::Int32(Some(100))),
+                        histogram: Some(vec![
+                            (ScalarValue::Int32(Some(10)), 900),
+                            (ScalarValue::Int32(Some(100)), 1000),
+                        ]),
+                        ..Default::default()
+                    },
+                    ColumnStatistics {
+                        min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
+                        max_value: Precision::Inexact(ScalarValue::Int32(Some(100))),
+                        ..Default::default()
+                    },
+                ],
+            },
+            schema.clone(),
+        ));
+
+        // WHERE a <= 10
+        let filter: Arc<dyn ExecutionPlan> = Arc::new(FilterExec::try_new(
+            binary(col("a", &schema)?, Operator::LtEq, lit(10i32), &schema)?,
+            input.clone(),
+        )?);
+        let statistics = filter.statistics()?;
+        // The uniform assumption would estimate 10% of the rows; the histogram
+        // tells us that 90% of the rows satisfy the predicate.
+        assert_eq!(statistics.num_rows, Precision::Inexact(900));
+
+        // WHERE a <= 55 AND b <= 50
+        let filter: Arc<dyn ExecutionPlan> = Arc::new(FilterExec::try_new(
+            binary(
+                binary(col("a", &schema)?, Operator::LtEq, lit(55i32), &schema)?,
+                Operator::And,
+                binary(col("b", &schema)?, Operator::LtEq, lit(50i32), &schema)?,
+                &schema,
+            )?,
+            input,
+        )?);
+        let statistics = filter.statistics()?;
+        // The predicate on 'a' covers the whole first bucket (90%) and half of
+        // the second bucket (5%), while the predicate on 'b' uniformly selects
+        // half of the rows: (0.9 + 0.05) * 0.5 = 0.475
+        assert_eq!(statistics.num_rows, Precision::Inexact(475));
+        assert_eq!(
+            statistics.column_statistics,
+            vec![
+                ColumnStatistics {
+                    min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
+                    max_value: Precision::Inexact(ScalarValue::Int32(Some(55))),
+                    histogram: Some(vec![
+                        (ScalarValue::Int32(Some(10)), 900),
+                        (ScalarValue::Int32(Some(55)), 950),
+                    ]),
+                    ..Default::default()
+                },
+                ColumnStatistics {
+                    min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
+                    max_value: Precision::Inexact(ScalarValue::Int32(Some(50))),
+                    ..Default::default()
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_filter_statistics_with_null_count() -> Result<()> {
+        // Table:
+        //      a: min=1, max=100, null_count=40
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let input = Arc::new(StatisticsExec::new(
+            Statistics {
+                num_rows: Precision::Inexact(100),
+                total_byte_size: Precision::Absent,
+                column_statistics: vec![ColumnStatistics {
+                    min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
+                    max_value: Precision::Inexact(ScalarValue::Int32(Some(100))),
+                    null_count: Precision::Inexact(40),
+                    ..Default::default()
+                }],
+            },
+            schema.clone(),
+        ));
+
+        // WHERE a <= 25
+        let predicate = binary(col("a", &schema)?, Operator::LtEq, lit(25i32), &schema)?;
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, input.clone())?);
+        assert_eq!(filter.statistics()?.num_rows, Precision::Inexact(15));
+
+        // WHERE a IS NULL
+        let filter: Arc<dyn ExecutionPlan> = Arc::new(FilterExec::try_new(
+            is_null(col("a", &schema)?)?,
+            input.clone(),
+        )?);
+        assert_eq!(filter.statistics()?.num_rows, Precision::Inexact(40));
+
+        // WHERE a IS NOT NULL
+        let filter: Arc<dyn ExecutionPlan> = Arc::new(FilterExec::try_new(
+            is_not_null(col("a", &schema)?)?,
+            input,
+        )?);
+        assert_eq!(filter.statistics()?.num_rows, Precision::Inexact(60));
+
+        // Without a null count, the selectivity of null checks is unknown, so
+        // all rows of the input may be selected
+        let input = Arc::new(StatisticsExec::new(
+            Statistics {
+                num_rows: Precision::Exact(100),
+                total_byte_size: Precision::Exact(800),
+                column_statistics: vec![ColumnStatistics::new_unknown()],
+            },
+            schema.clone(),
+        ));
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(is_null(col("a", &schema)?)?, input)?);
+        let statistics = filter.statistics()?;
+        assert_eq!(statistics.num_rows, Precision::Inexact(100));
+        assert_eq!(statistics.total_byte_size, Precision::Inexact(800));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_filter_statistics_of_negation() -> Result<()> {
+        // Table:
+        //      a: min=1, max=100
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let input = Arc::new(StatisticsExec::new(
+            Statistics {
+                num_rows: Precision::Inexact(100),
+                total_byte_size: Precision::Absent,
+                column_statistics: vec![ColumnStatistics {
+                    min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
+                    max_value: Precision::Inexact(ScalarValue::Int32(Some(100))),
+                    ..Default::default()
+                }],
+            },
+            schema.clone(),
+        ));
+
+        // WHERE NOT (a BETWEEN 1 AND 25)
+        let predicate = not(binary(
+            binary(col("a", &schema)?, Operator::GtEq, lit(1i32), &schema)?,
+            Operator::And,
+            binary(col("a", &schema)?, Operator::LtEq, lit(25i32), &schema)?,
+            &schema,
+        )?)?;
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, input.clone())?);
+        let statistics = filter.statistics()?;
+        assert_eq!(statistics.num_rows, Precision::Inexact(75));
+        assert_eq!(
+            statistics.column_statistics[0].max_value,
+            Precision::Inexact(ScalarValue::Int32(Some(100)))
+        );
+
+        // WHERE NOT (a < 26)
+        let predicate = not(binary(
+            col("a", &schema)?,
+            Operator::Lt,
+            lit(26i32),
+            &schema,
+        )?)?;
+        let filter: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::try_new(predicate, input)?);
+        let statistics = filter.statistics()?;
+        assert_eq!(statistics.num_rows, Precision::Inexact(75));
+        assert_eq!(
+            statistics.column_statistics[0].min_value,
+            Precision::Inexact(ScalarValue::Int32(Some(26)))
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_empty_input_statistics() -> Result<()> {
         let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
@@ -966,6 +1367,7 @@ mod tests {
                 min_value: Precision::Inexact(ScalarValue::Int32(Some(5))),
                 max_value: Precision::Inexact(ScalarValue::Int32(Some(10))),
                 distinct_count: Precision::Absent,
+                histogram: None,
             }],
         };
 
@@ -973,4 +1375,142 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_filter_statistics_with_feedback() -> Result<()> {
+        // Table:
+        //      a: min=1, max=100
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let input = Arc::new(StatisticsExec::new(
+            Statistics {
+                num_rows: Precision::Inexact(100),
+                total_byte_size: Precision::Absent,
+                column_statistics: vec![ColumnStatistics {
+                    min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
+                    max_value: Precision::Inexact(ScalarValue::Int32(Some(100))),
+                    ..Default::default()
+                }],
+            },
+            schema.clone(),
+        ));
+
+        // WHERE a <= 25
+        let predicate: Arc<dyn PhysicalExpr> =
+            binary(col("a", &schema)?, Operator::LtEq, lit(25i32), &schema)?;
+        let predicate_id = StatisticsFeedback::predicate_id(predicate.as_ref());
+        let feedback = Arc::new(Mutex::new(StatisticsFeedback::new()));
+        let filter: Arc<dyn ExecutionPlan> = Arc::new(
+            FilterExec::try_new(predicate, input.clone())?
+                .with_feedback(feedback.clone()),
+        );
+        assert_eq!(filter.statistics()?.num_rows, Precision::Inexact(25));
+
+        // The predicate was observed to select half of the rows:
+        feedback.lock().record_actual(predicate_id, 0.25, 0.5);
+        assert_eq!(filter.statistics()?.num_rows, Precision::Inexact(50));
+        let filter = filter.with_new_children(vec![input])?;
+        assert_eq!(filter.statistics()?.num_rows, Precision::Inexact(50));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_filter_execution_records_feedback() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![
+                None,
+                None,
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(4),
+                Some(5),
+                Some(6),
+                Some(7),
+                Some(8),
+            ]))],
+        )?;
+        let input = Arc::new(MockExec::new(vec![Ok(batch)], schema.clone()));
+
+        // WHERE a IS NULL, which is estimated from the null count
+        let predicate = is_null(col("a", &schema)?)?;
+        let predicate_id = StatisticsFeedback::predicate_id(predicate.as_ref());
+        let feedback = Arc::new(Mutex::new(StatisticsFeedback::new()));
+        let filter = Arc::new(
+            FilterExec::try_new(predicate, input)?.with_feedback(feedback.clone()),
+        );
+        assert_eq!(feedback.lock().correction_factor(predicate_id), None);
+        // The selectivity is only estimated once the filter is executed
+        assert_eq!(filter.estimated_selectivity.get(), None);
+
+        let batches = collect(filter.clone(), Arc::new(TaskContext::default())).await?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].column(0).null_count(), 2);
+        assert_eq!(filter.estimated_selectivity.get(), Some(&Some(0.2)));
+        // The predicate selected as many rows as estimated:
+        assert_eq!(feedback.lock().correction_factor(predicate_id), Some(1.0));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_filter_output_nullability() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Int32, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![None, Some(1), Some(2), Some(3)])),
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(2), Some(3)])),
+                Arc::new(Int32Array::from(vec![None, None, None, Some(3)])),
+            ],
+        )?;
+        let input = Arc::new(MockExec::new(vec![Ok(batch)], schema.clone()));
+        let nullability = |filter: &FilterExec| {
+            filter
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| field.is_nullable())
+                .collect::<Vec<_>>()
+        };
+
+        // WHERE a IS NOT NULL AND NOT (b IS NULL) AND c > 1
+        let predicate = binary(
+            binary(
+                is_not_null(col("a", &schema)?)?,
+                Operator::And,
+                not(is_null(col("b", &schema)?)?)?,
+                &schema,
+            )?,
+            Operator::And,
+            binary(col("c", &schema)?, Operator::Gt, lit(1i32), &schema)?,
+            &schema,
+        )?;
+        let filter = Arc::new(FilterExec::try_new(predicate, input.clone())?);
+        assert_eq!(nullability(&filter), vec![false, false, true]);
+
+        let batches = collect(filter.clone(), Arc::new(TaskContext::default())).await?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].num_rows(), 1);
+        assert_eq!(batches[0].schema(), filter.schema());
+
+        // Disjunctions do not guarantee that either column is non-null
+        let predicate = binary(
+            is_not_null(col("a", &schema)?)?,
+            Operator::Or,
+            is_not_null(col("b", &schema)?)?,
+            &schema,
+        )?;
+        let filter = FilterExec::try_new(predicate, input)?;
+        assert_eq!(nullability(&filter), vec![true, true, true]);
+        assert_eq!(filter.schema(), schema);
+
+        Ok(())
+    }
 }
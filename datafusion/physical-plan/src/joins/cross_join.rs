@@ -285,7 +285,8 @@ fn stats_cartesian_product(
     let right_col_stats = right_stats.column_statistics;
 
     // the null counts must be multiplied by the row counts of the other side (if defined)
-    // Min, max and distinct_count on the other hand are invariants.
+    // Min, max, distinct_count and the shape of the histogram on the other
+    // hand are invariants.
     let cross_join_stats = left_col_stats
         .into_iter()
         .map(|s| ColumnStatistics {
@@ -293,12 +294,14 @@ fn stats_cartesian_product(
             distinct_count: s.distinct_count,
             min_value: s.min_value,
             max_value: s.max_value,
+            histogram: s.histogram,
         })
         .chain(right_col_stats.into_iter().map(|s| ColumnStatistics {
             null_count: s.null_count.multiply(&left_row_count),
             distinct_count: s.distinct_count,
             min_value: s.min_value,
             max_value: s.max_value,
+            histogram: s.histogram,
         }))
         .collect();
 
@@ -471,6 +474,7 @@ mod tests {
                     max_value: Precision::Exact(ScalarValue::Int64(Some(21))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(-4))),
                     null_count: Precision::Exact(0),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Exact(1),
@@ -481,6 +485,7 @@ mod tests {
                         "a",
                     )))),
                     null_count: Precision::Exact(3),
+                    histogram: None,
                 },
             ],
         };
@@ -493,6 +498,7 @@ mod tests {
                 max_value: Precision::Exact(ScalarValue::Int64(Some(12))),
                 min_value: Precision::Exact(ScalarValue::Int64(Some(0))),
                 null_count: Precision::Exact(2),
+                histogram: None,
             }],
         };
 
@@ -507,6 +513,7 @@ mod tests {
                     max_value: Precision::Exact(ScalarValue::Int64(Some(21))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(-4))),
                     null_count: Precision::Exact(0),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Exact(1),
@@ -517,12 +524,14 @@ mod tests {
                         "a",
                     )))),
                     null_count: Precision::Exact(3 * right_row_count),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Exact(3),
                     max_value: Precision::Exact(ScalarValue::Int64(Some(12))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(0))),
                     null_count: Precision::Exact(2 * left_row_count),
+                    histogram: None,
                 },
             ],
         };
@@ -543,6 +552,7 @@ mod tests {
                     max_value: Precision::Exact(ScalarValue::Int64(Some(21))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(-4))),
                     null_count: Precision::Exact(0),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Exact(1),
@@ -553,6 +563,7 @@ mod tests {
                         "a",
                     )))),
                     null_count: Precision::Exact(3),
+                    histogram: None,
                 },
             ],
         };
@@ -565,6 +576,7 @@ mod tests {
                 max_value: Precision::Exact(ScalarValue::Int64(Some(12))),
                 min_value: Precision::Exact(ScalarValue::Int64(Some(0))),
                 null_count: Precision::Exact(2),
+                histogram: None,
             }],
         };
 
@@ -579,6 +591,7 @@ mod tests {
                     max_value: Precision::Exact(ScalarValue::Int64(Some(21))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(-4))),
                     null_count: Precision::Absent, // we don't know the row count on the right
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Exact(1),
@@ -589,12 +602,14 @@ mod tests {
                         "a",
                     )))),
                     null_count: Precision::Absent, // we don't know the row count on the right
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Exact(3),
                     max_value: Precision::Exact(ScalarValue::Int64(Some(12))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(0))),
                     null_count: Precision::Exact(2 * left_row_count),
+                    histogram: None,
                 },
             ],
         };
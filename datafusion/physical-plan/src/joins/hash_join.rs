@@ -232,10 +232,15 @@ impl DisplayAs for HashJoinExec {
                     .map(|(c1, c2)| format!("({}, {})", c1, c2))
                     .collect::<Vec<String>>()
                     .join(", ");
+                let display_null_equality = if self.null_equals_null {
+                    ", null_equals_null=true"
+                } else {
+                    ""
+                };
                 write!(
                     f,
-                    "HashJoinExec: mode={:?}, join_type={:?}, on=[{}]{}",
-                    self.mode, self.join_type, on, display_filter
+                    "HashJoinExec: mode={:?}, join_type={:?}, on=[{}]{}{}",
+                    self.mode, self.join_type, on, display_null_equality, display_filter
                 )
             }
         }
@@ -2904,6 +2909,130 @@ mod tests {
         Ok(())
     }
 
+    fn build_table_nullable_keys(
+        key: (&str, Vec<Option<i32>>),
+        value: (&str, Vec<i32>),
+    ) -> Arc<dyn ExecutionPlan> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(key.0, DataType::Int32, true),
+            Field::new(value.0, DataType::Int32, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(key.1)),
+                Arc::new(Int32Array::from(value.1)),
+            ],
+        )
+        .unwrap();
+        Arc::new(MemoryExec::try_new(&[vec![batch]], schema, None).unwrap())
+    }
+
+    #[tokio::test]
+    async fn join_null_equals_null() -> Result<()> {
+        let task_ctx = Arc::new(TaskContext::default());
+        let left = build_table_nullable_keys(
+            ("a1", vec![Some(1), None, Some(3), None]),
+            ("b1", vec![10, 20, 30, 40]),
+        );
+        let right = build_table_nullable_keys(
+            ("a2", vec![Some(1), None, Some(4)]),
+            ("b2", vec![100, 200, 300]),
+        );
+        let on = vec![(
+            Column::new_with_schema("a1", &left.schema())?,
+            Column::new_with_schema("a2", &right.schema())?,
+        )];
+
+        // NULL keys do not match each other by default
+        let expected_null_not_equal = [
+            "+----+----+----+-----+",
+            "| a1 | b1 | a2 | b2  |",
+            "+----+----+----+-----+",
+            "| 1  | 10 | 1  | 100 |",
+            "+----+----+----+-----+",
+        ];
+        // NULL keys on both sides match each other in null-equality mode
+        let expected_null_equal = [
+            "+----+----+----+-----+",
+            "| a1 | b1 | a2 | b2  |",
+            "+----+----+----+-----+",
+            "|    | 20 |    | 200 |",
+            "|    | 40 |    | 200 |",
+            "| 1  | 10 | 1  | 100 |",
+            "+----+----+----+-----+",
+        ];
+
+        for (null_equals_null, expected) in [
+            (false, &expected_null_not_equal[..]),
+            (true, &expected_null_equal[..]),
+        ] {
+            let (_, batches) = join_collect(
+                left.clone(),
+                right.clone(),
+                on.clone(),
+                &JoinType::Inner,
+                null_equals_null,
+                task_ctx.clone(),
+            )
+            .await?;
+            assert_batches_sorted_eq!(expected, &batches);
+
+            let (_, batches) = partitioned_join_collect(
+                left.clone(),
+                right.clone(),
+                on.clone(),
+                &JoinType::Inner,
+                null_equals_null,
+                task_ctx.clone(),
+            )
+            .await?;
+            assert_batches_sorted_eq!(expected, &batches);
+        }
+
+        // In a full join, rows with NULL keys are emitted as unmatched rows by
+        // default, whereas they match each other in null-equality mode.
+        let (_, batches) = join_collect(
+            left.clone(),
+            right.clone(),
+            on.clone(),
+            &JoinType::Full,
+            false,
+            task_ctx.clone(),
+        )
+        .await?;
+        let expected = [
+            "+----+----+----+-----+",
+            "| a1 | b1 | a2 | b2  |",
+            "+----+----+----+-----+",
+            "|    |    |    | 200 |",
+            "|    |    | 4  | 300 |",
+            "|    | 20 |    |     |",
+            "|    | 40 |    |     |",
+            "| 1  | 10 | 1  | 100 |",
+            "| 3  | 30 |    |     |",
+            "+----+----+----+-----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        let (_, batches) =
+            join_collect(left, right, on, &JoinType::Full, true, task_ctx).await?;
+        let expected = [
+            "+----+----+----+-----+",
+            "| a1 | b1 | a2 | b2  |",
+            "+----+----+----+-----+",
+            "|    |    | 4  | 300 |",
+            "|    | 20 |    | 200 |",
+            "|    | 40 |    | 200 |",
+            "| 1  | 10 | 1  | 100 |",
+            "| 3  | 30 |    |     |",
+            "+----+----+----+-----+",
+        ];
+        assert_batches_sorted_eq!(expected, &batches);
+
+        Ok(())
+    }
+
     /// Returns the column names on the schema
     fn columns(schema: &Schema) -> Vec<String> {
         schema.fields().iter().map(|f| f.name().clone()).collect()
@@ -62,6 +62,7 @@ pub mod metrics;
 pub mod projection;
 pub mod repartition;
 pub mod sorts;
+pub mod statistics_feedback;
 pub mod stream;
 pub mod streaming;
 pub mod tree_node;
@@ -0,0 +1,181 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Marker operator that declares the sort order of its input without sorting it
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::expressions::PhysicalSortExpr;
+use crate::{
+    DisplayAs, DisplayFormatType, EquivalenceProperties, ExecutionPlan, Partitioning,
+    SendableRecordBatchStream, Statistics,
+};
+
+use arrow::datatypes::SchemaRef;
+use datafusion_common::Result;
+use datafusion_execution::TaskContext;
+
+/// Passes the batches of its input through unchanged while reporting a
+/// caller-provided ordering as its output ordering.
+///
+/// This lets a caller who knows that the data is sorted (e.g. because it was
+/// written that way) inform the optimizer so that subsequent sorts on the
+/// same keys are removed. The ordering applies to every partition
+/// individually, just like [`ExecutionPlan::output_ordering`].
+///
+/// The ordering is trusted as is: if the input is not actually sorted by
+/// `expr`, operators relying on the ordering produce incorrect results.
+#[derive(Debug)]
+pub struct DeclaredOrderExec {
+    /// The input plan
+    input: Arc<dyn ExecutionPlan>,
+    /// The ordering the input is declared to have
+    expr: Vec<PhysicalSortExpr>,
+}
+
+impl DeclaredOrderExec {
+    /// Create a new DeclaredOrderExec declaring that `input` is sorted by `expr`
+    pub fn new(expr: Vec<PhysicalSortExpr>, input: Arc<dyn ExecutionPlan>) -> Self {
+        Self { input, expr }
+    }
+
+    /// The input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// The declared ordering
+    pub fn expr(&self) -> &[PhysicalSortExpr] {
+        &self.expr
+    }
+}
+
+impl DisplayAs for DeclaredOrderExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let expr = PhysicalSortExpr::format_list(&self.expr);
+                write!(f, "DeclaredOrderExec: expr=[{expr}]")
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for DeclaredOrderExec {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.input.schema()
+    }
+
+    fn output_partitioning(&self) -> Partitioning {
+        self.input.output_partitioning()
+    }
+
+    fn unbounded_output(&self, children: &[bool]) -> Result<bool> {
+        Ok(children[0])
+    }
+
+    fn output_ordering(&self) -> Option<&[PhysicalSortExpr]> {
+        Some(&self.expr)
+    }
+
+    /// The output ordering is declared rather than derived from the input,
+    /// so sorts must not be pushed through this operator.
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![false]
+    }
+
+    /// Repartitioning the input would destroy the declared ordering.
+    fn benefits_from_input_partitioning(&self) -> Vec<bool> {
+        vec![false]
+    }
+
+    fn equivalence_properties(&self) -> EquivalenceProperties {
+        self.input.equivalence_properties()
+    }
+
+    fn children(&self) -> Vec<Arc<dyn ExecutionPlan>> {
+        vec![self.input.clone()]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(DeclaredOrderExec::new(
+            self.expr.clone(),
+            children[0].clone(),
+        )))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        self.input.execute(partition, context)
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        self.input.statistics()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collect;
+    use crate::expressions::col;
+    use crate::memory::MemoryExec;
+    use arrow::array::Int32Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+
+    #[tokio::test]
+    async fn test_declared_order_passes_input_through() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+        )?;
+        let input = Arc::new(MemoryExec::try_new(
+            &[vec![batch.clone()]],
+            schema.clone(),
+            None,
+        )?);
+        assert!(input.output_ordering().is_none());
+
+        let expr = vec![PhysicalSortExpr {
+            expr: col("a", &schema)?,
+            options: Default::default(),
+        }];
+        let exec = Arc::new(DeclaredOrderExec::new(expr.clone(), input));
+        assert_eq!(exec.output_ordering(), Some(expr.as_slice()));
+
+        let result = collect(exec, Arc::new(TaskContext::default())).await?;
+        assert_eq!(result, vec![batch]);
+        Ok(())
+    }
+}
@@ -19,6 +19,7 @@
 
 mod builder;
 mod cursor;
+pub mod declared_order;
 mod index;
 mod merge;
 pub mod sort;
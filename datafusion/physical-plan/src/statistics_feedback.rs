@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Feedback of the selectivities observed during execution into the
+//! selectivity estimates of the statistics analysis
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use datafusion_common::{plan_err, DataFusionError, Result};
+use datafusion_physical_expr::{PhysicalExpr, SelectivityFeedback};
+
+/// The weight of the latest observation in the moving averages of
+/// [`StatisticsFeedback`], unless set by [`StatisticsFeedback::with_smoothing`]
+const DEFAULT_SMOOTHING: f64 = 0.5;
+
+/// Collects the actual selectivities of predicates observed during execution,
+/// e.g. by a [`FilterExec`], to correct the selectivities estimated from
+/// statistics when the same predicate is planned or executed again, such as a
+/// sub-plan that is re-used by a recursive query or rebuilt by a join.
+///
+/// For each predicate, identified by [`Self::predicate_id`], it keeps an
+/// exponential moving average of the ratio between the actual and the
+/// estimated selectivity. Estimates are corrected by multiplying them with
+/// this ratio.
+///
+/// [`FilterExec`]: crate::filter::FilterExec
+#[derive(Debug, Clone)]
+pub struct StatisticsFeedback {
+    /// The weight of the latest observation in the moving averages, in (0, 1]
+    smoothing: f64,
+    /// The moving average of the ratio between the actual and the estimated
+    /// selectivity, for each predicate id
+    corrections: HashMap<u64, f64>,
+}
+
+impl Default for StatisticsFeedback {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatisticsFeedback {
+    /// Creates feedback without any observations
+    pub fn new() -> Self {
+        Self {
+            smoothing: DEFAULT_SMOOTHING,
+            corrections: HashMap::new(),
+        }
+    }
+
+    /// Sets the weight of the latest observation in the moving averages, which
+    /// must be greater than 0.0 and at most 1.0. The larger it is, the faster
+    /// the corrections follow changes of the actual selectivities.
+    pub fn with_smoothing(mut self, smoothing: f64) -> Result<Self> {
+        if !(smoothing > 0.0 && smoothing <= 1.0) {
+            return plan_err!(
+                "Smoothing of statistics feedback must be in (0.0, 1.0], got {smoothing}"
+            );
+        }
+        self.smoothing = smoothing;
+        Ok(self)
+    }
+
+    /// Returns the id of `predicate`, which is a hash of its display form.
+    /// Equal predicates on the same input get the same id across plans.
+    pub fn predicate_id(predicate: &dyn PhysicalExpr) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        predicate.to_string().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Records that the predicate identified by `predicate_id`, whose
+    /// selectivity was estimated to be `estimated_selectivity`, selected
+    /// `actual_selectivity` of its input. Observations for estimates of zero
+    /// are ignored, as they can not be corrected by a factor.
+    pub fn record_actual(
+        &mut self,
+        predicate_id: u64,
+        estimated_selectivity: f64,
+        actual_selectivity: f64,
+    ) {
+        if !(estimated_selectivity > 0.0 && actual_selectivity.is_finite()) {
+            return;
+        }
+        let ratio = actual_selectivity.max(0.0) / estimated_selectivity;
+        let smoothing = self.smoothing;
+        self.corrections
+            .entry(predicate_id)
+            .and_modify(|average| {
+                *average = smoothing * ratio + (1.0 - smoothing) * *average
+            })
+            .or_insert(ratio);
+    }
+
+    /// Returns the factor by which the estimated selectivity of the predicate
+    /// identified by `predicate_id` is corrected, if anything was recorded
+    /// for it.
+    pub fn correction_factor(&self, predicate_id: u64) -> Option<f64> {
+        self.corrections.get(&predicate_id).copied()
+    }
+
+    /// Returns the `estimated` selectivity of the predicate identified by
+    /// `predicate_id`, multiplied by its correction factor and capped at 1.0.
+    /// Estimates of predicates without any observations are returned as is.
+    pub fn adjust_selectivity(&self, estimated: f64, predicate_id: u64) -> f64 {
+        match self.correction_factor(predicate_id) {
+            Some(factor) => (estimated * factor).clamp(0.0, 1.0),
+            None => estimated,
+        }
+    }
+}
+
+impl SelectivityFeedback for StatisticsFeedback {
+    fn adjust_selectivity(&self, estimated: f64, predicate_id: u64) -> f64 {
+        StatisticsFeedback::adjust_selectivity(self, estimated, predicate_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::expressions::{binary, col, lit};
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_expr::Operator;
+
+    #[test]
+    fn test_moving_average() -> Result<()> {
+        let mut feedback = StatisticsFeedback::new();
+        assert_eq!(feedback.correction_factor(1), None);
+        assert_eq!(feedback.adjust_selectivity(0.25, 1), 0.25);
+
+        // The predicate selects half of the rows instead of a quarter:
+        feedback.record_actual(1, 0.25, 0.5);
+        assert_eq!(feedback.correction_factor(1), Some(2.0));
+        assert_eq!(feedback.adjust_selectivity(0.25, 1), 0.5);
+        assert_eq!(feedback.adjust_selectivity(0.75, 1), 1.0);
+
+        // Later, it selects as many rows as estimated:
+        feedback.record_actual(1, 0.25, 0.25);
+        assert_eq!(feedback.correction_factor(1), Some(1.5));
+        feedback.record_actual(1, 0.25, 0.25);
+        assert_eq!(feedback.correction_factor(1), Some(1.25));
+
+        // Other predicates are not affected, and estimates of zero are ignored:
+        feedback.record_actual(2, 0.0, 0.5);
+        assert_eq!(feedback.correction_factor(2), None);
+        assert_eq!(feedback.adjust_selectivity(0.25, 2), 0.25);
+
+        let mut feedback = StatisticsFeedback::new().with_smoothing(1.0)?;
+        feedback.record_actual(1, 0.5, 0.1);
+        feedback.record_actual(1, 0.5, 0.25);
+        assert_eq!(feedback.correction_factor(1), Some(0.5));
+
+        assert!(StatisticsFeedback::new().with_smoothing(0.0).is_err());
+        assert!(StatisticsFeedback::new().with_smoothing(1.5).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_predicate_id() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]);
+        let predicate = |name: &str, value: i32| {
+            binary(col(name, &schema)?, Operator::Lt, lit(value), &schema)
+        };
+        let id = StatisticsFeedback::predicate_id(predicate("a", 10)?.as_ref());
+        assert_eq!(
+            id,
+            StatisticsFeedback::predicate_id(predicate("a", 10)?.as_ref())
+        );
+        assert_ne!(
+            id,
+            StatisticsFeedback::predicate_id(predicate("a", 20)?.as_ref())
+        );
+        assert_ne!(
+            id,
+            StatisticsFeedback::predicate_id(predicate("b", 10)?.as_ref())
+        );
+        Ok(())
+    }
+}
@@ -576,6 +576,9 @@ fn col_stats_union(
     left.min_value = left.min_value.min(&right.min_value);
     left.max_value = left.max_value.max(&right.max_value);
     left.null_count = left.null_count.add(&right.null_count);
+    // The buckets of the inputs need not line up, so the histograms are not
+    // merged
+    left.histogram = None;
 
     left
 }
@@ -631,6 +634,7 @@ mod tests {
                     max_value: Precision::Exact(ScalarValue::Int64(Some(21))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(-4))),
                     null_count: Precision::Exact(0),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Exact(1),
@@ -641,12 +645,14 @@ mod tests {
                         "a",
                     )))),
                     null_count: Precision::Exact(3),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Absent,
                     max_value: Precision::Exact(ScalarValue::Float32(Some(1.1))),
                     min_value: Precision::Exact(ScalarValue::Float32(Some(0.1))),
                     null_count: Precision::Absent,
+                    histogram: None,
                 },
             ],
         };
@@ -660,6 +666,7 @@ mod tests {
                     max_value: Precision::Exact(ScalarValue::Int64(Some(34))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(1))),
                     null_count: Precision::Exact(1),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Absent,
@@ -670,12 +677,14 @@ mod tests {
                         "b",
                     )))),
                     null_count: Precision::Absent,
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Absent,
                     max_value: Precision::Absent,
                     min_value: Precision::Absent,
                     null_count: Precision::Absent,
+                    histogram: None,
                 },
             ],
         };
@@ -690,6 +699,7 @@ mod tests {
                     max_value: Precision::Exact(ScalarValue::Int64(Some(34))),
                     min_value: Precision::Exact(ScalarValue::Int64(Some(-4))),
                     null_count: Precision::Exact(1),
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Absent,
@@ -700,16 +710,42 @@ mod tests {
                         "a",
                     )))),
                     null_count: Precision::Absent,
+                    histogram: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Absent,
                     max_value: Precision::Absent,
                     min_value: Precision::Absent,
                     null_count: Precision::Absent,
+                    histogram: None,
                 },
             ],
         };
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_stats_union_histograms() {
+        let stats = |histogram: Vec<(i64, u64)>| Statistics {
+            num_rows: Precision::Exact(10),
+            total_byte_size: Precision::Absent,
+            column_statistics: vec![ColumnStatistics {
+                histogram: Some(
+                    histogram
+                        .into_iter()
+                        .map(|(bound, count)| (ScalarValue::Int64(Some(bound)), count))
+                        .collect(),
+                ),
+                ..ColumnStatistics::new_unknown()
+            }],
+        };
+
+        let result = stats_union(
+            stats(vec![(10, 5), (20, 10)]),
+            stats(vec![(100, 2), (200, 10)]),
+        );
+        assert_eq!(result.num_rows, Precision::Exact(20));
+        assert_eq!(result.column_statistics[0].histogram, None);
+    }
 }
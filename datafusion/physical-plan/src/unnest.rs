@@ -320,7 +320,8 @@ fn build_batch_generic_list<T: OffsetSizeTrait, P: ArrowPrimitiveType<Native = T
 /// ```
 ///
 /// So if there are no null values or `UnnestOptions.preserve_nulls` is false
-/// we can return the values array without any copying.
+/// we can return the values array without any copying, unless
+/// `UnnestOptions.outer` is true and there are empty lists.
 ///
 /// Otherwise we'll transfrom the values array using the take kernel and the following take indicies:
 ///
@@ -333,7 +334,12 @@ fn unnest_generic_list<T: OffsetSizeTrait, P: ArrowPrimitiveType<Native = T>>(
     options: &UnnestOptions,
 ) -> Result<Arc<dyn Array + 'static>> {
     let values = list_array.values();
-    if list_array.null_count() == 0 || !options.preserve_nulls {
+    let has_empty_lists = options.outer
+        && (0..list_array.len()).any(|row| {
+            list_array.is_valid(row) && list_array.value_length(row).as_usize() == 0
+        });
+    let preserve_nulls = options.preserve_nulls || options.outer;
+    if (list_array.null_count() == 0 || !preserve_nulls) && !has_empty_lists {
         Ok(values.clone())
     } else {
         let mut take_indicies_builder =
@@ -341,6 +347,9 @@ fn unnest_generic_list<T: OffsetSizeTrait, P: ArrowPrimitiveType<Native = T>>(
         let mut take_offset = 0;
 
         list_array.iter().for_each(|elem| match elem {
+            Some(array) if array.is_empty() && options.outer => {
+                take_indicies_builder.append_null();
+            }
             Some(array) => {
                 for i in 0..array.len() {
                     // take_offset + i is always positive
@@ -349,9 +358,10 @@ fn unnest_generic_list<T: OffsetSizeTrait, P: ArrowPrimitiveType<Native = T>>(
                 }
                 take_offset += array.len();
             }
-            None => {
+            None if preserve_nulls => {
                 take_indicies_builder.append_null();
             }
+            None => {}
         });
         Ok(kernels::take::take(
             &values,
@@ -393,8 +403,8 @@ fn build_batch_fixedsize_list(
 /// [1, 2, null, null 3, 4, null, null, 5, 6]
 /// ```
 ///
-/// So if there are no null values
-/// we can return the values array without any copying.
+/// So if there are no null values we can return the values array without
+/// any copying, unless `UnnestOptions.outer` is true and the lists are empty.
 ///
 /// Otherwise we'll transfrom the values array using the take kernel.
 ///
@@ -414,13 +424,15 @@ fn unnest_fixed_list(
     options: &UnnestOptions,
 ) -> Result<Arc<dyn Array + 'static>> {
     let values = list_array.values();
+    let empty_lists = options.outer && list_array.value_length() == 0;
 
-    if list_array.null_count() == 0 {
+    if list_array.null_count() == 0 && !empty_lists {
         Ok(values.clone())
     } else {
         let len_without_nulls =
             values.len() - list_array.null_count() * list_array.value_length() as usize;
-        let null_count = if options.preserve_nulls {
+        let preserve_nulls = options.preserve_nulls || options.outer;
+        let null_count = if preserve_nulls {
             list_array.null_count()
         } else {
             0
@@ -430,6 +442,9 @@ fn unnest_fixed_list(
         let mut take_offset = 0;
         let fixed_value_length = list_array.value_length() as usize;
         list_array.iter().for_each(|elem| match elem {
+            Some(_) if empty_lists => {
+                builder.append_null();
+            }
             Some(_) => {
                 for i in 0..fixed_value_length {
                     //take_offset + i is always positive
@@ -439,7 +454,7 @@ fn unnest_fixed_list(
                 take_offset += fixed_value_length;
             }
             None => {
-                if options.preserve_nulls {
+                if preserve_nulls {
                     builder.append_null();
                 }
                 take_offset += fixed_value_length;
@@ -469,13 +484,21 @@ fn create_take_indicies_generic<T: OffsetSizeTrait, P: ArrowPrimitiveType<Native
     options: &UnnestOptions,
 ) -> PrimitiveArray<P> {
     let mut builder = PrimitiveArray::<P>::builder(capacity);
-    let null_repeat: usize = if options.preserve_nulls { 1 } else { 0 };
+    let null_repeat: usize = if options.preserve_nulls || options.outer {
+        1
+    } else {
+        0
+    };
+    let empty_repeat: usize = if options.outer { 1 } else { 0 };
 
     for row in 0..list_array.len() {
         let repeat = if list_array.is_null(row) {
             null_repeat
         } else {
-            list_array.value(row).len()
+            match list_array.value_length(row).as_usize() {
+                0 => empty_repeat,
+                len => len,
+            }
         };
 
         // `index` is a positive interger.
@@ -492,13 +515,21 @@ fn create_take_indicies_fixed(
     options: &UnnestOptions,
 ) -> PrimitiveArray<Int32Type> {
     let mut builder = PrimitiveArray::<Int32Type>::builder(capacity);
-    let null_repeat: usize = if options.preserve_nulls { 1 } else { 0 };
+    let null_repeat: usize = if options.preserve_nulls || options.outer {
+        1
+    } else {
+        0
+    };
+    let empty_repeat: usize = if options.outer { 1 } else { 0 };
 
     for row in 0..list_array.len() {
         let repeat = if list_array.is_null(row) {
             null_repeat
         } else {
-            list_array.value_length() as usize
+            match list_array.value_length() as usize {
+                0 => empty_repeat,
+                len => len,
+            }
         };
 
         // `index` is a positive interger.
@@ -1121,3 +1121,141 @@ fn get_aggregate_result_out_column(
     result
         .ok_or_else(|| DataFusionError::Execution("Should contain something".to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::col;
+    use crate::memory::MemoryExec;
+    use crate::windows::create_window_expr;
+
+    use arrow::array::Int64Array;
+    use arrow::compute::SortOptions;
+    use arrow::datatypes::{DataType, Field};
+    use datafusion_common::cast::as_int64_array;
+    use datafusion_common::ScalarValue;
+    use datafusion_expr::{
+        AggregateFunction, WindowFrame, WindowFrameBound, WindowFrameUnits,
+        WindowFunction,
+    };
+    use futures::StreamExt;
+
+    const BATCH_SIZE: usize = 100;
+    const N_BATCHES: usize = 1000;
+
+    /// Runs `SUM(x) OVER ([PARTITION BY p] ORDER BY ts ROWS BETWEEN UNBOUNDED
+    /// PRECEDING AND CURRENT ROW)` over `N_BATCHES` batches, where `x` is the
+    /// row number and `p` changes every `partition_len` rows. Checks that the
+    /// stream never buffers much more than a batch, and returns the results.
+    async fn running_sum(partition_len: usize, partitioned: bool) -> Result<Vec<i64>> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("p", DataType::Int64, false),
+            Field::new("ts", DataType::Int64, false),
+            Field::new("x", DataType::Int64, false),
+        ]));
+        let batches = (0..N_BATCHES)
+            .map(|batch_idx| {
+                let rows = (batch_idx * BATCH_SIZE) as i64
+                    ..((batch_idx + 1) * BATCH_SIZE) as i64;
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(Int64Array::from_iter_values(
+                            rows.clone().map(|row| row / partition_len as i64),
+                        )),
+                        Arc::new(Int64Array::from_iter_values(rows.clone())),
+                        Arc::new(Int64Array::from_iter_values(rows)),
+                    ],
+                )
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let sort_expr = |name: &str| -> Result<PhysicalSortExpr> {
+            Ok(PhysicalSortExpr {
+                expr: col(name, &schema)?,
+                options: SortOptions::default(),
+            })
+        };
+        let input = Arc::new(
+            MemoryExec::try_new(&[batches], schema.clone(), None)?
+                .with_sort_information(vec![vec![sort_expr("p")?, sort_expr("ts")?]]),
+        );
+
+        let partition_by = if partitioned {
+            vec![col("p", &schema)?]
+        } else {
+            vec![]
+        };
+        let order_by = vec![sort_expr("ts")?];
+        let window_frame = WindowFrame {
+            units: WindowFrameUnits::Rows,
+            start_bound: WindowFrameBound::Preceding(ScalarValue::UInt64(None)),
+            end_bound: WindowFrameBound::CurrentRow,
+        };
+        let window_expr = create_window_expr(
+            &WindowFunction::AggregateFunction(AggregateFunction::Sum),
+            "running_sum".to_string(),
+            &[col("x", &schema)?],
+            &partition_by,
+            &order_by,
+            Arc::new(window_frame),
+            &schema,
+        )?;
+        let exec = BoundedWindowAggExec::try_new(
+            vec![window_expr],
+            input.clone(),
+            partition_by,
+            PartitionSearchMode::Sorted,
+        )?;
+
+        let task_ctx = Arc::new(TaskContext::default());
+        let mut stream = BoundedWindowAggStream::new(
+            exec.schema(),
+            exec.window_expr().to_vec(),
+            input.execute(0, task_ctx)?,
+            BaselineMetrics::new(&ExecutionPlanMetricsSet::new(), 0),
+            exec.get_search_algo()?,
+        )?;
+        let mut results = vec![];
+        while let Some(batch) = stream.next().await {
+            let batch = batch?;
+            let buffered_rows = stream
+                .partition_buffers
+                .values()
+                .map(|state| state.record_batch.num_rows())
+                .sum::<usize>();
+            assert!(
+                buffered_rows <= 2 * BATCH_SIZE,
+                "{buffered_rows} rows buffered"
+            );
+            assert!(stream.input_buffer.num_rows() <= 2 * BATCH_SIZE);
+            let sums = as_int64_array(batch.column(3))?;
+            results.extend(sums.values().iter().copied());
+        }
+        Ok(results)
+    }
+
+    #[tokio::test]
+    async fn test_running_sum_single_partition_uses_bounded_memory() -> Result<()> {
+        let results = running_sum(N_BATCHES * BATCH_SIZE, false).await?;
+        assert_eq!(results.len(), N_BATCHES * BATCH_SIZE);
+        for (row, sum) in results.into_iter().enumerate() {
+            let row = row as i64;
+            assert_eq!(sum, row * (row + 1) / 2);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_running_sum_restarts_at_partition_boundaries() -> Result<()> {
+        // Partitions span batch boundaries:
+        let partition_len = 250;
+        let results = running_sum(partition_len, true).await?;
+        assert_eq!(results.len(), N_BATCHES * BATCH_SIZE);
+        for (row, sum) in results.into_iter().enumerate() {
+            let first = (row / partition_len * partition_len) as i64;
+            let row = row as i64;
+            assert_eq!(sum, (first + row) * (row - first + 1) / 2);
+        }
+        Ok(())
+    }
+}
@@ -14825,6 +14825,9 @@ impl serde::Serialize for PhysicalBinaryExprNode {
         if !self.op.is_empty() {
             len += 1;
         }
+        if !self.overflow_mode.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.PhysicalBinaryExprNode", len)?;
         if let Some(v) = self.l.as_ref() {
             struct_ser.serialize_field("l", v)?;
@@ -14835,6 +14838,9 @@ impl serde::Serialize for PhysicalBinaryExprNode {
         if !self.op.is_empty() {
             struct_ser.serialize_field("op", &self.op)?;
         }
+        if !self.overflow_mode.is_empty() {
+            struct_ser.serialize_field("overflowMode", &self.overflow_mode)?;
+        }
         struct_ser.end()
     }
 }
@@ -14848,6 +14854,8 @@ impl<'de> serde::Deserialize<'de> for PhysicalBinaryExprNode {
             "l",
             "r",
             "op",
+            "overflow_mode",
+            "overflowMode",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -14855,6 +14863,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalBinaryExprNode {
             L,
             R,
             Op,
+            OverflowMode,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -14879,6 +14888,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalBinaryExprNode {
                             "l" => Ok(GeneratedField::L),
                             "r" => Ok(GeneratedField::R),
                             "op" => Ok(GeneratedField::Op),
+                            "overflowMode" | "overflow_mode" => Ok(GeneratedField::OverflowMode),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -14901,6 +14911,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalBinaryExprNode {
                 let mut l__ = None;
                 let mut r__ = None;
                 let mut op__ = None;
+                let mut overflow_mode__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::L => {
@@ -14921,12 +14932,19 @@ impl<'de> serde::Deserialize<'de> for PhysicalBinaryExprNode {
                             }
                             op__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::OverflowMode => {
+                            if overflow_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("overflowMode"));
+                            }
+                            overflow_mode__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(PhysicalBinaryExprNode {
                     l: l__,
                     r: r__,
                     op: op__.unwrap_or_default(),
+                    overflow_mode: overflow_mode__.unwrap_or_default(),
                 })
             }
         }
@@ -16427,6 +16445,9 @@ impl serde::Serialize for PhysicalLikeExprNode {
         if self.pattern.is_some() {
             len += 1;
         }
+        if !self.escape_char.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("datafusion.PhysicalLikeExprNode", len)?;
         if self.negated {
             struct_ser.serialize_field("negated", &self.negated)?;
@@ -16440,6 +16461,9 @@ impl serde::Serialize for PhysicalLikeExprNode {
         if let Some(v) = self.pattern.as_ref() {
             struct_ser.serialize_field("pattern", v)?;
         }
+        if !self.escape_char.is_empty() {
+            struct_ser.serialize_field("escapeChar", &self.escape_char)?;
+        }
         struct_ser.end()
     }
 }
@@ -16455,6 +16479,8 @@ impl<'de> serde::Deserialize<'de> for PhysicalLikeExprNode {
             "caseInsensitive",
             "expr",
             "pattern",
+            "escape_char",
+            "escapeChar",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -16463,6 +16489,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalLikeExprNode {
             CaseInsensitive,
             Expr,
             Pattern,
+            EscapeChar,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -16488,6 +16515,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalLikeExprNode {
                             "caseInsensitive" | "case_insensitive" => Ok(GeneratedField::CaseInsensitive),
                             "expr" => Ok(GeneratedField::Expr),
                             "pattern" => Ok(GeneratedField::Pattern),
+                            "escapeChar" | "escape_char" => Ok(GeneratedField::EscapeChar),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -16511,6 +16539,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalLikeExprNode {
                 let mut case_insensitive__ = None;
                 let mut expr__ = None;
                 let mut pattern__ = None;
+                let mut escape_char__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Negated => {
@@ -16537,6 +16566,12 @@ impl<'de> serde::Deserialize<'de> for PhysicalLikeExprNode {
                             }
                             pattern__ = map_.next_value()?;
                         }
+                        GeneratedField::EscapeChar => {
+                            if escape_char__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("escapeChar"));
+                            }
+                            escape_char__ = Some(map_.next_value()?);
+                        }
                     }
                 }
                 Ok(PhysicalLikeExprNode {
@@ -16544,6 +16579,7 @@ impl<'de> serde::Deserialize<'de> for PhysicalLikeExprNode {
                     case_insensitive: case_insensitive__.unwrap_or_default(),
                     expr: expr__,
                     pattern: pattern__,
+                    escape_char: escape_char__.unwrap_or_default(),
                 })
             }
         }
@@ -18167,6 +18203,9 @@ impl serde::Serialize for PlanType {
                 plan_type::PlanTypeEnum::FinalPhysicalPlan(v) => {
                     struct_ser.serialize_field("FinalPhysicalPlan", v)?;
                 }
+                plan_type::PlanTypeEnum::SubqueryDecorrelation(v) => {
+                    struct_ser.serialize_field("SubqueryDecorrelation", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -18187,6 +18226,7 @@ impl<'de> serde::Deserialize<'de> for PlanType {
             "InitialPhysicalPlan",
             "OptimizedPhysicalPlan",
             "FinalPhysicalPlan",
+            "SubqueryDecorrelation",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -18199,6 +18239,7 @@ impl<'de> serde::Deserialize<'de> for PlanType {
             InitialPhysicalPlan,
             OptimizedPhysicalPlan,
             FinalPhysicalPlan,
+            SubqueryDecorrelation,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -18228,6 +18269,7 @@ impl<'de> serde::Deserialize<'de> for PlanType {
                             "InitialPhysicalPlan" => Ok(GeneratedField::InitialPhysicalPlan),
                             "OptimizedPhysicalPlan" => Ok(GeneratedField::OptimizedPhysicalPlan),
                             "FinalPhysicalPlan" => Ok(GeneratedField::FinalPhysicalPlan),
+                            "SubqueryDecorrelation" => Ok(GeneratedField::SubqueryDecorrelation),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -18304,6 +18346,13 @@ impl<'de> serde::Deserialize<'de> for PlanType {
                                 return Err(serde::de::Error::duplicate_field("FinalPhysicalPlan"));
                             }
                             plan_type_enum__ = map_.next_value::<::std::option::Option<_>>()?.map(plan_type::PlanTypeEnum::FinalPhysicalPlan)
+;
+                        }
+                        GeneratedField::SubqueryDecorrelation => {
+                            if plan_type_enum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("SubqueryDecorrelation"));
+                            }
+                            plan_type_enum__ = map_.next_value::<::std::option::Option<_>>()?.map(plan_type::PlanTypeEnum::SubqueryDecorrelation)
 ;
                         }
                     }
@@ -19772,6 +19821,7 @@ impl serde::Serialize for ScalarFunction {
             Self::ArrayEmpty => "ArrayEmpty",
             Self::ArrayPopBack => "ArrayPopBack",
             Self::StringToArray => "StringToArray",
+            Self::ToUnixtime => "ToUnixtime",
         };
         serializer.serialize_str(variant)
     }
@@ -19901,6 +19951,7 @@ impl<'de> serde::Deserialize<'de> for ScalarFunction {
             "ArrayEmpty",
             "ArrayPopBack",
             "StringToArray",
+            "ToUnixtime",
         ];
 
         struct GeneratedVisitor;
@@ -20059,6 +20110,7 @@ impl<'de> serde::Deserialize<'de> for ScalarFunction {
                     "ArrayEmpty" => Ok(ScalarFunction::ArrayEmpty),
                     "ArrayPopBack" => Ok(ScalarFunction::ArrayPopBack),
                     "StringToArray" => Ok(ScalarFunction::StringToArray),
+                    "ToUnixtime" => Ok(ScalarFunction::ToUnixtime),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -20,7 +20,7 @@ use crate::protobuf::{
     plan_type::PlanTypeEnum::{
         AnalyzedLogicalPlan, FinalAnalyzedLogicalPlan, FinalLogicalPlan,
         FinalPhysicalPlan, InitialLogicalPlan, InitialPhysicalPlan, OptimizedLogicalPlan,
-        OptimizedPhysicalPlan,
+        OptimizedPhysicalPlan, SubqueryDecorrelation,
     },
     AnalyzedLogicalPlanType, CubeNode, GroupingSetNode, OptimizedLogicalPlanType,
     OptimizedPhysicalPlanType, PlaceholderNode, RollupNode,
@@ -44,17 +44,18 @@ use datafusion_expr::{
     array_positions, array_prepend, array_remove, array_remove_all, array_remove_n,
     array_repeat, array_replace, array_replace_all, array_replace_n, array_slice,
     array_to_string, ascii, asin, asinh, atan, atan2, atanh, bit_length, btrim,
-    cardinality, cbrt, ceil, character_length, chr, coalesce, concat_expr,
-    concat_ws_expr, cos, cosh, cot, current_date, current_time, date_bin, date_part,
-    date_trunc, degrees, digest, exp,
+    cardinality, cbrt, character_length, chr, coalesce, concat_expr, concat_ws_expr, cos,
+    cosh, cot, current_date, current_time, date_bin, date_part, date_trunc, degrees,
+    digest, exp,
     expr::{self, InList, Sort, WindowFunction},
-    factorial, floor, from_unixtime, gcd, isnan, iszero, lcm, left, ln, log, log10, log2,
+    factorial, gcd, isnan, iszero, lcm, left, ln, log, log10, log2,
     logical_plan::{PlanType, StringifiedPlan},
     lower, lpad, ltrim, md5, nanvl, now, nullif, octet_length, pi, power, radians,
     random, regexp_match, regexp_replace, repeat, replace, reverse, right, round, rpad,
     rtrim, sha224, sha256, sha384, sha512, signum, sin, sinh, split_part, sqrt,
     starts_with, strpos, substr, substring, tan, tanh, to_hex, to_timestamp_micros,
-    to_timestamp_millis, to_timestamp_seconds, translate, trim, trunc, upper, uuid,
+    to_timestamp_millis, to_timestamp_seconds, to_unixtime, translate, trim, trunc,
+    upper, uuid,
     window_frame::regularize,
     AggregateFunction, Between, BinaryExpr, BuiltInWindowFunction, BuiltinScalarFunction,
     Case, Cast, Expr, GetFieldAccess, GetIndexedField, GroupingSet,
@@ -410,6 +411,7 @@ impl From<&protobuf::StringifiedPlan> for StringifiedPlan {
                     }
                 }
                 FinalPhysicalPlan(_) => PlanType::FinalPhysicalPlan,
+                SubqueryDecorrelation(_) => PlanType::SubqueryDecorrelation,
             },
             plan: Arc::new(stringified_plan.plan.clone()),
         }
@@ -533,6 +535,7 @@ impl From<&protobuf::ScalarFunction> for BuiltinScalarFunction {
             ScalarFunction::Power => Self::Power,
             ScalarFunction::StructFun => Self::Struct,
             ScalarFunction::FromUnixtime => Self::FromUnixtime,
+            ScalarFunction::ToUnixtime => Self::ToUnixtime,
             ScalarFunction::Atan2 => Self::Atan2,
             ScalarFunction::Nanvl => Self::Nanvl,
             ScalarFunction::Isnan => Self::Isnan,
@@ -1425,11 +1428,27 @@ pub fn parse_expr(
                 ScalarFunction::Log2 => Ok(log2(parse_expr(&args[0], registry)?)),
                 ScalarFunction::Ln => Ok(ln(parse_expr(&args[0], registry)?)),
                 ScalarFunction::Log10 => Ok(log10(parse_expr(&args[0], registry)?)),
-                ScalarFunction::Floor => Ok(floor(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Floor => {
+                    Ok(Expr::ScalarFunction(expr::ScalarFunction::new(
+                        BuiltinScalarFunction::Floor,
+                        args.to_owned()
+                            .iter()
+                            .map(|expr| parse_expr(expr, registry))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    )))
+                }
                 ScalarFunction::Factorial => {
                     Ok(factorial(parse_expr(&args[0], registry)?))
                 }
-                ScalarFunction::Ceil => Ok(ceil(parse_expr(&args[0], registry)?)),
+                ScalarFunction::Ceil => {
+                    Ok(Expr::ScalarFunction(expr::ScalarFunction::new(
+                        BuiltinScalarFunction::Ceil,
+                        args.to_owned()
+                            .iter()
+                            .map(|expr| parse_expr(expr, registry))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    )))
+                }
                 ScalarFunction::Round => Ok(round(
                     args.to_owned()
                         .iter()
@@ -1617,7 +1636,16 @@ pub fn parse_expr(
                     parse_expr(&args[1], registry)?,
                 )),
                 ScalarFunction::FromUnixtime => {
-                    Ok(from_unixtime(parse_expr(&args[0], registry)?))
+                    Ok(Expr::ScalarFunction(expr::ScalarFunction::new(
+                        BuiltinScalarFunction::FromUnixtime,
+                        args.to_owned()
+                            .iter()
+                            .map(|expr| parse_expr(expr, registry))
+                            .collect::<Result<Vec<_>, _>>()?,
+                    )))
+                }
+                ScalarFunction::ToUnixtime => {
+                    Ok(to_unixtime(parse_expr(&args[0], registry)?))
                 }
                 ScalarFunction::Atan2 => Ok(atan2(
                     parse_expr(&args[0], registry)?,
@@ -1696,7 +1724,7 @@ pub fn parse_expr(
 }
 
 /// Parse an optional escape_char for Like, ILike, SimilarTo
-fn parse_escape_char(s: &str) -> Result<Option<char>> {
+pub(crate) fn parse_escape_char(s: &str) -> Result<Option<char>> {
     match s.len() {
         0 => Ok(None),
         1 => Ok(s.chars().next()),
@@ -32,8 +32,8 @@ use datafusion::execution::FunctionRegistry;
 use datafusion::logical_expr::window_function::WindowFunction;
 use datafusion::physical_expr::{PhysicalSortExpr, ScalarFunctionExpr};
 use datafusion::physical_plan::expressions::{
-    in_list, BinaryExpr, CaseExpr, CastExpr, Column, IsNotNullExpr, IsNullExpr, LikeExpr,
-    Literal, NegativeExpr, NotExpr, TryCastExpr,
+    in_list, ArithmeticOverflowMode, BinaryExpr, CaseExpr, CastExpr, Column,
+    IsNotNullExpr, IsNullExpr, LikeExpr, Literal, NegativeExpr, NotExpr, TryCastExpr,
 };
 use datafusion::physical_plan::expressions::{GetFieldAccessExpr, GetIndexedFieldExpr};
 use datafusion::physical_plan::joins::utils::JoinSide;
@@ -164,21 +164,31 @@ pub fn parse_physical_expr(
             Arc::new(pcol)
         }
         ExprType::Literal(scalar) => Arc::new(Literal::new(scalar.try_into()?)),
-        ExprType::BinaryExpr(binary_expr) => Arc::new(BinaryExpr::new(
-            parse_required_physical_expr(
-                binary_expr.l.as_deref(),
-                registry,
-                "left",
-                input_schema,
-            )?,
-            logical_plan::from_proto::from_proto_binary_op(&binary_expr.op)?,
-            parse_required_physical_expr(
-                binary_expr.r.as_deref(),
-                registry,
-                "right",
-                input_schema,
-            )?,
-        )),
+        ExprType::BinaryExpr(binary_expr) => {
+            let overflow_mode = if binary_expr.overflow_mode.is_empty() {
+                ArithmeticOverflowMode::default()
+            } else {
+                binary_expr.overflow_mode.parse()?
+            };
+            Arc::new(
+                BinaryExpr::new(
+                    parse_required_physical_expr(
+                        binary_expr.l.as_deref(),
+                        registry,
+                        "left",
+                        input_schema,
+                    )?,
+                    logical_plan::from_proto::from_proto_binary_op(&binary_expr.op)?,
+                    parse_required_physical_expr(
+                        binary_expr.r.as_deref(),
+                        registry,
+                        "right",
+                        input_schema,
+                    )?,
+                )
+                .with_overflow_mode(overflow_mode),
+            )
+        }
         ExprType::AggregateExpr(_) => {
             return not_impl_err!(
                 "Cannot convert aggregate expr node to physical expression"
@@ -331,22 +341,27 @@ pub fn parse_physical_expr(
                 None,
             ))
         }
-        ExprType::LikeExpr(like_expr) => Arc::new(LikeExpr::new(
-            like_expr.negated,
-            like_expr.case_insensitive,
-            parse_required_physical_expr(
-                like_expr.expr.as_deref(),
-                registry,
-                "expr",
-                input_schema,
-            )?,
-            parse_required_physical_expr(
-                like_expr.pattern.as_deref(),
-                registry,
-                "pattern",
-                input_schema,
-            )?,
-        )),
+        ExprType::LikeExpr(like_expr) => Arc::new(
+            LikeExpr::new(
+                like_expr.negated,
+                like_expr.case_insensitive,
+                parse_required_physical_expr(
+                    like_expr.expr.as_deref(),
+                    registry,
+                    "expr",
+                    input_schema,
+                )?,
+                parse_required_physical_expr(
+                    like_expr.pattern.as_deref(),
+                    registry,
+                    "pattern",
+                    input_schema,
+                )?,
+            )
+            .with_escape_char(logical_plan::from_proto::parse_escape_char(
+                &like_expr.escape_char,
+            )?),
+        ),
         ExprType::GetIndexedFieldExpr(get_indexed_field_expr) => {
             let field = match &get_indexed_field_expr.field {
                 Some(protobuf::physical_get_indexed_field_expr_node::Field::NamedStructFieldExpr(named_struct_field_expr)) => GetFieldAccessExpr::NamedStructField{
@@ -600,6 +615,9 @@ impl From<&protobuf::ColumnStats> for ColumnStatistics {
             } else {
                 Precision::Absent
             },
+            // `ColumnStats` has no histogram field, see the `From<&ColumnStatistics>`
+            // conversion in `to_proto.rs`
+            histogram: None,
         }
     }
 }
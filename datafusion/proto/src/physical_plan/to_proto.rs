@@ -373,6 +373,7 @@ impl TryFrom<Arc<dyn PhysicalExpr>> for protobuf::PhysicalExprNode {
                 l: Some(Box::new(expr.left().to_owned().try_into()?)),
                 r: Some(Box::new(expr.right().to_owned().try_into()?)),
                 op: format!("{:?}", expr.op()),
+                overflow_mode: expr.overflow_mode().to_string(),
             });
 
             Ok(protobuf::PhysicalExprNode {
@@ -526,6 +527,10 @@ impl TryFrom<Arc<dyn PhysicalExpr>> for protobuf::PhysicalExprNode {
                         case_insensitive: expr.case_insensitive(),
                         expr: Some(Box::new(expr.expr().to_owned().try_into()?)),
                         pattern: Some(Box::new(expr.pattern().to_owned().try_into()?)),
+                        escape_char: expr
+                            .escape_char()
+                            .map(|ch| ch.to_string())
+                            .unwrap_or_default(),
                     }),
                 )),
             })
@@ -676,6 +681,8 @@ impl From<&Statistics> for protobuf::Statistics {
     }
 }
 
+/// Note that [`ColumnStatistics::histogram`] is not serialized, so column
+/// statistics deserialized from protobuf never carry a histogram.
 impl From<&ColumnStatistics> for protobuf::ColumnStats {
     fn from(s: &ColumnStatistics) -> protobuf::ColumnStats {
         protobuf::ColumnStats {
@@ -44,7 +44,7 @@ use datafusion_expr::expr::{
 use datafusion_expr::logical_plan::{Extension, UserDefinedLogicalNodeCore};
 use datafusion_expr::{
     col, create_udaf, lit, Accumulator, AggregateFunction,
-    BuiltinScalarFunction::{Sqrt, Substr},
+    BuiltinScalarFunction::{FromUnixtime, Sqrt, Substr, ToUnixtime},
     Expr, LogicalPlan, Operator, PartitionEvaluator, Signature, TryCast, Volatility,
     WindowFrame, WindowFrameBound, WindowFrameUnits, WindowFunction, WindowUDF,
 };
@@ -1380,6 +1380,28 @@ fn roundtrip_substr() {
     roundtrip_expr_test(test_expr, ctx.clone());
     roundtrip_expr_test(test_expr_with_count, ctx);
 }
+
+#[test]
+fn roundtrip_unixtime() {
+    // from_unixtime(seconds)
+    let test_expr =
+        Expr::ScalarFunction(ScalarFunction::new(FromUnixtime, vec![col("col")]));
+
+    // from_unixtime(value, unit)
+    let test_expr_with_unit = Expr::ScalarFunction(ScalarFunction::new(
+        FromUnixtime,
+        vec![col("col"), lit("microsecond")],
+    ));
+
+    // to_unixtime(timestamp)
+    let test_expr_to_unixtime =
+        Expr::ScalarFunction(ScalarFunction::new(ToUnixtime, vec![col("col")]));
+
+    let ctx = SessionContext::new();
+    roundtrip_expr_test(test_expr, ctx.clone());
+    roundtrip_expr_test(test_expr_with_unit, ctx.clone());
+    roundtrip_expr_test(test_expr_to_unixtime, ctx);
+}
 #[test]
 fn roundtrip_window() {
     let ctx = SessionContext::new();
@@ -36,8 +36,9 @@ use datafusion::physical_plan::aggregates::{
 use datafusion::physical_plan::analyze::AnalyzeExec;
 use datafusion::physical_plan::empty::EmptyExec;
 use datafusion::physical_plan::expressions::{
-    binary, cast, col, in_list, like, lit, Avg, BinaryExpr, Column, DistinctCount,
-    GetFieldAccessExpr, GetIndexedFieldExpr, NotExpr, NthValue, PhysicalSortExpr, Sum,
+    binary, cast, col, in_list, like, lit, ArithmeticOverflowMode, Avg, BinaryExpr,
+    Column, DistinctCount, GetFieldAccessExpr, GetIndexedFieldExpr, NotExpr, NthValue,
+    PhysicalSortExpr, Sum,
 };
 use datafusion::physical_plan::filter::FilterExec;
 use datafusion::physical_plan::functions::make_scalar_function;
@@ -399,6 +400,18 @@ fn roundtrip_filter_with_not_and_in_list() -> Result<()> {
     )?))
 }
 
+#[test]
+fn roundtrip_binary_overflow_mode() -> Result<()> {
+    let field_a = Field::new("a", DataType::Int64, false);
+    let schema = Arc::new(Schema::new(vec![field_a]));
+    let plus = BinaryExpr::new(col("a", &schema)?, Operator::Plus, lit(1i64))
+        .with_overflow_mode(ArithmeticOverflowMode::Error);
+    roundtrip_test(Arc::new(ProjectionExec::try_new(
+        vec![(Arc::new(plus), "a_plus_one".to_string())],
+        Arc::new(EmptyExec::new(false, schema.clone())),
+    )?))
+}
+
 #[test]
 fn roundtrip_sort() -> Result<()> {
     let field_a = Field::new("a", DataType::Boolean, false);
@@ -569,11 +582,18 @@ fn roundtrip_distinct_count() -> Result<()> {
     let field_b = Field::new("b", DataType::Int64, false);
     let schema = Arc::new(Schema::new(vec![field_a, field_b]));
 
-    let aggregates: Vec<Arc<dyn AggregateExpr>> = vec![Arc::new(DistinctCount::new(
-        DataType::Int64,
-        col("b", &schema)?,
-        "COUNT(DISTINCT b)".to_string(),
-    ))];
+    let aggregates: Vec<Arc<dyn AggregateExpr>> = vec![
+        Arc::new(DistinctCount::new(
+            DataType::Int64,
+            col("b", &schema)?,
+            "COUNT(DISTINCT b)".to_string(),
+        )),
+        Arc::new(DistinctCount::new_with_multiple_exprs(
+            vec![DataType::Int64, DataType::Int64],
+            vec![col("a", &schema)?, col("b", &schema)?],
+            "COUNT(DISTINCT a, b)".to_string(),
+        )),
+    ];
 
     let groups: Vec<(Arc<dyn PhysicalExpr>, String)> =
         vec![(col("a", &schema)?, "unused".to_string())];
@@ -601,10 +621,22 @@ fn roundtrip_like() -> Result<()> {
         false,
         col("a", &schema)?,
         col("b", &schema)?,
+        None,
+        &schema,
+    )?;
+    let escaped_like_expr = like(
+        true,
+        false,
+        col("a", &schema)?,
+        col("b", &schema)?,
+        Some('#'),
         &schema,
     )?;
     let plan = Arc::new(ProjectionExec::try_new(
-        vec![(like_expr, "result".to_string())],
+        vec![
+            (like_expr, "result".to_string()),
+            (escaped_like_expr, "escaped_result".to_string()),
+        ],
         input,
     )?);
     roundtrip_test(plan)
@@ -502,17 +502,22 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
             None
         };
 
-        if let Some(limit) = limit {
-            return not_impl_err!("LIMIT not supported in ARRAY_AGG: {limit}");
-        }
-
         if within_group {
             return not_impl_err!("WITHIN GROUP not supported in ARRAY_AGG");
         }
 
-        let args =
+        let mut args =
             vec![self.sql_expr_to_logical_expr(*expr, input_schema, planner_context)?];
 
+        // The LIMIT is passed as a second argument
+        if let Some(limit) = limit {
+            args.push(self.sql_expr_to_logical_expr(
+                *limit,
+                input_schema,
+                planner_context,
+            )?);
+        }
+
         // next, aggregate built-ins
         let fun = AggregateFunction::ArrayAgg;
         Ok(Expr::AggregateFunction(expr::AggregateFunction::new(
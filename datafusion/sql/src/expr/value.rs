@@ -225,11 +225,41 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 let df_op = match op {
                     BinaryOperator::Plus => Operator::Plus,
                     BinaryOperator::Minus => Operator::Minus,
+                    BinaryOperator::Multiply => Operator::Multiply,
+                    BinaryOperator::Divide => Operator::Divide,
                     _ => {
                         return not_impl_err!("Unsupported interval operator: {op:?}");
                     }
                 };
                 match (interval.leading_field, left.as_ref(), right.as_ref()) {
+                    // Intervals are scaled by independent exprs, e.g.
+                    // `interval '1 day' * 3`.
+                    (leading_field, _, _)
+                        if matches!(df_op, Operator::Multiply | Operator::Divide) =>
+                    {
+                        let left_expr = self.sql_interval_to_expr(
+                            negative,
+                            Interval {
+                                value: left,
+                                leading_field,
+                                leading_precision: None,
+                                last_field: None,
+                                fractional_seconds_precision: None,
+                            },
+                            schema,
+                            planner_context,
+                        )?;
+                        let right_expr = self.sql_expr_to_logical_expr(
+                            *right,
+                            schema,
+                            planner_context,
+                        )?;
+                        return Ok(Expr::BinaryExpr(BinaryExpr::new(
+                            Box::new(left_expr),
+                            df_op,
+                            Box::new(right_expr),
+                        )));
+                    }
                     (_, _, SQLExpr::Value(_)) => {
                         let left_expr = self.sql_interval_to_expr(
                             negative,
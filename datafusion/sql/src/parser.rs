@@ -81,9 +81,12 @@ impl fmt::Display for ExplainStatement {
 /// COPY <table_name | (<query>)>
 /// TO
 /// <destination_url>
+/// [STORED AS <file_type>]
 /// (key_value_list)
 /// ```
 ///
+/// `STORED AS <file_type>` is equivalent to the `format <file_type>` option.
+///
 /// # Examples
 ///
 /// ```sql
@@ -95,6 +98,8 @@ impl fmt::Display for ExplainStatement {
 ///  )
 ///
 /// COPY (SELECT l_orderkey from lineitem) to 'lineitem.parquet';
+///
+/// COPY lineitem TO 'lineitem.arrow' STORED AS ARROW;
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CopyToStatement {
@@ -396,13 +401,29 @@ impl<'a> DFParser<'a> {
 
         let target = self.parser.parse_literal_string()?;
 
+        let stored_as = if self.parser.parse_keywords(&[Keyword::STORED, Keyword::AS]) {
+            Some(self.parse_file_format()?)
+        } else {
+            None
+        };
+
         // check for options in parens
-        let options = if self.parser.peek_token().token == Token::LParen {
+        let mut options = if self.parser.peek_token().token == Token::LParen {
             self.parse_value_options()?
         } else {
             vec![]
         };
 
+        if let Some(file_type) = stored_as {
+            if options
+                .iter()
+                .any(|(k, _)| k.eq_ignore_ascii_case("format"))
+            {
+                return parser_err!("STORED AS specified along with the format option");
+            }
+            options.insert(0, ("format".to_string(), Value::UnQuotedString(file_type)));
+        }
+
         Ok(Statement::CopyTo(CopyToStatement {
             source,
             target,
@@ -1454,6 +1475,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn copy_to_stored_as() -> Result<(), ParserError> {
+        // STORED AS is a shorthand for the format option
+        let sql = "COPY foo TO bar STORED AS arrow (compression lz4)";
+        let expected = Statement::CopyTo(CopyToStatement {
+            source: object_name("foo"),
+            target: "bar".to_string(),
+            options: vec![
+                (
+                    "format".to_string(),
+                    Value::UnQuotedString("ARROW".to_string()),
+                ),
+                (
+                    "compression".to_string(),
+                    Value::UnQuotedString("lz4".to_string()),
+                ),
+            ],
+        });
+        assert_eq!(
+            one_statement_parses_to(
+                sql,
+                "COPY foo TO bar (format ARROW, compression lz4)"
+            ),
+            expected
+        );
+
+        expect_parse_error(
+            "COPY foo TO bar STORED AS arrow (format parquet)",
+            "STORED AS specified along with the format option",
+        );
+        Ok(())
+    }
+
     // For error cases, see: `copy.slt`
 
     fn object_name(name: &str) -> CopyToSource {
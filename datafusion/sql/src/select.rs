@@ -24,12 +24,15 @@ use crate::utils::{
     resolve_columns, resolve_positions_to_exprs,
 };
 
+use datafusion_common::tree_node::{
+    RewriteRecursion, TreeNode, TreeNodeRewriter, VisitRecursion,
+};
 use datafusion_common::Column;
 use datafusion_common::{
     get_target_functional_dependencies, not_impl_err, plan_err, DFSchemaRef,
     DataFusionError, Result,
 };
-use datafusion_expr::expr::Alias;
+use datafusion_expr::expr::{AggregateFunction, Alias};
 use datafusion_expr::expr_rewriter::{
     normalize_col, normalize_col_with_schemas_and_ambiguity_check,
 };
@@ -39,7 +42,8 @@ use datafusion_expr::utils::{
     find_aggregate_exprs, find_window_exprs,
 };
 use datafusion_expr::{
-    Expr, Filter, GroupingSet, LogicalPlan, LogicalPlanBuilder, Partitioning,
+    aggregate_function, Expr, Filter, GroupingSet, LogicalPlan, LogicalPlanBuilder,
+    Partitioning,
 };
 use sqlparser::ast::{
     Distinct, Expr as SQLExpr, GroupByExpr, ReplaceSelectItem, WildcardAdditionalOptions,
@@ -517,8 +521,39 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
         group_by_exprs: Vec<Expr>,
         aggr_exprs: Vec<Expr>,
     ) -> Result<(LogicalPlan, Vec<Expr>, Option<Expr>)> {
-        let group_by_exprs =
-            get_updated_group_by_exprs(&group_by_exprs, select_exprs, input.schema())?;
+        // aggregate the columns that depend on the group by expressions, so
+        // that they can be used like the group by expressions themselves
+        let dependent_columns = get_dependent_columns(
+            &group_by_exprs,
+            select_exprs,
+            having_expr_opt,
+            input.schema(),
+        )?;
+        let mut aggr_exprs = aggr_exprs;
+        for column in &dependent_columns {
+            let aggr_expr = first_value(column.clone());
+            if !aggr_exprs.contains(&aggr_expr) {
+                aggr_exprs.push(aggr_expr);
+            }
+        }
+        let select_exprs = select_exprs
+            .iter()
+            .map(|expr| {
+                let aggregated =
+                    aggregate_dependent_columns(expr.clone(), &dependent_columns)?;
+                // keep the names of the select expressions
+                Ok(match expr {
+                    _ if &aggregated == expr => aggregated,
+                    Expr::Alias(_) => aggregated,
+                    Expr::Column(column) => aggregated.alias(&column.name),
+                    _ => aggregated.alias(expr.display_name()?),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let having_expr_opt = having_expr_opt
+            .map(|expr| aggregate_dependent_columns(expr.clone(), &dependent_columns))
+            .transpose()?;
+        let having_expr_opt = having_expr_opt.as_ref();
 
         // create the aggregate plan
         let plan = LogicalPlanBuilder::from(input.clone())
@@ -643,7 +678,7 @@ fn match_window_definitions(
     Ok(())
 }
 
-/// Update group by exprs, according to functional dependencies
+/// Find the columns that are selected under GROUP BY, according to functional dependencies.
 /// The query below
 ///
 /// SELECT sn, amount
@@ -656,21 +691,24 @@ fn match_window_definitions(
 /// safely, determine value of `amount` for each distinct `sn`. For these cases
 /// we rewrite the query above as
 ///
-/// SELECT sn, amount
+/// SELECT sn, FIRST_VALUE(amount) AS amount
 /// FROM sales_global
-/// GROUP BY sn, amount
+/// GROUP BY sn
 ///
-/// Both queries, are functionally same. \[Because, (`sn`, `amount`) and (`sn`)
-/// defines the identical groups. \]
-/// This function updates group by expressions such that select expressions that are
-/// not in group by expression, are added to the group by expressions if they are dependent
-/// of the sub-set of group by expressions.
-fn get_updated_group_by_exprs(
+/// Both queries, are functionally same. \[Because, `amount` is constant within
+/// each group of `sn`, any of its values is the value of the group. \]
+/// This function returns the columns referenced by select expressions (outside of
+/// aggregate functions) or by the HAVING clause that are not in group by expression,
+/// but are dependent of the sub-set of group by expressions. These columns are then
+/// aggregated with [`aggregate_dependent_columns`], which makes it possible to use
+/// them within expressions, e.g. `SELECT sn, amount * 2 ... GROUP BY sn`, too.
+fn get_dependent_columns(
     group_by_exprs: &[Expr],
     select_exprs: &[Expr],
+    having_expr: Option<&Expr>,
     schema: &DFSchemaRef,
 ) -> Result<Vec<Expr>> {
-    let mut new_group_by_exprs = group_by_exprs.to_vec();
+    let mut dependent_columns = vec![];
     let fields = schema.fields();
     let group_by_expr_names = group_by_exprs
         .iter()
@@ -685,18 +723,85 @@ fn get_updated_group_by_exprs(
             .iter()
             .map(|idx| fields[*idx].qualified_name())
             .collect::<Vec<_>>();
-        // Expand GROUP BY expressions with select expressions: If a GROUP
-        // BY expression is a determinant key, we can use its dependent
-        // columns in select statements also.
-        for expr in select_exprs {
+        // Find the columns referenced by select expressions and the HAVING
+        // clause: If a GROUP BY expression is a determinant key, we can use
+        // its dependent columns in these expressions also.
+        let mut referenced_columns = vec![];
+        for expr in select_exprs.iter().chain(having_expr) {
+            collect_non_aggregated_columns(expr, &mut referenced_columns)?;
+        }
+        for expr in referenced_columns {
             let expr_name = format!("{}", expr);
-            if !new_group_by_exprs.contains(expr)
+            if !group_by_exprs.contains(&expr)
                 && associated_field_names.contains(&expr_name)
             {
-                new_group_by_exprs.push(expr.clone());
+                dependent_columns.push(expr);
             }
         }
     }
 
-    Ok(new_group_by_exprs)
+    Ok(dependent_columns)
+}
+
+/// Replaces the `dependent_columns` referenced by `expr` outside of aggregate
+/// functions with their `FIRST_VALUE`, see [`get_dependent_columns`].
+fn aggregate_dependent_columns(expr: Expr, dependent_columns: &[Expr]) -> Result<Expr> {
+    struct DependentColumnRewriter<'a> {
+        dependent_columns: &'a [Expr],
+    }
+
+    impl TreeNodeRewriter for DependentColumnRewriter<'_> {
+        type N = Expr;
+
+        fn pre_visit(&mut self, expr: &Expr) -> Result<RewriteRecursion> {
+            Ok(match expr {
+                Expr::AggregateFunction(_) | Expr::AggregateUDF(_) => {
+                    RewriteRecursion::Stop
+                }
+                _ => RewriteRecursion::Continue,
+            })
+        }
+
+        fn mutate(&mut self, expr: Expr) -> Result<Expr> {
+            Ok(if self.dependent_columns.contains(&expr) {
+                first_value(expr)
+            } else {
+                expr
+            })
+        }
+    }
+
+    if dependent_columns.is_empty() {
+        return Ok(expr);
+    }
+    expr.rewrite(&mut DependentColumnRewriter { dependent_columns })
+}
+
+/// Returns the `FIRST_VALUE` aggregate of `expr`
+fn first_value(expr: Expr) -> Expr {
+    Expr::AggregateFunction(AggregateFunction::new(
+        aggregate_function::AggregateFunction::FirstValue,
+        vec![expr],
+        false,
+        None,
+        None,
+    ))
+}
+
+/// Collects the column expressions referenced by `expr` into `columns`, skipping
+/// the arguments of aggregate functions, since aggregation consumes them.
+fn collect_non_aggregated_columns(expr: &Expr, columns: &mut Vec<Expr>) -> Result<()> {
+    expr.apply(&mut |expr| {
+        Ok(match expr {
+            Expr::AggregateFunction(_) | Expr::AggregateUDF(_) => VisitRecursion::Skip,
+            Expr::Column(_) => {
+                if !columns.contains(expr) {
+                    columns.push(expr.clone());
+                }
+                VisitRecursion::Continue
+            }
+            _ => VisitRecursion::Continue,
+        })
+    })?;
+    Ok(())
 }
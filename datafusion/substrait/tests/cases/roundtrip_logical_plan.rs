@@ -486,7 +486,7 @@ async fn simple_intersect() -> Result<()> {
     assert_expected_plan(
         "SELECT COUNT(*) FROM (SELECT data.a FROM data INTERSECT SELECT data2.a FROM data2);",
         "Aggregate: groupBy=[[]], aggr=[[COUNT(UInt8(1))]]\
-         \n  LeftSemi Join: data.a = data2.a\
+         \n  LeftSemi Join: data.a IS NOT DISTINCT FROM data2.a\
          \n    Aggregate: groupBy=[[data.a]], aggr=[[]]\
          \n      TableScan: data projection=[a]\
          \n    TableScan: data2 projection=[a]",
@@ -499,7 +499,7 @@ async fn simple_intersect_table_reuse() -> Result<()> {
     assert_expected_plan(
         "SELECT COUNT(*) FROM (SELECT data.a FROM data INTERSECT SELECT data.a FROM data);",
         "Aggregate: groupBy=[[]], aggr=[[COUNT(UInt8(1))]]\
-         \n  LeftSemi Join: data.a = data.a\
+         \n  LeftSemi Join: data.a IS NOT DISTINCT FROM data.a\
          \n    Aggregate: groupBy=[[data.a]], aggr=[[]]\
          \n      TableScan: data projection=[a]\
          \n    TableScan: data projection=[a]",